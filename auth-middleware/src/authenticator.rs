@@ -0,0 +1,114 @@
+use std::sync::Arc;
+use std::time::Duration;
+use async_trait::async_trait;
+use http::{header, HeaderMap};
+use crate::config::{AuthConfig, TokenValidation};
+use crate::error::AuthError;
+use crate::introspection::IntrospectionValidator;
+use crate::jwks::JwksValidator;
+use crate::principal::AuthenticatedPrincipal;
+
+/// Turns a credential into the principal it belongs to.
+#[async_trait]
+pub trait TokenValidator: Send + Sync {
+    async fn validate(&self, token: &str) -> Result<AuthenticatedPrincipal, AuthError>;
+
+    /// Checks once at startup that tokens can be validated at all, so a
+    /// misconfiguration stops the service instead of failing every request.
+    async fn check(&self) -> Result<(), AuthError> {
+        Ok(())
+    }
+}
+
+enum Credentials<'a> {
+    Bearer(&'a str),
+    ApiKey(&'a str),
+}
+
+/// Authenticates requests by their `Authorization` header:
+/// `Bearer <access token>` or `ApiKey <key>`.
+pub struct Authenticator {
+    bearer: Arc<dyn TokenValidator>,
+    api_key: Option<Arc<dyn TokenValidator>>,
+}
+
+impl Authenticator {
+    pub fn new(bearer: Arc<dyn TokenValidator>) -> Self {
+        Self { bearer, api_key: None }
+    }
+
+    /// Accepts `ApiKey` credentials, checked by `validator`.
+    pub fn with_api_keys(mut self, validator: Arc<dyn TokenValidator>) -> Self {
+        self.api_key = Some(validator);
+        self
+    }
+
+    /// API keys are accepted whenever an introspection client is configured,
+    /// since they can only be checked by auth-nz-service.
+    pub fn from_config(config: &AuthConfig) -> Result<Self, AuthError> {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(config.request_timeout_seconds))
+            .build()
+            .map_err(|e| AuthError::Unavailable(e.to_string()))?;
+
+        let introspection: Option<Arc<dyn TokenValidator>> = match (&config.client_id, &config.client_secret) {
+            (Some(client_id), Some(client_secret)) => Some(Arc::new(IntrospectionValidator::new(
+                client.clone(),
+                &config.service_url,
+                client_id.clone(),
+                client_secret.clone(),
+                Duration::from_secs(config.cache_ttl_seconds),
+            ))),
+            _ => None,
+        };
+
+        let bearer: Arc<dyn TokenValidator> = match (config.validation, &introspection) {
+            (TokenValidation::Introspection, Some(introspection)) => Arc::clone(introspection),
+            (TokenValidation::Introspection, None) => {
+                return Err(AuthError::Unavailable("Introspection requires client credentials".to_string()));
+            }
+            (TokenValidation::Jwks, _) => Arc::new(JwksValidator::new(
+                client,
+                &config.service_url,
+                config.issuer.clone(),
+                Duration::from_secs(config.jwks_refresh_seconds),
+            )),
+        };
+
+        Ok(Self { bearer, api_key: introspection })
+    }
+
+    /// See [`TokenValidator::check`]; call it before serving requests.
+    pub async fn check(&self) -> Result<(), AuthError> {
+        self.bearer.check().await
+    }
+
+    /// The principal behind the request's credentials, or `None` if it has none.
+    pub async fn authenticate(&self, headers: &HeaderMap) -> Result<Option<AuthenticatedPrincipal>, AuthError> {
+        let principal = match credentials(headers)? {
+            None => return Ok(None),
+            Some(Credentials::Bearer(token)) => self.bearer.validate(token).await?,
+            Some(Credentials::ApiKey(key)) => match &self.api_key {
+                Some(validator) => validator.validate(key).await?,
+                None => return Err(AuthError::InvalidToken("API keys are not accepted".to_string())),
+            },
+        };
+        Ok(Some(principal))
+    }
+}
+
+fn credentials(headers: &HeaderMap) -> Result<Option<Credentials<'_>>, AuthError> {
+    let Some(value) = headers.get(header::AUTHORIZATION) else {
+        return Ok(None);
+    };
+    let value = value.to_str()
+        .map_err(|_| AuthError::InvalidToken("Malformed authorization header".to_string()))?;
+
+    if let Some(token) = value.strip_prefix("Bearer ") {
+        Ok(Some(Credentials::Bearer(token.trim())))
+    } else if let Some(key) = value.strip_prefix("ApiKey ") {
+        Ok(Some(Credentials::ApiKey(key.trim())))
+    } else {
+        Err(AuthError::InvalidToken("Unsupported authorization scheme".to_string()))
+    }
+}
@@ -0,0 +1,42 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Small in-memory cache whose entries expire individually. Expired entries
+/// are dropped once the cache fills up.
+pub(crate) struct TtlCache<V> {
+    entries: Mutex<HashMap<String, (V, Instant)>>,
+    max_entries: usize,
+}
+
+impl<V: Clone> TtlCache<V> {
+    pub(crate) fn new(max_entries: usize) -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+            max_entries,
+        }
+    }
+
+    pub(crate) fn get(&self, key: &str) -> Option<V> {
+        let entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.get(key)
+            .filter(|(_, expires_at)| *expires_at > Instant::now())
+            .map(|(value, _)| value.clone())
+    }
+
+    pub(crate) fn insert(&self, key: String, value: V, ttl: Duration) {
+        if ttl.is_zero() {
+            return;
+        }
+        let now = Instant::now();
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        if entries.len() >= self.max_entries {
+            entries.retain(|_, (_, expires_at)| *expires_at > now);
+            // Still full of live entries: start over rather than grow without bound
+            if entries.len() >= self.max_entries {
+                entries.clear();
+            }
+        }
+        entries.insert(key, (value, now + ttl));
+    }
+}
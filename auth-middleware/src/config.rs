@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+
+/// How bearer access tokens are checked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TokenValidation {
+    /// Verify the JWT signature locally against the keys auth-nz-service
+    /// publishes. Fast and works offline, but a revoked token stays valid
+    /// until it expires. Needs asymmetric signing keys on auth-nz-service.
+    Jwks,
+    /// Ask auth-nz-service about each token (cached for `cache_ttl_seconds`),
+    /// which also sees revocations. Needs a confidential OAuth client.
+    Introspection,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthConfig {
+    /// Expected `iss` of access tokens.
+    pub issuer: String,
+    /// Base URL auth-nz-service is reachable at from this service.
+    pub service_url: String,
+    pub validation: TokenValidation,
+    /// OAuth client used for introspection. Without it, API keys are rejected.
+    pub client_id: Option<String>,
+    pub client_secret: Option<String>,
+    pub cache_ttl_seconds: u64,
+    pub jwks_refresh_seconds: u64,
+    pub request_timeout_seconds: u64,
+}
+
+impl AuthConfig {
+    pub fn load() -> Result<Self, Box<dyn std::error::Error>> {
+        let issuer = std::env::var("AUTH_ISSUER")
+            .unwrap_or_else(|_| "http://localhost:3001".to_string());
+        let service_url = std::env::var("AUTH_SERVICE_URL")
+            .unwrap_or_else(|_| issuer.clone())
+            .trim_end_matches('/')
+            .to_string();
+
+        let validation = match std::env::var("AUTH_TOKEN_VALIDATION").as_deref() {
+            Ok("introspection") => TokenValidation::Introspection,
+            Ok("jwks") | Err(_) => TokenValidation::Jwks,
+            Ok(other) => return Err(format!("Unknown AUTH_TOKEN_VALIDATION: {}", other).into()),
+        };
+
+        let client_id = std::env::var("AUTH_INTROSPECTION_CLIENT_ID").ok().filter(|v| !v.is_empty());
+        let client_secret = std::env::var("AUTH_INTROSPECTION_CLIENT_SECRET").ok().filter(|v| !v.is_empty());
+        if validation == TokenValidation::Introspection && (client_id.is_none() || client_secret.is_none()) {
+            return Err("AUTH_INTROSPECTION_CLIENT_ID and AUTH_INTROSPECTION_CLIENT_SECRET are required for introspection".into());
+        }
+
+        Ok(Self {
+            issuer,
+            service_url,
+            validation,
+            client_id,
+            client_secret,
+            cache_ttl_seconds: std::env::var("AUTH_CACHE_TTL_SECONDS")
+                .unwrap_or_else(|_| "60".to_string())
+                .parse()
+                .unwrap_or(60),
+            jwks_refresh_seconds: std::env::var("AUTH_JWKS_REFRESH_SECONDS")
+                .unwrap_or_else(|_| "300".to_string())
+                .parse()
+                .unwrap_or(300),
+            request_timeout_seconds: 5,
+        })
+    }
+
+    pub fn introspection_enabled(&self) -> bool {
+        self.client_id.is_some() && self.client_secret.is_some()
+    }
+}
@@ -0,0 +1,65 @@
+use http::{header, HeaderValue, Response, StatusCode};
+use thiserror::Error;
+
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum AuthError {
+    #[error("Missing credentials")]
+    MissingCredentials,
+
+    #[error("Invalid token: {0}")]
+    InvalidToken(String),
+
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
+
+    #[error("Authentication service unavailable: {0}")]
+    Unavailable(String),
+
+    /// Tokens cannot be validated with the current configuration at all.
+    #[error("Authentication is misconfigured: {0}")]
+    Misconfigured(String),
+}
+
+impl AuthError {
+    pub fn status(&self) -> StatusCode {
+        match self {
+            AuthError::MissingCredentials | AuthError::InvalidToken(_) => StatusCode::UNAUTHORIZED,
+            AuthError::Forbidden(_) => StatusCode::FORBIDDEN,
+            AuthError::Unavailable(_) => StatusCode::SERVICE_UNAVAILABLE,
+            AuthError::Misconfigured(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    /// Plain-text response in the style of the services' own errors, with a
+    /// `WWW-Authenticate` challenge on 401s (RFC 6750).
+    pub fn to_response<B: From<String>>(&self) -> Response<B> {
+        let body = match self {
+            AuthError::Unavailable(_) => {
+                tracing::error!("{}", self);
+                "Authentication service unavailable".to_string()
+            }
+            AuthError::Misconfigured(_) => {
+                tracing::error!("{}", self);
+                "Internal server error".to_string()
+            }
+            _ => self.to_string(),
+        };
+
+        let mut response = Response::new(B::from(body));
+        *response.status_mut() = self.status();
+        response.headers_mut().insert(header::CONTENT_TYPE, HeaderValue::from_static("text/plain; charset=utf-8"));
+        match self {
+            AuthError::MissingCredentials => {
+                response.headers_mut().insert(header::WWW_AUTHENTICATE, HeaderValue::from_static("Bearer"));
+            }
+            AuthError::InvalidToken(_) => {
+                response.headers_mut().insert(
+                    header::WWW_AUTHENTICATE,
+                    HeaderValue::from_static("Bearer error=\"invalid_token\""),
+                );
+            }
+            _ => {}
+        }
+        response
+    }
+}
@@ -0,0 +1,27 @@
+use axum::async_trait;
+use axum::body::Body;
+use axum::extract::FromRequestParts;
+use axum::http::request::Parts;
+use axum::response::{IntoResponse, Response};
+use crate::error::AuthError;
+use crate::principal::AuthenticatedPrincipal;
+
+/// The caller `AuthLayer` authenticated. Rejects with 401 when the request
+/// came without credentials; use `Option<AuthenticatedPrincipal>` on routes
+/// open to anonymous callers.
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for AuthenticatedPrincipal {
+    type Rejection = AuthError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        parts.extensions.get::<AuthenticatedPrincipal>()
+            .cloned()
+            .ok_or(AuthError::MissingCredentials)
+    }
+}
+
+impl IntoResponse for AuthError {
+    fn into_response(self) -> Response {
+        self.to_response::<Body>()
+    }
+}
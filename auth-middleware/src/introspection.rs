@@ -0,0 +1,136 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use async_trait::async_trait;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use crate::authenticator::TokenValidator;
+use crate::cache::TtlCache;
+use crate::error::AuthError;
+use crate::principal::{AuthenticatedPrincipal, PrincipalKind};
+
+const MAX_CACHED_TOKENS: usize = 10_000;
+
+/// RFC 7662 response of auth-nz-service's `/introspect`.
+#[derive(Debug, Deserialize)]
+struct IntrospectionResponse {
+    active: bool,
+    #[serde(default)]
+    scope: Option<String>,
+    #[serde(default)]
+    client_id: Option<String>,
+    #[serde(default)]
+    token_type: Option<String>,
+    #[serde(default)]
+    exp: Option<i64>,
+    #[serde(default)]
+    sub: Option<String>,
+    #[serde(default)]
+    roles: Vec<String>,
+    #[serde(default)]
+    org_id: Option<String>,
+}
+
+/// Checks access tokens and API keys with auth-nz-service, which also knows
+/// about revocations. Answers, including rejections, are cached for up to
+/// `cache_ttl`, which bounds how long a revoked token keeps working.
+pub struct IntrospectionValidator {
+    client: reqwest::Client,
+    introspection_url: String,
+    client_id: String,
+    client_secret: String,
+    cache_ttl: Duration,
+    cache: TtlCache<Option<AuthenticatedPrincipal>>,
+}
+
+impl IntrospectionValidator {
+    pub fn new(
+        client: reqwest::Client,
+        service_url: &str,
+        client_id: String,
+        client_secret: String,
+        cache_ttl: Duration,
+    ) -> Self {
+        Self {
+            client,
+            introspection_url: format!("{}/introspect", service_url.trim_end_matches('/')),
+            client_id,
+            client_secret,
+            cache_ttl,
+            cache: TtlCache::new(MAX_CACHED_TOKENS),
+        }
+    }
+
+    async fn introspect(&self, token: &str) -> Result<Option<AuthenticatedPrincipal>, AuthError> {
+        let response: IntrospectionResponse = self.client.post(&self.introspection_url)
+            .form(&[
+                ("token", token),
+                ("client_id", self.client_id.as_str()),
+                ("client_secret", self.client_secret.as_str()),
+            ])
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| AuthError::Unavailable(format!("Introspection request failed: {}", e)))?
+            .json()
+            .await
+            .map_err(|e| AuthError::Unavailable(format!("Invalid introspection response: {}", e)))?;
+
+        if !response.active {
+            return Ok(None);
+        }
+
+        let kind = match (response.token_type.as_deref(), &response.client_id) {
+            (Some("api_key"), _) => PrincipalKind::ServiceAccount,
+            (Some("Bearer"), Some(client_id)) if response.sub.as_ref() == Some(client_id) => PrincipalKind::Client,
+            (Some("Bearer"), _) => PrincipalKind::User,
+            // Refresh tokens are active too, but are no credential for an API
+            _ => return Ok(None),
+        };
+        let Some(user_id) = response.sub else {
+            return Ok(None);
+        };
+
+        Ok(Some(AuthenticatedPrincipal {
+            user_id,
+            kind,
+            roles: response.roles,
+            scopes: response.scope
+                .map(|scope| scope.split_whitespace().map(str::to_string).collect())
+                .unwrap_or_default(),
+            client_id: response.client_id,
+            org_id: response.org_id,
+            expires_at: response.exp,
+        }))
+    }
+}
+
+#[async_trait]
+impl TokenValidator for IntrospectionValidator {
+    async fn validate(&self, token: &str) -> Result<AuthenticatedPrincipal, AuthError> {
+        // Keyed by hash so the cache holds no usable credentials
+        let key = hex::encode(Sha256::digest(token.as_bytes()));
+
+        let principal = match self.cache.get(&key) {
+            Some(principal) => principal,
+            None => {
+                let principal = self.introspect(token).await?;
+                let ttl = match principal.as_ref().and_then(|p| p.expires_at) {
+                    Some(expires_at) => self.cache_ttl.min(Duration::from_secs(
+                        u64::try_from(expires_at - unix_now()).unwrap_or(0),
+                    )),
+                    None => self.cache_ttl,
+                };
+                self.cache.insert(key, principal.clone(), ttl);
+                principal
+            }
+        };
+
+        principal.ok_or_else(|| AuthError::InvalidToken("Token is not active".to_string()))
+    }
+}
+
+fn unix_now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
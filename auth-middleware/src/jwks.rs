@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use async_trait::async_trait;
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{decode, decode_header, Algorithm, DecodingKey, Validation};
+use serde::Deserialize;
+use tokio::sync::RwLock;
+use crate::authenticator::TokenValidator;
+use crate::error::AuthError;
+use crate::principal::{AuthenticatedPrincipal, PrincipalKind};
+
+/// Floor on refetching the key set for an unknown `kid`, so tokens with made
+/// up key ids cannot hammer auth-nz-service.
+const MIN_REFRESH_INTERVAL: Duration = Duration::from_secs(10);
+
+/// The claims of an auth-nz-service access token this crate relies on.
+#[derive(Debug, Deserialize)]
+struct AccessTokenClaims {
+    sub: String,
+    /// Absent from client credentials tokens, which have no user session.
+    #[serde(default)]
+    sid: Option<String>,
+    #[serde(default)]
+    roles: Vec<String>,
+    #[serde(default)]
+    permissions: Vec<String>,
+    #[serde(default)]
+    client_id: Option<String>,
+    #[serde(default)]
+    org_id: Option<String>,
+    exp: i64,
+}
+
+#[derive(Default)]
+struct KeySet {
+    keys: HashMap<String, DecodingKey>,
+    fetched_at: Option<Instant>,
+}
+
+/// Verifies access tokens locally against auth-nz-service's published keys.
+/// Revoked tokens are accepted until they expire.
+pub struct JwksValidator {
+    client: reqwest::Client,
+    jwks_url: String,
+    issuer: String,
+    refresh_interval: Duration,
+    key_set: RwLock<KeySet>,
+}
+
+impl JwksValidator {
+    pub fn new(client: reqwest::Client, service_url: &str, issuer: String, refresh_interval: Duration) -> Self {
+        Self {
+            client,
+            jwks_url: format!("{}/.well-known/jwks.json", service_url.trim_end_matches('/')),
+            issuer,
+            refresh_interval,
+            key_set: RwLock::new(KeySet::default()),
+        }
+    }
+
+    async fn decoding_key(&self, kid: &str) -> Result<DecodingKey, AuthError> {
+        {
+            let key_set = self.key_set.read().await;
+            let fresh = key_set.fetched_at.is_some_and(|at| at.elapsed() < self.refresh_interval);
+            if let (Some(key), true) = (key_set.keys.get(kid), fresh) {
+                return Ok(key.clone());
+            }
+        }
+
+        let mut key_set = self.key_set.write().await;
+        // Another request may have refreshed while this one waited for the lock
+        let recently_fetched = key_set.fetched_at.is_some_and(|at| at.elapsed() < MIN_REFRESH_INTERVAL);
+        if !recently_fetched {
+            match self.fetch().await {
+                Ok(keys) => {
+                    *key_set = KeySet { keys, fetched_at: Some(Instant::now()) };
+                }
+                // Keep using the keys we have; only fail if they do not cover this token
+                Err(e) if key_set.keys.contains_key(kid) => {
+                    tracing::warn!("Failed to refresh JWKS, using cached keys: {}", e);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        key_set.keys.get(kid)
+            .cloned()
+            .ok_or_else(|| AuthError::InvalidToken("Unknown signing key".to_string()))
+    }
+
+    async fn fetch(&self) -> Result<HashMap<String, DecodingKey>, AuthError> {
+        let jwks: JwkSet = self.client.get(&self.jwks_url)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| AuthError::Unavailable(format!("JWKS request failed: {}", e)))?
+            .json()
+            .await
+            .map_err(|e| AuthError::Unavailable(format!("Invalid JWKS: {}", e)))?;
+
+        let mut keys = HashMap::new();
+        for jwk in &jwks.keys {
+            let Some(kid) = jwk.common.key_id.clone() else {
+                continue;
+            };
+            match DecodingKey::from_jwk(jwk) {
+                Ok(key) => {
+                    keys.insert(kid, key);
+                }
+                Err(e) => tracing::warn!("Skipping unusable JWK {}: {}", kid, e),
+            }
+        }
+        Ok(keys)
+    }
+}
+
+#[async_trait]
+impl TokenValidator for JwksValidator {
+    async fn validate(&self, token: &str) -> Result<AuthenticatedPrincipal, AuthError> {
+        let header = decode_header(token)
+            .map_err(|e| AuthError::InvalidToken(e.to_string()))?;
+        // Symmetric keys are never published, and accepting HS* here would let
+        // anyone sign tokens with a public key as the secret
+        if matches!(header.alg, Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512) {
+            return Err(AuthError::InvalidToken("Unsupported signing algorithm".to_string()));
+        }
+        let kid = header.kid
+            .ok_or_else(|| AuthError::InvalidToken("Token has no key id".to_string()))?;
+
+        let key = self.decoding_key(&kid).await?;
+
+        let mut validation = Validation::new(header.alg);
+        validation.set_issuer(&[&self.issuer]);
+        validation.validate_aud = false;
+
+        let claims = decode::<AccessTokenClaims>(token, &key, &validation)
+            .map_err(|e| AuthError::InvalidToken(e.to_string()))?
+            .claims;
+
+        let kind = if claims.sid.is_some() { PrincipalKind::User } else { PrincipalKind::Client };
+        Ok(AuthenticatedPrincipal {
+            user_id: claims.sub,
+            kind,
+            roles: claims.roles,
+            scopes: claims.permissions,
+            client_id: claims.client_id,
+            org_id: claims.org_id,
+            expires_at: Some(claims.exp),
+        })
+    }
+
+    /// auth-nz-service signing with an HS* secret publishes an empty key set,
+    /// and every token would then be rejected. That is a configuration error,
+    /// reported here rather than as a 401 on each request. An unreachable
+    /// auth-nz-service is only logged, as it may simply not be up yet.
+    async fn check(&self) -> Result<(), AuthError> {
+        match self.fetch().await {
+            Ok(keys) if keys.is_empty() => Err(AuthError::Misconfigured(format!(
+                "{} has no usable signing keys; sign tokens with RS256 or EdDSA on auth-nz-service \
+                 (JWT_ALGORITHM, JWT_KEYS_DIR) or set AUTH_TOKEN_VALIDATION=introspection",
+                self.jwks_url
+            ))),
+            Ok(keys) => {
+                *self.key_set.write().await = KeySet { keys, fetched_at: Some(Instant::now()) };
+                Ok(())
+            }
+            Err(e) => {
+                tracing::warn!("Could not check the JWKS at startup, will retry on first use: {}", e);
+                Ok(())
+            }
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use http::{Request, Response};
+use tower::{Layer, Service};
+use crate::authenticator::Authenticator;
+use crate::error::AuthError;
+use crate::principal::AuthenticatedPrincipal;
+
+type BoxFuture<T> = Pin<Box<dyn Future<Output = T> + Send + 'static>>;
+
+/// Authenticates every request and stores the `AuthenticatedPrincipal` in its
+/// extensions. Requests without credentials go through as they are; requests
+/// with credentials that do not check out are rejected.
+#[derive(Clone)]
+pub struct AuthLayer {
+    authenticator: Arc<Authenticator>,
+}
+
+impl AuthLayer {
+    pub fn new(authenticator: Arc<Authenticator>) -> Self {
+        Self { authenticator }
+    }
+}
+
+impl<S> Layer<S> for AuthLayer {
+    type Service = AuthService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        AuthService {
+            inner,
+            authenticator: Arc::clone(&self.authenticator),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct AuthService<S> {
+    inner: S,
+    authenticator: Arc<Authenticator>,
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for AuthService<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    ReqBody: Send + 'static,
+    ResBody: From<String>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: Request<ReqBody>) -> Self::Future {
+        // The clone may not be ready; keep the service that was polled
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let authenticator = Arc::clone(&self.authenticator);
+
+        Box::pin(async move {
+            match authenticator.authenticate(request.headers()).await {
+                Ok(Some(principal)) => {
+                    request.extensions_mut().insert(principal);
+                }
+                Ok(None) => {}
+                Err(e) => return Ok(e.to_response()),
+            }
+            inner.call(request).await
+        })
+    }
+}
+
+/// Route layer that lets a request through only if `AuthLayer` found a
+/// principal holding `resource:action`. Answers 401 without credentials and
+/// 403 without the permission.
+#[derive(Clone)]
+pub struct RequirePermission {
+    resource: Arc<str>,
+    action: Arc<str>,
+}
+
+impl RequirePermission {
+    pub fn new(resource: &str, action: &str) -> Self {
+        Self {
+            resource: Arc::from(resource),
+            action: Arc::from(action),
+        }
+    }
+}
+
+impl<S> Layer<S> for RequirePermission {
+    type Service = RequirePermissionService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RequirePermissionService {
+            inner,
+            resource: Arc::clone(&self.resource),
+            action: Arc::clone(&self.action),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct RequirePermissionService<S> {
+    inner: S,
+    resource: Arc<str>,
+    action: Arc<str>,
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for RequirePermissionService<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>>,
+    S::Future: Send + 'static,
+    ResBody: From<String> + Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<ReqBody>) -> Self::Future {
+        let allowed = match request.extensions().get::<AuthenticatedPrincipal>() {
+            Some(principal) => principal.require_permission(&self.resource, &self.action),
+            None => Err(AuthError::MissingCredentials),
+        };
+
+        match allowed {
+            Ok(()) => Box::pin(self.inner.call(request)),
+            Err(e) => {
+                let response = e.to_response();
+                Box::pin(async move { Ok(response) })
+            }
+        }
+    }
+}
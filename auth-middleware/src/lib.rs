@@ -0,0 +1,34 @@
+//! Authentication for services behind auth-nz-service.
+//!
+//! `AuthLayer` validates the `Authorization` header of every request, either
+//! a `Bearer` access token or an `ApiKey`, and stores the resulting
+//! `AuthenticatedPrincipal` in the request extensions. Requests without
+//! credentials pass through unauthenticated, so public routes keep working;
+//! routes that need a caller either extract `AuthenticatedPrincipal` or are
+//! wrapped in `RequirePermission`.
+//!
+//! ```ignore
+//! let router = Router::new()
+//!     .route("/products", post(create_product).route_layer(RequirePermission::new("catalog", "write")))
+//!     .route("/products/:id", get(get_product))
+//!     .layer(AuthLayer::new(authenticator));
+//! ```
+
+mod authenticator;
+mod cache;
+mod config;
+mod error;
+#[cfg(feature = "axum")]
+mod extractor;
+mod introspection;
+mod jwks;
+mod layer;
+mod principal;
+
+pub use authenticator::{Authenticator, TokenValidator};
+pub use config::{AuthConfig, TokenValidation};
+pub use error::AuthError;
+pub use introspection::IntrospectionValidator;
+pub use jwks::JwksValidator;
+pub use layer::{AuthLayer, AuthService, RequirePermission, RequirePermissionService};
+pub use principal::{AuthenticatedPrincipal, PrincipalKind};
@@ -0,0 +1,57 @@
+use crate::error::AuthError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrincipalKind {
+    /// A person signed in to auth-nz-service, possibly through an OAuth client.
+    User,
+    /// A machine caller authenticated with an API key.
+    ServiceAccount,
+    /// An OAuth client acting on its own (client credentials grant).
+    Client,
+}
+
+/// The caller of a request, as vouched for by auth-nz-service.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthenticatedPrincipal {
+    /// The user id; the service account id for API keys and the client id
+    /// for client credentials tokens.
+    pub user_id: String,
+    pub kind: PrincipalKind,
+    pub roles: Vec<String>,
+    /// `resource:action` permissions the caller may use. For tokens issued to
+    /// an OAuth client and for API keys these are already narrowed to the
+    /// granted scopes.
+    pub scopes: Vec<String>,
+    /// The OAuth client the token was issued to, if any.
+    pub client_id: Option<String>,
+    /// The organization the token is scoped to. Roles and scopes then hold
+    /// within that organization only.
+    pub org_id: Option<String>,
+    /// Unix time at which the credentials stop being valid, if they expire.
+    pub expires_at: Option<i64>,
+}
+
+impl AuthenticatedPrincipal {
+    pub fn has_role(&self, role: &str) -> bool {
+        self.roles.iter().any(|r| r == role)
+    }
+
+    /// Whether any scope covers `action` on `resource`; `*` in a scope matches
+    /// any resource or action, as in auth-nz-service.
+    pub fn has_permission(&self, resource: &str, action: &str) -> bool {
+        self.scopes.iter().any(|scope| match scope.split_once(':') {
+            Some((scope_resource, scope_action)) => {
+                (scope_resource == "*" || scope_resource == resource) && (scope_action == "*" || scope_action == action)
+            }
+            None => false,
+        })
+    }
+
+    pub fn require_permission(&self, resource: &str, action: &str) -> Result<(), AuthError> {
+        if self.has_permission(resource, action) {
+            Ok(())
+        } else {
+            Err(AuthError::Forbidden(format!("Missing permission {}:{}", resource, action)))
+        }
+    }
+}
@@ -0,0 +1,15 @@
+-- The admin API requires the admin:* permission; grant this role to the first
+-- operator directly in the database.
+INSERT INTO roles (id, name)
+VALUES ('00000000-0000-0000-0000-000000000001', 'admin')
+ON CONFLICT (name) DO NOTHING;
+
+INSERT INTO permissions (id, name, resource, action)
+VALUES ('00000000-0000-0000-0000-000000000001', 'admin:all', 'admin', '*')
+ON CONFLICT (name) DO NOTHING;
+
+INSERT INTO role_permissions (role_id, permission_id)
+SELECT r.id, p.id
+FROM roles r, permissions p
+WHERE r.name = 'admin' AND p.name = 'admin:all'
+ON CONFLICT DO NOTHING;
@@ -0,0 +1,64 @@
+CREATE TABLE IF NOT EXISTS organizations (
+    id UUID PRIMARY KEY,
+    name TEXT NOT NULL UNIQUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE IF NOT EXISTS organization_members (
+    organization_id UUID NOT NULL REFERENCES organizations (id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    joined_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (organization_id, user_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_organization_members_user_id ON organization_members (user_id);
+
+-- Roles held within one organization; they go away with the membership
+CREATE TABLE IF NOT EXISTS organization_member_roles (
+    organization_id UUID NOT NULL,
+    user_id UUID NOT NULL,
+    role_id UUID NOT NULL REFERENCES roles (id) ON DELETE CASCADE,
+    PRIMARY KEY (organization_id, user_id, role_id),
+    FOREIGN KEY (organization_id, user_id) REFERENCES organization_members (organization_id, user_id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_organization_member_roles_role_id ON organization_member_roles (role_id);
+
+-- Only a hash of the token in the emailed link is stored
+CREATE TABLE IF NOT EXISTS organization_invitations (
+    id UUID PRIMARY KEY,
+    organization_id UUID NOT NULL REFERENCES organizations (id) ON DELETE CASCADE,
+    email TEXT NOT NULL,
+    role_id UUID REFERENCES roles (id) ON DELETE SET NULL,
+    token_hash TEXT NOT NULL UNIQUE,
+    invited_by UUID NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    expires_at TIMESTAMPTZ NOT NULL,
+    accepted_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_organization_invitations_organization_id ON organization_invitations (organization_id);
+
+-- Sessions opened within an organization carry it into every refreshed token
+ALTER TABLE sessions ADD COLUMN IF NOT EXISTS organization_id UUID REFERENCES organizations (id) ON DELETE CASCADE;
+
+-- Roles for managing an organization from within it. The founder of an
+-- organization gets org-admin there.
+INSERT INTO roles (id, name)
+VALUES
+    ('00000000-0000-0000-0000-000000000002', 'org-admin'),
+    ('00000000-0000-0000-0000-000000000003', 'org-viewer')
+ON CONFLICT (name) DO NOTHING;
+
+INSERT INTO permissions (id, name, resource, action)
+VALUES
+    ('00000000-0000-0000-0000-000000000002', 'organization:all', 'organization', '*'),
+    ('00000000-0000-0000-0000-000000000003', 'organization:read', 'organization', 'read')
+ON CONFLICT (name) DO NOTHING;
+
+INSERT INTO role_permissions (role_id, permission_id)
+SELECT r.id, p.id
+FROM roles r, permissions p
+WHERE (r.name = 'org-admin' AND p.name = 'organization:all')
+   OR (r.name = 'org-viewer' AND p.name = 'organization:read')
+ON CONFLICT DO NOTHING;
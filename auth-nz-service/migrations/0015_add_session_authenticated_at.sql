@@ -0,0 +1,11 @@
+ALTER TABLE sessions ADD COLUMN IF NOT EXISTS authenticated_at TIMESTAMPTZ;
+
+UPDATE sessions s
+SET authenticated_at = COALESCE(
+    (SELECT root.created_at FROM sessions root WHERE root.id = s.family_id),
+    s.created_at
+)
+WHERE authenticated_at IS NULL;
+
+ALTER TABLE sessions ALTER COLUMN authenticated_at SET NOT NULL;
+ALTER TABLE sessions ALTER COLUMN authenticated_at SET DEFAULT NOW();
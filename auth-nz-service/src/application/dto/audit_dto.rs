@@ -0,0 +1,116 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::domain::entities::audit_event::AuditEvent;
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::AuditEventQuery;
+use crate::domain::services::ChainVerification;
+use crate::domain::value_objects::{AuditEventType, UserId};
+
+/// Filters for reading the audit log. `from` and `to` are RFC 3339 times and
+/// both inclusive.
+#[derive(Debug, Default, Deserialize)]
+pub struct AuditEventFilterDto {
+    pub user_id: Option<String>,
+    pub actor: Option<String>,
+    pub event_type: Option<String>,
+    pub from: Option<String>,
+    pub to: Option<String>,
+}
+
+impl AuditEventFilterDto {
+    /// The filters as a query, without paging.
+    pub fn to_query(&self) -> Result<AuditEventQuery, DomainError> {
+        let user_id = match &self.user_id {
+            Some(id) => Some(UserId::from_uuid(uuid::Uuid::parse_str(id)
+                .map_err(|_| DomainError::ValidationError("Invalid user ID format".to_string()))?)),
+            None => None,
+        };
+
+        Ok(AuditEventQuery {
+            user_id,
+            actor: self.actor.clone(),
+            event_type: self.event_type.as_deref().map(AuditEventType::parse).transpose()?,
+            from: self.from.as_deref().map(|from| parse_time("from", from)).transpose()?,
+            to: self.to.as_deref().map(|to| parse_time("to", to)).transpose()?,
+            ..Default::default()
+        })
+    }
+}
+
+fn parse_time(name: &str, value: &str) -> Result<DateTime<Utc>, DomainError> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|time| time.with_timezone(&Utc))
+        .map_err(|_| DomainError::ValidationError(format!("{} must be an RFC 3339 time", name)))
+}
+
+/// One page of `GET /admin/audit-events`, newest first.
+#[derive(Debug, Deserialize)]
+pub struct AuditEventPageDto {
+    #[serde(flatten)]
+    pub filter: AuditEventFilterDto,
+    /// Continue below this sequence, i.e. `next_before` of the previous page.
+    pub before: Option<i64>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AuditEventListDto {
+    pub events: Vec<AuditEventDto>,
+    /// Pass as `before` to fetch the next page; absent on the last page.
+    pub next_before: Option<i64>,
+}
+
+/// An audit record with its chain fields, so an exported log can be checked
+/// without access to the service.
+#[derive(Debug, Serialize)]
+pub struct AuditEventDto {
+    pub sequence: i64,
+    pub event_type: String,
+    pub actor: Option<String>,
+    pub user_id: Option<String>,
+    pub ip_address: Option<String>,
+    pub details: Value,
+    pub occurred_at: String,
+    pub prev_hash: String,
+    pub hash: String,
+}
+
+impl From<&AuditEvent> for AuditEventDto {
+    fn from(event: &AuditEvent) -> Self {
+        Self {
+            sequence: event.sequence,
+            event_type: event.event_type.clone(),
+            actor: event.actor.clone(),
+            user_id: event.user_id.map(|id| id.as_uuid().to_string()),
+            ip_address: event.ip_address.clone(),
+            details: event.details.clone(),
+            // Same precision the hash was computed over
+            occurred_at: event.occurred_at.to_rfc3339_opts(SecondsFormat::Micros, true),
+            prev_hash: event.prev_hash.clone(),
+            hash: event.hash.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct AuditChainStatusDto {
+    pub valid: bool,
+    pub events_checked: u64,
+    pub first_invalid_sequence: Option<i64>,
+    /// Last intact record. Keep a copy to detect records later cut off the end.
+    pub head_sequence: i64,
+    pub head_hash: String,
+}
+
+impl From<ChainVerification> for AuditChainStatusDto {
+    fn from(verification: ChainVerification) -> Self {
+        Self {
+            valid: verification.valid,
+            events_checked: verification.events_checked,
+            first_invalid_sequence: verification.first_invalid_sequence,
+            head_sequence: verification.head_sequence,
+            head_hash: verification.head_hash,
+        }
+    }
+}
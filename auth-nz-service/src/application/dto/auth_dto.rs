@@ -1,4 +1,6 @@
 use serde::{Deserialize, Serialize};
+use crate::application::dto::MfaChallengeDto;
+use crate::domain::errors::FieldError;
 
 #[derive(Debug, Deserialize)]
 pub struct RegisterDto {
@@ -12,9 +14,33 @@ pub struct LoginDto {
     pub password: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct RefreshSessionDto {
+    pub refresh_token: String,
+}
+
 #[derive(Debug, Serialize)]
 pub struct AuthResponseDto {
     pub token: String,
     pub expires_at: String,
+    pub refresh_token: String,
+    pub refresh_expires_at: String,
 }
 
+
+/// A password login either opens a session or, for users with MFA enabled,
+/// returns a challenge to complete through `/login/mfa`.
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum LoginResponseDto {
+    Session(AuthResponseDto),
+    MfaRequired(MfaChallengeDto),
+}
+
+/// Body of a 400 response listing each invalid field, so clients can show
+/// the messages next to the inputs they belong to.
+#[derive(Debug, Serialize)]
+pub struct FieldErrorsDto {
+    pub error: String,
+    pub errors: Vec<FieldError>,
+}
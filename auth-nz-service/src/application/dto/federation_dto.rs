@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+use crate::domain::entities::federated_identity::FederatedIdentity;
+use crate::domain::entities::identity_provider::IdentityProvider;
+
+/// Query the provider appends to the callback URL: a code and the state on
+/// success, or an OAuth error.
+#[derive(Debug, Deserialize)]
+pub struct FederatedCallbackDto {
+    pub code: Option<String>,
+    pub state: Option<String>,
+    pub error: Option<String>,
+    pub error_description: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct IdentityProviderDto {
+    pub name: String,
+    /// Where to send the browser to start a login with this provider.
+    pub login_url: String,
+}
+
+impl From<&IdentityProvider> for IdentityProviderDto {
+    fn from(provider: &IdentityProvider) -> Self {
+        Self {
+            name: provider.name.clone(),
+            login_url: format!("/login/oidc/{}", provider.name),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct FederatedIdentityDto {
+    pub provider: String,
+    pub email: Option<String>,
+    pub linked_at: String,
+    pub last_login_at: String,
+}
+
+impl From<&FederatedIdentity> for FederatedIdentityDto {
+    fn from(identity: &FederatedIdentity) -> Self {
+        Self {
+            provider: identity.provider.clone(),
+            email: identity.email.clone(),
+            linked_at: identity.created_at.to_rfc3339(),
+            last_login_at: identity.last_login_at.to_rfc3339(),
+        }
+    }
+}
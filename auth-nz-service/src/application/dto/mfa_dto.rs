@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize)]
+pub struct MfaEnrollmentDto {
+    pub secret: String,
+    pub otpauth_uri: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ConfirmMfaDto {
+    pub code: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RecoveryCodesDto {
+    pub recovery_codes: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MfaChallengeDto {
+    pub mfa_required: bool,
+    pub challenge_token: String,
+    pub expires_at: String,
+}
+
+/// `code` may be a TOTP code or one of the recovery codes.
+#[derive(Debug, Deserialize)]
+pub struct VerifyMfaDto {
+    pub challenge_token: String,
+    pub code: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DisableMfaDto {
+    pub password: String,
+    pub code: String,
+}
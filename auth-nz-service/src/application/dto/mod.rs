@@ -1,4 +1,27 @@
+pub mod account_dto;
+pub mod audit_dto;
 pub mod auth_dto;
+pub mod discovery_dto;
+pub mod federation_dto;
+pub mod mfa_dto;
+pub mod oauth_dto;
+pub mod organization_dto;
+pub mod passwordless_dto;
+pub mod policy_dto;
+pub mod rbac_dto;
+pub mod service_account_dto;
+pub mod session_dto;
 
+pub use account_dto::*;
+pub use audit_dto::*;
 pub use auth_dto::*;
-
+pub use discovery_dto::*;
+pub use federation_dto::*;
+pub use mfa_dto::*;
+pub use oauth_dto::*;
+pub use organization_dto::*;
+pub use passwordless_dto::*;
+pub use policy_dto::*;
+pub use rbac_dto::*;
+pub use service_account_dto::*;
+pub use session_dto::*;
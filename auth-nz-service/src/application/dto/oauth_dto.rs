@@ -0,0 +1,220 @@
+use serde::{Deserialize, Serialize};
+use crate::domain::entities::oauth_client::OAuthClient;
+use crate::domain::entities::oauth_consent::OAuthConsent;
+use crate::domain::value_objects::GrantType;
+use crate::domain::errors::DomainError;
+
+/// Query of `GET /authorize` (RFC 6749 section 4.1.1 with RFC 7636 PKCE).
+#[derive(Debug, Deserialize)]
+pub struct AuthorizationRequestDto {
+    pub response_type: String,
+    pub client_id: String,
+    pub redirect_uri: String,
+    pub scope: Option<String>,
+    pub state: Option<String>,
+    pub code_challenge: Option<String>,
+    pub code_challenge_method: Option<String>,
+}
+
+/// The user's answer to a consent prompt, sent with the original request.
+#[derive(Debug, Deserialize)]
+pub struct ConsentDecisionDto {
+    #[serde(flatten)]
+    pub request: AuthorizationRequestDto,
+    pub approve: bool,
+}
+
+/// The frontend either sends the browser to `redirect_to`, which carries the
+/// code or an error, or asks the user for consent and posts the decision to
+/// `/authorize/consent`.
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum AuthorizationResponseDto {
+    Redirect {
+        redirect_to: String,
+    },
+    ConsentRequired {
+        consent_required: bool,
+        client_id: String,
+        client_name: String,
+        scopes: Vec<String>,
+    },
+}
+
+impl AuthorizationResponseDto {
+    /// Appends `params` to the client's redirect URI, leaving out empty ones such as an absent `state`.
+    pub fn redirect(redirect_uri: &str, params: &[(&str, &str)]) -> Self {
+        let query = params.iter()
+            .filter(|(_, value)| !value.is_empty())
+            .map(|(key, value)| format!("{}={}", key, urlencoding::encode(value)))
+            .collect::<Vec<_>>()
+            .join("&");
+        let separator = if redirect_uri.contains('?') { '&' } else { '?' };
+        AuthorizationResponseDto::Redirect {
+            redirect_to: format!("{}{}{}", redirect_uri, separator, query),
+        }
+    }
+}
+
+/// Form body of `POST /token`. Client credentials may come from here or
+/// from HTTP Basic authentication.
+#[derive(Debug, Deserialize)]
+pub struct TokenRequestDto {
+    pub grant_type: String,
+    pub code: Option<String>,
+    pub redirect_uri: Option<String>,
+    pub code_verifier: Option<String>,
+    pub refresh_token: Option<String>,
+    pub scope: Option<String>,
+    pub client_id: Option<String>,
+    pub client_secret: Option<String>,
+}
+
+/// Form body of `POST /introspect` (RFC 7662) and `POST /revoke` (RFC 7009).
+/// Client credentials may come from here or from HTTP Basic authentication.
+/// `token_type_hint` is ignored, since the token's shape already tells its type.
+#[derive(Debug, Deserialize)]
+pub struct TokenReferenceDto {
+    pub token: String,
+    pub client_id: Option<String>,
+    pub client_secret: Option<String>,
+}
+
+/// RFC 7662 response. Only `active` is set for tokens that are not.
+#[derive(Debug, Default, Serialize)]
+pub struct IntrospectionResponseDto {
+    pub active: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    /// `Bearer` for access tokens, `refresh_token` for refresh tokens, `api_key` for API keys.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iat: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sub: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iss: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jti: Option<String>,
+    /// Not part of RFC 7662: the roles behind an access token, so resource
+    /// servers that introspect can make the same role checks as those that
+    /// verify the JWT themselves.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub roles: Option<Vec<String>>,
+    /// Not part of RFC 7662: the organization a token is scoped to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub org_id: Option<String>,
+}
+
+impl IntrospectionResponseDto {
+    pub fn inactive() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct TokenResponseDto {
+    pub access_token: String,
+    pub token_type: String,
+    pub expires_in: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<String>,
+    pub scope: String,
+}
+
+/// Error body of the token endpoint (RFC 6749 section 5.2).
+#[derive(Debug, Serialize)]
+pub struct OAuthErrorDto {
+    pub error: String,
+    pub error_description: String,
+}
+
+impl OAuthErrorDto {
+    pub fn from_domain(error: &DomainError) -> Self {
+        let code = match error {
+            DomainError::InvalidClient => "invalid_client",
+            DomainError::InvalidGrant(_)
+            | DomainError::InvalidToken
+            | DomainError::TokenExpired
+            | DomainError::SessionExpired
+            | DomainError::RefreshTokenReused => "invalid_grant",
+            DomainError::InvalidScope(_) => "invalid_scope",
+            DomainError::UnauthorizedClient(_) => "unauthorized_client",
+            DomainError::UnsupportedGrantType(_) => "unsupported_grant_type",
+            DomainError::UnsupportedResponseType(_) => "unsupported_response_type",
+            DomainError::InvalidOAuthRequest(_) | DomainError::ValidationError(_) => "invalid_request",
+            _ => "server_error",
+        };
+        Self {
+            error: code.to_string(),
+            error_description: error.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateOAuthClientDto {
+    pub name: String,
+    /// Confidential clients get a secret; public clients such as mobile apps do not.
+    pub confidential: bool,
+    #[serde(default)]
+    pub redirect_uris: Vec<String>,
+    pub grant_types: Vec<String>,
+    pub scopes: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct OAuthClientDto {
+    pub client_id: String,
+    pub name: String,
+    pub confidential: bool,
+    pub redirect_uris: Vec<String>,
+    pub grant_types: Vec<String>,
+    pub scopes: Vec<String>,
+    pub created_at: String,
+}
+
+impl From<&OAuthClient> for OAuthClientDto {
+    fn from(client: &OAuthClient) -> Self {
+        Self {
+            client_id: client.id.clone(),
+            name: client.name.clone(),
+            confidential: client.is_confidential(),
+            redirect_uris: client.redirect_uris.clone(),
+            grant_types: client.grant_types.iter().map(GrantType::as_str).map(str::to_string).collect(),
+            scopes: client.scopes.to_vec(),
+            created_at: client.created_at.to_rfc3339(),
+        }
+    }
+}
+
+/// The secret is only ever returned here, at registration.
+#[derive(Debug, Serialize)]
+pub struct CreatedOAuthClientDto {
+    #[serde(flatten)]
+    pub client: OAuthClientDto,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_secret: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct OAuthConsentDto {
+    pub client_id: String,
+    pub scopes: Vec<String>,
+    pub granted_at: String,
+}
+
+impl From<&OAuthConsent> for OAuthConsentDto {
+    fn from(consent: &OAuthConsent) -> Self {
+        Self {
+            client_id: consent.client_id.clone(),
+            scopes: consent.scopes.to_vec(),
+            granted_at: consent.granted_at.to_rfc3339(),
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use serde::{Deserialize, Serialize};
+use crate::domain::entities::organization::{Organization, OrganizationMember};
+use crate::domain::entities::organization_invitation::OrganizationInvitation;
+use crate::domain::entities::role::Role;
+use crate::domain::entities::user::User;
+
+#[derive(Debug, Deserialize)]
+pub struct CreateOrganizationDto {
+    pub name: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct OrganizationDto {
+    pub id: String,
+    pub name: String,
+    /// Names of the roles the caller holds directly in the organization.
+    pub roles: Vec<String>,
+    pub created_at: String,
+}
+
+impl OrganizationDto {
+    pub fn new(organization: &Organization, roles: &[Role]) -> Self {
+        Self {
+            id: organization.id.as_uuid().to_string(),
+            name: organization.name.clone(),
+            roles: roles.iter().map(|role| role.name.as_str().to_string()).collect(),
+            created_at: organization.created_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct OrganizationMemberDto {
+    pub user_id: String,
+    pub email: String,
+    /// Names of the roles held directly in the organization.
+    pub roles: Vec<String>,
+    pub joined_at: String,
+}
+
+impl OrganizationMemberDto {
+    pub fn new(member: &OrganizationMember, user: &User, roles: &[Role]) -> Self {
+        Self {
+            user_id: member.user_id.as_uuid().to_string(),
+            email: user.email.as_str().to_string(),
+            roles: roles.iter().map(|role| role.name.as_str().to_string()).collect(),
+            joined_at: member.joined_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct InviteMemberDto {
+    pub email: String,
+    /// Role the invitee gets in the organization; omitted to join without one.
+    pub role_id: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct OrganizationInvitationDto {
+    pub id: String,
+    pub organization_id: String,
+    pub email: String,
+    pub role_id: Option<String>,
+    pub invited_by: String,
+    /// `pending`, `accepted` or `expired`.
+    pub status: String,
+    pub expires_at: String,
+    pub accepted_at: Option<String>,
+    pub created_at: String,
+}
+
+impl From<&OrganizationInvitation> for OrganizationInvitationDto {
+    fn from(invitation: &OrganizationInvitation) -> Self {
+        let status = if invitation.accepted_at.is_some() {
+            "accepted"
+        } else if invitation.is_expired() {
+            "expired"
+        } else {
+            "pending"
+        };
+        Self {
+            id: invitation.id.as_uuid().to_string(),
+            organization_id: invitation.organization_id.as_uuid().to_string(),
+            email: invitation.email.as_str().to_string(),
+            role_id: invitation.role_id.map(|id| id.as_uuid().to_string()),
+            invited_by: invitation.invited_by.as_uuid().to_string(),
+            status: status.to_string(),
+            expires_at: invitation.expires_at.to_rfc3339(),
+            accepted_at: invitation.accepted_at.map(|at| at.to_rfc3339()),
+            created_at: invitation.created_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AcceptInvitationDto {
+    pub token: String,
+}
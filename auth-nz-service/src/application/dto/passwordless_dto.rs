@@ -0,0 +1,208 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use crate::application::errors::ApplicationError;
+use crate::domain::entities::passkey::Passkey;
+use crate::domain::services::{AssertionResponse, AttestationResponse};
+
+#[derive(Debug, Deserialize)]
+pub struct RequestMagicLinkDto {
+    pub email: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MagicLinkLoginDto {
+    pub token: String,
+}
+
+/// Registration options. `public_key` is passed to `navigator.credentials.create()`
+/// as is (after decoding the base64url fields); `challenge_id` goes back with
+/// the result.
+#[derive(Debug, Serialize)]
+pub struct PasskeyRegistrationOptionsDto {
+    pub challenge_id: String,
+    pub public_key: PublicKeyCreationOptionsDto,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PublicKeyCreationOptionsDto {
+    pub rp: RelyingPartyDto,
+    pub user: PasskeyUserDto,
+    pub challenge: String,
+    pub pub_key_cred_params: Vec<CredentialParameterDto>,
+    pub timeout: i64,
+    pub exclude_credentials: Vec<CredentialDescriptorDto>,
+    pub authenticator_selection: AuthenticatorSelectionDto,
+    pub attestation: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RelyingPartyDto {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PasskeyUserDto {
+    /// The user id, which comes back as the user handle of assertions.
+    pub id: String,
+    pub name: String,
+    pub display_name: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CredentialParameterDto {
+    #[serde(rename = "type")]
+    pub credential_type: String,
+    pub alg: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CredentialDescriptorDto {
+    #[serde(rename = "type")]
+    pub credential_type: String,
+    pub id: String,
+}
+
+impl CredentialDescriptorDto {
+    pub fn new(credential_id: &[u8]) -> Self {
+        Self {
+            credential_type: "public-key".to_string(),
+            id: URL_SAFE_NO_PAD.encode(credential_id),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthenticatorSelectionDto {
+    pub resident_key: String,
+    pub user_verification: String,
+}
+
+/// Login options for `navigator.credentials.get()`.
+#[derive(Debug, Serialize)]
+pub struct PasskeyLoginOptionsDto {
+    pub challenge_id: String,
+    pub public_key: PublicKeyRequestOptionsDto,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PublicKeyRequestOptionsDto {
+    pub challenge: String,
+    pub timeout: i64,
+    pub rp_id: String,
+    pub allow_credentials: Vec<CredentialDescriptorDto>,
+    pub user_verification: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct StartPasskeyLoginDto {
+    /// Restricts the login to this account's passkeys; omitted for discoverable credentials.
+    pub email: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RegisterPasskeyDto {
+    pub challenge_id: String,
+    pub name: Option<String>,
+    /// The `PublicKeyCredential` from `navigator.credentials.create()`, in its JSON form.
+    pub credential: RegistrationCredentialDto,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RegistrationCredentialDto {
+    pub response: AttestationResponseDto,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AttestationResponseDto {
+    #[serde(rename = "clientDataJSON")]
+    pub client_data_json: String,
+    pub attestation_object: String,
+}
+
+impl TryFrom<RegistrationCredentialDto> for AttestationResponse {
+    type Error = ApplicationError;
+
+    fn try_from(credential: RegistrationCredentialDto) -> Result<Self, Self::Error> {
+        Ok(Self {
+            client_data_json: decode("clientDataJSON", &credential.response.client_data_json)?,
+            attestation_object: decode("attestationObject", &credential.response.attestation_object)?,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PasskeyLoginDto {
+    pub challenge_id: String,
+    /// The `PublicKeyCredential` from `navigator.credentials.get()`, in its JSON form.
+    pub credential: AuthenticationCredentialDto,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AuthenticationCredentialDto {
+    /// Base64url credential id.
+    pub id: String,
+    pub response: AssertionResponseDto,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssertionResponseDto {
+    #[serde(rename = "clientDataJSON")]
+    pub client_data_json: String,
+    pub authenticator_data: String,
+    pub signature: String,
+    pub user_handle: Option<String>,
+}
+
+impl TryFrom<AuthenticationCredentialDto> for AssertionResponse {
+    type Error = ApplicationError;
+
+    fn try_from(credential: AuthenticationCredentialDto) -> Result<Self, Self::Error> {
+        Ok(Self {
+            credential_id: decode("id", &credential.id)?,
+            client_data_json: decode("clientDataJSON", &credential.response.client_data_json)?,
+            authenticator_data: decode("authenticatorData", &credential.response.authenticator_data)?,
+            signature: decode("signature", &credential.response.signature)?,
+            user_handle: credential.response.user_handle
+                .filter(|handle| !handle.is_empty())
+                .map(|handle| decode("userHandle", &handle))
+                .transpose()?,
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct PasskeyDto {
+    pub id: String,
+    pub name: String,
+    /// Authenticator model, all zeros when not disclosed.
+    pub aaguid: String,
+    pub attestation_format: String,
+    pub last_used_at: Option<String>,
+    pub created_at: String,
+}
+
+impl From<&Passkey> for PasskeyDto {
+    fn from(passkey: &Passkey) -> Self {
+        Self {
+            id: passkey.id.as_uuid().to_string(),
+            name: passkey.name.clone(),
+            aaguid: passkey.aaguid.to_string(),
+            attestation_format: passkey.attestation_format.clone(),
+            last_used_at: passkey.last_used_at.map(|at| at.to_rfc3339()),
+            created_at: passkey.created_at.to_rfc3339(),
+        }
+    }
+}
+
+fn decode(field: &str, value: &str) -> Result<Vec<u8>, ApplicationError> {
+    URL_SAFE_NO_PAD.decode(value.trim_end_matches('='))
+        .map_err(|_| ApplicationError::Validation(format!("{} must be base64url", field)))
+}
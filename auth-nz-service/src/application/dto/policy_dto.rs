@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Debug, Deserialize)]
+pub struct AuthorizeRequestDto {
+    pub subject: Value,
+    pub resource: Value,
+    pub action: String,
+    #[serde(default)]
+    pub environment: Value,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AuthorizeQueryDto {
+    #[serde(default)]
+    pub explain: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RuleEvaluationDto {
+    pub rule: String,
+    pub effect: String,
+    pub applicable: bool,
+    pub matched: bool,
+    pub trace: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AuthorizeResponseDto {
+    pub decision: String,
+    pub allowed: bool,
+    pub matched_rule: Option<String>,
+    pub reason: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explanation: Option<Vec<RuleEvaluationDto>>,
+}
@@ -0,0 +1,87 @@
+use serde::{Deserialize, Serialize};
+use crate::domain::entities::permission::Permission;
+use crate::domain::entities::role::Role;
+
+#[derive(Debug, Deserialize)]
+pub struct CreateRoleDto {
+    pub name: String,
+    pub parent_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SetRoleParentDto {
+    pub parent_id: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RoleDto {
+    pub id: String,
+    pub name: String,
+    pub parent_id: Option<String>,
+    pub created_at: String,
+}
+
+impl From<&Role> for RoleDto {
+    fn from(role: &Role) -> Self {
+        Self {
+            id: role.id.as_uuid().to_string(),
+            name: role.name.as_str().to_string(),
+            parent_id: role.parent_id.map(|id| id.as_uuid().to_string()),
+            created_at: role.created_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreatePermissionDto {
+    pub name: String,
+    pub resource: String,
+    pub action: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PermissionDto {
+    pub id: String,
+    pub name: String,
+    pub resource: String,
+    pub action: String,
+    pub created_at: String,
+}
+
+impl From<&Permission> for PermissionDto {
+    fn from(permission: &Permission) -> Self {
+        Self {
+            id: permission.id.as_uuid().to_string(),
+            name: permission.name.as_str().to_string(),
+            resource: permission.resource.clone(),
+            action: permission.action.clone(),
+            created_at: permission.created_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GrantPermissionDto {
+    pub permission_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AssignRoleDto {
+    pub role_id: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct EffectivePermissionsQueryDto {
+    /// Looks at the roles held in this organization instead of those held
+    /// outside of any organization.
+    pub organization_id: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct EffectivePermissionsDto {
+    pub user_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organization_id: Option<String>,
+    pub roles: Vec<String>,
+    pub permissions: Vec<String>,
+}
@@ -0,0 +1,88 @@
+use serde::{Deserialize, Serialize};
+use crate::domain::entities::api_key::ApiKey;
+use crate::domain::entities::role::Role;
+use crate::domain::entities::service_account::ServiceAccount;
+
+#[derive(Debug, Deserialize)]
+pub struct CreateServiceAccountDto {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ServiceAccountDto {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    /// Names of the roles assigned directly to the account.
+    pub roles: Vec<String>,
+    pub created_at: String,
+}
+
+impl ServiceAccountDto {
+    pub fn new(service_account: &ServiceAccount, roles: &[Role]) -> Self {
+        Self {
+            id: service_account.id.as_uuid().to_string(),
+            name: service_account.name.clone(),
+            description: service_account.description.clone(),
+            roles: roles.iter().map(|role| role.name.as_str().to_string()).collect(),
+            created_at: service_account.created_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateApiKeyDto {
+    pub name: String,
+    /// `resource:action` permissions the key may use, out of those the account has.
+    pub scopes: Vec<String>,
+    /// Omitted for a key that does not expire.
+    pub expires_in_days: Option<u32>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct RotateApiKeyDto {
+    /// How long the replaced key keeps working; it is revoked at once when omitted.
+    pub grace_period_minutes: Option<u32>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ApiKeyDto {
+    pub id: String,
+    pub service_account_id: String,
+    pub name: String,
+    pub prefix: String,
+    pub scopes: Vec<String>,
+    pub active: bool,
+    pub expires_at: Option<String>,
+    pub last_used_at: Option<String>,
+    pub revoked_at: Option<String>,
+    pub rotated_from: Option<String>,
+    pub created_at: String,
+}
+
+impl From<&ApiKey> for ApiKeyDto {
+    fn from(key: &ApiKey) -> Self {
+        Self {
+            id: key.id.as_uuid().to_string(),
+            service_account_id: key.service_account_id.as_uuid().to_string(),
+            name: key.name.clone(),
+            prefix: key.prefix.clone(),
+            scopes: key.scopes.to_vec(),
+            active: key.is_active(),
+            expires_at: key.expires_at.map(|at| at.to_rfc3339()),
+            last_used_at: key.last_used_at.map(|at| at.to_rfc3339()),
+            revoked_at: key.revoked_at.map(|at| at.to_rfc3339()),
+            rotated_from: key.rotated_from.map(|id| id.as_uuid().to_string()),
+            created_at: key.created_at.to_rfc3339(),
+        }
+    }
+}
+
+/// The key is only ever returned here, when it is created or rotated.
+#[derive(Debug, Serialize)]
+pub struct CreatedApiKeyDto {
+    #[serde(flatten)]
+    pub key: ApiKeyDto,
+    pub api_key: String,
+}
@@ -0,0 +1,27 @@
+use serde::Serialize;
+use crate::domain::value_objects::DeviceInfo;
+
+/// The client making a request that opens or refreshes a session.
+#[derive(Debug, Clone)]
+pub struct ClientInfoDto {
+    pub ip_address: String,
+    pub user_agent: Option<String>,
+}
+
+impl ClientInfoDto {
+    pub fn device(&self) -> DeviceInfo {
+        DeviceInfo::new(self.user_agent.clone(), Some(self.ip_address.clone()))
+    }
+}
+
+/// A signed-in device. `id` identifies the sign-in across refreshes.
+#[derive(Debug, Serialize)]
+pub struct SessionDto {
+    pub id: String,
+    pub device_label: String,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+    pub last_seen_at: String,
+    pub expires_at: String,
+    pub current: bool,
+}
@@ -2,7 +2,3 @@ pub mod use_cases;
 pub mod dto;
 pub mod errors;
 
-pub use use_cases::*;
-pub use dto::*;
-pub use errors::*;
-
@@ -0,0 +1,9 @@
+pub mod request_email_verification;
+pub mod verify_email;
+pub mod forgot_password;
+pub mod reset_password;
+
+pub use request_email_verification::RequestEmailVerificationUseCase;
+pub use verify_email::VerifyEmailUseCase;
+pub use forgot_password::ForgotPasswordUseCase;
+pub use reset_password::ResetPasswordUseCase;
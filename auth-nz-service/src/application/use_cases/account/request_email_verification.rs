@@ -0,0 +1,37 @@
+use std::sync::Arc;
+use crate::application::errors::ApplicationError;
+use crate::domain::events::EventPublisher;
+use crate::domain::repositories::{UserRepository, AccountTokenRepository};
+use crate::domain::services::AccountNotifier;
+use crate::domain::value_objects::UserId;
+use crate::domain::errors::DomainError;
+
+pub struct RequestEmailVerificationUseCase<UR: UserRepository, TR: AccountTokenRepository, EP: EventPublisher> {
+    user_repository: Arc<UR>,
+    account_notifier: Arc<AccountNotifier<TR, EP>>,
+}
+
+impl<UR: UserRepository, TR: AccountTokenRepository, EP: EventPublisher> RequestEmailVerificationUseCase<UR, TR, EP> {
+    pub fn new(user_repository: Arc<UR>, account_notifier: Arc<AccountNotifier<TR, EP>>) -> Self {
+        Self {
+            user_repository,
+            account_notifier,
+        }
+    }
+
+    pub async fn execute(&self, user_id: &str) -> Result<(), ApplicationError> {
+        let user_id = uuid::Uuid::parse_str(user_id)
+            .map_err(|_| ApplicationError::Validation("Invalid user ID format".to_string()))?;
+
+        let user = self.user_repository.find_by_id(&UserId::from_uuid(user_id)).await?
+            .ok_or(ApplicationError::Domain(DomainError::UserNotFound))?;
+
+        if user.is_email_verified() {
+            return Err(ApplicationError::Domain(DomainError::EmailAlreadyVerified));
+        }
+
+        self.account_notifier.send_email_verification(&user).await?;
+
+        Ok(())
+    }
+}
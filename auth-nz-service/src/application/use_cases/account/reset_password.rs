@@ -0,0 +1,103 @@
+use std::sync::Arc;
+use serde_json::json;
+use crate::application::dto::ResetPasswordDto;
+use crate::application::errors::ApplicationError;
+use crate::domain::entities::audit_event::NewAuditEvent;
+use crate::domain::events::EventPublisher;
+use crate::domain::repositories::{
+    UserRepository, SessionRepository, AccountTokenRepository, AuditEventRepository, PasswordHistoryRepository,
+    BreachedPasswordRepository,
+};
+use crate::domain::services::{AccountNotifier, AccountTokenService, AuditLog, PasswordPolicyService, PasswordService};
+use crate::domain::value_objects::{AccountTokenPurpose, AuditEventType};
+use crate::domain::errors::DomainError;
+
+pub struct ResetPasswordUseCase<
+    UR: UserRepository,
+    SR: SessionRepository,
+    TR: AccountTokenRepository,
+    EP: EventPublisher,
+    AR: AuditEventRepository,
+    HR: PasswordHistoryRepository,
+    BR: BreachedPasswordRepository,
+> {
+    user_repository: Arc<UR>,
+    session_repository: Arc<SR>,
+    password_service: Arc<PasswordService>,
+    password_policy_service: Arc<PasswordPolicyService<HR, BR>>,
+    account_token_service: Arc<AccountTokenService<TR>>,
+    account_notifier: Arc<AccountNotifier<TR, EP>>,
+    audit_log: Arc<AuditLog<AR>>,
+}
+
+impl<UR, SR, TR, EP, AR, HR, BR> ResetPasswordUseCase<UR, SR, TR, EP, AR, HR, BR>
+where
+    UR: UserRepository,
+    SR: SessionRepository,
+    TR: AccountTokenRepository,
+    EP: EventPublisher,
+    AR: AuditEventRepository,
+    HR: PasswordHistoryRepository,
+    BR: BreachedPasswordRepository,
+{
+    pub fn new(
+        user_repository: Arc<UR>,
+        session_repository: Arc<SR>,
+        password_service: Arc<PasswordService>,
+        password_policy_service: Arc<PasswordPolicyService<HR, BR>>,
+        account_token_service: Arc<AccountTokenService<TR>>,
+        account_notifier: Arc<AccountNotifier<TR, EP>>,
+        audit_log: Arc<AuditLog<AR>>,
+    ) -> Self {
+        Self {
+            user_repository,
+            session_repository,
+            password_service,
+            password_policy_service,
+            account_token_service,
+            account_notifier,
+            audit_log,
+        }
+    }
+
+    pub async fn execute(&self, dto: ResetPasswordDto) -> Result<(), ApplicationError> {
+        if dto.new_password.is_empty() {
+            return Err(ApplicationError::Validation("New password cannot be empty".to_string()));
+        }
+
+        let user_id = self.account_token_service.peek(&dto.token, AccountTokenPurpose::PasswordReset)?;
+
+        let mut user = self.user_repository.find_by_id(&user_id).await?
+            .ok_or(ApplicationError::Domain(DomainError::UserNotFound))?;
+
+        // Checked before the token is spent, so the link still works for a better password
+        self.password_policy_service.check("new_password", &dto.new_password, Some(&user)).await?;
+
+        self.account_token_service.redeem(&dto.token, AccountTokenPurpose::PasswordReset).await?;
+
+        self.password_policy_service.remember(&user).await
+            .map_err(|e| ApplicationError::Repository(e.to_string()))?;
+        user.update_password(self.password_service.hash(&dto.new_password)?);
+        // Receiving the reset link proves control of the mailbox as well
+        user.verify_email();
+        self.user_repository.update(&user).await
+            .map_err(|e| ApplicationError::Repository(e.to_string()))?;
+
+        // Whoever knew the old password may still hold a session
+        self.session_repository.delete_by_user_id(&user.id).await
+            .map_err(|e| ApplicationError::Repository(e.to_string()))?;
+
+        self.audit_log.record(
+            NewAuditEvent::new(AuditEventType::PasswordChanged)
+                .with_actor(user.id.as_uuid().to_string())
+                .with_user(&user.id)
+                .with_details(json!({ "method": "reset_link", "sessions_ended": true })),
+        ).await;
+
+        if let Err(e) = self.account_notifier.password_changed(&user).await {
+            tracing::warn!("Failed to publish password change for user {}: {}", user.id.as_uuid(), e);
+        }
+
+        Ok(())
+    }
+}
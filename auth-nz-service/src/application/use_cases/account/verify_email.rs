@@ -0,0 +1,35 @@
+use std::sync::Arc;
+use crate::application::dto::VerifyEmailDto;
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::{UserRepository, AccountTokenRepository};
+use crate::domain::services::AccountTokenService;
+use crate::domain::value_objects::AccountTokenPurpose;
+use crate::domain::errors::DomainError;
+
+pub struct VerifyEmailUseCase<UR: UserRepository, TR: AccountTokenRepository> {
+    user_repository: Arc<UR>,
+    account_token_service: Arc<AccountTokenService<TR>>,
+}
+
+impl<UR: UserRepository, TR: AccountTokenRepository> VerifyEmailUseCase<UR, TR> {
+    pub fn new(user_repository: Arc<UR>, account_token_service: Arc<AccountTokenService<TR>>) -> Self {
+        Self {
+            user_repository,
+            account_token_service,
+        }
+    }
+
+    pub async fn execute(&self, dto: VerifyEmailDto) -> Result<(), ApplicationError> {
+        let user_id = self.account_token_service
+            .redeem(&dto.token, AccountTokenPurpose::EmailVerification).await?;
+
+        let mut user = self.user_repository.find_by_id(&user_id).await?
+            .ok_or(ApplicationError::Domain(DomainError::UserNotFound))?;
+
+        user.verify_email();
+        self.user_repository.update(&user).await
+            .map_err(|e| ApplicationError::Repository(e.to_string()))?;
+
+        Ok(())
+    }
+}
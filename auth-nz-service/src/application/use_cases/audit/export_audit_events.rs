@@ -0,0 +1,40 @@
+use std::sync::Arc;
+use crate::application::dto::{AuditEventDto, AuditEventFilterDto};
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::AuditEventRepository;
+use crate::domain::services::AuditLog;
+
+const BATCH_SIZE: i64 = 500;
+
+/// Every matching audit record as JSON Lines, oldest first. An unfiltered
+/// export holds the whole chain and can be verified offline.
+pub struct ExportAuditEventsUseCase<AR: AuditEventRepository> {
+    audit_log: Arc<AuditLog<AR>>,
+}
+
+impl<AR: AuditEventRepository> ExportAuditEventsUseCase<AR> {
+    pub fn new(audit_log: Arc<AuditLog<AR>>) -> Self {
+        Self { audit_log }
+    }
+
+    pub async fn execute(&self, dto: AuditEventFilterDto) -> Result<String, ApplicationError> {
+        let mut query = dto.to_query()?;
+        query.limit = BATCH_SIZE;
+
+        let mut lines = String::new();
+        loop {
+            let batch = self.audit_log.search(&query).await?;
+            for event in &batch {
+                let line = serde_json::to_string(&AuditEventDto::from(event))
+                    .map_err(|e| ApplicationError::Repository(e.to_string()))?;
+                lines.push_str(&line);
+                lines.push('\n');
+            }
+
+            match batch.last() {
+                Some(last) if batch.len() as i64 == BATCH_SIZE => query.after_sequence = Some(last.sequence),
+                _ => return Ok(lines),
+            }
+        }
+    }
+}
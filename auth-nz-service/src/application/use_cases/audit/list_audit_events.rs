@@ -0,0 +1,42 @@
+use std::sync::Arc;
+use crate::application::dto::{AuditEventDto, AuditEventListDto, AuditEventPageDto};
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::AuditEventRepository;
+use crate::domain::services::AuditLog;
+
+const DEFAULT_PAGE_SIZE: i64 = 50;
+const MAX_PAGE_SIZE: i64 = 500;
+
+pub struct ListAuditEventsUseCase<AR: AuditEventRepository> {
+    audit_log: Arc<AuditLog<AR>>,
+}
+
+impl<AR: AuditEventRepository> ListAuditEventsUseCase<AR> {
+    pub fn new(audit_log: Arc<AuditLog<AR>>) -> Self {
+        Self { audit_log }
+    }
+
+    pub async fn execute(&self, dto: AuditEventPageDto) -> Result<AuditEventListDto, ApplicationError> {
+        let limit = dto.limit.unwrap_or(DEFAULT_PAGE_SIZE);
+        if !(1..=MAX_PAGE_SIZE).contains(&limit) {
+            return Err(ApplicationError::Validation(format!("limit must be between 1 and {}", MAX_PAGE_SIZE)));
+        }
+
+        let mut query = dto.filter.to_query()?;
+        query.before_sequence = dto.before;
+        query.newest_first = true;
+        query.limit = limit;
+
+        let events = self.audit_log.search(&query).await?;
+        let next_before = if events.len() as i64 == limit {
+            events.last().map(|event| event.sequence)
+        } else {
+            None
+        };
+
+        Ok(AuditEventListDto {
+            events: events.iter().map(AuditEventDto::from).collect(),
+            next_before,
+        })
+    }
+}
@@ -0,0 +1,7 @@
+pub mod list_audit_events;
+pub mod export_audit_events;
+pub mod verify_audit_chain;
+
+pub use list_audit_events::ListAuditEventsUseCase;
+pub use export_audit_events::ExportAuditEventsUseCase;
+pub use verify_audit_chain::VerifyAuditChainUseCase;
@@ -0,0 +1,20 @@
+use std::sync::Arc;
+use crate::application::dto::AuditChainStatusDto;
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::AuditEventRepository;
+use crate::domain::services::AuditLog;
+
+pub struct VerifyAuditChainUseCase<AR: AuditEventRepository> {
+    audit_log: Arc<AuditLog<AR>>,
+}
+
+impl<AR: AuditEventRepository> VerifyAuditChainUseCase<AR> {
+    pub fn new(audit_log: Arc<AuditLog<AR>>) -> Self {
+        Self { audit_log }
+    }
+
+    pub async fn execute(&self) -> Result<AuditChainStatusDto, ApplicationError> {
+        let verification = self.audit_log.verify().await?;
+        Ok(AuditChainStatusDto::from(verification))
+    }
+}
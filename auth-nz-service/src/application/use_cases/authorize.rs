@@ -0,0 +1,69 @@
+use std::sync::Arc;
+use chrono::{Datelike, Timelike, Utc};
+use serde_json::{json, Value};
+use crate::application::dto::{AuthorizeRequestDto, AuthorizeResponseDto, RuleEvaluationDto};
+use crate::application::errors::ApplicationError;
+use crate::domain::entities::policy::PolicyEffect;
+use crate::domain::repositories::PolicyRepository;
+use crate::domain::services::{AuthorizationRequest, PolicyEngine};
+
+pub struct AuthorizeUseCase<R: PolicyRepository> {
+    policy_engine: PolicyEngine<R>,
+}
+
+impl<R: PolicyRepository> AuthorizeUseCase<R> {
+    pub fn new(policy_repository: Arc<R>) -> Self {
+        Self {
+            policy_engine: PolicyEngine::new(policy_repository),
+        }
+    }
+
+    pub async fn execute(&self, dto: AuthorizeRequestDto, explain: bool) -> Result<AuthorizeResponseDto, ApplicationError> {
+        if !dto.subject.is_object() || !dto.resource.is_object() {
+            return Err(ApplicationError::Validation("Subject and resource must be objects".to_string()));
+        }
+        if dto.action.is_empty() {
+            return Err(ApplicationError::Validation("Action is required".to_string()));
+        }
+
+        // Time attributes always come from the server so callers cannot spoof them
+        let now = Utc::now();
+        let mut environment = match dto.environment {
+            Value::Object(map) => map,
+            _ => serde_json::Map::new(),
+        };
+        environment.insert("time".to_string(), json!(now.to_rfc3339()));
+        environment.insert("hour".to_string(), json!(now.hour()));
+        environment.insert("weekday".to_string(), json!(now.weekday().to_string()));
+
+        let request = AuthorizationRequest {
+            subject: dto.subject,
+            resource: dto.resource,
+            action: dto.action,
+            environment: Value::Object(environment),
+        };
+
+        let decision = self.policy_engine.evaluate(&request, explain).await?;
+
+        Ok(AuthorizeResponseDto {
+            decision: if decision.allowed { "allow" } else { "deny" }.to_string(),
+            allowed: decision.allowed,
+            matched_rule: decision.matched_policy,
+            reason: decision.reason,
+            explanation: explain.then(|| {
+                decision.evaluations.into_iter()
+                    .map(|evaluation| RuleEvaluationDto {
+                        rule: evaluation.policy_id,
+                        effect: match evaluation.effect {
+                            PolicyEffect::Allow => "allow".to_string(),
+                            PolicyEffect::Deny => "deny".to_string(),
+                        },
+                        applicable: evaluation.applicable,
+                        matched: evaluation.matched,
+                        trace: evaluation.trace,
+                    })
+                    .collect()
+            }),
+        })
+    }
+}
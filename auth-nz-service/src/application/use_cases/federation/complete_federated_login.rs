@@ -0,0 +1,168 @@
+use std::sync::Arc;
+use serde_json::json;
+use crate::application::dto::{AuthResponseDto, ClientInfoDto, FederatedCallbackDto, LoginResponseDto, MfaChallengeDto};
+use crate::application::errors::ApplicationError;
+use crate::domain::entities::audit_event::NewAuditEvent;
+use crate::domain::entities::user::User;
+use crate::domain::repositories::{
+    UserRepository, SessionRepository, RoleRepository, PermissionRepository, MfaRepository,
+    IdentityProviderRepository, FederatedIdentityRepository, AuditEventRepository,
+};
+use crate::domain::services::{AuditLog, AuthorizationService, ExternalIdentity, FederationService, MfaService, PasswordService, SessionService};
+use crate::domain::value_objects::{AuditEventType, Email, UserId};
+use crate::domain::errors::DomainError;
+
+/// Handles the provider callback: signs in the linked user, or links the
+/// provider account to the user with the same verified email, creating that
+/// user on first login.
+pub struct CompleteFederatedLoginUseCase<
+    UR: UserRepository,
+    SR: SessionRepository,
+    RR: RoleRepository,
+    PR: PermissionRepository,
+    MR: MfaRepository,
+    IR: IdentityProviderRepository,
+    FR: FederatedIdentityRepository,
+    AR: AuditEventRepository,
+> {
+    user_repository: Arc<UR>,
+    password_service: Arc<PasswordService>,
+    session_service: Arc<SessionService<SR>>,
+    authorization_service: Arc<AuthorizationService<RR, PR>>,
+    mfa_service: Arc<MfaService<MR>>,
+    federation_service: Arc<FederationService<IR, FR>>,
+    audit_log: Arc<AuditLog<AR>>,
+}
+
+impl<UR, SR, RR, PR, MR, IR, FR, AR> CompleteFederatedLoginUseCase<UR, SR, RR, PR, MR, IR, FR, AR>
+where
+    UR: UserRepository,
+    SR: SessionRepository,
+    RR: RoleRepository,
+    PR: PermissionRepository,
+    MR: MfaRepository,
+    IR: IdentityProviderRepository,
+    FR: FederatedIdentityRepository,
+    AR: AuditEventRepository,
+{
+    pub fn new(
+        user_repository: Arc<UR>,
+        password_service: Arc<PasswordService>,
+        session_service: Arc<SessionService<SR>>,
+        authorization_service: Arc<AuthorizationService<RR, PR>>,
+        mfa_service: Arc<MfaService<MR>>,
+        federation_service: Arc<FederationService<IR, FR>>,
+        audit_log: Arc<AuditLog<AR>>,
+    ) -> Self {
+        Self {
+            user_repository,
+            password_service,
+            session_service,
+            authorization_service,
+            mfa_service,
+            federation_service,
+            audit_log,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        provider: &str,
+        dto: FederatedCallbackDto,
+        client: ClientInfoDto,
+    ) -> Result<LoginResponseDto, ApplicationError> {
+        if let Some(error) = dto.error {
+            let description = dto.error_description.map(|d| format!(" ({})", d)).unwrap_or_default();
+            return Err(ApplicationError::Domain(DomainError::FederatedLoginFailed(format!(
+                "Provider returned {}{}",
+                error, description
+            ))));
+        }
+        let (Some(code), Some(state)) = (dto.code, dto.state) else {
+            return Err(ApplicationError::Validation("code and state are required".to_string()));
+        };
+
+        let external = self.federation_service.complete(provider, &code, &state).await?;
+
+        let user = match self.federation_service.linked_user(&external).await? {
+            Some(user_id) => self.user_repository.find_by_id(&user_id).await?
+                .ok_or(ApplicationError::Domain(DomainError::UserNotFound))?,
+            None => self.link(&external).await?,
+        };
+
+        if !user.is_active {
+            return Err(ApplicationError::Domain(DomainError::Unauthorized));
+        }
+
+        // The provider stands in for the password only; a second factor is still required
+        if self.mfa_service.is_enabled(&user.id).await? {
+            let (challenge_token, expires_at) = self.mfa_service.start_challenge(&user.id).await?;
+            return Ok(LoginResponseDto::MfaRequired(MfaChallengeDto {
+                mfa_required: true,
+                challenge_token: challenge_token.as_str().to_string(),
+                expires_at: expires_at.to_rfc3339(),
+            }));
+        }
+
+        let grants = self.authorization_service.grants(&user.id).await?;
+        let issued = self.session_service.start(&user.id, &grants, client.device()).await?;
+        self.audit_log.record(
+            NewAuditEvent::new(AuditEventType::LoginSucceeded)
+                .with_actor(user.id.as_uuid().to_string())
+                .with_user(&user.id)
+                .with_ip(client.ip_address.clone())
+                .with_details(json!({
+                    "method": "oidc",
+                    "provider": provider,
+                    "session_id": issued.session.family_id.as_uuid().to_string(),
+                })),
+        ).await;
+
+        Ok(LoginResponseDto::Session(AuthResponseDto {
+            token: issued.session.token.as_str().to_string(),
+            expires_at: issued.session.expires_at.to_rfc3339(),
+            refresh_token: issued.refresh_token.as_str().to_string(),
+            refresh_expires_at: issued.session.refresh_expires_at.to_rfc3339(),
+        }))
+    }
+
+    /// First login with this provider account.
+    async fn link(&self, external: &ExternalIdentity) -> Result<User, ApplicationError> {
+        let email = match external.verified_email() {
+            Some(email) => Email::new(email.to_string())?,
+            None => {
+                return Err(ApplicationError::Domain(DomainError::FederatedLoginFailed(
+                    "Provider did not return a verified email".to_string(),
+                )))
+            }
+        };
+
+        let user = match self.user_repository.find_by_email(&email).await? {
+            Some(mut user) => {
+                if !user.is_email_verified() {
+                    // Whoever registered the address never proved they own it; their
+                    // password and sessions must not outlive the owner signing in
+                    user.update_password(self.password_service.unusable_hash()?);
+                    user.verify_email();
+                    self.user_repository.update(&user).await
+                        .map_err(|e| ApplicationError::Repository(e.to_string()))?;
+                    self.session_service.end_all(&user.id).await
+                        .map_err(|e| ApplicationError::Repository(e.to_string()))?;
+                }
+                user
+            }
+            None => {
+                let mut user = User::new(UserId::new(), email, self.password_service.unusable_hash()?);
+                user.verify_email();
+                self.user_repository.create(&user).await
+                    .map_err(|e| ApplicationError::Repository(e.to_string()))?;
+                user
+            }
+        };
+
+        self.federation_service.link(external, &user.id).await
+            .map_err(|e| ApplicationError::Repository(e.to_string()))?;
+
+        Ok(user)
+    }
+}
@@ -0,0 +1,23 @@
+use std::sync::Arc;
+use crate::application::dto::FederatedIdentityDto;
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::FederatedIdentityRepository;
+use crate::domain::value_objects::UserId;
+
+pub struct ListFederatedIdentitiesUseCase<FR: FederatedIdentityRepository> {
+    identity_repository: Arc<FR>,
+}
+
+impl<FR: FederatedIdentityRepository> ListFederatedIdentitiesUseCase<FR> {
+    pub fn new(identity_repository: Arc<FR>) -> Self {
+        Self { identity_repository }
+    }
+
+    pub async fn execute(&self, user_id: &str) -> Result<Vec<FederatedIdentityDto>, ApplicationError> {
+        let user_id = uuid::Uuid::parse_str(user_id)
+            .map_err(|_| ApplicationError::Validation("Invalid user ID format".to_string()))?;
+
+        let identities = self.identity_repository.find_identities_by_user_id(&UserId::from_uuid(user_id)).await?;
+        Ok(identities.iter().map(FederatedIdentityDto::from).collect())
+    }
+}
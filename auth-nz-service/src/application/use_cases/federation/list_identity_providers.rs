@@ -0,0 +1,19 @@
+use std::sync::Arc;
+use crate::application::dto::IdentityProviderDto;
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::IdentityProviderRepository;
+
+pub struct ListIdentityProvidersUseCase<IR: IdentityProviderRepository> {
+    provider_repository: Arc<IR>,
+}
+
+impl<IR: IdentityProviderRepository> ListIdentityProvidersUseCase<IR> {
+    pub fn new(provider_repository: Arc<IR>) -> Self {
+        Self { provider_repository }
+    }
+
+    pub async fn execute(&self) -> Result<Vec<IdentityProviderDto>, ApplicationError> {
+        let providers = self.provider_repository.find_all().await?;
+        Ok(providers.iter().map(IdentityProviderDto::from).collect())
+    }
+}
@@ -0,0 +1,9 @@
+pub mod list_identity_providers;
+pub mod start_federated_login;
+pub mod complete_federated_login;
+pub mod list_federated_identities;
+
+pub use list_identity_providers::ListIdentityProvidersUseCase;
+pub use start_federated_login::StartFederatedLoginUseCase;
+pub use complete_federated_login::CompleteFederatedLoginUseCase;
+pub use list_federated_identities::ListFederatedIdentitiesUseCase;
@@ -0,0 +1,19 @@
+use std::sync::Arc;
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::{FederatedIdentityRepository, IdentityProviderRepository};
+use crate::domain::services::FederationService;
+
+/// Returns the provider authorization URL the browser is redirected to.
+pub struct StartFederatedLoginUseCase<IR: IdentityProviderRepository, FR: FederatedIdentityRepository> {
+    federation_service: Arc<FederationService<IR, FR>>,
+}
+
+impl<IR: IdentityProviderRepository, FR: FederatedIdentityRepository> StartFederatedLoginUseCase<IR, FR> {
+    pub fn new(federation_service: Arc<FederationService<IR, FR>>) -> Self {
+        Self { federation_service }
+    }
+
+    pub async fn execute(&self, provider: &str) -> Result<String, ApplicationError> {
+        Ok(self.federation_service.start(provider).await?)
+    }
+}
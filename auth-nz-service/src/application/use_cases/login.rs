@@ -1,53 +1,148 @@
 use std::sync::Arc;
-use crate::application::dto::{LoginDto, AuthResponseDto};
+use serde_json::json;
+use crate::application::dto::{LoginDto, AuthResponseDto, ClientInfoDto, LoginResponseDto, MfaChallengeDto};
 use crate::application::errors::ApplicationError;
-use crate::domain::repositories::{UserRepository, SessionRepository};
-use crate::domain::value_objects::{Email, PasswordHash, SessionId, Token};
-use crate::domain::entities::session::Session;
+use crate::domain::entities::audit_event::NewAuditEvent;
+use crate::domain::events::EventPublisher;
+use crate::domain::repositories::{UserRepository, SessionRepository, RoleRepository, PermissionRepository, MfaRepository, LoginAttemptRepository, AuditEventRepository};
+use crate::domain::services::{AuditLog, AuthorizationService, LoginThrottle, MfaService, PasswordService, SessionService};
+use crate::domain::value_objects::{AuditEventType, Email, UserId};
 use crate::domain::errors::DomainError;
-use chrono::{Duration, Utc};
 
-pub struct LoginUseCase<UR: UserRepository, SR: SessionRepository> {
+pub struct LoginUseCase<
+    UR: UserRepository,
+    SR: SessionRepository,
+    RR: RoleRepository,
+    PR: PermissionRepository,
+    MR: MfaRepository,
+    LR: LoginAttemptRepository,
+    EP: EventPublisher,
+    AR: AuditEventRepository,
+> {
     user_repository: Arc<UR>,
-    session_repository: Arc<SR>,
+    password_service: Arc<PasswordService>,
+    session_service: Arc<SessionService<SR>>,
+    authorization_service: Arc<AuthorizationService<RR, PR>>,
+    mfa_service: Arc<MfaService<MR>>,
+    login_throttle: Arc<LoginThrottle<LR, EP>>,
+    audit_log: Arc<AuditLog<AR>>,
 }
 
-impl<UR: UserRepository, SR: SessionRepository> LoginUseCase<UR, SR> {
-    pub fn new(user_repository: Arc<UR>, session_repository: Arc<SR>) -> Self {
+impl<UR, SR, RR, PR, MR, LR, EP, AR> LoginUseCase<UR, SR, RR, PR, MR, LR, EP, AR>
+where
+    UR: UserRepository,
+    SR: SessionRepository,
+    RR: RoleRepository,
+    PR: PermissionRepository,
+    MR: MfaRepository,
+    LR: LoginAttemptRepository,
+    EP: EventPublisher,
+    AR: AuditEventRepository,
+{
+    pub fn new(
+        user_repository: Arc<UR>,
+        password_service: Arc<PasswordService>,
+        session_service: Arc<SessionService<SR>>,
+        authorization_service: Arc<AuthorizationService<RR, PR>>,
+        mfa_service: Arc<MfaService<MR>>,
+        login_throttle: Arc<LoginThrottle<LR, EP>>,
+        audit_log: Arc<AuditLog<AR>>,
+    ) -> Self {
         Self {
             user_repository,
-            session_repository,
+            password_service,
+            session_service,
+            authorization_service,
+            mfa_service,
+            login_throttle,
+            audit_log,
         }
     }
 
-    pub async fn execute(&self, dto: LoginDto) -> Result<AuthResponseDto, ApplicationError> {
+    pub async fn execute(&self, dto: LoginDto, client: ClientInfoDto) -> Result<LoginResponseDto, ApplicationError> {
         let email = Email::new(dto.email)?;
-        
-        let user = self.user_repository.find_by_email(&email).await?
-            .ok_or(ApplicationError::Domain(DomainError::UserNotFound))?;
 
+        if let Err(e) = self.login_throttle.check(&email, &client.ip_address).await {
+            if matches!(e, DomainError::AccountLocked(_)) {
+                self.record_failure(&email, None, &client, "locked").await;
+            }
+            return Err(e.into());
+        }
+
+        // Every failure below looks the same to the caller, so /login cannot be
+        // used to find out which emails are registered
+        let mut user = match self.user_repository.find_by_email(&email).await? {
+            Some(user) => user,
+            None => {
+                self.password_service.verify_dummy(&dto.password);
+                // Unknown emails count too, so probing for accounts is throttled the same way
+                self.login_throttle.record_failure(&email, &client.ip_address, None).await?;
+                self.record_failure(&email, None, &client, "unknown_email").await;
+                return Err(ApplicationError::Domain(DomainError::InvalidCredentials));
+            }
+        };
+
+        if !self.password_service.verify(&dto.password, &user.password_hash)? {
+            self.login_throttle.record_failure(&email, &client.ip_address, Some(&user.id)).await?;
+            self.record_failure(&email, Some(&user.id), &client, "invalid_password").await;
+            return Err(ApplicationError::Domain(DomainError::InvalidCredentials));
+        }
+
+        // Only checked once the password matched, or it would tell the account exists
         if !user.is_active {
-            return Err(ApplicationError::Domain(DomainError::Unauthorized));
+            self.record_failure(&email, Some(&user.id), &client, "inactive").await;
+            return Err(ApplicationError::Domain(DomainError::InvalidCredentials));
+        }
+
+        // Upgrade legacy or weaker hashes while the plaintext is at hand
+        if self.password_service.needs_rehash(&user.password_hash) {
+            user.update_password(self.password_service.hash(&dto.password)?);
+            if let Err(e) = self.user_repository.update(&user).await {
+                tracing::warn!("Failed to rehash password for user {}: {}", user.id.as_uuid(), e);
+            }
         }
 
-        // TODO: Verify password hash
-        // For now, just check if password matches (in real implementation, use argon2)
-        if user.password_hash.as_str() != dto.password {
-            return Err(ApplicationError::Domain(DomainError::InvalidPassword));
+        // The password alone is not enough; the session opens once the second factor is
+        // verified, and only then are the account's failed attempts forgotten
+        if self.mfa_service.is_enabled(&user.id).await? {
+            let (challenge_token, expires_at) = self.mfa_service.start_challenge(&user.id).await?;
+            return Ok(LoginResponseDto::MfaRequired(MfaChallengeDto {
+                mfa_required: true,
+                challenge_token: challenge_token.as_str().to_string(),
+                expires_at: expires_at.to_rfc3339(),
+            }));
         }
 
         // Create session
-        let token = Token::new(uuid::Uuid::new_v4().to_string()); // TODO: generate JWT
-        let expires_at = Utc::now() + Duration::hours(24);
-        let session = Session::new(SessionId::new(), user.id, token.clone(), expires_at);
-        
-        self.session_repository.create(&session).await
-            .map_err(|e| ApplicationError::Repository(e.to_string()))?;
-
-        Ok(AuthResponseDto {
-            token: token.as_str().to_string(),
-            expires_at: expires_at.to_rfc3339(),
-        })
+        let grants = self.authorization_service.grants(&user.id).await?;
+        let issued = self.session_service.start(&user.id, &grants, client.device()).await?;
+        self.login_throttle.record_success(&email).await?;
+        self.audit_log.record(
+            NewAuditEvent::new(AuditEventType::LoginSucceeded)
+                .with_actor(user.id.as_uuid().to_string())
+                .with_user(&user.id)
+                .with_ip(client.ip_address.clone())
+                .with_details(json!({
+                    "method": "password",
+                    "session_id": issued.session.family_id.as_uuid().to_string(),
+                })),
+        ).await;
+
+        Ok(LoginResponseDto::Session(AuthResponseDto {
+            token: issued.session.token.as_str().to_string(),
+            expires_at: issued.session.expires_at.to_rfc3339(),
+            refresh_token: issued.refresh_token.as_str().to_string(),
+            refresh_expires_at: issued.session.refresh_expires_at.to_rfc3339(),
+        }))
     }
-}
 
+    async fn record_failure(&self, email: &Email, user_id: Option<&UserId>, client: &ClientInfoDto, reason: &str) {
+        let mut event = NewAuditEvent::new(AuditEventType::LoginFailed)
+            .with_ip(client.ip_address.clone())
+            .with_details(json!({ "email": email.as_str(), "reason": reason }));
+        if let Some(user_id) = user_id {
+            event = event.with_user(user_id);
+        }
+        self.audit_log.record(event).await;
+    }
+}
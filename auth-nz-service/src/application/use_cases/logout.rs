@@ -1,16 +1,23 @@
 use std::sync::Arc;
+use serde_json::json;
 use crate::application::errors::ApplicationError;
-use crate::domain::repositories::SessionRepository;
-use crate::domain::value_objects::{SessionId, Token};
+use crate::domain::entities::audit_event::NewAuditEvent;
+use crate::domain::repositories::{SessionRepository, AuditEventRepository};
+use crate::domain::services::AuditLog;
+use crate::domain::value_objects::{AuditEventType, Token};
 use crate::domain::errors::DomainError;
 
-pub struct LogoutUseCase<SR: SessionRepository> {
+pub struct LogoutUseCase<SR: SessionRepository, AR: AuditEventRepository> {
     session_repository: Arc<SR>,
+    audit_log: Arc<AuditLog<AR>>,
 }
 
-impl<SR: SessionRepository> LogoutUseCase<SR> {
-    pub fn new(session_repository: Arc<SR>) -> Self {
-        Self { session_repository }
+impl<SR: SessionRepository, AR: AuditEventRepository> LogoutUseCase<SR, AR> {
+    pub fn new(session_repository: Arc<SR>, audit_log: Arc<AuditLog<AR>>) -> Self {
+        Self {
+            session_repository,
+            audit_log,
+        }
     }
 
     pub async fn execute(&self, token: &str) -> Result<(), ApplicationError> {
@@ -21,7 +28,16 @@ impl<SR: SessionRepository> LogoutUseCase<SR> {
         self.session_repository.delete(&session.id).await
             .map_err(|e| ApplicationError::Repository(e.to_string()))?;
 
+        self.audit_log.record(
+            NewAuditEvent::new(AuditEventType::SessionRevoked)
+                .with_actor(session.user_id.as_uuid().to_string())
+                .with_user(&session.user_id)
+                .with_details(json!({
+                    "reason": "logout",
+                    "session_id": session.family_id.as_uuid().to_string(),
+                })),
+        ).await;
+
         Ok(())
     }
 }
-
@@ -0,0 +1,27 @@
+use std::sync::Arc;
+use crate::application::dto::{ConfirmMfaDto, RecoveryCodesDto};
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::MfaRepository;
+use crate::domain::services::MfaService;
+use crate::domain::value_objects::UserId;
+
+pub struct ConfirmMfaUseCase<MR: MfaRepository> {
+    mfa_service: Arc<MfaService<MR>>,
+}
+
+impl<MR: MfaRepository> ConfirmMfaUseCase<MR> {
+    pub fn new(mfa_service: Arc<MfaService<MR>>) -> Self {
+        Self { mfa_service }
+    }
+
+    pub async fn execute(&self, user_id: &str, dto: ConfirmMfaDto) -> Result<RecoveryCodesDto, ApplicationError> {
+        let user_id = uuid::Uuid::parse_str(user_id)
+            .map_err(|_| ApplicationError::Validation("Invalid user ID format".to_string()))?;
+
+        let codes = self.mfa_service.confirm(&UserId::from_uuid(user_id), &dto.code).await?;
+
+        Ok(RecoveryCodesDto {
+            recovery_codes: codes.into_iter().map(String::from).collect(),
+        })
+    }
+}
@@ -0,0 +1,49 @@
+use std::sync::Arc;
+use crate::application::dto::DisableMfaDto;
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::{UserRepository, MfaRepository};
+use crate::domain::services::{MfaService, PasswordService};
+use crate::domain::value_objects::UserId;
+use crate::domain::errors::DomainError;
+
+/// Turns MFA off. A valid access token is not enough: the caller re-enters
+/// their password and a current code so a stolen session cannot strip MFA.
+pub struct DisableMfaUseCase<UR: UserRepository, MR: MfaRepository> {
+    user_repository: Arc<UR>,
+    password_service: Arc<PasswordService>,
+    mfa_service: Arc<MfaService<MR>>,
+}
+
+impl<UR: UserRepository, MR: MfaRepository> DisableMfaUseCase<UR, MR> {
+    pub fn new(
+        user_repository: Arc<UR>,
+        password_service: Arc<PasswordService>,
+        mfa_service: Arc<MfaService<MR>>,
+    ) -> Self {
+        Self {
+            user_repository,
+            password_service,
+            mfa_service,
+        }
+    }
+
+    pub async fn execute(&self, user_id: &str, dto: DisableMfaDto) -> Result<(), ApplicationError> {
+        let user_id = uuid::Uuid::parse_str(user_id)
+            .map_err(|_| ApplicationError::Validation("Invalid user ID format".to_string()))?;
+
+        let user = self.user_repository.find_by_id(&UserId::from_uuid(user_id)).await?
+            .ok_or(ApplicationError::Domain(DomainError::UserNotFound))?;
+
+        if !self.password_service.verify(&dto.password, &user.password_hash)? {
+            return Err(ApplicationError::Domain(DomainError::InvalidPassword));
+        }
+
+        self.mfa_service.verify(&user.id, &dto.code).await?;
+        self.mfa_service.disable(&user.id).await
+            .map_err(|e| ApplicationError::Repository(e.to_string()))?;
+
+        tracing::info!("MFA disabled for user {}", user.id.as_uuid());
+
+        Ok(())
+    }
+}
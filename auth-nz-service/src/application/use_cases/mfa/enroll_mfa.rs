@@ -0,0 +1,36 @@
+use std::sync::Arc;
+use crate::application::dto::MfaEnrollmentDto;
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::{UserRepository, MfaRepository};
+use crate::domain::services::MfaService;
+use crate::domain::value_objects::UserId;
+use crate::domain::errors::DomainError;
+
+pub struct EnrollMfaUseCase<UR: UserRepository, MR: MfaRepository> {
+    user_repository: Arc<UR>,
+    mfa_service: Arc<MfaService<MR>>,
+}
+
+impl<UR: UserRepository, MR: MfaRepository> EnrollMfaUseCase<UR, MR> {
+    pub fn new(user_repository: Arc<UR>, mfa_service: Arc<MfaService<MR>>) -> Self {
+        Self {
+            user_repository,
+            mfa_service,
+        }
+    }
+
+    pub async fn execute(&self, user_id: &str) -> Result<MfaEnrollmentDto, ApplicationError> {
+        let user_id = uuid::Uuid::parse_str(user_id)
+            .map_err(|_| ApplicationError::Validation("Invalid user ID format".to_string()))?;
+
+        let user = self.user_repository.find_by_id(&UserId::from_uuid(user_id)).await?
+            .ok_or(ApplicationError::Domain(DomainError::UserNotFound))?;
+
+        let (secret, otpauth_uri) = self.mfa_service.enroll(&user.id, user.email.as_str()).await?;
+
+        Ok(MfaEnrollmentDto {
+            secret: secret.as_str().to_string(),
+            otpauth_uri,
+        })
+    }
+}
@@ -0,0 +1,9 @@
+pub mod enroll_mfa;
+pub mod confirm_mfa;
+pub mod verify_mfa;
+pub mod disable_mfa;
+
+pub use enroll_mfa::EnrollMfaUseCase;
+pub use confirm_mfa::ConfirmMfaUseCase;
+pub use verify_mfa::VerifyMfaUseCase;
+pub use disable_mfa::DisableMfaUseCase;
@@ -0,0 +1,124 @@
+use std::sync::Arc;
+use serde_json::json;
+use crate::application::dto::{VerifyMfaDto, AuthResponseDto, ClientInfoDto};
+use crate::application::errors::ApplicationError;
+use crate::domain::entities::audit_event::NewAuditEvent;
+use crate::domain::events::EventPublisher;
+use crate::domain::repositories::{
+    UserRepository, SessionRepository, RoleRepository, PermissionRepository, MfaRepository, LoginAttemptRepository,
+    AuditEventRepository,
+};
+use crate::domain::services::{AuditLog, AuthorizationService, LoginThrottle, MfaService, SessionService};
+use crate::domain::value_objects::{AuditEventType, MfaChallengeToken};
+use crate::domain::errors::DomainError;
+
+/// Second step of a login for users with MFA enabled. Wrong codes count
+/// against the same per-account and per-IP limits as wrong passwords, and the
+/// account's failures are only cleared once this step succeeds.
+pub struct VerifyMfaUseCase<
+    UR: UserRepository,
+    SR: SessionRepository,
+    RR: RoleRepository,
+    PR: PermissionRepository,
+    MR: MfaRepository,
+    LR: LoginAttemptRepository,
+    EP: EventPublisher,
+    AR: AuditEventRepository,
+> {
+    user_repository: Arc<UR>,
+    mfa_service: Arc<MfaService<MR>>,
+    session_service: Arc<SessionService<SR>>,
+    authorization_service: Arc<AuthorizationService<RR, PR>>,
+    login_throttle: Arc<LoginThrottle<LR, EP>>,
+    audit_log: Arc<AuditLog<AR>>,
+}
+
+impl<UR, SR, RR, PR, MR, LR, EP, AR> VerifyMfaUseCase<UR, SR, RR, PR, MR, LR, EP, AR>
+where
+    UR: UserRepository,
+    SR: SessionRepository,
+    RR: RoleRepository,
+    PR: PermissionRepository,
+    MR: MfaRepository,
+    LR: LoginAttemptRepository,
+    EP: EventPublisher,
+    AR: AuditEventRepository,
+{
+    pub fn new(
+        user_repository: Arc<UR>,
+        mfa_service: Arc<MfaService<MR>>,
+        session_service: Arc<SessionService<SR>>,
+        authorization_service: Arc<AuthorizationService<RR, PR>>,
+        login_throttle: Arc<LoginThrottle<LR, EP>>,
+        audit_log: Arc<AuditLog<AR>>,
+    ) -> Self {
+        Self {
+            user_repository,
+            mfa_service,
+            session_service,
+            authorization_service,
+            login_throttle,
+            audit_log,
+        }
+    }
+
+    pub async fn execute(&self, dto: VerifyMfaDto, client: ClientInfoDto) -> Result<AuthResponseDto, ApplicationError> {
+        let challenge_token = MfaChallengeToken::new(dto.challenge_token);
+
+        // Unknown or spent challenges fail below without touching any account
+        let user = match self.mfa_service.challenge_user(&challenge_token).await? {
+            Some(user_id) => self.user_repository.find_by_id(&user_id).await?,
+            None => None,
+        };
+        if let Some(user) = &user {
+            self.login_throttle.check(&user.email, &client.ip_address).await?;
+        }
+
+        let user_id = match self.mfa_service.complete_challenge(&challenge_token, &dto.code).await {
+            Ok(user_id) => user_id,
+            Err(e) => {
+                let reason = match e {
+                    DomainError::InvalidMfaCode => Some("invalid_mfa_code"),
+                    DomainError::InvalidMfaChallenge => Some("invalid_mfa_challenge"),
+                    _ => None,
+                };
+                if let (DomainError::InvalidMfaCode, Some(user)) = (&e, &user) {
+                    self.login_throttle.record_failure(&user.email, &client.ip_address, Some(&user.id)).await?;
+                }
+                if let Some(reason) = reason {
+                    let mut event = NewAuditEvent::new(AuditEventType::LoginFailed)
+                        .with_ip(client.ip_address.clone())
+                        .with_details(json!({ "method": "mfa", "reason": reason }));
+                    if let Some(user) = &user {
+                        event = event.with_user(&user.id);
+                    }
+                    self.audit_log.record(event).await;
+                }
+                return Err(e.into());
+            }
+        };
+
+        let grants = self.authorization_service.grants(&user_id).await?;
+        let issued = self.session_service.start(&user_id, &grants, client.device()).await?;
+        if let Some(user) = &user {
+            self.login_throttle.record_success(&user.email).await?;
+        }
+        self.audit_log.record(
+            NewAuditEvent::new(AuditEventType::LoginSucceeded)
+                .with_actor(user_id.as_uuid().to_string())
+                .with_user(&user_id)
+                .with_ip(client.ip_address.clone())
+                .with_details(json!({
+                    "method": "mfa",
+                    "session_id": issued.session.family_id.as_uuid().to_string(),
+                })),
+        ).await;
+
+        Ok(AuthResponseDto {
+            token: issued.session.token.as_str().to_string(),
+            expires_at: issued.session.expires_at.to_rfc3339(),
+            refresh_token: issued.refresh_token.as_str().to_string(),
+            refresh_expires_at: issued.session.refresh_expires_at.to_rfc3339(),
+        })
+    }
+}
@@ -1,8 +1,31 @@
 pub mod register;
 pub mod login;
 pub mod logout;
+pub mod refresh_session;
+pub mod rbac;
+pub mod mfa;
+pub mod account;
+pub mod sessions;
+pub mod oauth;
+pub mod federation;
+pub mod audit;
+pub mod service_accounts;
+pub mod passwordless;
+pub mod organizations;
+pub mod authorize;
 
 pub use register::RegisterUseCase;
 pub use login::LoginUseCase;
 pub use logout::LogoutUseCase;
-
+pub use refresh_session::RefreshSessionUseCase;
+pub use rbac::*;
+pub use mfa::*;
+pub use account::*;
+pub use sessions::*;
+pub use oauth::*;
+pub use federation::*;
+pub use audit::*;
+pub use service_accounts::*;
+pub use passwordless::*;
+pub use organizations::*;
+pub use authorize::AuthorizeUseCase;
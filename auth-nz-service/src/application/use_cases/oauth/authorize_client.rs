@@ -0,0 +1,61 @@
+use std::sync::Arc;
+use crate::application::dto::{AuthorizationRequestDto, AuthorizationResponseDto, OAuthErrorDto};
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::{OAuthClientRepository, OAuthGrantRepository};
+use crate::domain::services::OAuthService;
+use crate::domain::value_objects::UserId;
+
+/// `GET /authorize` for a signed-in user. Issues a code straight away when
+/// the user already consented to the requested scopes.
+pub struct AuthorizeClientUseCase<CR: OAuthClientRepository, GR: OAuthGrantRepository> {
+    oauth_service: Arc<OAuthService<CR, GR>>,
+}
+
+impl<CR: OAuthClientRepository, GR: OAuthGrantRepository> AuthorizeClientUseCase<CR, GR> {
+    pub fn new(oauth_service: Arc<OAuthService<CR, GR>>) -> Self {
+        Self { oauth_service }
+    }
+
+    pub async fn execute(&self, user_id: &str, dto: AuthorizationRequestDto) -> Result<AuthorizationResponseDto, ApplicationError> {
+        let user_id = uuid::Uuid::parse_str(user_id)
+            .map_err(|_| ApplicationError::Validation("Invalid user ID format".to_string()))?;
+        let user_id = UserId::from_uuid(user_id);
+
+        let client = self.oauth_service.authorization_client(&dto.client_id, &dto.redirect_uri).await?;
+        let state = dto.state.as_deref().unwrap_or_default();
+
+        // From here on the redirect URI is trusted, so errors go back to the client through it
+        let scopes = match self.oauth_service.validate_authorization(
+            &client,
+            &dto.response_type,
+            dto.scope.as_deref(),
+            dto.code_challenge.as_deref(),
+            dto.code_challenge_method.as_deref(),
+        ) {
+            Ok(scopes) => scopes,
+            Err(e) => {
+                let error = OAuthErrorDto::from_domain(&e);
+                return Ok(AuthorizationResponseDto::redirect(
+                    &dto.redirect_uri,
+                    &[("error", &error.error), ("error_description", &error.error_description), ("state", state)],
+                ));
+            }
+        };
+
+        if !self.oauth_service.has_consent(&user_id, &client.id, &scopes).await? {
+            return Ok(AuthorizationResponseDto::ConsentRequired {
+                consent_required: true,
+                client_id: client.id,
+                client_name: client.name,
+                scopes: scopes.to_vec(),
+            });
+        }
+
+        let code_challenge = dto.code_challenge.unwrap_or_default();
+        let code = self.oauth_service
+            .issue_code(&user_id, &client, &dto.redirect_uri, scopes, &code_challenge)
+            .await?;
+
+        Ok(AuthorizationResponseDto::redirect(&dto.redirect_uri, &[("code", code.as_str()), ("state", state)]))
+    }
+}
@@ -0,0 +1,70 @@
+use std::sync::Arc;
+use crate::application::dto::{CreateOAuthClientDto, CreatedOAuthClientDto, OAuthClientDto};
+use crate::application::errors::ApplicationError;
+use crate::domain::entities::oauth_client::OAuthClient;
+use crate::domain::entities::permission::Permission;
+use crate::domain::repositories::{OAuthClientRepository, PermissionRepository};
+use crate::domain::value_objects::{ClientSecret, GrantType, Scopes};
+use crate::domain::errors::DomainError;
+
+pub struct CreateOAuthClientUseCase<CR: OAuthClientRepository, PR: PermissionRepository> {
+    client_repository: Arc<CR>,
+    permission_repository: Arc<PR>,
+}
+
+impl<CR: OAuthClientRepository, PR: PermissionRepository> CreateOAuthClientUseCase<CR, PR> {
+    pub fn new(client_repository: Arc<CR>, permission_repository: Arc<PR>) -> Self {
+        Self {
+            client_repository,
+            permission_repository,
+        }
+    }
+
+    pub async fn execute(&self, dto: CreateOAuthClientDto) -> Result<CreatedOAuthClientDto, ApplicationError> {
+        if dto.name.trim().is_empty() {
+            return Err(ApplicationError::Validation("Client name is required".to_string()));
+        }
+
+        let grant_types = dto.grant_types.iter()
+            .map(|grant_type| GrantType::parse(grant_type))
+            .collect::<Result<Vec<_>, _>>()?;
+        if grant_types.is_empty() {
+            return Err(ApplicationError::Validation("At least one grant type is required".to_string()));
+        }
+        if grant_types.contains(&GrantType::ClientCredentials) && !dto.confidential {
+            return Err(ApplicationError::Validation("client_credentials requires a confidential client".to_string()));
+        }
+        if grant_types.contains(&GrantType::AuthorizationCode) && dto.redirect_uris.is_empty() {
+            return Err(ApplicationError::Validation("authorization_code requires at least one redirect URI".to_string()));
+        }
+        if let Some(uri) = dto.redirect_uris.iter().find(|uri| !uri.contains(':') || uri.contains('#')) {
+            return Err(ApplicationError::Validation(format!("Invalid redirect URI: {}", uri)));
+        }
+
+        // Every scope has to name an existing permission
+        let scopes = Scopes::from_vec(dto.scopes)?;
+        let known: Vec<String> = self.permission_repository.find_all().await?
+            .iter()
+            .map(Permission::scope)
+            .collect();
+        if let Some(unknown) = scopes.iter().find(|scope| !known.iter().any(|known| known == scope)) {
+            return Err(ApplicationError::Domain(DomainError::InvalidScope(format!("No permission matches '{}'", unknown))));
+        }
+
+        let secret = dto.confidential.then(ClientSecret::generate);
+        let client = OAuthClient::new(
+            dto.name,
+            secret.as_ref().map(ClientSecret::hash),
+            dto.redirect_uris,
+            grant_types,
+            scopes,
+        );
+        self.client_repository.create(&client).await
+            .map_err(|e| ApplicationError::Repository(e.to_string()))?;
+
+        Ok(CreatedOAuthClientDto {
+            client: OAuthClientDto::from(&client),
+            client_secret: secret.map(String::from),
+        })
+    }
+}
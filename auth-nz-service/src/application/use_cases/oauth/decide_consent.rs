@@ -0,0 +1,63 @@
+use std::sync::Arc;
+use crate::application::dto::{AuthorizationResponseDto, ConsentDecisionDto, OAuthErrorDto};
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::{OAuthClientRepository, OAuthGrantRepository};
+use crate::domain::services::OAuthService;
+use crate::domain::value_objects::UserId;
+
+/// Records the user's answer to a consent prompt and completes the
+/// authorization request it was shown for.
+pub struct DecideConsentUseCase<CR: OAuthClientRepository, GR: OAuthGrantRepository> {
+    oauth_service: Arc<OAuthService<CR, GR>>,
+}
+
+impl<CR: OAuthClientRepository, GR: OAuthGrantRepository> DecideConsentUseCase<CR, GR> {
+    pub fn new(oauth_service: Arc<OAuthService<CR, GR>>) -> Self {
+        Self { oauth_service }
+    }
+
+    pub async fn execute(&self, user_id: &str, dto: ConsentDecisionDto) -> Result<AuthorizationResponseDto, ApplicationError> {
+        let user_id = uuid::Uuid::parse_str(user_id)
+            .map_err(|_| ApplicationError::Validation("Invalid user ID format".to_string()))?;
+        let user_id = UserId::from_uuid(user_id);
+        let request = dto.request;
+
+        let client = self.oauth_service.authorization_client(&request.client_id, &request.redirect_uri).await?;
+        let state = request.state.as_deref().unwrap_or_default();
+
+        // The request is validated again; nothing from the prompt is trusted
+        let scopes = match self.oauth_service.validate_authorization(
+            &client,
+            &request.response_type,
+            request.scope.as_deref(),
+            request.code_challenge.as_deref(),
+            request.code_challenge_method.as_deref(),
+        ) {
+            Ok(scopes) => scopes,
+            Err(e) => {
+                let error = OAuthErrorDto::from_domain(&e);
+                return Ok(AuthorizationResponseDto::redirect(
+                    &request.redirect_uri,
+                    &[("error", &error.error), ("error_description", &error.error_description), ("state", state)],
+                ));
+            }
+        };
+
+        if !dto.approve {
+            return Ok(AuthorizationResponseDto::redirect(
+                &request.redirect_uri,
+                &[("error", "access_denied"), ("state", state)],
+            ));
+        }
+
+        self.oauth_service.grant_consent(&user_id, &client.id, &scopes).await
+            .map_err(|e| ApplicationError::Repository(e.to_string()))?;
+
+        let code_challenge = request.code_challenge.unwrap_or_default();
+        let code = self.oauth_service
+            .issue_code(&user_id, &client, &request.redirect_uri, scopes, &code_challenge)
+            .await?;
+
+        Ok(AuthorizationResponseDto::redirect(&request.redirect_uri, &[("code", code.as_str()), ("state", state)]))
+    }
+}
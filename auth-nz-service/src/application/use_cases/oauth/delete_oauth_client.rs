@@ -0,0 +1,26 @@
+use std::sync::Arc;
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::OAuthClientRepository;
+use crate::domain::errors::DomainError;
+
+/// Removes the client together with its codes, consents and sessions.
+pub struct DeleteOAuthClientUseCase<CR: OAuthClientRepository> {
+    client_repository: Arc<CR>,
+}
+
+impl<CR: OAuthClientRepository> DeleteOAuthClientUseCase<CR> {
+    pub fn new(client_repository: Arc<CR>) -> Self {
+        Self { client_repository }
+    }
+
+    pub async fn execute(&self, client_id: &str) -> Result<(), ApplicationError> {
+        if self.client_repository.find_by_id(client_id).await?.is_none() {
+            return Err(ApplicationError::Domain(DomainError::OAuthClientNotFound));
+        }
+
+        self.client_repository.delete(client_id).await
+            .map_err(|e| ApplicationError::Repository(e.to_string()))?;
+
+        Ok(())
+    }
+}
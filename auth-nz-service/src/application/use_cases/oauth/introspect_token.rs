@@ -0,0 +1,143 @@
+use std::sync::Arc;
+use crate::application::dto::{IntrospectionResponseDto, TokenReferenceDto};
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::{
+    SessionRepository, RevocationListRepository, OAuthClientRepository, OAuthGrantRepository,
+    ServiceAccountRepository, ApiKeyRepository, RoleRepository, PermissionRepository,
+};
+use crate::domain::services::{ApiKeyService, OAuthService, RevocationService, TokenService};
+use crate::domain::value_objects::{ApiKeyToken, RefreshToken, Token};
+use crate::domain::errors::DomainError;
+
+/// `POST /introspect`, for resource servers that need to know whether a token
+/// was revoked before it expired. Also answers for service account API keys,
+/// so resource servers can accept those without a database of their own.
+pub struct IntrospectTokenUseCase<SR, RL, CR, GR, SAR, KR, RR, PR>
+where
+    SR: SessionRepository,
+    RL: RevocationListRepository,
+    CR: OAuthClientRepository,
+    GR: OAuthGrantRepository,
+    SAR: ServiceAccountRepository,
+    KR: ApiKeyRepository,
+    RR: RoleRepository,
+    PR: PermissionRepository,
+{
+    oauth_service: Arc<OAuthService<CR, GR>>,
+    token_service: Arc<TokenService>,
+    revocation_service: Arc<RevocationService<SR, RL>>,
+    api_key_service: Arc<ApiKeyService<SAR, KR, RR, PR>>,
+}
+
+impl<SR, RL, CR, GR, SAR, KR, RR, PR> IntrospectTokenUseCase<SR, RL, CR, GR, SAR, KR, RR, PR>
+where
+    SR: SessionRepository,
+    RL: RevocationListRepository,
+    CR: OAuthClientRepository,
+    GR: OAuthGrantRepository,
+    SAR: ServiceAccountRepository,
+    KR: ApiKeyRepository,
+    RR: RoleRepository,
+    PR: PermissionRepository,
+{
+    pub fn new(
+        oauth_service: Arc<OAuthService<CR, GR>>,
+        token_service: Arc<TokenService>,
+        revocation_service: Arc<RevocationService<SR, RL>>,
+        api_key_service: Arc<ApiKeyService<SAR, KR, RR, PR>>,
+    ) -> Self {
+        Self {
+            oauth_service,
+            token_service,
+            revocation_service,
+            api_key_service,
+        }
+    }
+
+    pub async fn execute(&self, dto: TokenReferenceDto) -> Result<IntrospectionResponseDto, ApplicationError> {
+        // Only confidential clients may ask, or anyone could probe stolen tokens
+        let (Some(client_id), Some(client_secret)) = (dto.client_id.as_deref(), dto.client_secret.as_deref()) else {
+            return Err(ApplicationError::Domain(DomainError::InvalidClient));
+        };
+        self.oauth_service.authenticate_client(client_id, Some(client_secret)).await?;
+
+        // Access tokens are JWTs; refresh tokens are opaque and never contain a dot
+        if ApiKeyToken::is_api_key(&dto.token) {
+            self.introspect_api_key(dto.token).await
+        } else if dto.token.contains('.') {
+            self.introspect_access_token(dto.token).await
+        } else {
+            self.introspect_refresh_token(dto.token).await
+        }
+    }
+
+    async fn introspect_access_token(&self, token: String) -> Result<IntrospectionResponseDto, ApplicationError> {
+        let Ok(claims) = self.token_service.verify_access_token(&Token::new(token)) else {
+            return Ok(IntrospectionResponseDto::inactive());
+        };
+        if !self.revocation_service.is_access_token_active(&claims).await? {
+            return Ok(IntrospectionResponseDto::inactive());
+        }
+
+        // First-party tokens carry no scope; their permissions play that role
+        let scope = claims.scope.clone()
+            .or_else(|| (!claims.permissions.is_empty()).then(|| claims.permissions.join(" ")));
+        let roles = (!claims.roles.is_empty()).then_some(claims.roles);
+
+        Ok(IntrospectionResponseDto {
+            active: true,
+            scope,
+            client_id: claims.client_id,
+            token_type: Some("Bearer".to_string()),
+            exp: Some(claims.exp),
+            iat: Some(claims.iat),
+            sub: Some(claims.sub),
+            iss: Some(claims.iss),
+            jti: Some(claims.jti),
+            roles,
+            org_id: claims.org_id,
+        })
+    }
+
+    async fn introspect_refresh_token(&self, token: String) -> Result<IntrospectionResponseDto, ApplicationError> {
+        let Some(session) = self.revocation_service.refresh_session(&RefreshToken::new(token)).await? else {
+            return Ok(IntrospectionResponseDto::inactive());
+        };
+
+        Ok(IntrospectionResponseDto {
+            active: true,
+            scope: session.delegation.as_ref().map(|d| d.scopes.to_string()),
+            client_id: session.delegation.as_ref().map(|d| d.client_id.clone()),
+            token_type: Some("refresh_token".to_string()),
+            exp: Some(session.refresh_expires_at.timestamp()),
+            iat: Some(session.created_at.timestamp()),
+            sub: Some(session.user_id.as_uuid().to_string()),
+            iss: Some(self.token_service.issuer().to_string()),
+            jti: None,
+            roles: None,
+            org_id: session.organization_id.map(|id| id.as_uuid().to_string()),
+        })
+    }
+
+    async fn introspect_api_key(&self, token: String) -> Result<IntrospectionResponseDto, ApplicationError> {
+        let authenticated = match self.api_key_service.authenticate(&ApiKeyToken::new(token)).await {
+            Ok(authenticated) => authenticated,
+            Err(DomainError::InvalidApiKey) => return Ok(IntrospectionResponseDto::inactive()),
+            Err(e) => return Err(e.into()),
+        };
+
+        Ok(IntrospectionResponseDto {
+            active: true,
+            scope: (!authenticated.grants.permissions.is_empty()).then(|| authenticated.grants.permissions.join(" ")),
+            client_id: None,
+            token_type: Some("api_key".to_string()),
+            exp: authenticated.key.expires_at.map(|expires_at| expires_at.timestamp()),
+            iat: Some(authenticated.key.created_at.timestamp()),
+            sub: Some(authenticated.service_account.id.as_uuid().to_string()),
+            iss: Some(self.token_service.issuer().to_string()),
+            jti: Some(authenticated.key.id.as_uuid().to_string()),
+            roles: None,
+            org_id: None,
+        })
+    }
+}
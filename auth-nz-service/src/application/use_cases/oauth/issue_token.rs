@@ -0,0 +1,159 @@
+use std::sync::Arc;
+use crate::application::dto::{ClientInfoDto, TokenRequestDto, TokenResponseDto};
+use crate::application::errors::ApplicationError;
+use crate::domain::entities::oauth_client::OAuthClient;
+use crate::domain::repositories::{
+    UserRepository, SessionRepository, RoleRepository, PermissionRepository, OAuthClientRepository, OAuthGrantRepository,
+};
+use crate::domain::services::{AuthorizationService, IssuedSession, OAuthService, SessionService, TokenService};
+use crate::domain::value_objects::{GrantType, OAuthDelegation, RefreshToken};
+use crate::domain::errors::DomainError;
+
+/// `POST /token` for the `authorization_code`, `refresh_token` and
+/// `client_credentials` grants.
+pub struct IssueTokenUseCase<
+    UR: UserRepository,
+    SR: SessionRepository,
+    RR: RoleRepository,
+    PR: PermissionRepository,
+    CR: OAuthClientRepository,
+    GR: OAuthGrantRepository,
+> {
+    user_repository: Arc<UR>,
+    oauth_service: Arc<OAuthService<CR, GR>>,
+    session_service: Arc<SessionService<SR>>,
+    authorization_service: Arc<AuthorizationService<RR, PR>>,
+    token_service: Arc<TokenService>,
+}
+
+impl<UR, SR, RR, PR, CR, GR> IssueTokenUseCase<UR, SR, RR, PR, CR, GR>
+where
+    UR: UserRepository,
+    SR: SessionRepository,
+    RR: RoleRepository,
+    PR: PermissionRepository,
+    CR: OAuthClientRepository,
+    GR: OAuthGrantRepository,
+{
+    pub fn new(
+        user_repository: Arc<UR>,
+        oauth_service: Arc<OAuthService<CR, GR>>,
+        session_service: Arc<SessionService<SR>>,
+        authorization_service: Arc<AuthorizationService<RR, PR>>,
+        token_service: Arc<TokenService>,
+    ) -> Self {
+        Self {
+            user_repository,
+            oauth_service,
+            session_service,
+            authorization_service,
+            token_service,
+        }
+    }
+
+    pub async fn execute(&self, dto: TokenRequestDto, client_info: ClientInfoDto) -> Result<TokenResponseDto, ApplicationError> {
+        let grant_type = GrantType::parse(&dto.grant_type)?;
+        let client_id = dto.client_id.as_deref()
+            .ok_or(ApplicationError::Domain(DomainError::InvalidClient))?;
+        let client = self.oauth_service.authenticate_client(client_id, dto.client_secret.as_deref()).await?;
+
+        if !client.allows_grant(grant_type) {
+            return Err(ApplicationError::Domain(DomainError::UnauthorizedClient(format!(
+                "{} grant is not allowed",
+                grant_type.as_str()
+            ))));
+        }
+
+        match grant_type {
+            GrantType::AuthorizationCode => self.exchange_code(&client, dto, client_info).await,
+            GrantType::RefreshToken => self.refresh(&client, dto, client_info).await,
+            GrantType::ClientCredentials => self.client_credentials(&client, dto),
+        }
+    }
+
+    async fn exchange_code(
+        &self,
+        client: &OAuthClient,
+        dto: TokenRequestDto,
+        client_info: ClientInfoDto,
+    ) -> Result<TokenResponseDto, ApplicationError> {
+        let code = required(dto.code.as_deref(), "code")?;
+        let redirect_uri = required(dto.redirect_uri.as_deref(), "redirect_uri")?;
+        let code_verifier = required(dto.code_verifier.as_deref(), "code_verifier")?;
+
+        let grant = self.oauth_service.redeem_code(client, code, redirect_uri, code_verifier).await?;
+
+        let user = self.user_repository.find_by_id(&grant.user_id).await?
+            .filter(|user| user.is_active)
+            .ok_or_else(|| ApplicationError::Domain(DomainError::InvalidGrant("User is no longer active".to_string())))?;
+
+        let grants = self.authorization_service.grants(&user.id).await?;
+        let issued = self.session_service.start_delegated(
+            &user.id,
+            &grants,
+            client_info.device(),
+            OAuthDelegation {
+                client_id: client.id.clone(),
+                scopes: grant.scopes,
+            },
+        ).await?;
+
+        Ok(self.session_response(client, issued))
+    }
+
+    async fn refresh(
+        &self,
+        client: &OAuthClient,
+        dto: TokenRequestDto,
+        client_info: ClientInfoDto,
+    ) -> Result<TokenResponseDto, ApplicationError> {
+        let refresh_token = RefreshToken::new(required(dto.refresh_token.as_deref(), "refresh_token")?.to_string());
+
+        let previous = self.session_service.redeem(&refresh_token, Some(&client.id)).await?;
+        let grants = self.authorization_service.grants(&previous.user_id).await?;
+        let issued = self.session_service.rotate(&previous, &grants, client_info.device()).await?;
+
+        Ok(self.session_response(client, issued))
+    }
+
+    fn client_credentials(&self, client: &OAuthClient, dto: TokenRequestDto) -> Result<TokenResponseDto, ApplicationError> {
+        // Without a secret anyone could act as the client
+        if !client.is_confidential() {
+            return Err(ApplicationError::Domain(DomainError::UnauthorizedClient(
+                "Public clients cannot use client_credentials".to_string(),
+            )));
+        }
+
+        let scopes = self.oauth_service.resolve_scopes(client, dto.scope.as_deref())?;
+        let (token, _) = self.token_service.issue_client_token(&client.id, &scopes)?;
+
+        Ok(TokenResponseDto {
+            access_token: token.as_str().to_string(),
+            token_type: "Bearer".to_string(),
+            expires_in: self.token_service.access_token_ttl().num_seconds(),
+            refresh_token: None,
+            scope: scopes.to_string(),
+        })
+    }
+
+    fn session_response(&self, client: &OAuthClient, issued: IssuedSession) -> TokenResponseDto {
+        let scope = issued.session.delegation.as_ref()
+            .map(|delegation| delegation.scopes.to_string())
+            .unwrap_or_default();
+
+        TokenResponseDto {
+            access_token: issued.session.token.as_str().to_string(),
+            token_type: "Bearer".to_string(),
+            expires_in: self.token_service.access_token_ttl().num_seconds(),
+            // Clients not allowed to refresh only get the access token
+            refresh_token: client.allows_grant(GrantType::RefreshToken)
+                .then(|| issued.refresh_token.as_str().to_string()),
+            scope,
+        }
+    }
+}
+
+fn required<'a>(value: Option<&'a str>, name: &str) -> Result<&'a str, ApplicationError> {
+    value.filter(|value| !value.is_empty())
+        .ok_or_else(|| ApplicationError::Domain(DomainError::InvalidOAuthRequest(format!("{} is required", name))))
+}
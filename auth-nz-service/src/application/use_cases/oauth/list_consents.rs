@@ -0,0 +1,23 @@
+use std::sync::Arc;
+use crate::application::dto::OAuthConsentDto;
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::OAuthGrantRepository;
+use crate::domain::value_objects::UserId;
+
+pub struct ListConsentsUseCase<GR: OAuthGrantRepository> {
+    grant_repository: Arc<GR>,
+}
+
+impl<GR: OAuthGrantRepository> ListConsentsUseCase<GR> {
+    pub fn new(grant_repository: Arc<GR>) -> Self {
+        Self { grant_repository }
+    }
+
+    pub async fn execute(&self, user_id: &str) -> Result<Vec<OAuthConsentDto>, ApplicationError> {
+        let user_id = uuid::Uuid::parse_str(user_id)
+            .map_err(|_| ApplicationError::Validation("Invalid user ID format".to_string()))?;
+
+        let consents = self.grant_repository.find_consents_by_user_id(&UserId::from_uuid(user_id)).await?;
+        Ok(consents.iter().map(OAuthConsentDto::from).collect())
+    }
+}
@@ -0,0 +1,19 @@
+use std::sync::Arc;
+use crate::application::dto::OAuthClientDto;
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::OAuthClientRepository;
+
+pub struct ListOAuthClientsUseCase<CR: OAuthClientRepository> {
+    client_repository: Arc<CR>,
+}
+
+impl<CR: OAuthClientRepository> ListOAuthClientsUseCase<CR> {
+    pub fn new(client_repository: Arc<CR>) -> Self {
+        Self { client_repository }
+    }
+
+    pub async fn execute(&self) -> Result<Vec<OAuthClientDto>, ApplicationError> {
+        let clients = self.client_repository.find_all().await?;
+        Ok(clients.iter().map(OAuthClientDto::from).collect())
+    }
+}
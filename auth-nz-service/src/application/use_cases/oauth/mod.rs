@@ -0,0 +1,21 @@
+pub mod authorize_client;
+pub mod decide_consent;
+pub mod issue_token;
+pub mod create_oauth_client;
+pub mod list_oauth_clients;
+pub mod delete_oauth_client;
+pub mod list_consents;
+pub mod revoke_consent;
+pub mod introspect_token;
+pub mod revoke_token;
+
+pub use authorize_client::AuthorizeClientUseCase;
+pub use decide_consent::DecideConsentUseCase;
+pub use issue_token::IssueTokenUseCase;
+pub use create_oauth_client::CreateOAuthClientUseCase;
+pub use list_oauth_clients::ListOAuthClientsUseCase;
+pub use delete_oauth_client::DeleteOAuthClientUseCase;
+pub use list_consents::ListConsentsUseCase;
+pub use revoke_consent::RevokeConsentUseCase;
+pub use introspect_token::IntrospectTokenUseCase;
+pub use revoke_token::RevokeTokenUseCase;
@@ -0,0 +1,36 @@
+use std::sync::Arc;
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::{OAuthGrantRepository, SessionRepository};
+use crate::domain::value_objects::UserId;
+use crate::domain::errors::DomainError;
+
+/// Withdraws the user's consent for a client and ends the sessions the
+/// client holds for them, so it has to ask again.
+pub struct RevokeConsentUseCase<SR: SessionRepository, GR: OAuthGrantRepository> {
+    session_repository: Arc<SR>,
+    grant_repository: Arc<GR>,
+}
+
+impl<SR: SessionRepository, GR: OAuthGrantRepository> RevokeConsentUseCase<SR, GR> {
+    pub fn new(session_repository: Arc<SR>, grant_repository: Arc<GR>) -> Self {
+        Self {
+            session_repository,
+            grant_repository,
+        }
+    }
+
+    pub async fn execute(&self, user_id: &str, client_id: &str) -> Result<(), ApplicationError> {
+        let user_id = uuid::Uuid::parse_str(user_id)
+            .map_err(|_| ApplicationError::Validation("Invalid user ID format".to_string()))?;
+        let user_id = UserId::from_uuid(user_id);
+
+        if !self.grant_repository.delete_consent(&user_id, client_id).await? {
+            return Err(ApplicationError::Domain(DomainError::OAuthConsentNotFound));
+        }
+
+        self.session_repository.revoke_by_client_id(&user_id, client_id).await
+            .map_err(|e| ApplicationError::Repository(e.to_string()))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,113 @@
+use std::sync::Arc;
+use serde_json::{json, Value};
+use crate::application::dto::TokenReferenceDto;
+use crate::application::errors::ApplicationError;
+use crate::domain::entities::audit_event::NewAuditEvent;
+use crate::domain::repositories::{SessionRepository, RevocationListRepository, OAuthClientRepository, OAuthGrantRepository, AuditEventRepository};
+use crate::domain::services::{AuditLog, OAuthService, RevocationService, TokenService};
+use crate::domain::value_objects::{AuditEventType, RefreshToken, Token, UserId};
+use crate::domain::errors::DomainError;
+
+/// `POST /revoke`. Revoking either token of a session ends the session's
+/// whole refresh chain along with its access tokens.
+///
+/// Tokens issued to a client can only be revoked by that client; first-party
+/// tokens by whoever holds them. Unknown, expired or already revoked tokens
+/// are accepted silently, as RFC 7009 requires.
+pub struct RevokeTokenUseCase<
+    SR: SessionRepository,
+    RL: RevocationListRepository,
+    CR: OAuthClientRepository,
+    GR: OAuthGrantRepository,
+    AR: AuditEventRepository,
+> {
+    oauth_service: Arc<OAuthService<CR, GR>>,
+    token_service: Arc<TokenService>,
+    revocation_service: Arc<RevocationService<SR, RL>>,
+    audit_log: Arc<AuditLog<AR>>,
+}
+
+impl<SR, RL, CR, GR, AR> RevokeTokenUseCase<SR, RL, CR, GR, AR>
+where
+    SR: SessionRepository,
+    RL: RevocationListRepository,
+    CR: OAuthClientRepository,
+    GR: OAuthGrantRepository,
+    AR: AuditEventRepository,
+{
+    pub fn new(
+        oauth_service: Arc<OAuthService<CR, GR>>,
+        token_service: Arc<TokenService>,
+        revocation_service: Arc<RevocationService<SR, RL>>,
+        audit_log: Arc<AuditLog<AR>>,
+    ) -> Self {
+        Self {
+            oauth_service,
+            token_service,
+            revocation_service,
+            audit_log,
+        }
+    }
+
+    pub async fn execute(&self, dto: TokenReferenceDto) -> Result<(), ApplicationError> {
+        let caller = match dto.client_id.as_deref() {
+            Some(client_id) => Some(
+                self.oauth_service.authenticate_client(client_id, dto.client_secret.as_deref()).await?.id,
+            ),
+            None => None,
+        };
+
+        if dto.token.contains('.') {
+            let Ok(claims) = self.token_service.verify_access_token(&Token::new(dto.token)) else {
+                return Ok(());
+            };
+            Self::check_owner(claims.client_id.as_deref(), caller.as_deref())?;
+            self.revocation_service.revoke_access_token(&claims).await?;
+            // Client credentials tokens have no user; their subject is the client
+            let user_id = claims.sid.as_ref()
+                .and_then(|_| uuid::Uuid::parse_str(&claims.sub).ok())
+                .map(UserId::from_uuid);
+            self.record(caller.as_deref(), user_id, json!({
+                "reason": "token_revoked",
+                "token_type": "access_token",
+                "jti": claims.jti,
+            })).await;
+        } else {
+            let Some(session) = self.revocation_service.refresh_session(&RefreshToken::new(dto.token)).await? else {
+                return Ok(());
+            };
+            Self::check_owner(session.delegation.as_ref().map(|d| d.client_id.as_str()), caller.as_deref())?;
+            self.revocation_service.revoke_session(&session).await?;
+            self.record(caller.as_deref(), Some(session.user_id), json!({
+                "reason": "token_revoked",
+                "token_type": "refresh_token",
+                "session_id": session.family_id.as_uuid().to_string(),
+            })).await;
+        }
+
+        Ok(())
+    }
+
+    /// The actor is the client, or the user for first-party tokens.
+    async fn record(&self, caller: Option<&str>, user_id: Option<UserId>, details: Value) {
+        let actor = caller.map(str::to_string)
+            .or_else(|| user_id.map(|id| id.as_uuid().to_string()));
+        let mut event = NewAuditEvent::new(AuditEventType::SessionRevoked).with_details(details);
+        if let Some(actor) = actor {
+            event = event.with_actor(actor);
+        }
+        if let Some(user_id) = &user_id {
+            event = event.with_user(user_id);
+        }
+        self.audit_log.record(event).await;
+    }
+
+    fn check_owner(issued_to: Option<&str>, caller: Option<&str>) -> Result<(), ApplicationError> {
+        if issued_to != caller {
+            return Err(ApplicationError::Domain(DomainError::UnauthorizedClient(
+                "Token was not issued to this client".to_string(),
+            )));
+        }
+        Ok(())
+    }
+}
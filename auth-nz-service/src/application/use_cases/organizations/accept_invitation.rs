@@ -0,0 +1,80 @@
+use std::sync::Arc;
+use serde_json::json;
+use crate::application::dto::{AcceptInvitationDto, OrganizationDto};
+use crate::application::errors::ApplicationError;
+use crate::domain::entities::audit_event::NewAuditEvent;
+use crate::domain::repositories::{
+    UserRepository, OrganizationRepository, OrganizationInvitationRepository, RoleRepository, AuditEventRepository,
+};
+use crate::domain::services::{AuditLog, OrganizationService};
+use crate::domain::value_objects::{AuditEventType, InvitationToken, UserId};
+use crate::domain::errors::DomainError;
+
+pub struct AcceptInvitationUseCase<UR, OR, IR, RR, AR>
+where
+    UR: UserRepository,
+    OR: OrganizationRepository,
+    IR: OrganizationInvitationRepository,
+    RR: RoleRepository,
+    AR: AuditEventRepository,
+{
+    user_repository: Arc<UR>,
+    organization_service: Arc<OrganizationService<OR, IR>>,
+    role_repository: Arc<RR>,
+    audit_log: Arc<AuditLog<AR>>,
+}
+
+impl<UR, OR, IR, RR, AR> AcceptInvitationUseCase<UR, OR, IR, RR, AR>
+where
+    UR: UserRepository,
+    OR: OrganizationRepository,
+    IR: OrganizationInvitationRepository,
+    RR: RoleRepository,
+    AR: AuditEventRepository,
+{
+    pub fn new(
+        user_repository: Arc<UR>,
+        organization_service: Arc<OrganizationService<OR, IR>>,
+        role_repository: Arc<RR>,
+        audit_log: Arc<AuditLog<AR>>,
+    ) -> Self {
+        Self {
+            user_repository,
+            organization_service,
+            role_repository,
+            audit_log,
+        }
+    }
+
+    /// Joins the signed-in user to the organization, with the role named in
+    /// the invitation if any.
+    pub async fn execute(&self, user_id: &str, dto: AcceptInvitationDto) -> Result<OrganizationDto, ApplicationError> {
+        let user_id = UserId::from_uuid(uuid::Uuid::parse_str(user_id)
+            .map_err(|_| ApplicationError::Validation("Invalid user ID format".to_string()))?);
+        let user = self.user_repository.find_by_id(&user_id).await?
+            .ok_or(ApplicationError::Domain(DomainError::UserNotFound))?;
+
+        let invitation = self.organization_service.accept(&InvitationToken::new(dto.token), &user).await?;
+        let organization = self.organization_service.find(&invitation.organization_id).await?;
+
+        if let Some(role_id) = invitation.role_id {
+            self.role_repository.assign_to_member(&organization.id, &user.id, &role_id).await
+                .map_err(|e| ApplicationError::Repository(e.to_string()))?;
+        }
+        let roles = self.role_repository.find_by_member(&organization.id, &user.id).await?;
+
+        self.audit_log.record(
+            NewAuditEvent::new(AuditEventType::OrganizationMemberAdded)
+                .with_actor(user.id.as_uuid().to_string())
+                .with_user(&user.id)
+                .with_details(json!({
+                    "organization_id": organization.id.as_uuid().to_string(),
+                    "invitation_id": invitation.id.as_uuid().to_string(),
+                    "invited_by": invitation.invited_by.as_uuid().to_string(),
+                    "roles": roles.iter().map(|role| role.name.as_str()).collect::<Vec<_>>(),
+                })),
+        ).await;
+
+        Ok(OrganizationDto::new(&organization, &roles))
+    }
+}
@@ -0,0 +1,79 @@
+use std::sync::Arc;
+use serde_json::json;
+use crate::application::dto::{CreateOrganizationDto, OrganizationDto};
+use crate::application::errors::ApplicationError;
+use crate::domain::entities::audit_event::NewAuditEvent;
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::{OrganizationRepository, OrganizationInvitationRepository, RoleRepository, AuditEventRepository};
+use crate::domain::services::{AuditLog, OrganizationService};
+use crate::domain::value_objects::{AuditEventType, RoleName, UserId};
+
+pub struct CreateOrganizationUseCase<OR, IR, RR, AR>
+where
+    OR: OrganizationRepository,
+    IR: OrganizationInvitationRepository,
+    RR: RoleRepository,
+    AR: AuditEventRepository,
+{
+    organization_service: Arc<OrganizationService<OR, IR>>,
+    role_repository: Arc<RR>,
+    founder_role: String,
+    audit_log: Arc<AuditLog<AR>>,
+}
+
+impl<OR, IR, RR, AR> CreateOrganizationUseCase<OR, IR, RR, AR>
+where
+    OR: OrganizationRepository,
+    IR: OrganizationInvitationRepository,
+    RR: RoleRepository,
+    AR: AuditEventRepository,
+{
+    pub fn new(
+        organization_service: Arc<OrganizationService<OR, IR>>,
+        role_repository: Arc<RR>,
+        founder_role: String,
+        audit_log: Arc<AuditLog<AR>>,
+    ) -> Self {
+        Self {
+            organization_service,
+            role_repository,
+            founder_role,
+            audit_log,
+        }
+    }
+
+    /// Creates an organization with the caller as its first member, holding
+    /// the founder role there.
+    pub async fn execute(&self, user_id: &str, dto: CreateOrganizationDto) -> Result<OrganizationDto, ApplicationError> {
+        let user_id = UserId::from_uuid(uuid::Uuid::parse_str(user_id)
+            .map_err(|_| ApplicationError::Validation("Invalid user ID format".to_string()))?);
+        let name = dto.name.trim();
+        if name.is_empty() {
+            return Err(ApplicationError::Validation("Organization name is required".to_string()));
+        }
+
+        let founder_role = self.role_repository.find_by_name(&RoleName::new(self.founder_role.clone())?).await?
+            .ok_or_else(|| ApplicationError::Repository(format!("Founder role '{}' does not exist", self.founder_role)))?;
+
+        let organization = self.organization_service.create(name.to_string(), &user_id).await
+            .map_err(|e| match e {
+                DomainError::OrganizationAlreadyExists => ApplicationError::Domain(e),
+                _ => ApplicationError::Repository(e.to_string()),
+            })?;
+        self.role_repository.assign_to_member(&organization.id, &user_id, &founder_role.id).await
+            .map_err(|e| ApplicationError::Repository(e.to_string()))?;
+
+        self.audit_log.record(
+            NewAuditEvent::new(AuditEventType::OrganizationCreated)
+                .with_actor(user_id.as_uuid().to_string())
+                .with_user(&user_id)
+                .with_details(json!({
+                    "organization_id": organization.id.as_uuid().to_string(),
+                    "name": organization.name,
+                    "role": founder_role.name.as_str(),
+                })),
+        ).await;
+
+        Ok(OrganizationDto::new(&organization, &[founder_role]))
+    }
+}
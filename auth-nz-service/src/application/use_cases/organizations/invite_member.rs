@@ -0,0 +1,99 @@
+use std::sync::Arc;
+use serde_json::json;
+use crate::application::dto::{InviteMemberDto, OrganizationInvitationDto};
+use crate::application::errors::ApplicationError;
+use crate::domain::entities::audit_event::NewAuditEvent;
+use crate::domain::events::EventPublisher;
+use crate::domain::repositories::{
+    OrganizationRepository, OrganizationInvitationRepository, RoleRepository, PermissionRepository, AccountTokenRepository,
+    AuditEventRepository,
+};
+use crate::domain::services::{AccountNotifier, AuditLog, AuthorizationService, OrganizationService};
+use crate::domain::value_objects::{AuditEventType, Email, OrganizationId, RoleId, UserId};
+use crate::domain::errors::DomainError;
+
+pub struct InviteMemberUseCase<OR, IR, RR, PR, TR, EP, AR>
+where
+    OR: OrganizationRepository,
+    IR: OrganizationInvitationRepository,
+    RR: RoleRepository,
+    PR: PermissionRepository,
+    TR: AccountTokenRepository,
+    EP: EventPublisher,
+    AR: AuditEventRepository,
+{
+    organization_service: Arc<OrganizationService<OR, IR>>,
+    role_repository: Arc<RR>,
+    authorization_service: Arc<AuthorizationService<RR, PR>>,
+    account_notifier: Arc<AccountNotifier<TR, EP>>,
+    audit_log: Arc<AuditLog<AR>>,
+}
+
+impl<OR, IR, RR, PR, TR, EP, AR> InviteMemberUseCase<OR, IR, RR, PR, TR, EP, AR>
+where
+    OR: OrganizationRepository,
+    IR: OrganizationInvitationRepository,
+    RR: RoleRepository,
+    PR: PermissionRepository,
+    TR: AccountTokenRepository,
+    EP: EventPublisher,
+    AR: AuditEventRepository,
+{
+    pub fn new(
+        organization_service: Arc<OrganizationService<OR, IR>>,
+        role_repository: Arc<RR>,
+        authorization_service: Arc<AuthorizationService<RR, PR>>,
+        account_notifier: Arc<AccountNotifier<TR, EP>>,
+        audit_log: Arc<AuditLog<AR>>,
+    ) -> Self {
+        Self {
+            organization_service,
+            role_repository,
+            authorization_service,
+            account_notifier,
+            audit_log,
+        }
+    }
+
+    /// `actor_id` is the caller, who needs `organization:write` in the organization.
+    pub async fn execute(&self, actor_id: &str, organization_id: &str, dto: InviteMemberDto) -> Result<OrganizationInvitationDto, ApplicationError> {
+        let actor_id = UserId::from_uuid(uuid::Uuid::parse_str(actor_id)
+            .map_err(|_| ApplicationError::Validation("Invalid user ID format".to_string()))?);
+        let organization_id = OrganizationId::from_uuid(uuid::Uuid::parse_str(organization_id)
+            .map_err(|_| ApplicationError::Validation("Invalid organization ID format".to_string()))?);
+        let email = Email::new(dto.email.trim().to_string())?;
+        let role_id = dto.role_id
+            .map(|role_id| uuid::Uuid::parse_str(&role_id).map(RoleId::from_uuid))
+            .transpose()
+            .map_err(|_| ApplicationError::Validation("Invalid role ID format".to_string()))?;
+
+        self.authorization_service
+            .require_in_organization(&actor_id, &organization_id, "organization", "write").await?;
+
+        let organization = self.organization_service.find(&organization_id).await?;
+        let role = match role_id {
+            Some(role_id) => Some(self.role_repository.find_by_id(&role_id).await?
+                .ok_or(ApplicationError::Domain(DomainError::RoleNotFound))?),
+            None => None,
+        };
+
+        let (invitation, token) = self.organization_service
+            .invite(&organization_id, email, role.as_ref().map(|role| role.id), &actor_id).await
+            .map_err(|e| ApplicationError::Repository(e.to_string()))?;
+        self.account_notifier.send_organization_invitation(&organization, &invitation, &token).await
+            .map_err(|e| ApplicationError::Repository(e.to_string()))?;
+
+        self.audit_log.record(
+            NewAuditEvent::new(AuditEventType::OrganizationInvitationSent)
+                .with_actor(actor_id.as_uuid().to_string())
+                .with_details(json!({
+                    "organization_id": organization_id.as_uuid().to_string(),
+                    "invitation_id": invitation.id.as_uuid().to_string(),
+                    "email": invitation.email.as_str(),
+                    "role": role.as_ref().map(|role| role.name.as_str()),
+                })),
+        ).await;
+
+        Ok(OrganizationInvitationDto::from(&invitation))
+    }
+}
@@ -0,0 +1,46 @@
+use std::sync::Arc;
+use crate::application::dto::OrganizationInvitationDto;
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::{OrganizationRepository, OrganizationInvitationRepository, RoleRepository, PermissionRepository};
+use crate::domain::services::{AuthorizationService, OrganizationService};
+use crate::domain::value_objects::{OrganizationId, UserId};
+
+pub struct ListInvitationsUseCase<OR, IR, RR, PR>
+where
+    OR: OrganizationRepository,
+    IR: OrganizationInvitationRepository,
+    RR: RoleRepository,
+    PR: PermissionRepository,
+{
+    organization_service: Arc<OrganizationService<OR, IR>>,
+    authorization_service: Arc<AuthorizationService<RR, PR>>,
+}
+
+impl<OR, IR, RR, PR> ListInvitationsUseCase<OR, IR, RR, PR>
+where
+    OR: OrganizationRepository,
+    IR: OrganizationInvitationRepository,
+    RR: RoleRepository,
+    PR: PermissionRepository,
+{
+    pub fn new(organization_service: Arc<OrganizationService<OR, IR>>, authorization_service: Arc<AuthorizationService<RR, PR>>) -> Self {
+        Self {
+            organization_service,
+            authorization_service,
+        }
+    }
+
+    /// `user_id` is the caller, who needs `organization:read` in the organization.
+    pub async fn execute(&self, user_id: &str, organization_id: &str) -> Result<Vec<OrganizationInvitationDto>, ApplicationError> {
+        let user_id = UserId::from_uuid(uuid::Uuid::parse_str(user_id)
+            .map_err(|_| ApplicationError::Validation("Invalid user ID format".to_string()))?);
+        let organization_id = OrganizationId::from_uuid(uuid::Uuid::parse_str(organization_id)
+            .map_err(|_| ApplicationError::Validation("Invalid organization ID format".to_string()))?);
+
+        self.authorization_service
+            .require_in_organization(&user_id, &organization_id, "organization", "read").await?;
+
+        let invitations = self.organization_service.invitations(&organization_id).await?;
+        Ok(invitations.iter().map(OrganizationInvitationDto::from).collect())
+    }
+}
@@ -0,0 +1,66 @@
+use std::sync::Arc;
+use crate::application::dto::OrganizationMemberDto;
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::{
+    UserRepository, OrganizationRepository, OrganizationInvitationRepository, RoleRepository, PermissionRepository,
+};
+use crate::domain::services::{AuthorizationService, OrganizationService};
+use crate::domain::value_objects::{OrganizationId, UserId};
+
+pub struct ListMembersUseCase<UR, OR, IR, RR, PR>
+where
+    UR: UserRepository,
+    OR: OrganizationRepository,
+    IR: OrganizationInvitationRepository,
+    RR: RoleRepository,
+    PR: PermissionRepository,
+{
+    user_repository: Arc<UR>,
+    organization_service: Arc<OrganizationService<OR, IR>>,
+    role_repository: Arc<RR>,
+    authorization_service: Arc<AuthorizationService<RR, PR>>,
+}
+
+impl<UR, OR, IR, RR, PR> ListMembersUseCase<UR, OR, IR, RR, PR>
+where
+    UR: UserRepository,
+    OR: OrganizationRepository,
+    IR: OrganizationInvitationRepository,
+    RR: RoleRepository,
+    PR: PermissionRepository,
+{
+    pub fn new(
+        user_repository: Arc<UR>,
+        organization_service: Arc<OrganizationService<OR, IR>>,
+        role_repository: Arc<RR>,
+        authorization_service: Arc<AuthorizationService<RR, PR>>,
+    ) -> Self {
+        Self {
+            user_repository,
+            organization_service,
+            role_repository,
+            authorization_service,
+        }
+    }
+
+    /// `user_id` is the caller, who needs `organization:read` in the organization.
+    pub async fn execute(&self, user_id: &str, organization_id: &str) -> Result<Vec<OrganizationMemberDto>, ApplicationError> {
+        let user_id = UserId::from_uuid(uuid::Uuid::parse_str(user_id)
+            .map_err(|_| ApplicationError::Validation("Invalid user ID format".to_string()))?);
+        let organization_id = OrganizationId::from_uuid(uuid::Uuid::parse_str(organization_id)
+            .map_err(|_| ApplicationError::Validation("Invalid organization ID format".to_string()))?);
+
+        self.authorization_service
+            .require_in_organization(&user_id, &organization_id, "organization", "read").await?;
+
+        let mut members = Vec::new();
+        for member in self.organization_service.members(&organization_id).await? {
+            let Some(user) = self.user_repository.find_by_id(&member.user_id).await? else {
+                continue;
+            };
+            let roles = self.role_repository.find_by_member(&organization_id, &member.user_id).await?;
+            members.push(OrganizationMemberDto::new(&member, &user, &roles));
+        }
+        Ok(members)
+    }
+}
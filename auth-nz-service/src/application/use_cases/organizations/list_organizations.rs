@@ -0,0 +1,38 @@
+use std::sync::Arc;
+use crate::application::dto::OrganizationDto;
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::{OrganizationRepository, OrganizationInvitationRepository, RoleRepository};
+use crate::domain::services::OrganizationService;
+use crate::domain::value_objects::UserId;
+
+pub struct ListOrganizationsUseCase<OR: OrganizationRepository, IR: OrganizationInvitationRepository, RR: RoleRepository> {
+    organization_service: Arc<OrganizationService<OR, IR>>,
+    role_repository: Arc<RR>,
+}
+
+impl<OR, IR, RR> ListOrganizationsUseCase<OR, IR, RR>
+where
+    OR: OrganizationRepository,
+    IR: OrganizationInvitationRepository,
+    RR: RoleRepository,
+{
+    pub fn new(organization_service: Arc<OrganizationService<OR, IR>>, role_repository: Arc<RR>) -> Self {
+        Self {
+            organization_service,
+            role_repository,
+        }
+    }
+
+    /// The organizations the user belongs to, with the roles held in each.
+    pub async fn execute(&self, user_id: &str) -> Result<Vec<OrganizationDto>, ApplicationError> {
+        let user_id = UserId::from_uuid(uuid::Uuid::parse_str(user_id)
+            .map_err(|_| ApplicationError::Validation("Invalid user ID format".to_string()))?);
+
+        let mut organizations = Vec::new();
+        for organization in self.organization_service.organizations_of(&user_id).await? {
+            let roles = self.role_repository.find_by_member(&organization.id, &user_id).await?;
+            organizations.push(OrganizationDto::new(&organization, &roles));
+        }
+        Ok(organizations)
+    }
+}
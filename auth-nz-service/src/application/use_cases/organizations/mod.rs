@@ -0,0 +1,23 @@
+pub mod create_organization;
+pub mod list_organizations;
+pub mod switch_organization;
+pub mod list_members;
+pub mod remove_member;
+pub mod assign_member_role;
+pub mod unassign_member_role;
+pub mod invite_member;
+pub mod list_invitations;
+pub mod revoke_invitation;
+pub mod accept_invitation;
+
+pub use create_organization::CreateOrganizationUseCase;
+pub use list_organizations::ListOrganizationsUseCase;
+pub use switch_organization::SwitchOrganizationUseCase;
+pub use list_members::ListMembersUseCase;
+pub use remove_member::RemoveMemberUseCase;
+pub use assign_member_role::AssignMemberRoleUseCase;
+pub use unassign_member_role::UnassignMemberRoleUseCase;
+pub use invite_member::InviteMemberUseCase;
+pub use list_invitations::ListInvitationsUseCase;
+pub use revoke_invitation::RevokeInvitationUseCase;
+pub use accept_invitation::AcceptInvitationUseCase;
@@ -0,0 +1,70 @@
+use std::sync::Arc;
+use serde_json::json;
+use crate::application::errors::ApplicationError;
+use crate::domain::entities::audit_event::NewAuditEvent;
+use crate::domain::repositories::{
+    OrganizationRepository, OrganizationInvitationRepository, RoleRepository, PermissionRepository, AuditEventRepository,
+};
+use crate::domain::services::{AuditLog, AuthorizationService, OrganizationService};
+use crate::domain::value_objects::{AuditEventType, OrganizationId, UserId};
+
+pub struct RemoveMemberUseCase<OR, IR, RR, PR, AR>
+where
+    OR: OrganizationRepository,
+    IR: OrganizationInvitationRepository,
+    RR: RoleRepository,
+    PR: PermissionRepository,
+    AR: AuditEventRepository,
+{
+    organization_service: Arc<OrganizationService<OR, IR>>,
+    authorization_service: Arc<AuthorizationService<RR, PR>>,
+    audit_log: Arc<AuditLog<AR>>,
+}
+
+impl<OR, IR, RR, PR, AR> RemoveMemberUseCase<OR, IR, RR, PR, AR>
+where
+    OR: OrganizationRepository,
+    IR: OrganizationInvitationRepository,
+    RR: RoleRepository,
+    PR: PermissionRepository,
+    AR: AuditEventRepository,
+{
+    pub fn new(
+        organization_service: Arc<OrganizationService<OR, IR>>,
+        authorization_service: Arc<AuthorizationService<RR, PR>>,
+        audit_log: Arc<AuditLog<AR>>,
+    ) -> Self {
+        Self {
+            organization_service,
+            authorization_service,
+            audit_log,
+        }
+    }
+
+    /// `actor_id` is the caller, who needs `organization:write` in the
+    /// organization unless they are leaving it themselves.
+    pub async fn execute(&self, actor_id: &str, organization_id: &str, user_id: &str) -> Result<(), ApplicationError> {
+        let actor_id = UserId::from_uuid(uuid::Uuid::parse_str(actor_id)
+            .map_err(|_| ApplicationError::Validation("Invalid user ID format".to_string()))?);
+        let organization_id = OrganizationId::from_uuid(uuid::Uuid::parse_str(organization_id)
+            .map_err(|_| ApplicationError::Validation("Invalid organization ID format".to_string()))?);
+        let user_id = UserId::from_uuid(uuid::Uuid::parse_str(user_id)
+            .map_err(|_| ApplicationError::Validation("Invalid user ID format".to_string()))?);
+
+        if actor_id != user_id {
+            self.authorization_service
+                .require_in_organization(&actor_id, &organization_id, "organization", "write").await?;
+        }
+
+        self.organization_service.remove_member(&organization_id, &user_id).await?;
+
+        self.audit_log.record(
+            NewAuditEvent::new(AuditEventType::OrganizationMemberRemoved)
+                .with_actor(actor_id.as_uuid().to_string())
+                .with_user(&user_id)
+                .with_details(json!({ "organization_id": organization_id.as_uuid().to_string() })),
+        ).await;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+use std::sync::Arc;
+use serde_json::json;
+use crate::application::errors::ApplicationError;
+use crate::domain::entities::audit_event::NewAuditEvent;
+use crate::domain::repositories::{
+    OrganizationRepository, OrganizationInvitationRepository, RoleRepository, PermissionRepository, AuditEventRepository,
+};
+use crate::domain::services::{AuditLog, AuthorizationService, OrganizationService};
+use crate::domain::value_objects::{AuditEventType, InvitationId, OrganizationId, UserId};
+
+pub struct RevokeInvitationUseCase<OR, IR, RR, PR, AR>
+where
+    OR: OrganizationRepository,
+    IR: OrganizationInvitationRepository,
+    RR: RoleRepository,
+    PR: PermissionRepository,
+    AR: AuditEventRepository,
+{
+    organization_service: Arc<OrganizationService<OR, IR>>,
+    authorization_service: Arc<AuthorizationService<RR, PR>>,
+    audit_log: Arc<AuditLog<AR>>,
+}
+
+impl<OR, IR, RR, PR, AR> RevokeInvitationUseCase<OR, IR, RR, PR, AR>
+where
+    OR: OrganizationRepository,
+    IR: OrganizationInvitationRepository,
+    RR: RoleRepository,
+    PR: PermissionRepository,
+    AR: AuditEventRepository,
+{
+    pub fn new(
+        organization_service: Arc<OrganizationService<OR, IR>>,
+        authorization_service: Arc<AuthorizationService<RR, PR>>,
+        audit_log: Arc<AuditLog<AR>>,
+    ) -> Self {
+        Self {
+            organization_service,
+            authorization_service,
+            audit_log,
+        }
+    }
+
+    /// `actor_id` is the caller, who needs `organization:write` in the organization.
+    pub async fn execute(&self, actor_id: &str, organization_id: &str, invitation_id: &str) -> Result<(), ApplicationError> {
+        let actor_id = UserId::from_uuid(uuid::Uuid::parse_str(actor_id)
+            .map_err(|_| ApplicationError::Validation("Invalid user ID format".to_string()))?);
+        let organization_id = OrganizationId::from_uuid(uuid::Uuid::parse_str(organization_id)
+            .map_err(|_| ApplicationError::Validation("Invalid organization ID format".to_string()))?);
+        let invitation_id = InvitationId::from_uuid(uuid::Uuid::parse_str(invitation_id)
+            .map_err(|_| ApplicationError::Validation("Invalid invitation ID format".to_string()))?);
+
+        self.authorization_service
+            .require_in_organization(&actor_id, &organization_id, "organization", "write").await?;
+
+        let invitation = self.organization_service.revoke_invitation(&organization_id, &invitation_id).await?;
+
+        self.audit_log.record(
+            NewAuditEvent::new(AuditEventType::OrganizationInvitationRevoked)
+                .with_actor(actor_id.as_uuid().to_string())
+                .with_details(json!({
+                    "organization_id": organization_id.as_uuid().to_string(),
+                    "invitation_id": invitation.id.as_uuid().to_string(),
+                    "email": invitation.email.as_str(),
+                })),
+        ).await;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+use std::sync::Arc;
+use chrono::DateTime;
+use crate::application::dto::{AuthResponseDto, ClientInfoDto};
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::{
+    SessionRepository, OrganizationRepository, OrganizationInvitationRepository, RoleRepository, PermissionRepository,
+};
+use crate::domain::services::{AccessTokenClaims, AuthorizationService, OrganizationService, SessionService};
+use crate::domain::value_objects::{OrganizationId, UserId};
+use crate::domain::errors::DomainError;
+
+pub struct SwitchOrganizationUseCase<SR, OR, IR, RR, PR>
+where
+    SR: SessionRepository,
+    OR: OrganizationRepository,
+    IR: OrganizationInvitationRepository,
+    RR: RoleRepository,
+    PR: PermissionRepository,
+{
+    session_service: Arc<SessionService<SR>>,
+    organization_service: Arc<OrganizationService<OR, IR>>,
+    authorization_service: Arc<AuthorizationService<RR, PR>>,
+}
+
+impl<SR, OR, IR, RR, PR> SwitchOrganizationUseCase<SR, OR, IR, RR, PR>
+where
+    SR: SessionRepository,
+    OR: OrganizationRepository,
+    IR: OrganizationInvitationRepository,
+    RR: RoleRepository,
+    PR: PermissionRepository,
+{
+    pub fn new(
+        session_service: Arc<SessionService<SR>>,
+        organization_service: Arc<OrganizationService<OR, IR>>,
+        authorization_service: Arc<AuthorizationService<RR, PR>>,
+    ) -> Self {
+        Self {
+            session_service,
+            organization_service,
+            authorization_service,
+        }
+    }
+
+    /// Opens a session within the organization for a member signed in with a
+    /// first-party session. Its tokens carry the `org_id` claim and the roles
+    /// held in the organization, and keep both when refreshed. A token
+    /// delegated to an OAuth client cannot be traded for one, as the new
+    /// session would not be limited to the client's scopes. The new session
+    /// keeps the caller's sign-in time.
+    pub async fn execute(&self, caller: &AccessTokenClaims, organization_id: &str, client: ClientInfoDto) -> Result<AuthResponseDto, ApplicationError> {
+        if caller.client_id.is_some() {
+            return Err(ApplicationError::Domain(DomainError::Forbidden));
+        }
+        let user_id = UserId::from_uuid(uuid::Uuid::parse_str(&caller.sub)
+            .map_err(|_| ApplicationError::Validation("Invalid user ID format".to_string()))?);
+        let organization_id = OrganizationId::from_uuid(uuid::Uuid::parse_str(organization_id)
+            .map_err(|_| ApplicationError::Validation("Invalid organization ID format".to_string()))?);
+
+        self.organization_service.membership(&organization_id, &user_id).await?;
+        let grants = self.authorization_service.organization_grants(&user_id, &organization_id).await?;
+        // Switching is not signing in, so the new session is no fresher than the caller's
+        let authenticated_at = caller.auth_time
+            .and_then(|auth_time| DateTime::from_timestamp(auth_time, 0))
+            .unwrap_or(DateTime::UNIX_EPOCH);
+        let issued = self.session_service
+            .start_continued(&user_id, &grants, client.device(), authenticated_at).await?;
+
+        Ok(AuthResponseDto {
+            token: issued.session.token.as_str().to_string(),
+            expires_at: issued.session.expires_at.to_rfc3339(),
+            refresh_token: issued.refresh_token.as_str().to_string(),
+            refresh_expires_at: issued.session.refresh_expires_at.to_rfc3339(),
+        })
+    }
+}
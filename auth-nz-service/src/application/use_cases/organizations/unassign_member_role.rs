@@ -0,0 +1,77 @@
+use std::sync::Arc;
+use serde_json::json;
+use crate::application::errors::ApplicationError;
+use crate::domain::entities::audit_event::NewAuditEvent;
+use crate::domain::repositories::{
+    OrganizationRepository, OrganizationInvitationRepository, RoleRepository, PermissionRepository, AuditEventRepository,
+};
+use crate::domain::services::{AuditLog, AuthorizationService, OrganizationService};
+use crate::domain::value_objects::{AuditEventType, OrganizationId, RoleId, UserId};
+
+pub struct UnassignMemberRoleUseCase<OR, IR, RR, PR, AR>
+where
+    OR: OrganizationRepository,
+    IR: OrganizationInvitationRepository,
+    RR: RoleRepository,
+    PR: PermissionRepository,
+    AR: AuditEventRepository,
+{
+    organization_service: Arc<OrganizationService<OR, IR>>,
+    role_repository: Arc<RR>,
+    authorization_service: Arc<AuthorizationService<RR, PR>>,
+    audit_log: Arc<AuditLog<AR>>,
+}
+
+impl<OR, IR, RR, PR, AR> UnassignMemberRoleUseCase<OR, IR, RR, PR, AR>
+where
+    OR: OrganizationRepository,
+    IR: OrganizationInvitationRepository,
+    RR: RoleRepository,
+    PR: PermissionRepository,
+    AR: AuditEventRepository,
+{
+    pub fn new(
+        organization_service: Arc<OrganizationService<OR, IR>>,
+        role_repository: Arc<RR>,
+        authorization_service: Arc<AuthorizationService<RR, PR>>,
+        audit_log: Arc<AuditLog<AR>>,
+    ) -> Self {
+        Self {
+            organization_service,
+            role_repository,
+            authorization_service,
+            audit_log,
+        }
+    }
+
+    /// `actor_id` is the caller, who needs `organization:write` in the organization.
+    pub async fn execute(&self, actor_id: &str, organization_id: &str, user_id: &str, role_id: &str) -> Result<(), ApplicationError> {
+        let actor_id = UserId::from_uuid(uuid::Uuid::parse_str(actor_id)
+            .map_err(|_| ApplicationError::Validation("Invalid user ID format".to_string()))?);
+        let organization_id = OrganizationId::from_uuid(uuid::Uuid::parse_str(organization_id)
+            .map_err(|_| ApplicationError::Validation("Invalid organization ID format".to_string()))?);
+        let user_id = UserId::from_uuid(uuid::Uuid::parse_str(user_id)
+            .map_err(|_| ApplicationError::Validation("Invalid user ID format".to_string()))?);
+        let role_id = RoleId::from_uuid(uuid::Uuid::parse_str(role_id)
+            .map_err(|_| ApplicationError::Validation("Invalid role ID format".to_string()))?);
+
+        self.authorization_service
+            .require_in_organization(&actor_id, &organization_id, "organization", "write").await?;
+
+        self.organization_service.membership(&organization_id, &user_id).await?;
+        self.role_repository.unassign_from_member(&organization_id, &user_id, &role_id).await
+            .map_err(|e| ApplicationError::Repository(e.to_string()))?;
+
+        self.audit_log.record(
+            NewAuditEvent::new(AuditEventType::RoleUnassigned)
+                .with_actor(actor_id.as_uuid().to_string())
+                .with_user(&user_id)
+                .with_details(json!({
+                    "organization_id": organization_id.as_uuid().to_string(),
+                    "role_id": role_id.as_uuid().to_string(),
+                })),
+        ).await;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,39 @@
+use std::sync::Arc;
+use serde_json::json;
+use crate::application::errors::ApplicationError;
+use crate::domain::entities::audit_event::NewAuditEvent;
+use crate::domain::repositories::{PasskeyRepository, AuditEventRepository};
+use crate::domain::services::{AuditLog, WebAuthnService};
+use crate::domain::value_objects::{AuditEventType, PasskeyId, UserId};
+
+pub struct DeletePasskeyUseCase<PK: PasskeyRepository, AR: AuditEventRepository> {
+    webauthn_service: Arc<WebAuthnService<PK>>,
+    audit_log: Arc<AuditLog<AR>>,
+}
+
+impl<PK: PasskeyRepository, AR: AuditEventRepository> DeletePasskeyUseCase<PK, AR> {
+    pub fn new(webauthn_service: Arc<WebAuthnService<PK>>, audit_log: Arc<AuditLog<AR>>) -> Self {
+        Self {
+            webauthn_service,
+            audit_log,
+        }
+    }
+
+    pub async fn execute(&self, user_id: &str, passkey_id: &str) -> Result<(), ApplicationError> {
+        let user_id = UserId::from_uuid(uuid::Uuid::parse_str(user_id)
+            .map_err(|_| ApplicationError::Validation("Invalid user ID format".to_string()))?);
+        let passkey_id = PasskeyId::from_uuid(uuid::Uuid::parse_str(passkey_id)
+            .map_err(|_| ApplicationError::Validation("Invalid passkey ID format".to_string()))?);
+
+        let passkey = self.webauthn_service.remove(&user_id, &passkey_id).await?;
+
+        self.audit_log.record(
+            NewAuditEvent::new(AuditEventType::PasskeyDeleted)
+                .with_actor(user_id.as_uuid().to_string())
+                .with_user(&user_id)
+                .with_details(json!({ "passkey_id": passkey.id.as_uuid().to_string(), "name": passkey.name })),
+        ).await;
+
+        Ok(())
+    }
+}
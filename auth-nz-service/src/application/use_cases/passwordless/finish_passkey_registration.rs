@@ -0,0 +1,60 @@
+use std::sync::Arc;
+use serde_json::json;
+use uuid::Uuid;
+use crate::application::dto::{PasskeyDto, RegisterPasskeyDto};
+use crate::application::errors::ApplicationError;
+use crate::domain::entities::audit_event::NewAuditEvent;
+use crate::domain::repositories::{PasskeyRepository, AuditEventRepository};
+use crate::domain::services::{AttestationResponse, AuditLog, WebAuthnService};
+use crate::domain::value_objects::{AuditEventType, UserId};
+use crate::domain::errors::DomainError;
+
+const MAX_PASSKEY_NAME_LENGTH: usize = 100;
+
+pub struct FinishPasskeyRegistrationUseCase<PK: PasskeyRepository, AR: AuditEventRepository> {
+    webauthn_service: Arc<WebAuthnService<PK>>,
+    audit_log: Arc<AuditLog<AR>>,
+}
+
+impl<PK: PasskeyRepository, AR: AuditEventRepository> FinishPasskeyRegistrationUseCase<PK, AR> {
+    pub fn new(webauthn_service: Arc<WebAuthnService<PK>>, audit_log: Arc<AuditLog<AR>>) -> Self {
+        Self {
+            webauthn_service,
+            audit_log,
+        }
+    }
+
+    pub async fn execute(&self, user_id: &str, dto: RegisterPasskeyDto) -> Result<PasskeyDto, ApplicationError> {
+        let user_id = UserId::from_uuid(uuid::Uuid::parse_str(user_id)
+            .map_err(|_| ApplicationError::Validation("Invalid user ID format".to_string()))?);
+        let challenge_id = Uuid::parse_str(&dto.challenge_id)
+            .map_err(|_| ApplicationError::Domain(DomainError::InvalidWebAuthnChallenge))?;
+        let name = match dto.name.as_deref().map(str::trim) {
+            Some(name) if name.chars().count() > MAX_PASSKEY_NAME_LENGTH => {
+                return Err(ApplicationError::Validation(format!(
+                    "Passkey name must be at most {} characters",
+                    MAX_PASSKEY_NAME_LENGTH
+                )));
+            }
+            Some(name) if !name.is_empty() => name.to_string(),
+            _ => "Passkey".to_string(),
+        };
+        let response = AttestationResponse::try_from(dto.credential)?;
+
+        let passkey = self.webauthn_service
+            .finish_registration(&user_id, &challenge_id, name, &response).await?;
+
+        self.audit_log.record(
+            NewAuditEvent::new(AuditEventType::PasskeyRegistered)
+                .with_actor(user_id.as_uuid().to_string())
+                .with_user(&user_id)
+                .with_details(json!({
+                    "passkey_id": passkey.id.as_uuid().to_string(),
+                    "aaguid": passkey.aaguid.to_string(),
+                    "attestation_format": passkey.attestation_format,
+                })),
+        ).await;
+
+        Ok(PasskeyDto::from(&passkey))
+    }
+}
@@ -0,0 +1,24 @@
+use std::sync::Arc;
+use crate::application::dto::PasskeyDto;
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::PasskeyRepository;
+use crate::domain::services::WebAuthnService;
+use crate::domain::value_objects::UserId;
+
+pub struct ListPasskeysUseCase<PK: PasskeyRepository> {
+    webauthn_service: Arc<WebAuthnService<PK>>,
+}
+
+impl<PK: PasskeyRepository> ListPasskeysUseCase<PK> {
+    pub fn new(webauthn_service: Arc<WebAuthnService<PK>>) -> Self {
+        Self { webauthn_service }
+    }
+
+    pub async fn execute(&self, user_id: &str) -> Result<Vec<PasskeyDto>, ApplicationError> {
+        let user_id = UserId::from_uuid(uuid::Uuid::parse_str(user_id)
+            .map_err(|_| ApplicationError::Validation("Invalid user ID format".to_string()))?);
+        let passkeys = self.webauthn_service.list(&user_id).await?;
+
+        Ok(passkeys.iter().map(PasskeyDto::from).collect())
+    }
+}
@@ -0,0 +1,130 @@
+use std::sync::Arc;
+use serde_json::json;
+use crate::application::dto::{AuthResponseDto, ClientInfoDto, LoginResponseDto, MagicLinkLoginDto, MfaChallengeDto};
+use crate::application::errors::ApplicationError;
+use crate::domain::entities::audit_event::NewAuditEvent;
+use crate::domain::repositories::{
+    UserRepository, SessionRepository, RoleRepository, PermissionRepository, MfaRepository,
+    AccountTokenRepository, AuditEventRepository,
+};
+use crate::domain::services::{AccountTokenService, AuditLog, AuthorizationService, MfaService, PasswordService, SessionService};
+use crate::domain::value_objects::{AccountTokenPurpose, AuditEventType};
+use crate::domain::errors::DomainError;
+
+/// Signs in the user a magic link was sent to.
+pub struct MagicLinkLoginUseCase<
+    UR: UserRepository,
+    SR: SessionRepository,
+    RR: RoleRepository,
+    PR: PermissionRepository,
+    MR: MfaRepository,
+    TR: AccountTokenRepository,
+    AR: AuditEventRepository,
+> {
+    user_repository: Arc<UR>,
+    password_service: Arc<PasswordService>,
+    session_service: Arc<SessionService<SR>>,
+    authorization_service: Arc<AuthorizationService<RR, PR>>,
+    mfa_service: Arc<MfaService<MR>>,
+    account_token_service: Arc<AccountTokenService<TR>>,
+    audit_log: Arc<AuditLog<AR>>,
+}
+
+impl<UR, SR, RR, PR, MR, TR, AR> MagicLinkLoginUseCase<UR, SR, RR, PR, MR, TR, AR>
+where
+    UR: UserRepository,
+    SR: SessionRepository,
+    RR: RoleRepository,
+    PR: PermissionRepository,
+    MR: MfaRepository,
+    TR: AccountTokenRepository,
+    AR: AuditEventRepository,
+{
+    pub fn new(
+        user_repository: Arc<UR>,
+        password_service: Arc<PasswordService>,
+        session_service: Arc<SessionService<SR>>,
+        authorization_service: Arc<AuthorizationService<RR, PR>>,
+        mfa_service: Arc<MfaService<MR>>,
+        account_token_service: Arc<AccountTokenService<TR>>,
+        audit_log: Arc<AuditLog<AR>>,
+    ) -> Self {
+        Self {
+            user_repository,
+            password_service,
+            session_service,
+            authorization_service,
+            mfa_service,
+            account_token_service,
+            audit_log,
+        }
+    }
+
+    pub async fn execute(&self, dto: MagicLinkLoginDto, client: ClientInfoDto) -> Result<LoginResponseDto, ApplicationError> {
+        let user_id = match self.account_token_service.redeem(&dto.token, AccountTokenPurpose::MagicLink).await {
+            Ok(user_id) => user_id,
+            Err(e) => {
+                self.audit_log.record(
+                    NewAuditEvent::new(AuditEventType::LoginFailed)
+                        .with_ip(client.ip_address.clone())
+                        .with_details(json!({ "method": "magic_link", "reason": "invalid_token" })),
+                ).await;
+                return Err(e.into());
+            }
+        };
+
+        let mut user = self.user_repository.find_by_id(&user_id).await?
+            .ok_or(ApplicationError::Domain(DomainError::UserNotFound))?;
+
+        if !user.is_active {
+            self.audit_log.record(
+                NewAuditEvent::new(AuditEventType::LoginFailed)
+                    .with_user(&user.id)
+                    .with_ip(client.ip_address.clone())
+                    .with_details(json!({ "method": "magic_link", "reason": "inactive" })),
+            ).await;
+            return Err(ApplicationError::Domain(DomainError::Unauthorized));
+        }
+
+        // Opening the link proves ownership of the address. Whoever registered it
+        // without proving that must not keep a password or sessions on the account
+        if !user.is_email_verified() {
+            user.update_password(self.password_service.unusable_hash()?);
+            user.verify_email();
+            self.user_repository.update(&user).await
+                .map_err(|e| ApplicationError::Repository(e.to_string()))?;
+            self.session_service.end_all(&user.id).await
+                .map_err(|e| ApplicationError::Repository(e.to_string()))?;
+        }
+
+        // The link stands in for the password only; a second factor is still required
+        if self.mfa_service.is_enabled(&user.id).await? {
+            let (challenge_token, expires_at) = self.mfa_service.start_challenge(&user.id).await?;
+            return Ok(LoginResponseDto::MfaRequired(MfaChallengeDto {
+                mfa_required: true,
+                challenge_token: challenge_token.as_str().to_string(),
+                expires_at: expires_at.to_rfc3339(),
+            }));
+        }
+
+        let grants = self.authorization_service.grants(&user.id).await?;
+        let issued = self.session_service.start(&user.id, &grants, client.device()).await?;
+        self.audit_log.record(
+            NewAuditEvent::new(AuditEventType::LoginSucceeded)
+                .with_actor(user.id.as_uuid().to_string())
+                .with_user(&user.id)
+                .with_ip(client.ip_address.clone())
+                .with_details(json!({
+                    "method": "magic_link",
+                    "session_id": issued.session.family_id.as_uuid().to_string(),
+                })),
+        ).await;
+
+        Ok(LoginResponseDto::Session(AuthResponseDto {
+            token: issued.session.token.as_str().to_string(),
+            expires_at: issued.session.expires_at.to_rfc3339(),
+            refresh_token: issued.refresh_token.as_str().to_string(),
+            refresh_expires_at: issued.session.refresh_expires_at.to_rfc3339(),
+        }))
+    }
+}
@@ -0,0 +1,17 @@
+pub mod request_magic_link;
+pub mod magic_link_login;
+pub mod start_passkey_registration;
+pub mod finish_passkey_registration;
+pub mod list_passkeys;
+pub mod delete_passkey;
+pub mod start_passkey_login;
+pub mod passkey_login;
+
+pub use request_magic_link::RequestMagicLinkUseCase;
+pub use magic_link_login::MagicLinkLoginUseCase;
+pub use start_passkey_registration::StartPasskeyRegistrationUseCase;
+pub use finish_passkey_registration::FinishPasskeyRegistrationUseCase;
+pub use list_passkeys::ListPasskeysUseCase;
+pub use delete_passkey::DeletePasskeyUseCase;
+pub use start_passkey_login::StartPasskeyLoginUseCase;
+pub use passkey_login::PasskeyLoginUseCase;
@@ -0,0 +1,108 @@
+use std::sync::Arc;
+use serde_json::json;
+use uuid::Uuid;
+use crate::application::dto::{AuthResponseDto, ClientInfoDto, LoginResponseDto, PasskeyLoginDto};
+use crate::application::errors::ApplicationError;
+use crate::domain::entities::audit_event::NewAuditEvent;
+use crate::domain::repositories::{
+    UserRepository, SessionRepository, RoleRepository, PermissionRepository, PasskeyRepository, AuditEventRepository,
+};
+use crate::domain::services::{AssertionResponse, AuditLog, AuthorizationService, SessionService, WebAuthnService};
+use crate::domain::value_objects::AuditEventType;
+use crate::domain::errors::DomainError;
+
+/// Signs in with a passkey assertion.
+pub struct PasskeyLoginUseCase<
+    UR: UserRepository,
+    SR: SessionRepository,
+    RR: RoleRepository,
+    PR: PermissionRepository,
+    PK: PasskeyRepository,
+    AR: AuditEventRepository,
+> {
+    user_repository: Arc<UR>,
+    session_service: Arc<SessionService<SR>>,
+    authorization_service: Arc<AuthorizationService<RR, PR>>,
+    webauthn_service: Arc<WebAuthnService<PK>>,
+    audit_log: Arc<AuditLog<AR>>,
+}
+
+impl<UR, SR, RR, PR, PK, AR> PasskeyLoginUseCase<UR, SR, RR, PR, PK, AR>
+where
+    UR: UserRepository,
+    SR: SessionRepository,
+    RR: RoleRepository,
+    PR: PermissionRepository,
+    PK: PasskeyRepository,
+    AR: AuditEventRepository,
+{
+    pub fn new(
+        user_repository: Arc<UR>,
+        session_service: Arc<SessionService<SR>>,
+        authorization_service: Arc<AuthorizationService<RR, PR>>,
+        webauthn_service: Arc<WebAuthnService<PK>>,
+        audit_log: Arc<AuditLog<AR>>,
+    ) -> Self {
+        Self {
+            user_repository,
+            session_service,
+            authorization_service,
+            webauthn_service,
+            audit_log,
+        }
+    }
+
+    pub async fn execute(&self, dto: PasskeyLoginDto, client: ClientInfoDto) -> Result<LoginResponseDto, ApplicationError> {
+        let challenge_id = Uuid::parse_str(&dto.challenge_id)
+            .map_err(|_| ApplicationError::Domain(DomainError::InvalidWebAuthnChallenge))?;
+        let response = AssertionResponse::try_from(dto.credential)?;
+
+        let passkey = match self.webauthn_service.finish_authentication(&challenge_id, &response).await {
+            Ok(passkey) => passkey,
+            Err(e) => {
+                self.audit_log.record(
+                    NewAuditEvent::new(AuditEventType::LoginFailed)
+                        .with_ip(client.ip_address.clone())
+                        .with_details(json!({ "method": "passkey", "reason": e.to_string() })),
+                ).await;
+                return Err(e.into());
+            }
+        };
+
+        let user = self.user_repository.find_by_id(&passkey.user_id).await?
+            .ok_or(ApplicationError::Domain(DomainError::UserNotFound))?;
+
+        if !user.is_active {
+            self.audit_log.record(
+                NewAuditEvent::new(AuditEventType::LoginFailed)
+                    .with_user(&user.id)
+                    .with_ip(client.ip_address.clone())
+                    .with_details(json!({ "method": "passkey", "reason": "inactive" })),
+            ).await;
+            return Err(ApplicationError::Domain(DomainError::Unauthorized));
+        }
+
+        // User verification is required, so the passkey is already two factors
+        // (the device and its PIN or biometric) and no TOTP step follows
+        let grants = self.authorization_service.grants(&user.id).await?;
+        let issued = self.session_service.start(&user.id, &grants, client.device()).await?;
+        self.audit_log.record(
+            NewAuditEvent::new(AuditEventType::LoginSucceeded)
+                .with_actor(user.id.as_uuid().to_string())
+                .with_user(&user.id)
+                .with_ip(client.ip_address.clone())
+                .with_details(json!({
+                    "method": "passkey",
+                    "passkey_id": passkey.id.as_uuid().to_string(),
+                    "session_id": issued.session.family_id.as_uuid().to_string(),
+                })),
+        ).await;
+
+        Ok(LoginResponseDto::Session(AuthResponseDto {
+            token: issued.session.token.as_str().to_string(),
+            expires_at: issued.session.expires_at.to_rfc3339(),
+            refresh_token: issued.refresh_token.as_str().to_string(),
+            refresh_expires_at: issued.session.refresh_expires_at.to_rfc3339(),
+        }))
+    }
+}
@@ -0,0 +1,34 @@
+use std::sync::Arc;
+use crate::application::dto::RequestMagicLinkDto;
+use crate::application::errors::ApplicationError;
+use crate::domain::events::EventPublisher;
+use crate::domain::repositories::{UserRepository, AccountTokenRepository};
+use crate::domain::services::AccountNotifier;
+use crate::domain::value_objects::Email;
+
+pub struct RequestMagicLinkUseCase<UR: UserRepository, TR: AccountTokenRepository, EP: EventPublisher> {
+    user_repository: Arc<UR>,
+    account_notifier: Arc<AccountNotifier<TR, EP>>,
+}
+
+impl<UR: UserRepository, TR: AccountTokenRepository, EP: EventPublisher> RequestMagicLinkUseCase<UR, TR, EP> {
+    pub fn new(user_repository: Arc<UR>, account_notifier: Arc<AccountNotifier<TR, EP>>) -> Self {
+        Self {
+            user_repository,
+            account_notifier,
+        }
+    }
+
+    /// Succeeds whether or not the email belongs to an account, so the
+    /// endpoint cannot be used to discover registered addresses.
+    pub async fn execute(&self, dto: RequestMagicLinkDto) -> Result<(), ApplicationError> {
+        let email = Email::new(dto.email)?;
+
+        match self.user_repository.find_by_email(&email).await? {
+            Some(user) if user.is_active => self.account_notifier.send_magic_link(&user).await?,
+            _ => tracing::info!("Magic link requested for unknown or inactive account"),
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::Utc;
+use crate::application::dto::{CredentialDescriptorDto, PasskeyLoginOptionsDto, PublicKeyRequestOptionsDto, StartPasskeyLoginDto};
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::{UserRepository, PasskeyRepository};
+use crate::domain::services::WebAuthnService;
+use crate::domain::value_objects::Email;
+
+pub struct StartPasskeyLoginUseCase<UR: UserRepository, PK: PasskeyRepository> {
+    user_repository: Arc<UR>,
+    webauthn_service: Arc<WebAuthnService<PK>>,
+}
+
+impl<UR: UserRepository, PK: PasskeyRepository> StartPasskeyLoginUseCase<UR, PK> {
+    pub fn new(user_repository: Arc<UR>, webauthn_service: Arc<WebAuthnService<PK>>) -> Self {
+        Self {
+            user_repository,
+            webauthn_service,
+        }
+    }
+
+    /// An email that matches no account gets the same options as no email at
+    /// all, so the endpoint does not reveal which addresses are registered.
+    pub async fn execute(&self, dto: StartPasskeyLoginDto) -> Result<PasskeyLoginOptionsDto, ApplicationError> {
+        let user = match dto.email {
+            Some(email) => self.user_repository.find_by_email(&Email::new(email)?).await?,
+            None => None,
+        };
+
+        let authentication = self.webauthn_service
+            .start_authentication(user.as_ref().map(|user| &user.id)).await?;
+
+        Ok(PasskeyLoginOptionsDto {
+            challenge_id: authentication.challenge.id.to_string(),
+            public_key: PublicKeyRequestOptionsDto {
+                challenge: URL_SAFE_NO_PAD.encode(&authentication.challenge.challenge),
+                timeout: (authentication.challenge.expires_at - Utc::now()).num_milliseconds(),
+                rp_id: authentication.rp_id,
+                allow_credentials: authentication.allowed_credentials.iter()
+                    .map(|id| CredentialDescriptorDto::new(id))
+                    .collect(),
+                user_verification: "required".to_string(),
+            },
+        })
+    }
+}
@@ -0,0 +1,68 @@
+use std::sync::Arc;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::Utc;
+use crate::application::dto::{
+    AuthenticatorSelectionDto, CredentialDescriptorDto, CredentialParameterDto, PasskeyRegistrationOptionsDto,
+    PasskeyUserDto, PublicKeyCreationOptionsDto, RelyingPartyDto,
+};
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::{UserRepository, PasskeyRepository};
+use crate::domain::services::WebAuthnService;
+use crate::domain::value_objects::{UserId, SUPPORTED_COSE_ALGORITHMS};
+use crate::domain::errors::DomainError;
+
+pub struct StartPasskeyRegistrationUseCase<UR: UserRepository, PK: PasskeyRepository> {
+    user_repository: Arc<UR>,
+    webauthn_service: Arc<WebAuthnService<PK>>,
+}
+
+impl<UR: UserRepository, PK: PasskeyRepository> StartPasskeyRegistrationUseCase<UR, PK> {
+    pub fn new(user_repository: Arc<UR>, webauthn_service: Arc<WebAuthnService<PK>>) -> Self {
+        Self {
+            user_repository,
+            webauthn_service,
+        }
+    }
+
+    pub async fn execute(&self, user_id: &str) -> Result<PasskeyRegistrationOptionsDto, ApplicationError> {
+        let user_id = UserId::from_uuid(uuid::Uuid::parse_str(user_id)
+            .map_err(|_| ApplicationError::Validation("Invalid user ID format".to_string()))?);
+        let user = self.user_repository.find_by_id(&user_id).await?
+            .ok_or(ApplicationError::Domain(DomainError::UserNotFound))?;
+
+        let registration = self.webauthn_service.start_registration(&user.id).await?;
+
+        Ok(PasskeyRegistrationOptionsDto {
+            challenge_id: registration.challenge.id.to_string(),
+            public_key: PublicKeyCreationOptionsDto {
+                rp: RelyingPartyDto {
+                    id: registration.rp_id,
+                    name: registration.rp_name,
+                },
+                user: PasskeyUserDto {
+                    id: URL_SAFE_NO_PAD.encode(user.id.as_uuid().as_bytes()),
+                    name: user.email.as_str().to_string(),
+                    display_name: user.email.as_str().to_string(),
+                },
+                challenge: URL_SAFE_NO_PAD.encode(&registration.challenge.challenge),
+                pub_key_cred_params: SUPPORTED_COSE_ALGORITHMS.iter()
+                    .map(|&alg| CredentialParameterDto {
+                        credential_type: "public-key".to_string(),
+                        alg,
+                    })
+                    .collect(),
+                timeout: (registration.challenge.expires_at - Utc::now()).num_milliseconds(),
+                exclude_credentials: registration.excluded_credentials.iter()
+                    .map(|id| CredentialDescriptorDto::new(id))
+                    .collect(),
+                // Discoverable, so the passkey also works without typing an email first
+                authenticator_selection: AuthenticatorSelectionDto {
+                    resident_key: "required".to_string(),
+                    user_verification: "required".to_string(),
+                },
+                attestation: "direct".to_string(),
+            },
+        })
+    }
+}
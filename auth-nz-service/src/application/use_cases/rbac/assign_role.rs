@@ -0,0 +1,53 @@
+use std::sync::Arc;
+use serde_json::json;
+use crate::application::dto::AssignRoleDto;
+use crate::application::errors::ApplicationError;
+use crate::domain::entities::audit_event::NewAuditEvent;
+use crate::domain::repositories::{UserRepository, RoleRepository, AuditEventRepository};
+use crate::domain::services::AuditLog;
+use crate::domain::value_objects::{AuditEventType, RoleId, UserId};
+use crate::domain::errors::DomainError;
+
+pub struct AssignRoleUseCase<UR: UserRepository, RR: RoleRepository, AR: AuditEventRepository> {
+    user_repository: Arc<UR>,
+    role_repository: Arc<RR>,
+    audit_log: Arc<AuditLog<AR>>,
+}
+
+impl<UR: UserRepository, RR: RoleRepository, AR: AuditEventRepository> AssignRoleUseCase<UR, RR, AR> {
+    pub fn new(user_repository: Arc<UR>, role_repository: Arc<RR>, audit_log: Arc<AuditLog<AR>>) -> Self {
+        Self {
+            user_repository,
+            role_repository,
+            audit_log,
+        }
+    }
+
+    /// `actor_id` is the admin making the change.
+    pub async fn execute(&self, actor_id: &str, user_id: &str, dto: AssignRoleDto) -> Result<(), ApplicationError> {
+        let user_id = uuid::Uuid::parse_str(user_id)
+            .map_err(|_| ApplicationError::Validation("Invalid user ID format".to_string()))?;
+        let role_id = uuid::Uuid::parse_str(&dto.role_id)
+            .map_err(|_| ApplicationError::Validation("Invalid role ID format".to_string()))?;
+
+        let user = self.user_repository.find_by_id(&UserId::from_uuid(user_id)).await?
+            .ok_or(ApplicationError::Domain(DomainError::UserNotFound))?;
+        let role = self.role_repository.find_by_id(&RoleId::from_uuid(role_id)).await?
+            .ok_or(ApplicationError::Domain(DomainError::RoleNotFound))?;
+
+        self.role_repository.assign_to_user(&user.id, &role.id).await
+            .map_err(|e| ApplicationError::Repository(e.to_string()))?;
+
+        self.audit_log.record(
+            NewAuditEvent::new(AuditEventType::RoleAssigned)
+                .with_actor(actor_id)
+                .with_user(&user.id)
+                .with_details(json!({
+                    "role_id": role.id.as_uuid().to_string(),
+                    "role": role.name.as_str(),
+                })),
+        ).await;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,34 @@
+use std::sync::Arc;
+use crate::application::dto::{CreatePermissionDto, PermissionDto};
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::PermissionRepository;
+use crate::domain::value_objects::{PermissionId, PermissionName};
+use crate::domain::entities::permission::Permission;
+use crate::domain::errors::DomainError;
+
+pub struct CreatePermissionUseCase<PR: PermissionRepository> {
+    permission_repository: Arc<PR>,
+}
+
+impl<PR: PermissionRepository> CreatePermissionUseCase<PR> {
+    pub fn new(permission_repository: Arc<PR>) -> Self {
+        Self { permission_repository }
+    }
+
+    pub async fn execute(&self, dto: CreatePermissionDto) -> Result<PermissionDto, ApplicationError> {
+        let name = PermissionName::new(dto.name)?;
+        if dto.resource.is_empty() || dto.action.is_empty() {
+            return Err(ApplicationError::Validation("Resource and action are required".to_string()));
+        }
+
+        if self.permission_repository.find_by_name(&name).await?.is_some() {
+            return Err(ApplicationError::Domain(DomainError::PermissionAlreadyExists));
+        }
+
+        let permission = Permission::new(PermissionId::new(), name, dto.resource, dto.action);
+        self.permission_repository.create(&permission).await
+            .map_err(|e| ApplicationError::Repository(e.to_string()))?;
+
+        Ok(PermissionDto::from(&permission))
+    }
+}
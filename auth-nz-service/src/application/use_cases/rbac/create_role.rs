@@ -0,0 +1,41 @@
+use std::sync::Arc;
+use crate::application::dto::{CreateRoleDto, RoleDto};
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::RoleRepository;
+use crate::domain::services::RoleService;
+use crate::domain::value_objects::{RoleId, RoleName};
+use crate::domain::entities::role::Role;
+
+pub struct CreateRoleUseCase<RR: RoleRepository> {
+    role_repository: Arc<RR>,
+    role_service: RoleService<RR>,
+}
+
+impl<RR: RoleRepository> CreateRoleUseCase<RR> {
+    pub fn new(role_repository: Arc<RR>) -> Self {
+        let role_service = RoleService::new(Arc::clone(&role_repository));
+        Self {
+            role_repository,
+            role_service,
+        }
+    }
+
+    pub async fn execute(&self, dto: CreateRoleDto) -> Result<RoleDto, ApplicationError> {
+        let name = RoleName::new(dto.name)?;
+        self.role_service.validate_role_creation(&name).await?;
+
+        let mut role = Role::new(RoleId::new(), name);
+        if let Some(parent_id) = dto.parent_id {
+            let parent_id = uuid::Uuid::parse_str(&parent_id)
+                .map_err(|_| ApplicationError::Validation("Invalid role ID format".to_string()))?;
+            let parent_id = RoleId::from_uuid(parent_id);
+            self.role_service.validate_parent(&role.id, &parent_id).await?;
+            role.set_parent(Some(parent_id));
+        }
+
+        self.role_repository.create(&role).await
+            .map_err(|e| ApplicationError::Repository(e.to_string()))?;
+
+        Ok(RoleDto::from(&role))
+    }
+}
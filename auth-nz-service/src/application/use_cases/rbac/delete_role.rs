@@ -0,0 +1,27 @@
+use std::sync::Arc;
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::RoleRepository;
+use crate::domain::value_objects::RoleId;
+use crate::domain::errors::DomainError;
+
+pub struct DeleteRoleUseCase<RR: RoleRepository> {
+    role_repository: Arc<RR>,
+}
+
+impl<RR: RoleRepository> DeleteRoleUseCase<RR> {
+    pub fn new(role_repository: Arc<RR>) -> Self {
+        Self { role_repository }
+    }
+
+    pub async fn execute(&self, role_id: &str) -> Result<(), ApplicationError> {
+        let role_id = uuid::Uuid::parse_str(role_id)
+            .map_err(|_| ApplicationError::Validation("Invalid role ID format".to_string()))?;
+        let role = self.role_repository.find_by_id(&RoleId::from_uuid(role_id)).await?
+            .ok_or(ApplicationError::Domain(DomainError::RoleNotFound))?;
+
+        self.role_repository.delete(&role.id).await
+            .map_err(|e| ApplicationError::Repository(e.to_string()))?;
+
+        Ok(())
+    }
+}
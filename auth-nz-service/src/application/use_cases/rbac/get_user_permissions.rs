@@ -0,0 +1,38 @@
+use std::sync::Arc;
+use crate::application::dto::{EffectivePermissionsDto, EffectivePermissionsQueryDto};
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::{RoleRepository, PermissionRepository};
+use crate::domain::services::AuthorizationService;
+use crate::domain::value_objects::{OrganizationId, UserId};
+
+pub struct GetUserPermissionsUseCase<RR: RoleRepository, PR: PermissionRepository> {
+    authorization_service: Arc<AuthorizationService<RR, PR>>,
+}
+
+impl<RR: RoleRepository, PR: PermissionRepository> GetUserPermissionsUseCase<RR, PR> {
+    pub fn new(authorization_service: Arc<AuthorizationService<RR, PR>>) -> Self {
+        Self { authorization_service }
+    }
+
+    pub async fn execute(&self, user_id: &str, query: EffectivePermissionsQueryDto) -> Result<EffectivePermissionsDto, ApplicationError> {
+        let uuid = uuid::Uuid::parse_str(user_id)
+            .map_err(|_| ApplicationError::Validation("Invalid user ID format".to_string()))?;
+        let organization_id = query.organization_id
+            .map(|id| uuid::Uuid::parse_str(&id).map(OrganizationId::from_uuid))
+            .transpose()
+            .map_err(|_| ApplicationError::Validation("Invalid organization ID format".to_string()))?;
+
+        let user_id = UserId::from_uuid(uuid);
+        let grants = match organization_id {
+            Some(organization_id) => self.authorization_service.organization_grants(&user_id, &organization_id).await?,
+            None => self.authorization_service.grants(&user_id).await?,
+        };
+
+        Ok(EffectivePermissionsDto {
+            user_id: uuid.to_string(),
+            organization_id: grants.organization_id.map(|id| id.as_uuid().to_string()),
+            roles: grants.roles,
+            permissions: grants.permissions,
+        })
+    }
+}
@@ -0,0 +1,54 @@
+use std::sync::Arc;
+use serde_json::json;
+use crate::application::dto::GrantPermissionDto;
+use crate::application::errors::ApplicationError;
+use crate::domain::entities::audit_event::NewAuditEvent;
+use crate::domain::repositories::{RoleRepository, PermissionRepository, AuditEventRepository};
+use crate::domain::services::AuditLog;
+use crate::domain::value_objects::{AuditEventType, PermissionId, RoleId};
+use crate::domain::errors::DomainError;
+
+pub struct GrantPermissionUseCase<RR: RoleRepository, PR: PermissionRepository, AR: AuditEventRepository> {
+    role_repository: Arc<RR>,
+    permission_repository: Arc<PR>,
+    audit_log: Arc<AuditLog<AR>>,
+}
+
+impl<RR: RoleRepository, PR: PermissionRepository, AR: AuditEventRepository> GrantPermissionUseCase<RR, PR, AR> {
+    pub fn new(role_repository: Arc<RR>, permission_repository: Arc<PR>, audit_log: Arc<AuditLog<AR>>) -> Self {
+        Self {
+            role_repository,
+            permission_repository,
+            audit_log,
+        }
+    }
+
+    /// `actor_id` is the admin making the change.
+    pub async fn execute(&self, actor_id: &str, role_id: &str, dto: GrantPermissionDto) -> Result<(), ApplicationError> {
+        let role_id = uuid::Uuid::parse_str(role_id)
+            .map_err(|_| ApplicationError::Validation("Invalid role ID format".to_string()))?;
+        let permission_id = uuid::Uuid::parse_str(&dto.permission_id)
+            .map_err(|_| ApplicationError::Validation("Invalid permission ID format".to_string()))?;
+
+        let role = self.role_repository.find_by_id(&RoleId::from_uuid(role_id)).await?
+            .ok_or(ApplicationError::Domain(DomainError::RoleNotFound))?;
+        let permission = self.permission_repository.find_by_id(&PermissionId::from_uuid(permission_id)).await?
+            .ok_or(ApplicationError::Domain(DomainError::PermissionNotFound))?;
+
+        self.permission_repository.grant_to_role(&role.id, &permission.id).await
+            .map_err(|e| ApplicationError::Repository(e.to_string()))?;
+
+        self.audit_log.record(
+            NewAuditEvent::new(AuditEventType::PermissionGranted)
+                .with_actor(actor_id)
+                .with_details(json!({
+                    "role_id": role.id.as_uuid().to_string(),
+                    "role": role.name.as_str(),
+                    "permission_id": permission.id.as_uuid().to_string(),
+                    "permission": permission.name.as_str(),
+                })),
+        ).await;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,19 @@
+use std::sync::Arc;
+use crate::application::dto::PermissionDto;
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::PermissionRepository;
+
+pub struct ListPermissionsUseCase<PR: PermissionRepository> {
+    permission_repository: Arc<PR>,
+}
+
+impl<PR: PermissionRepository> ListPermissionsUseCase<PR> {
+    pub fn new(permission_repository: Arc<PR>) -> Self {
+        Self { permission_repository }
+    }
+
+    pub async fn execute(&self) -> Result<Vec<PermissionDto>, ApplicationError> {
+        let permissions = self.permission_repository.find_all().await?;
+        Ok(permissions.iter().map(PermissionDto::from).collect())
+    }
+}
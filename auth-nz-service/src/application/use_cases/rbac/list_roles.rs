@@ -0,0 +1,19 @@
+use std::sync::Arc;
+use crate::application::dto::RoleDto;
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::RoleRepository;
+
+pub struct ListRolesUseCase<RR: RoleRepository> {
+    role_repository: Arc<RR>,
+}
+
+impl<RR: RoleRepository> ListRolesUseCase<RR> {
+    pub fn new(role_repository: Arc<RR>) -> Self {
+        Self { role_repository }
+    }
+
+    pub async fn execute(&self) -> Result<Vec<RoleDto>, ApplicationError> {
+        let roles = self.role_repository.find_all().await?;
+        Ok(roles.iter().map(RoleDto::from).collect())
+    }
+}
@@ -0,0 +1,23 @@
+pub mod create_role;
+pub mod list_roles;
+pub mod set_role_parent;
+pub mod delete_role;
+pub mod create_permission;
+pub mod list_permissions;
+pub mod grant_permission;
+pub mod revoke_permission;
+pub mod assign_role;
+pub mod unassign_role;
+pub mod get_user_permissions;
+
+pub use create_role::CreateRoleUseCase;
+pub use list_roles::ListRolesUseCase;
+pub use set_role_parent::SetRoleParentUseCase;
+pub use delete_role::DeleteRoleUseCase;
+pub use create_permission::CreatePermissionUseCase;
+pub use list_permissions::ListPermissionsUseCase;
+pub use grant_permission::GrantPermissionUseCase;
+pub use revoke_permission::RevokePermissionUseCase;
+pub use assign_role::AssignRoleUseCase;
+pub use unassign_role::UnassignRoleUseCase;
+pub use get_user_permissions::GetUserPermissionsUseCase;
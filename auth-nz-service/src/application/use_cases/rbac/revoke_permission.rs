@@ -0,0 +1,45 @@
+use std::sync::Arc;
+use serde_json::json;
+use crate::application::errors::ApplicationError;
+use crate::domain::entities::audit_event::NewAuditEvent;
+use crate::domain::repositories::{PermissionRepository, AuditEventRepository};
+use crate::domain::services::AuditLog;
+use crate::domain::value_objects::{AuditEventType, PermissionId, RoleId};
+
+pub struct RevokePermissionUseCase<PR: PermissionRepository, AR: AuditEventRepository> {
+    permission_repository: Arc<PR>,
+    audit_log: Arc<AuditLog<AR>>,
+}
+
+impl<PR: PermissionRepository, AR: AuditEventRepository> RevokePermissionUseCase<PR, AR> {
+    pub fn new(permission_repository: Arc<PR>, audit_log: Arc<AuditLog<AR>>) -> Self {
+        Self {
+            permission_repository,
+            audit_log,
+        }
+    }
+
+    /// `actor_id` is the admin making the change.
+    pub async fn execute(&self, actor_id: &str, role_id: &str, permission_id: &str) -> Result<(), ApplicationError> {
+        let role_id = uuid::Uuid::parse_str(role_id)
+            .map_err(|_| ApplicationError::Validation("Invalid role ID format".to_string()))?;
+        let permission_id = uuid::Uuid::parse_str(permission_id)
+            .map_err(|_| ApplicationError::Validation("Invalid permission ID format".to_string()))?;
+
+        self.permission_repository
+            .revoke_from_role(&RoleId::from_uuid(role_id), &PermissionId::from_uuid(permission_id))
+            .await
+            .map_err(|e| ApplicationError::Repository(e.to_string()))?;
+
+        self.audit_log.record(
+            NewAuditEvent::new(AuditEventType::PermissionRevoked)
+                .with_actor(actor_id)
+                .with_details(json!({
+                    "role_id": role_id.to_string(),
+                    "permission_id": permission_id.to_string(),
+                })),
+        ).await;
+
+        Ok(())
+    }
+}
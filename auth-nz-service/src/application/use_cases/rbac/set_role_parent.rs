@@ -0,0 +1,46 @@
+use std::sync::Arc;
+use crate::application::dto::{SetRoleParentDto, RoleDto};
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::RoleRepository;
+use crate::domain::services::RoleService;
+use crate::domain::value_objects::RoleId;
+use crate::domain::errors::DomainError;
+
+pub struct SetRoleParentUseCase<RR: RoleRepository> {
+    role_repository: Arc<RR>,
+    role_service: RoleService<RR>,
+}
+
+impl<RR: RoleRepository> SetRoleParentUseCase<RR> {
+    pub fn new(role_repository: Arc<RR>) -> Self {
+        let role_service = RoleService::new(Arc::clone(&role_repository));
+        Self {
+            role_repository,
+            role_service,
+        }
+    }
+
+    pub async fn execute(&self, role_id: &str, dto: SetRoleParentDto) -> Result<RoleDto, ApplicationError> {
+        let role_id = uuid::Uuid::parse_str(role_id)
+            .map_err(|_| ApplicationError::Validation("Invalid role ID format".to_string()))?;
+        let mut role = self.role_repository.find_by_id(&RoleId::from_uuid(role_id)).await?
+            .ok_or(ApplicationError::Domain(DomainError::RoleNotFound))?;
+
+        let parent_id = match dto.parent_id {
+            Some(parent_id) => {
+                let parent_id = uuid::Uuid::parse_str(&parent_id)
+                    .map_err(|_| ApplicationError::Validation("Invalid role ID format".to_string()))?;
+                let parent_id = RoleId::from_uuid(parent_id);
+                self.role_service.validate_parent(&role.id, &parent_id).await?;
+                Some(parent_id)
+            }
+            None => None,
+        };
+
+        role.set_parent(parent_id);
+        self.role_repository.update(&role).await
+            .map_err(|e| ApplicationError::Repository(e.to_string()))?;
+
+        Ok(RoleDto::from(&role))
+    }
+}
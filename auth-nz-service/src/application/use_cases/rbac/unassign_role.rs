@@ -0,0 +1,44 @@
+use std::sync::Arc;
+use serde_json::json;
+use crate::application::errors::ApplicationError;
+use crate::domain::entities::audit_event::NewAuditEvent;
+use crate::domain::repositories::{RoleRepository, AuditEventRepository};
+use crate::domain::services::AuditLog;
+use crate::domain::value_objects::{AuditEventType, RoleId, UserId};
+
+pub struct UnassignRoleUseCase<RR: RoleRepository, AR: AuditEventRepository> {
+    role_repository: Arc<RR>,
+    audit_log: Arc<AuditLog<AR>>,
+}
+
+impl<RR: RoleRepository, AR: AuditEventRepository> UnassignRoleUseCase<RR, AR> {
+    pub fn new(role_repository: Arc<RR>, audit_log: Arc<AuditLog<AR>>) -> Self {
+        Self {
+            role_repository,
+            audit_log,
+        }
+    }
+
+    /// `actor_id` is the admin making the change.
+    pub async fn execute(&self, actor_id: &str, user_id: &str, role_id: &str) -> Result<(), ApplicationError> {
+        let user_id = uuid::Uuid::parse_str(user_id)
+            .map_err(|_| ApplicationError::Validation("Invalid user ID format".to_string()))?;
+        let role_id = uuid::Uuid::parse_str(role_id)
+            .map_err(|_| ApplicationError::Validation("Invalid role ID format".to_string()))?;
+        let user_id = UserId::from_uuid(user_id);
+
+        self.role_repository
+            .unassign_from_user(&user_id, &RoleId::from_uuid(role_id))
+            .await
+            .map_err(|e| ApplicationError::Repository(e.to_string()))?;
+
+        self.audit_log.record(
+            NewAuditEvent::new(AuditEventType::RoleUnassigned)
+                .with_actor(actor_id)
+                .with_user(&user_id)
+                .with_details(json!({ "role_id": role_id.to_string() })),
+        ).await;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+use std::sync::Arc;
+use crate::application::dto::{RefreshSessionDto, AuthResponseDto, ClientInfoDto};
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::{
+    SessionRepository, RoleRepository, PermissionRepository, OrganizationRepository, OrganizationInvitationRepository,
+};
+use crate::domain::services::{AuthorizationService, OrganizationService, SessionService};
+use crate::domain::value_objects::RefreshToken;
+
+pub struct RefreshSessionUseCase<SR, RR, PR, OR, IR>
+where
+    SR: SessionRepository,
+    RR: RoleRepository,
+    PR: PermissionRepository,
+    OR: OrganizationRepository,
+    IR: OrganizationInvitationRepository,
+{
+    session_service: Arc<SessionService<SR>>,
+    authorization_service: Arc<AuthorizationService<RR, PR>>,
+    organization_service: Arc<OrganizationService<OR, IR>>,
+}
+
+impl<SR, RR, PR, OR, IR> RefreshSessionUseCase<SR, RR, PR, OR, IR>
+where
+    SR: SessionRepository,
+    RR: RoleRepository,
+    PR: PermissionRepository,
+    OR: OrganizationRepository,
+    IR: OrganizationInvitationRepository,
+{
+    pub fn new(
+        session_service: Arc<SessionService<SR>>,
+        authorization_service: Arc<AuthorizationService<RR, PR>>,
+        organization_service: Arc<OrganizationService<OR, IR>>,
+    ) -> Self {
+        Self {
+            session_service,
+            authorization_service,
+            organization_service,
+        }
+    }
+
+    pub async fn execute(&self, dto: RefreshSessionDto, client: ClientInfoDto) -> Result<AuthResponseDto, ApplicationError> {
+        let refresh_token = RefreshToken::new(dto.refresh_token);
+        let previous = self.session_service.redeem(&refresh_token, None).await?;
+        let grants = match previous.organization_id {
+            // Someone removed from the organization gets no more tokens for it
+            Some(organization_id) => {
+                self.organization_service.membership(&organization_id, &previous.user_id).await?;
+                self.authorization_service.organization_grants(&previous.user_id, &organization_id).await?
+            }
+            None => self.authorization_service.grants(&previous.user_id).await?,
+        };
+        let issued = self.session_service.rotate(&previous, &grants, client.device()).await?;
+
+        Ok(AuthResponseDto {
+            token: issued.session.token.as_str().to_string(),
+            expires_at: issued.session.expires_at.to_rfc3339(),
+            refresh_token: issued.refresh_token.as_str().to_string(),
+            refresh_expires_at: issued.session.refresh_expires_at.to_rfc3339(),
+        })
+    }
+}
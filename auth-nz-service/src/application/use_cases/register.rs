@@ -1,53 +1,91 @@
 use std::sync::Arc;
-use crate::application::dto::{RegisterDto, AuthResponseDto};
+use crate::application::dto::{RegisterDto, AuthResponseDto, ClientInfoDto};
 use crate::application::errors::ApplicationError;
-use crate::domain::repositories::{UserRepository, SessionRepository};
-use crate::domain::services::AuthService;
-use crate::domain::value_objects::{Email, PasswordHash, UserId, SessionId, Token};
+use crate::domain::events::EventPublisher;
+use crate::domain::repositories::{
+    UserRepository, SessionRepository, RoleRepository, PermissionRepository, AccountTokenRepository,
+    PasswordHistoryRepository, BreachedPasswordRepository,
+};
+use crate::domain::services::{AccountNotifier, AuthService, AuthorizationService, PasswordPolicyService, PasswordService, SessionService};
+use crate::domain::value_objects::{Email, UserId};
 use crate::domain::entities::user::User;
-use crate::domain::entities::session::Session;
-use chrono::{Duration, Utc};
 
-pub struct RegisterUseCase<UR: UserRepository, SR: SessionRepository> {
+pub struct RegisterUseCase<
+    UR: UserRepository,
+    SR: SessionRepository,
+    RR: RoleRepository,
+    PR: PermissionRepository,
+    TR: AccountTokenRepository,
+    EP: EventPublisher,
+    HR: PasswordHistoryRepository,
+    BR: BreachedPasswordRepository,
+> {
     user_repository: Arc<UR>,
-    session_repository: Arc<SR>,
     auth_service: AuthService<UR>,
+    password_service: Arc<PasswordService>,
+    password_policy_service: Arc<PasswordPolicyService<HR, BR>>,
+    session_service: Arc<SessionService<SR>>,
+    authorization_service: Arc<AuthorizationService<RR, PR>>,
+    account_notifier: Arc<AccountNotifier<TR, EP>>,
 }
 
-impl<UR: UserRepository, SR: SessionRepository> RegisterUseCase<UR, SR> {
-    pub fn new(user_repository: Arc<UR>, session_repository: Arc<SR>) -> Self {
+impl<UR, SR, RR, PR, TR, EP, HR, BR> RegisterUseCase<UR, SR, RR, PR, TR, EP, HR, BR>
+where
+    UR: UserRepository,
+    SR: SessionRepository,
+    RR: RoleRepository,
+    PR: PermissionRepository,
+    TR: AccountTokenRepository,
+    EP: EventPublisher,
+    HR: PasswordHistoryRepository,
+    BR: BreachedPasswordRepository,
+{
+    pub fn new(
+        user_repository: Arc<UR>,
+        password_service: Arc<PasswordService>,
+        password_policy_service: Arc<PasswordPolicyService<HR, BR>>,
+        session_service: Arc<SessionService<SR>>,
+        authorization_service: Arc<AuthorizationService<RR, PR>>,
+        account_notifier: Arc<AccountNotifier<TR, EP>>,
+    ) -> Self {
         let auth_service = AuthService::new(Arc::clone(&user_repository));
         Self {
             user_repository,
-            session_repository,
             auth_service,
+            password_service,
+            password_policy_service,
+            session_service,
+            authorization_service,
+            account_notifier,
         }
     }
 
-    pub async fn execute(&self, dto: RegisterDto) -> Result<AuthResponseDto, ApplicationError> {
+    pub async fn execute(&self, dto: RegisterDto, client: ClientInfoDto) -> Result<AuthResponseDto, ApplicationError> {
         let email = Email::new(dto.email)?;
         
         self.auth_service.validate_user_creation(&email).await?;
+        self.password_policy_service.check("password", &dto.password, None).await?;
 
-        // In real implementation, hash password using argon2
-        let password_hash = PasswordHash::new(dto.password); // TODO: hash password
+        let password_hash = self.password_service.hash(&dto.password)?;
         
         let user = User::new(UserId::new(), email, password_hash);
         self.user_repository.create(&user).await
             .map_err(|e| ApplicationError::Repository(e.to_string()))?;
 
+        // The account is usable right away; a failed send can be retried from /email/verification
+        if let Err(e) = self.account_notifier.send_email_verification(&user).await {
+            tracing::warn!("Failed to send verification email for user {}: {}", user.id.as_uuid(), e);
+        }
+
         // Create session
-        let token = Token::new(uuid::Uuid::new_v4().to_string()); // TODO: generate JWT
-        let expires_at = Utc::now() + Duration::hours(24);
-        let session = Session::new(SessionId::new(), user.id, token.clone(), expires_at);
-        
-        self.session_repository.create(&session).await
-            .map_err(|e| ApplicationError::Repository(e.to_string()))?;
+        let grants = self.authorization_service.grants(&user.id).await?;
+        let issued = self.session_service.start(&user.id, &grants, client.device()).await?;
 
         Ok(AuthResponseDto {
-            token: token.as_str().to_string(),
-            expires_at: expires_at.to_rfc3339(),
+            token: issued.session.token.as_str().to_string(),
+            expires_at: issued.session.expires_at.to_rfc3339(),
+            refresh_token: issued.refresh_token.as_str().to_string(),
+            refresh_expires_at: issued.session.refresh_expires_at.to_rfc3339(),
         })
     }
 }
-
@@ -0,0 +1,59 @@
+use std::sync::Arc;
+use serde_json::json;
+use crate::application::dto::AssignRoleDto;
+use crate::application::errors::ApplicationError;
+use crate::domain::entities::audit_event::NewAuditEvent;
+use crate::domain::repositories::{ServiceAccountRepository, RoleRepository, AuditEventRepository};
+use crate::domain::services::AuditLog;
+use crate::domain::value_objects::{AuditEventType, RoleId, ServiceAccountId};
+use crate::domain::errors::DomainError;
+
+pub struct AssignServiceAccountRoleUseCase<SAR: ServiceAccountRepository, RR: RoleRepository, AR: AuditEventRepository> {
+    service_account_repository: Arc<SAR>,
+    role_repository: Arc<RR>,
+    audit_log: Arc<AuditLog<AR>>,
+}
+
+impl<SAR, RR, AR> AssignServiceAccountRoleUseCase<SAR, RR, AR>
+where
+    SAR: ServiceAccountRepository,
+    RR: RoleRepository,
+    AR: AuditEventRepository,
+{
+    pub fn new(service_account_repository: Arc<SAR>, role_repository: Arc<RR>, audit_log: Arc<AuditLog<AR>>) -> Self {
+        Self {
+            service_account_repository,
+            role_repository,
+            audit_log,
+        }
+    }
+
+    /// `actor_id` is the admin making the change.
+    pub async fn execute(&self, actor_id: &str, service_account_id: &str, dto: AssignRoleDto) -> Result<(), ApplicationError> {
+        let service_account_id = uuid::Uuid::parse_str(service_account_id)
+            .map_err(|_| ApplicationError::Validation("Invalid service account ID format".to_string()))?;
+        let role_id = uuid::Uuid::parse_str(&dto.role_id)
+            .map_err(|_| ApplicationError::Validation("Invalid role ID format".to_string()))?;
+
+        let service_account = self.service_account_repository
+            .find_by_id(&ServiceAccountId::from_uuid(service_account_id)).await?
+            .ok_or(ApplicationError::Domain(DomainError::ServiceAccountNotFound))?;
+        let role = self.role_repository.find_by_id(&RoleId::from_uuid(role_id)).await?
+            .ok_or(ApplicationError::Domain(DomainError::RoleNotFound))?;
+
+        self.role_repository.assign_to_service_account(&service_account.id, &role.id).await
+            .map_err(|e| ApplicationError::Repository(e.to_string()))?;
+
+        self.audit_log.record(
+            NewAuditEvent::new(AuditEventType::RoleAssigned)
+                .with_actor(actor_id)
+                .with_details(json!({
+                    "service_account_id": service_account.id.as_uuid().to_string(),
+                    "role_id": role.id.as_uuid().to_string(),
+                    "role": role.name.as_str(),
+                })),
+        ).await;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,106 @@
+use std::sync::Arc;
+use chrono::{Duration, Utc};
+use serde_json::json;
+use crate::application::dto::{ApiKeyDto, CreateApiKeyDto, CreatedApiKeyDto};
+use crate::application::errors::ApplicationError;
+use crate::domain::entities::audit_event::NewAuditEvent;
+use crate::domain::entities::permission::Permission;
+use crate::domain::repositories::{
+    ServiceAccountRepository, ApiKeyRepository, RoleRepository, PermissionRepository, AuditEventRepository,
+};
+use crate::domain::services::{ApiKeyService, AuditLog};
+use crate::domain::value_objects::{AuditEventType, Scopes, ServiceAccountId};
+use crate::domain::errors::DomainError;
+
+pub struct CreateApiKeyUseCase<SAR, KR, RR, PR, AR>
+where
+    SAR: ServiceAccountRepository,
+    KR: ApiKeyRepository,
+    RR: RoleRepository,
+    PR: PermissionRepository,
+    AR: AuditEventRepository,
+{
+    service_account_repository: Arc<SAR>,
+    permission_repository: Arc<PR>,
+    api_key_service: Arc<ApiKeyService<SAR, KR, RR, PR>>,
+    audit_log: Arc<AuditLog<AR>>,
+}
+
+impl<SAR, KR, RR, PR, AR> CreateApiKeyUseCase<SAR, KR, RR, PR, AR>
+where
+    SAR: ServiceAccountRepository,
+    KR: ApiKeyRepository,
+    RR: RoleRepository,
+    PR: PermissionRepository,
+    AR: AuditEventRepository,
+{
+    pub fn new(
+        service_account_repository: Arc<SAR>,
+        permission_repository: Arc<PR>,
+        api_key_service: Arc<ApiKeyService<SAR, KR, RR, PR>>,
+        audit_log: Arc<AuditLog<AR>>,
+    ) -> Self {
+        Self {
+            service_account_repository,
+            permission_repository,
+            api_key_service,
+            audit_log,
+        }
+    }
+
+    /// `actor_id` is the admin making the change.
+    pub async fn execute(
+        &self,
+        actor_id: &str,
+        service_account_id: &str,
+        dto: CreateApiKeyDto,
+    ) -> Result<CreatedApiKeyDto, ApplicationError> {
+        let service_account_id = uuid::Uuid::parse_str(service_account_id)
+            .map_err(|_| ApplicationError::Validation("Invalid service account ID format".to_string()))?;
+        let name = dto.name.trim();
+        if name.is_empty() {
+            return Err(ApplicationError::Validation("API key name is required".to_string()));
+        }
+        if dto.expires_in_days == Some(0) {
+            return Err(ApplicationError::Validation("expires_in_days must be positive".to_string()));
+        }
+
+        // A key without scopes could do nothing, and every scope has to name an existing permission
+        let scopes = Scopes::from_vec(dto.scopes)?;
+        if scopes.is_empty() {
+            return Err(ApplicationError::Validation("At least one scope is required".to_string()));
+        }
+        let known: Vec<String> = self.permission_repository.find_all().await?
+            .iter()
+            .map(Permission::scope)
+            .collect();
+        if let Some(unknown) = scopes.iter().find(|scope| !known.iter().any(|known| known == scope)) {
+            return Err(ApplicationError::Domain(DomainError::InvalidScope(format!("No permission matches '{}'", unknown))));
+        }
+
+        let service_account = self.service_account_repository
+            .find_by_id(&ServiceAccountId::from_uuid(service_account_id)).await?
+            .ok_or(ApplicationError::Domain(DomainError::ServiceAccountNotFound))?;
+
+        let expires_at = dto.expires_in_days.map(|days| Utc::now() + Duration::days(days as i64));
+        let (key, token) = self.api_key_service
+            .issue(&service_account, name.to_string(), scopes, expires_at).await
+            .map_err(|e| ApplicationError::Repository(e.to_string()))?;
+
+        self.audit_log.record(
+            NewAuditEvent::new(AuditEventType::ApiKeyCreated)
+                .with_actor(actor_id)
+                .with_details(json!({
+                    "service_account_id": service_account.id.as_uuid().to_string(),
+                    "api_key_id": key.id.as_uuid().to_string(),
+                    "prefix": key.prefix,
+                    "scopes": key.scopes.to_vec(),
+                })),
+        ).await;
+
+        Ok(CreatedApiKeyDto {
+            key: ApiKeyDto::from(&key),
+            api_key: token.into(),
+        })
+    }
+}
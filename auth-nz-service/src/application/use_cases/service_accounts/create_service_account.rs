@@ -0,0 +1,50 @@
+use std::sync::Arc;
+use serde_json::json;
+use crate::application::dto::{CreateServiceAccountDto, ServiceAccountDto};
+use crate::application::errors::ApplicationError;
+use crate::domain::entities::audit_event::NewAuditEvent;
+use crate::domain::entities::service_account::ServiceAccount;
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::{ServiceAccountRepository, AuditEventRepository};
+use crate::domain::services::AuditLog;
+use crate::domain::value_objects::AuditEventType;
+
+pub struct CreateServiceAccountUseCase<SAR: ServiceAccountRepository, AR: AuditEventRepository> {
+    service_account_repository: Arc<SAR>,
+    audit_log: Arc<AuditLog<AR>>,
+}
+
+impl<SAR: ServiceAccountRepository, AR: AuditEventRepository> CreateServiceAccountUseCase<SAR, AR> {
+    pub fn new(service_account_repository: Arc<SAR>, audit_log: Arc<AuditLog<AR>>) -> Self {
+        Self {
+            service_account_repository,
+            audit_log,
+        }
+    }
+
+    /// `actor_id` is the admin making the change.
+    pub async fn execute(&self, actor_id: &str, dto: CreateServiceAccountDto) -> Result<ServiceAccountDto, ApplicationError> {
+        let name = dto.name.trim();
+        if name.is_empty() {
+            return Err(ApplicationError::Validation("Service account name is required".to_string()));
+        }
+
+        let service_account = ServiceAccount::new(name.to_string(), dto.description);
+        self.service_account_repository.create(&service_account).await
+            .map_err(|e| match e {
+                DomainError::ServiceAccountAlreadyExists => ApplicationError::Domain(e),
+                _ => ApplicationError::Repository(e.to_string()),
+            })?;
+
+        self.audit_log.record(
+            NewAuditEvent::new(AuditEventType::ServiceAccountCreated)
+                .with_actor(actor_id)
+                .with_details(json!({
+                    "service_account_id": service_account.id.as_uuid().to_string(),
+                    "name": service_account.name,
+                })),
+        ).await;
+
+        Ok(ServiceAccountDto::new(&service_account, &[]))
+    }
+}
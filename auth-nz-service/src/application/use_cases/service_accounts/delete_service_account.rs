@@ -0,0 +1,46 @@
+use std::sync::Arc;
+use serde_json::json;
+use crate::application::errors::ApplicationError;
+use crate::domain::entities::audit_event::NewAuditEvent;
+use crate::domain::repositories::{ServiceAccountRepository, AuditEventRepository};
+use crate::domain::services::AuditLog;
+use crate::domain::value_objects::{AuditEventType, ServiceAccountId};
+use crate::domain::errors::DomainError;
+
+/// Removes the account together with its role assignments and API keys.
+pub struct DeleteServiceAccountUseCase<SAR: ServiceAccountRepository, AR: AuditEventRepository> {
+    service_account_repository: Arc<SAR>,
+    audit_log: Arc<AuditLog<AR>>,
+}
+
+impl<SAR: ServiceAccountRepository, AR: AuditEventRepository> DeleteServiceAccountUseCase<SAR, AR> {
+    pub fn new(service_account_repository: Arc<SAR>, audit_log: Arc<AuditLog<AR>>) -> Self {
+        Self {
+            service_account_repository,
+            audit_log,
+        }
+    }
+
+    /// `actor_id` is the admin making the change.
+    pub async fn execute(&self, actor_id: &str, service_account_id: &str) -> Result<(), ApplicationError> {
+        let service_account_id = uuid::Uuid::parse_str(service_account_id)
+            .map_err(|_| ApplicationError::Validation("Invalid service account ID format".to_string()))?;
+        let service_account = self.service_account_repository
+            .find_by_id(&ServiceAccountId::from_uuid(service_account_id)).await?
+            .ok_or(ApplicationError::Domain(DomainError::ServiceAccountNotFound))?;
+
+        self.service_account_repository.delete(&service_account.id).await
+            .map_err(|e| ApplicationError::Repository(e.to_string()))?;
+
+        self.audit_log.record(
+            NewAuditEvent::new(AuditEventType::ServiceAccountDeleted)
+                .with_actor(actor_id)
+                .with_details(json!({
+                    "service_account_id": service_account.id.as_uuid().to_string(),
+                    "name": service_account.name,
+                })),
+        ).await;
+
+        Ok(())
+    }
+}
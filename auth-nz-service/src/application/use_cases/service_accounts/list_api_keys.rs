@@ -0,0 +1,34 @@
+use std::sync::Arc;
+use crate::application::dto::ApiKeyDto;
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::{ServiceAccountRepository, ApiKeyRepository};
+use crate::domain::value_objects::ServiceAccountId;
+use crate::domain::errors::DomainError;
+
+/// Keys of a service account, newest first, including revoked and expired ones.
+pub struct ListApiKeysUseCase<SAR: ServiceAccountRepository, KR: ApiKeyRepository> {
+    service_account_repository: Arc<SAR>,
+    api_key_repository: Arc<KR>,
+}
+
+impl<SAR: ServiceAccountRepository, KR: ApiKeyRepository> ListApiKeysUseCase<SAR, KR> {
+    pub fn new(service_account_repository: Arc<SAR>, api_key_repository: Arc<KR>) -> Self {
+        Self {
+            service_account_repository,
+            api_key_repository,
+        }
+    }
+
+    pub async fn execute(&self, service_account_id: &str) -> Result<Vec<ApiKeyDto>, ApplicationError> {
+        let service_account_id = uuid::Uuid::parse_str(service_account_id)
+            .map_err(|_| ApplicationError::Validation("Invalid service account ID format".to_string()))?;
+        let service_account_id = ServiceAccountId::from_uuid(service_account_id);
+
+        if self.service_account_repository.find_by_id(&service_account_id).await?.is_none() {
+            return Err(ApplicationError::Domain(DomainError::ServiceAccountNotFound));
+        }
+
+        let keys = self.api_key_repository.find_by_service_account_id(&service_account_id).await?;
+        Ok(keys.iter().map(ApiKeyDto::from).collect())
+    }
+}
@@ -0,0 +1,27 @@
+use std::sync::Arc;
+use crate::application::dto::ServiceAccountDto;
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::{ServiceAccountRepository, RoleRepository};
+
+pub struct ListServiceAccountsUseCase<SAR: ServiceAccountRepository, RR: RoleRepository> {
+    service_account_repository: Arc<SAR>,
+    role_repository: Arc<RR>,
+}
+
+impl<SAR: ServiceAccountRepository, RR: RoleRepository> ListServiceAccountsUseCase<SAR, RR> {
+    pub fn new(service_account_repository: Arc<SAR>, role_repository: Arc<RR>) -> Self {
+        Self {
+            service_account_repository,
+            role_repository,
+        }
+    }
+
+    pub async fn execute(&self) -> Result<Vec<ServiceAccountDto>, ApplicationError> {
+        let mut service_accounts = Vec::new();
+        for service_account in self.service_account_repository.find_all().await? {
+            let roles = self.role_repository.find_by_service_account_id(&service_account.id).await?;
+            service_accounts.push(ServiceAccountDto::new(&service_account, &roles));
+        }
+        Ok(service_accounts)
+    }
+}
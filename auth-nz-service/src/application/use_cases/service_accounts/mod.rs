@@ -0,0 +1,19 @@
+pub mod create_service_account;
+pub mod list_service_accounts;
+pub mod delete_service_account;
+pub mod assign_service_account_role;
+pub mod unassign_service_account_role;
+pub mod create_api_key;
+pub mod list_api_keys;
+pub mod rotate_api_key;
+pub mod revoke_api_key;
+
+pub use create_service_account::CreateServiceAccountUseCase;
+pub use list_service_accounts::ListServiceAccountsUseCase;
+pub use delete_service_account::DeleteServiceAccountUseCase;
+pub use assign_service_account_role::AssignServiceAccountRoleUseCase;
+pub use unassign_service_account_role::UnassignServiceAccountRoleUseCase;
+pub use create_api_key::CreateApiKeyUseCase;
+pub use list_api_keys::ListApiKeysUseCase;
+pub use rotate_api_key::RotateApiKeyUseCase;
+pub use revoke_api_key::RevokeApiKeyUseCase;
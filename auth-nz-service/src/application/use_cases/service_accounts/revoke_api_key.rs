@@ -0,0 +1,75 @@
+use std::sync::Arc;
+use serde_json::json;
+use crate::application::errors::ApplicationError;
+use crate::domain::entities::audit_event::NewAuditEvent;
+use crate::domain::repositories::{
+    ServiceAccountRepository, ApiKeyRepository, RoleRepository, PermissionRepository, AuditEventRepository,
+};
+use crate::domain::services::{ApiKeyService, AuditLog};
+use crate::domain::value_objects::{ApiKeyId, AuditEventType};
+use crate::domain::errors::DomainError;
+
+/// Stops a key from working. The key stays listed, marked as revoked.
+pub struct RevokeApiKeyUseCase<SAR, KR, RR, PR, AR>
+where
+    SAR: ServiceAccountRepository,
+    KR: ApiKeyRepository,
+    RR: RoleRepository,
+    PR: PermissionRepository,
+    AR: AuditEventRepository,
+{
+    api_key_repository: Arc<KR>,
+    api_key_service: Arc<ApiKeyService<SAR, KR, RR, PR>>,
+    audit_log: Arc<AuditLog<AR>>,
+}
+
+impl<SAR, KR, RR, PR, AR> RevokeApiKeyUseCase<SAR, KR, RR, PR, AR>
+where
+    SAR: ServiceAccountRepository,
+    KR: ApiKeyRepository,
+    RR: RoleRepository,
+    PR: PermissionRepository,
+    AR: AuditEventRepository,
+{
+    pub fn new(
+        api_key_repository: Arc<KR>,
+        api_key_service: Arc<ApiKeyService<SAR, KR, RR, PR>>,
+        audit_log: Arc<AuditLog<AR>>,
+    ) -> Self {
+        Self {
+            api_key_repository,
+            api_key_service,
+            audit_log,
+        }
+    }
+
+    /// `actor_id` is the admin making the change.
+    pub async fn execute(&self, actor_id: &str, service_account_id: &str, key_id: &str) -> Result<(), ApplicationError> {
+        let service_account_id = uuid::Uuid::parse_str(service_account_id)
+            .map_err(|_| ApplicationError::Validation("Invalid service account ID format".to_string()))?;
+        let key_id = uuid::Uuid::parse_str(key_id)
+            .map_err(|_| ApplicationError::Validation("Invalid API key ID format".to_string()))?;
+
+        let key = self.api_key_repository.find_by_id(&ApiKeyId::from_uuid(key_id)).await?
+            .filter(|key| key.service_account_id.as_uuid() == service_account_id)
+            .ok_or(ApplicationError::Domain(DomainError::ApiKeyNotFound))?;
+        if key.is_revoked() {
+            return Ok(());
+        }
+
+        self.api_key_service.revoke(&key).await
+            .map_err(|e| ApplicationError::Repository(e.to_string()))?;
+
+        self.audit_log.record(
+            NewAuditEvent::new(AuditEventType::ApiKeyRevoked)
+                .with_actor(actor_id)
+                .with_details(json!({
+                    "service_account_id": service_account_id.to_string(),
+                    "api_key_id": key.id.as_uuid().to_string(),
+                    "prefix": key.prefix,
+                })),
+        ).await;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+use std::sync::Arc;
+use crate::application::dto::SessionDto;
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::SessionRepository;
+use crate::domain::value_objects::{SessionId, UserId};
+
+pub struct ListSessionsUseCase<SR: SessionRepository> {
+    session_repository: Arc<SR>,
+}
+
+impl<SR: SessionRepository> ListSessionsUseCase<SR> {
+    pub fn new(session_repository: Arc<SR>) -> Self {
+        Self { session_repository }
+    }
+
+    /// `current_session_id` is the `sid` of the caller's token, used to flag
+    /// the session the request came from.
+    pub async fn execute(&self, user_id: &str, current_session_id: Option<&str>) -> Result<Vec<SessionDto>, ApplicationError> {
+        let user_id = uuid::Uuid::parse_str(user_id)
+            .map_err(|_| ApplicationError::Validation("Invalid user ID format".to_string()))?;
+
+        let current_family = match current_session_id.and_then(|id| uuid::Uuid::parse_str(id).ok()) {
+            Some(id) => self.session_repository.find_by_id(&SessionId::from_uuid(id)).await?
+                .map(|session| session.family_id),
+            None => None,
+        };
+
+        let sessions = self.session_repository.find_active_by_user_id(&UserId::from_uuid(user_id)).await?;
+
+        Ok(sessions.into_iter().map(|session| SessionDto {
+            id: session.family_id.as_uuid().to_string(),
+            device_label: session.device.label().to_string(),
+            user_agent: session.device.user_agent().map(str::to_string),
+            ip_address: session.device.ip_address().map(str::to_string),
+            last_seen_at: session.last_seen_at.to_rfc3339(),
+            expires_at: session.refresh_expires_at.to_rfc3339(),
+            current: Some(session.family_id) == current_family,
+        }).collect())
+    }
+}
@@ -0,0 +1,7 @@
+pub mod list_sessions;
+pub mod revoke_session;
+pub mod revoke_sessions;
+
+pub use list_sessions::ListSessionsUseCase;
+pub use revoke_session::RevokeSessionUseCase;
+pub use revoke_sessions::RevokeSessionsUseCase;
@@ -0,0 +1,53 @@
+use std::sync::Arc;
+use serde_json::json;
+use crate::application::errors::ApplicationError;
+use crate::domain::entities::audit_event::NewAuditEvent;
+use crate::domain::repositories::{SessionRepository, AuditEventRepository};
+use crate::domain::services::AuditLog;
+use crate::domain::value_objects::{AuditEventType, SessionId, UserId};
+use crate::domain::errors::DomainError;
+
+/// Signs one device out. Its refresh token stops working immediately; access
+/// tokens already issued to it run until they expire.
+pub struct RevokeSessionUseCase<SR: SessionRepository, AR: AuditEventRepository> {
+    session_repository: Arc<SR>,
+    audit_log: Arc<AuditLog<AR>>,
+}
+
+impl<SR: SessionRepository, AR: AuditEventRepository> RevokeSessionUseCase<SR, AR> {
+    pub fn new(session_repository: Arc<SR>, audit_log: Arc<AuditLog<AR>>) -> Self {
+        Self {
+            session_repository,
+            audit_log,
+        }
+    }
+
+    /// `actor_id` is the user themselves or an admin.
+    pub async fn execute(&self, actor_id: &str, user_id: &str, session_id: &str) -> Result<(), ApplicationError> {
+        let user_id = uuid::Uuid::parse_str(user_id)
+            .map_err(|_| ApplicationError::Validation("Invalid user ID format".to_string()))?;
+        let family_id = uuid::Uuid::parse_str(session_id)
+            .map_err(|_| ApplicationError::Validation("Invalid session ID format".to_string()))?;
+        let family_id = SessionId::from_uuid(family_id);
+
+        // The first session of a family carries the family's id
+        let session = self.session_repository.find_by_id(&family_id).await?
+            .filter(|session| session.user_id == UserId::from_uuid(user_id))
+            .ok_or(ApplicationError::Domain(DomainError::SessionNotFound))?;
+
+        self.session_repository.revoke_family(&session.family_id).await
+            .map_err(|e| ApplicationError::Repository(e.to_string()))?;
+
+        self.audit_log.record(
+            NewAuditEvent::new(AuditEventType::SessionRevoked)
+                .with_actor(actor_id)
+                .with_user(&session.user_id)
+                .with_details(json!({
+                    "reason": "revoked",
+                    "session_id": session.family_id.as_uuid().to_string(),
+                })),
+        ).await;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use std::sync::Arc;
+use serde_json::json;
+use crate::application::errors::ApplicationError;
+use crate::domain::entities::audit_event::NewAuditEvent;
+use crate::domain::repositories::{SessionRepository, AuditEventRepository};
+use crate::domain::services::AuditLog;
+use crate::domain::value_objects::{AuditEventType, SessionId, UserId};
+
+/// Signs the user out everywhere, or everywhere else when `keep_session_id`
+/// names the caller's own session.
+pub struct RevokeSessionsUseCase<SR: SessionRepository, AR: AuditEventRepository> {
+    session_repository: Arc<SR>,
+    audit_log: Arc<AuditLog<AR>>,
+}
+
+impl<SR: SessionRepository, AR: AuditEventRepository> RevokeSessionsUseCase<SR, AR> {
+    pub fn new(session_repository: Arc<SR>, audit_log: Arc<AuditLog<AR>>) -> Self {
+        Self {
+            session_repository,
+            audit_log,
+        }
+    }
+
+    /// `actor_id` is the user themselves or an admin.
+    pub async fn execute(&self, actor_id: &str, user_id: &str, keep_session_id: Option<&str>) -> Result<(), ApplicationError> {
+        let user_id = uuid::Uuid::parse_str(user_id)
+            .map_err(|_| ApplicationError::Validation("Invalid user ID format".to_string()))?;
+        let user_id = UserId::from_uuid(user_id);
+
+        let keep_family = match keep_session_id {
+            Some(id) => {
+                let id = uuid::Uuid::parse_str(id)
+                    .map_err(|_| ApplicationError::Validation("Invalid session ID format".to_string()))?;
+                self.session_repository.find_by_id(&SessionId::from_uuid(id)).await?
+                    .map(|session| session.family_id)
+            }
+            None => None,
+        };
+
+        self.session_repository
+            .revoke_by_user_id(&user_id, keep_family.as_ref())
+            .await
+            .map_err(|e| ApplicationError::Repository(e.to_string()))?;
+
+        self.audit_log.record(
+            NewAuditEvent::new(AuditEventType::SessionRevoked)
+                .with_actor(actor_id)
+                .with_user(&user_id)
+                .with_details(json!({
+                    "reason": "revoked",
+                    "all_sessions": true,
+                    "kept_session_id": keep_family.map(|id| id.as_uuid().to_string()),
+                })),
+        ).await;
+
+        Ok(())
+    }
+}
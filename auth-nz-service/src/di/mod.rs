@@ -0,0 +1,648 @@
+use std::path::Path;
+use std::sync::Arc;
+use chrono::Duration;
+use crate::application::use_cases::{
+    RegisterUseCase, LoginUseCase, LogoutUseCase, RefreshSessionUseCase, AuthorizeUseCase,
+    CreateRoleUseCase, ListRolesUseCase, SetRoleParentUseCase, DeleteRoleUseCase,
+    CreatePermissionUseCase, ListPermissionsUseCase, GrantPermissionUseCase, RevokePermissionUseCase,
+    AssignRoleUseCase, UnassignRoleUseCase, GetUserPermissionsUseCase,
+    EnrollMfaUseCase, ConfirmMfaUseCase, VerifyMfaUseCase, DisableMfaUseCase,
+    RequestEmailVerificationUseCase, VerifyEmailUseCase, ForgotPasswordUseCase, ResetPasswordUseCase,
+    ListSessionsUseCase, RevokeSessionUseCase, RevokeSessionsUseCase,
+    AuthorizeClientUseCase, DecideConsentUseCase, IssueTokenUseCase, CreateOAuthClientUseCase, ListOAuthClientsUseCase,
+    DeleteOAuthClientUseCase, ListConsentsUseCase, RevokeConsentUseCase, IntrospectTokenUseCase, RevokeTokenUseCase,
+    ListIdentityProvidersUseCase, StartFederatedLoginUseCase, CompleteFederatedLoginUseCase, ListFederatedIdentitiesUseCase,
+    ListAuditEventsUseCase, ExportAuditEventsUseCase, VerifyAuditChainUseCase,
+    CreateServiceAccountUseCase, ListServiceAccountsUseCase, DeleteServiceAccountUseCase,
+    AssignServiceAccountRoleUseCase, UnassignServiceAccountRoleUseCase,
+    CreateApiKeyUseCase, ListApiKeysUseCase, RotateApiKeyUseCase, RevokeApiKeyUseCase,
+    RequestMagicLinkUseCase, MagicLinkLoginUseCase, StartPasskeyRegistrationUseCase, FinishPasskeyRegistrationUseCase,
+    ListPasskeysUseCase, DeletePasskeyUseCase, StartPasskeyLoginUseCase, PasskeyLoginUseCase,
+    CreateOrganizationUseCase, ListOrganizationsUseCase, SwitchOrganizationUseCase, ListMembersUseCase, RemoveMemberUseCase,
+    AssignMemberRoleUseCase, UnassignMemberRoleUseCase, InviteMemberUseCase, ListInvitationsUseCase, RevokeInvitationUseCase,
+    AcceptInvitationUseCase,
+};
+use crate::domain::services::{
+    AccountNotifier, AccountTokenService, ApiKeyService, AuditLog, AuthorizationService, FederationService, LoginThrottle, LoginThrottlePolicy, MfaService, OAuthService, OrganizationService, PasswordPolicy,
+    PasswordPolicyService, PasswordService,
+    RevocationService, SessionService, TokenService, TotpService, WebAuthnService,
+};
+use crate::infrastructure::config::Config;
+use crate::infrastructure::events::ConfiguredEventPublisher;
+use crate::infrastructure::persistence::{create_pool, run_migrations};
+use crate::infrastructure::repositories::{
+    ConfiguredLoginAttemptRepository, ConfiguredRevocationListRepository, FileBreachedPasswordRepository, FilePolicyRepository,
+    HttpIdentityProviderRepository, PostgresPasswordHistoryRepository,
+    PostgresAccountTokenRepository, PostgresApiKeyRepository, PostgresAuditEventRepository, PostgresFederatedIdentityRepository, PostgresMfaRepository,
+    PostgresOAuthClientRepository, PostgresOAuthGrantRepository, PostgresOrganizationInvitationRepository, PostgresOrganizationRepository,
+    PostgresPasskeyRepository, PostgresPermissionRepository, PostgresRoleRepository,
+    PostgresServiceAccountRepository, PostgresSessionRepository, PostgresUserRepository,
+};
+use crate::infrastructure::token_keys::load_token_keys;
+
+type UserRepo = PostgresUserRepository;
+type SessionRepo = PostgresSessionRepository;
+type RoleRepo = PostgresRoleRepository;
+type PermissionRepo = PostgresPermissionRepository;
+type MfaRepo = PostgresMfaRepository;
+type AccountTokenRepo = PostgresAccountTokenRepository;
+type OAuthClientRepo = PostgresOAuthClientRepository;
+type OAuthGrantRepo = PostgresOAuthGrantRepository;
+type IdentityProviderRepo = HttpIdentityProviderRepository;
+type FederatedIdentityRepo = PostgresFederatedIdentityRepository;
+type AuditEventRepo = PostgresAuditEventRepository;
+type ServiceAccountRepo = PostgresServiceAccountRepository;
+type ApiKeyRepo = PostgresApiKeyRepository;
+type PasskeyRepo = PostgresPasskeyRepository;
+type PasswordHistoryRepo = PostgresPasswordHistoryRepository;
+type OrganizationRepo = PostgresOrganizationRepository;
+type InvitationRepo = PostgresOrganizationInvitationRepository;
+type BreachedPasswordRepo = FileBreachedPasswordRepository;
+type LoginAttemptRepo = ConfiguredLoginAttemptRepository;
+type RevocationListRepo = ConfiguredRevocationListRepository;
+type Publisher = ConfiguredEventPublisher;
+
+type Register = RegisterUseCase<
+    UserRepo,
+    SessionRepo,
+    RoleRepo,
+    PermissionRepo,
+    AccountTokenRepo,
+    Publisher,
+    PasswordHistoryRepo,
+    BreachedPasswordRepo,
+>;
+type ResetPassword = ResetPasswordUseCase<
+    UserRepo,
+    SessionRepo,
+    AccountTokenRepo,
+    Publisher,
+    AuditEventRepo,
+    PasswordHistoryRepo,
+    BreachedPasswordRepo,
+>;
+type Login = LoginUseCase<
+    UserRepo,
+    SessionRepo,
+    RoleRepo,
+    PermissionRepo,
+    MfaRepo,
+    LoginAttemptRepo,
+    Publisher,
+    AuditEventRepo,
+>;
+type VerifyMfa = VerifyMfaUseCase<
+    UserRepo,
+    SessionRepo,
+    RoleRepo,
+    PermissionRepo,
+    MfaRepo,
+    LoginAttemptRepo,
+    Publisher,
+    AuditEventRepo,
+>;
+type CompleteFederatedLogin = CompleteFederatedLoginUseCase<
+    UserRepo,
+    SessionRepo,
+    RoleRepo,
+    PermissionRepo,
+    MfaRepo,
+    IdentityProviderRepo,
+    FederatedIdentityRepo,
+    AuditEventRepo,
+>;
+type MagicLinkLogin = MagicLinkLoginUseCase<
+    UserRepo,
+    SessionRepo,
+    RoleRepo,
+    PermissionRepo,
+    MfaRepo,
+    AccountTokenRepo,
+    AuditEventRepo,
+>;
+type PasskeyLogin = PasskeyLoginUseCase<UserRepo, SessionRepo, RoleRepo, PermissionRepo, PasskeyRepo, AuditEventRepo>;
+type ApiKeys = ApiKeyService<ServiceAccountRepo, ApiKeyRepo, RoleRepo, PermissionRepo>;
+type IntrospectToken = IntrospectTokenUseCase<
+    SessionRepo,
+    RevocationListRepo,
+    OAuthClientRepo,
+    OAuthGrantRepo,
+    ServiceAccountRepo,
+    ApiKeyRepo,
+    RoleRepo,
+    PermissionRepo,
+>;
+type CreateApiKey = CreateApiKeyUseCase<ServiceAccountRepo, ApiKeyRepo, RoleRepo, PermissionRepo, AuditEventRepo>;
+type RotateApiKey = RotateApiKeyUseCase<ServiceAccountRepo, ApiKeyRepo, RoleRepo, PermissionRepo, AuditEventRepo>;
+type RevokeApiKey = RevokeApiKeyUseCase<ServiceAccountRepo, ApiKeyRepo, RoleRepo, PermissionRepo, AuditEventRepo>;
+type RefreshSession = RefreshSessionUseCase<SessionRepo, RoleRepo, PermissionRepo, OrganizationRepo, InvitationRepo>;
+type CreateOrganization = CreateOrganizationUseCase<OrganizationRepo, InvitationRepo, RoleRepo, AuditEventRepo>;
+type SwitchOrganization = SwitchOrganizationUseCase<SessionRepo, OrganizationRepo, InvitationRepo, RoleRepo, PermissionRepo>;
+type ListMembers = ListMembersUseCase<UserRepo, OrganizationRepo, InvitationRepo, RoleRepo, PermissionRepo>;
+type RemoveMember = RemoveMemberUseCase<OrganizationRepo, InvitationRepo, RoleRepo, PermissionRepo, AuditEventRepo>;
+type AssignMemberRole = AssignMemberRoleUseCase<OrganizationRepo, InvitationRepo, RoleRepo, PermissionRepo, AuditEventRepo>;
+type UnassignMemberRole = UnassignMemberRoleUseCase<OrganizationRepo, InvitationRepo, RoleRepo, PermissionRepo, AuditEventRepo>;
+type InviteMember = InviteMemberUseCase<
+    OrganizationRepo,
+    InvitationRepo,
+    RoleRepo,
+    PermissionRepo,
+    AccountTokenRepo,
+    Publisher,
+    AuditEventRepo,
+>;
+type RevokeInvitation = RevokeInvitationUseCase<OrganizationRepo, InvitationRepo, RoleRepo, PermissionRepo, AuditEventRepo>;
+type AcceptInvitation = AcceptInvitationUseCase<UserRepo, OrganizationRepo, InvitationRepo, RoleRepo, AuditEventRepo>;
+
+#[derive(Clone)]
+pub struct AppContext {
+    pub trust_forwarded_for: bool,
+    pub reauthentication_max_age: Duration,
+    pub token_service: Arc<TokenService>,
+    pub revocation_service: Arc<RevocationService<SessionRepo, RevocationListRepo>>,
+    pub authorization_service: Arc<AuthorizationService<RoleRepo, PermissionRepo>>,
+    pub api_key_service: Arc<ApiKeys>,
+    pub register_use_case: Arc<Register>,
+    pub login_use_case: Arc<Login>,
+    pub logout_use_case: Arc<LogoutUseCase<SessionRepo, AuditEventRepo>>,
+    pub list_sessions_use_case: Arc<ListSessionsUseCase<SessionRepo>>,
+    pub revoke_session_use_case: Arc<RevokeSessionUseCase<SessionRepo, AuditEventRepo>>,
+    pub revoke_sessions_use_case: Arc<RevokeSessionsUseCase<SessionRepo, AuditEventRepo>>,
+    pub refresh_session_use_case: Arc<RefreshSession>,
+    pub authorize_use_case: Arc<AuthorizeUseCase<FilePolicyRepository>>,
+    pub create_role_use_case: Arc<CreateRoleUseCase<RoleRepo>>,
+    pub list_roles_use_case: Arc<ListRolesUseCase<RoleRepo>>,
+    pub set_role_parent_use_case: Arc<SetRoleParentUseCase<RoleRepo>>,
+    pub delete_role_use_case: Arc<DeleteRoleUseCase<RoleRepo>>,
+    pub create_permission_use_case: Arc<CreatePermissionUseCase<PermissionRepo>>,
+    pub list_permissions_use_case: Arc<ListPermissionsUseCase<PermissionRepo>>,
+    pub grant_permission_use_case: Arc<GrantPermissionUseCase<RoleRepo, PermissionRepo, AuditEventRepo>>,
+    pub revoke_permission_use_case: Arc<RevokePermissionUseCase<PermissionRepo, AuditEventRepo>>,
+    pub assign_role_use_case: Arc<AssignRoleUseCase<UserRepo, RoleRepo, AuditEventRepo>>,
+    pub unassign_role_use_case: Arc<UnassignRoleUseCase<RoleRepo, AuditEventRepo>>,
+    pub get_user_permissions_use_case: Arc<GetUserPermissionsUseCase<RoleRepo, PermissionRepo>>,
+    pub enroll_mfa_use_case: Arc<EnrollMfaUseCase<UserRepo, MfaRepo>>,
+    pub confirm_mfa_use_case: Arc<ConfirmMfaUseCase<MfaRepo>>,
+    pub verify_mfa_use_case: Arc<VerifyMfa>,
+    pub disable_mfa_use_case: Arc<DisableMfaUseCase<UserRepo, MfaRepo>>,
+    pub request_email_verification_use_case: Arc<RequestEmailVerificationUseCase<UserRepo, AccountTokenRepo, Publisher>>,
+    pub verify_email_use_case: Arc<VerifyEmailUseCase<UserRepo, AccountTokenRepo>>,
+    pub forgot_password_use_case: Arc<ForgotPasswordUseCase<UserRepo, AccountTokenRepo, Publisher>>,
+    pub authorize_client_use_case: Arc<AuthorizeClientUseCase<OAuthClientRepo, OAuthGrantRepo>>,
+    pub decide_consent_use_case: Arc<DecideConsentUseCase<OAuthClientRepo, OAuthGrantRepo>>,
+    pub issue_token_use_case: Arc<IssueTokenUseCase<UserRepo, SessionRepo, RoleRepo, PermissionRepo, OAuthClientRepo, OAuthGrantRepo>>,
+    pub introspect_token_use_case: Arc<IntrospectToken>,
+    pub revoke_token_use_case: Arc<RevokeTokenUseCase<SessionRepo, RevocationListRepo, OAuthClientRepo, OAuthGrantRepo, AuditEventRepo>>,
+    pub create_oauth_client_use_case: Arc<CreateOAuthClientUseCase<OAuthClientRepo, PermissionRepo>>,
+    pub list_oauth_clients_use_case: Arc<ListOAuthClientsUseCase<OAuthClientRepo>>,
+    pub delete_oauth_client_use_case: Arc<DeleteOAuthClientUseCase<OAuthClientRepo>>,
+    pub list_consents_use_case: Arc<ListConsentsUseCase<OAuthGrantRepo>>,
+    pub revoke_consent_use_case: Arc<RevokeConsentUseCase<SessionRepo, OAuthGrantRepo>>,
+    pub list_identity_providers_use_case: Arc<ListIdentityProvidersUseCase<IdentityProviderRepo>>,
+    pub start_federated_login_use_case: Arc<StartFederatedLoginUseCase<IdentityProviderRepo, FederatedIdentityRepo>>,
+    pub complete_federated_login_use_case: Arc<CompleteFederatedLogin>,
+    pub list_federated_identities_use_case: Arc<ListFederatedIdentitiesUseCase<FederatedIdentityRepo>>,
+    pub reset_password_use_case: Arc<ResetPassword>,
+    pub list_audit_events_use_case: Arc<ListAuditEventsUseCase<AuditEventRepo>>,
+    pub export_audit_events_use_case: Arc<ExportAuditEventsUseCase<AuditEventRepo>>,
+    pub verify_audit_chain_use_case: Arc<VerifyAuditChainUseCase<AuditEventRepo>>,
+    pub create_service_account_use_case: Arc<CreateServiceAccountUseCase<ServiceAccountRepo, AuditEventRepo>>,
+    pub list_service_accounts_use_case: Arc<ListServiceAccountsUseCase<ServiceAccountRepo, RoleRepo>>,
+    pub delete_service_account_use_case: Arc<DeleteServiceAccountUseCase<ServiceAccountRepo, AuditEventRepo>>,
+    pub assign_service_account_role_use_case: Arc<AssignServiceAccountRoleUseCase<ServiceAccountRepo, RoleRepo, AuditEventRepo>>,
+    pub unassign_service_account_role_use_case: Arc<UnassignServiceAccountRoleUseCase<RoleRepo, AuditEventRepo>>,
+    pub create_api_key_use_case: Arc<CreateApiKey>,
+    pub list_api_keys_use_case: Arc<ListApiKeysUseCase<ServiceAccountRepo, ApiKeyRepo>>,
+    pub rotate_api_key_use_case: Arc<RotateApiKey>,
+    pub revoke_api_key_use_case: Arc<RevokeApiKey>,
+    pub request_magic_link_use_case: Arc<RequestMagicLinkUseCase<UserRepo, AccountTokenRepo, Publisher>>,
+    pub magic_link_login_use_case: Arc<MagicLinkLogin>,
+    pub start_passkey_registration_use_case: Arc<StartPasskeyRegistrationUseCase<UserRepo, PasskeyRepo>>,
+    pub finish_passkey_registration_use_case: Arc<FinishPasskeyRegistrationUseCase<PasskeyRepo, AuditEventRepo>>,
+    pub list_passkeys_use_case: Arc<ListPasskeysUseCase<PasskeyRepo>>,
+    pub delete_passkey_use_case: Arc<DeletePasskeyUseCase<PasskeyRepo, AuditEventRepo>>,
+    pub start_passkey_login_use_case: Arc<StartPasskeyLoginUseCase<UserRepo, PasskeyRepo>>,
+    pub passkey_login_use_case: Arc<PasskeyLogin>,
+    pub create_organization_use_case: Arc<CreateOrganization>,
+    pub list_organizations_use_case: Arc<ListOrganizationsUseCase<OrganizationRepo, InvitationRepo, RoleRepo>>,
+    pub switch_organization_use_case: Arc<SwitchOrganization>,
+    pub list_members_use_case: Arc<ListMembers>,
+    pub remove_member_use_case: Arc<RemoveMember>,
+    pub assign_member_role_use_case: Arc<AssignMemberRole>,
+    pub unassign_member_role_use_case: Arc<UnassignMemberRole>,
+    pub invite_member_use_case: Arc<InviteMember>,
+    pub list_invitations_use_case: Arc<ListInvitationsUseCase<OrganizationRepo, InvitationRepo, RoleRepo, PermissionRepo>>,
+    pub revoke_invitation_use_case: Arc<RevokeInvitation>,
+    pub accept_invitation_use_case: Arc<AcceptInvitation>,
+}
+
+impl AppContext {
+    pub async fn new(config: Config) -> Result<Self, Box<dyn std::error::Error>> {
+        let pool = create_pool(&config.database).await?;
+        run_migrations(&pool).await?;
+
+        let user_repository = Arc::new(PostgresUserRepository::new(pool.clone()));
+        let session_repository = Arc::new(PostgresSessionRepository::new(pool.clone()));
+        let role_repository = Arc::new(PostgresRoleRepository::new(pool.clone()));
+        let permission_repository = Arc::new(PostgresPermissionRepository::new(pool.clone()));
+        let mfa_repository = Arc::new(PostgresMfaRepository::new(pool.clone()));
+        let account_token_repository = Arc::new(PostgresAccountTokenRepository::new(pool.clone()));
+        let oauth_client_repository = Arc::new(PostgresOAuthClientRepository::new(pool.clone()));
+        let oauth_grant_repository = Arc::new(PostgresOAuthGrantRepository::new(pool.clone()));
+        let federated_identity_repository = Arc::new(PostgresFederatedIdentityRepository::new(pool.clone()));
+        let service_account_repository = Arc::new(PostgresServiceAccountRepository::new(pool.clone()));
+        let api_key_repository = Arc::new(PostgresApiKeyRepository::new(pool.clone()));
+        let passkey_repository = Arc::new(PostgresPasskeyRepository::new(pool.clone()));
+        let password_history_repository = Arc::new(PostgresPasswordHistoryRepository::new(pool.clone()));
+        let organization_repository = Arc::new(PostgresOrganizationRepository::new(pool.clone()));
+        let organization_invitation_repository = Arc::new(PostgresOrganizationInvitationRepository::new(pool.clone()));
+        let audit_log = Arc::new(AuditLog::new(Arc::new(PostgresAuditEventRepository::new(pool))));
+        let identity_provider_repository = Arc::new(HttpIdentityProviderRepository::new(&config.federation)?);
+
+        let token_service = Arc::new(TokenService::new(
+            load_token_keys(&config.jwt)?,
+            config.jwt.signing_kid.clone(),
+            config.jwt.issuer.clone(),
+            Duration::minutes(config.jwt.access_token_ttl_minutes as i64),
+        )?);
+        let password_service = Arc::new(PasswordService::new(
+            config.password.memory_cost_kib,
+            config.password.time_cost,
+            config.password.parallelism,
+        )?);
+        let breached_password_repository = match &config.password_policy.breach_corpus_path {
+            Some(path) => FileBreachedPasswordRepository::load(Path::new(path)).await?,
+            None => {
+                tracing::warn!("PASSWORD_BREACH_CORPUS is not set, breached passwords will not be rejected");
+                FileBreachedPasswordRepository::empty()
+            }
+        };
+        let password_policy_service = Arc::new(PasswordPolicyService::new(
+            password_history_repository,
+            Arc::new(breached_password_repository),
+            Arc::clone(&password_service),
+            PasswordPolicy {
+                min_length: config.password_policy.min_length,
+                max_length: config.password_policy.max_length,
+                require_lowercase: config.password_policy.require_lowercase,
+                require_uppercase: config.password_policy.require_uppercase,
+                require_digit: config.password_policy.require_digit,
+                require_symbol: config.password_policy.require_symbol,
+                history_size: config.password_policy.history_size,
+            },
+        ));
+        let session_service = Arc::new(SessionService::new(
+            Arc::clone(&session_repository),
+            Arc::clone(&token_service),
+            Duration::days(config.session.refresh_token_ttl_days as i64),
+        ));
+        let authorization_service = Arc::new(AuthorizationService::new(
+            Arc::clone(&role_repository),
+            Arc::clone(&permission_repository),
+        ));
+
+        let organization_service = Arc::new(OrganizationService::new(
+            organization_repository,
+            organization_invitation_repository,
+            Duration::hours(config.organizations.invitation_ttl_hours as i64),
+        ));
+
+        let api_key_service = Arc::new(ApiKeyService::new(
+            Arc::clone(&service_account_repository),
+            Arc::clone(&api_key_repository),
+            Arc::clone(&authorization_service),
+        ));
+
+        let mfa_service = Arc::new(MfaService::new(
+            mfa_repository,
+            TotpService::new(config.mfa.issuer.clone()),
+            Duration::minutes(config.mfa.challenge_ttl_minutes as i64),
+        ));
+
+        let account_token_service = Arc::new(AccountTokenService::new(
+            account_token_repository,
+            &config.account_tokens.secret,
+            Duration::hours(config.account_tokens.email_verification_ttl_hours as i64),
+            Duration::minutes(config.account_tokens.password_reset_ttl_minutes as i64),
+            Duration::minutes(config.account_tokens.magic_link_ttl_minutes as i64),
+        ));
+        let event_publisher = Arc::new(ConfiguredEventPublisher::from_config(&config.redis, &config.events)?);
+        let login_throttle = Arc::new(LoginThrottle::new(
+            Arc::new(ConfiguredLoginAttemptRepository::from_config(&config.redis)?),
+            Arc::clone(&event_publisher),
+            LoginThrottlePolicy {
+                max_account_failures: config.login_throttle.max_account_failures,
+                max_ip_failures: config.login_throttle.max_ip_failures,
+                failure_window: Duration::minutes(config.login_throttle.failure_window_minutes as i64),
+                lockout: Duration::minutes(config.login_throttle.lockout_minutes as i64),
+                base_delay: std::time::Duration::from_millis(config.login_throttle.base_delay_ms),
+                max_delay: std::time::Duration::from_millis(config.login_throttle.max_delay_ms),
+            },
+        ));
+        let account_notifier = Arc::new(AccountNotifier::new(
+            Arc::clone(&account_token_service),
+            event_publisher,
+            config.account_tokens.link_base_url.clone(),
+        ));
+
+        let oauth_service = Arc::new(OAuthService::new(
+            Arc::clone(&oauth_client_repository),
+            Arc::clone(&oauth_grant_repository),
+            Duration::seconds(config.oauth.authorization_code_ttl_seconds as i64),
+        ));
+
+        let revocation_service = Arc::new(RevocationService::new(
+            Arc::clone(&session_repository),
+            Arc::new(ConfiguredRevocationListRepository::from_config(&config.redis)?),
+        ));
+
+        let federation_service = Arc::new(FederationService::new(
+            Arc::clone(&identity_provider_repository),
+            Arc::clone(&federated_identity_repository),
+            Duration::minutes(config.federation.login_ttl_minutes as i64),
+        ));
+
+        let webauthn_service = Arc::new(WebAuthnService::new(
+            passkey_repository,
+            config.webauthn.rp_id.clone(),
+            config.webauthn.rp_name.clone(),
+            config.webauthn.origins.clone(),
+            Duration::minutes(config.webauthn.challenge_ttl_minutes as i64),
+        ));
+
+        let policy_repository = Arc::new(FilePolicyRepository::load(Path::new(&config.policy.dir)).await?);
+
+        Ok(Self {
+            register_use_case: Arc::new(RegisterUseCase::new(
+                Arc::clone(&user_repository),
+                Arc::clone(&password_service),
+                Arc::clone(&password_policy_service),
+                Arc::clone(&session_service),
+                Arc::clone(&authorization_service),
+                Arc::clone(&account_notifier),
+            )),
+            login_use_case: Arc::new(LoginUseCase::new(
+                Arc::clone(&user_repository),
+                Arc::clone(&password_service),
+                Arc::clone(&session_service),
+                Arc::clone(&authorization_service),
+                Arc::clone(&mfa_service),
+                Arc::clone(&login_throttle),
+                Arc::clone(&audit_log),
+            )),
+            request_magic_link_use_case: Arc::new(RequestMagicLinkUseCase::new(
+                Arc::clone(&user_repository),
+                Arc::clone(&account_notifier),
+            )),
+            magic_link_login_use_case: Arc::new(MagicLinkLoginUseCase::new(
+                Arc::clone(&user_repository),
+                Arc::clone(&password_service),
+                Arc::clone(&session_service),
+                Arc::clone(&authorization_service),
+                Arc::clone(&mfa_service),
+                Arc::clone(&account_token_service),
+                Arc::clone(&audit_log),
+            )),
+            start_passkey_registration_use_case: Arc::new(StartPasskeyRegistrationUseCase::new(
+                Arc::clone(&user_repository),
+                Arc::clone(&webauthn_service),
+            )),
+            finish_passkey_registration_use_case: Arc::new(FinishPasskeyRegistrationUseCase::new(
+                Arc::clone(&webauthn_service),
+                Arc::clone(&audit_log),
+            )),
+            list_passkeys_use_case: Arc::new(ListPasskeysUseCase::new(Arc::clone(&webauthn_service))),
+            delete_passkey_use_case: Arc::new(DeletePasskeyUseCase::new(Arc::clone(&webauthn_service), Arc::clone(&audit_log))),
+            start_passkey_login_use_case: Arc::new(StartPasskeyLoginUseCase::new(
+                Arc::clone(&user_repository),
+                Arc::clone(&webauthn_service),
+            )),
+            passkey_login_use_case: Arc::new(PasskeyLoginUseCase::new(
+                Arc::clone(&user_repository),
+                Arc::clone(&session_service),
+                Arc::clone(&authorization_service),
+                webauthn_service,
+                Arc::clone(&audit_log),
+            )),
+            logout_use_case: Arc::new(LogoutUseCase::new(Arc::clone(&session_repository), Arc::clone(&audit_log))),
+            list_sessions_use_case: Arc::new(ListSessionsUseCase::new(Arc::clone(&session_repository))),
+            revoke_session_use_case: Arc::new(RevokeSessionUseCase::new(Arc::clone(&session_repository), Arc::clone(&audit_log))),
+            revoke_sessions_use_case: Arc::new(RevokeSessionsUseCase::new(Arc::clone(&session_repository), Arc::clone(&audit_log))),
+            refresh_session_use_case: Arc::new(RefreshSessionUseCase::new(
+                Arc::clone(&session_service),
+                Arc::clone(&authorization_service),
+                Arc::clone(&organization_service),
+            )),
+            create_organization_use_case: Arc::new(CreateOrganizationUseCase::new(
+                Arc::clone(&organization_service),
+                Arc::clone(&role_repository),
+                config.organizations.founder_role.clone(),
+                Arc::clone(&audit_log),
+            )),
+            list_organizations_use_case: Arc::new(ListOrganizationsUseCase::new(
+                Arc::clone(&organization_service),
+                Arc::clone(&role_repository),
+            )),
+            switch_organization_use_case: Arc::new(SwitchOrganizationUseCase::new(
+                Arc::clone(&session_service),
+                Arc::clone(&organization_service),
+                Arc::clone(&authorization_service),
+            )),
+            list_members_use_case: Arc::new(ListMembersUseCase::new(
+                Arc::clone(&user_repository),
+                Arc::clone(&organization_service),
+                Arc::clone(&role_repository),
+                Arc::clone(&authorization_service),
+            )),
+            remove_member_use_case: Arc::new(RemoveMemberUseCase::new(
+                Arc::clone(&organization_service),
+                Arc::clone(&authorization_service),
+                Arc::clone(&audit_log),
+            )),
+            assign_member_role_use_case: Arc::new(AssignMemberRoleUseCase::new(
+                Arc::clone(&organization_service),
+                Arc::clone(&role_repository),
+                Arc::clone(&authorization_service),
+                Arc::clone(&audit_log),
+            )),
+            unassign_member_role_use_case: Arc::new(UnassignMemberRoleUseCase::new(
+                Arc::clone(&organization_service),
+                Arc::clone(&role_repository),
+                Arc::clone(&authorization_service),
+                Arc::clone(&audit_log),
+            )),
+            invite_member_use_case: Arc::new(InviteMemberUseCase::new(
+                Arc::clone(&organization_service),
+                Arc::clone(&role_repository),
+                Arc::clone(&authorization_service),
+                Arc::clone(&account_notifier),
+                Arc::clone(&audit_log),
+            )),
+            list_invitations_use_case: Arc::new(ListInvitationsUseCase::new(
+                Arc::clone(&organization_service),
+                Arc::clone(&authorization_service),
+            )),
+            revoke_invitation_use_case: Arc::new(RevokeInvitationUseCase::new(
+                Arc::clone(&organization_service),
+                Arc::clone(&authorization_service),
+                Arc::clone(&audit_log),
+            )),
+            accept_invitation_use_case: Arc::new(AcceptInvitationUseCase::new(
+                Arc::clone(&user_repository),
+                organization_service,
+                Arc::clone(&role_repository),
+                Arc::clone(&audit_log),
+            )),
+            authorize_use_case: Arc::new(AuthorizeUseCase::new(policy_repository)),
+            authorize_client_use_case: Arc::new(AuthorizeClientUseCase::new(Arc::clone(&oauth_service))),
+            decide_consent_use_case: Arc::new(DecideConsentUseCase::new(Arc::clone(&oauth_service))),
+            issue_token_use_case: Arc::new(IssueTokenUseCase::new(
+                Arc::clone(&user_repository),
+                Arc::clone(&oauth_service),
+                Arc::clone(&session_service),
+                Arc::clone(&authorization_service),
+                Arc::clone(&token_service),
+            )),
+            introspect_token_use_case: Arc::new(IntrospectTokenUseCase::new(
+                Arc::clone(&oauth_service),
+                Arc::clone(&token_service),
+                Arc::clone(&revocation_service),
+                Arc::clone(&api_key_service),
+            )),
+            revoke_token_use_case: Arc::new(RevokeTokenUseCase::new(
+                oauth_service,
+                Arc::clone(&token_service),
+                Arc::clone(&revocation_service),
+                Arc::clone(&audit_log),
+            )),
+            create_service_account_use_case: Arc::new(CreateServiceAccountUseCase::new(
+                Arc::clone(&service_account_repository),
+                Arc::clone(&audit_log),
+            )),
+            list_service_accounts_use_case: Arc::new(ListServiceAccountsUseCase::new(
+                Arc::clone(&service_account_repository),
+                Arc::clone(&role_repository),
+            )),
+            delete_service_account_use_case: Arc::new(DeleteServiceAccountUseCase::new(
+                Arc::clone(&service_account_repository),
+                Arc::clone(&audit_log),
+            )),
+            assign_service_account_role_use_case: Arc::new(AssignServiceAccountRoleUseCase::new(
+                Arc::clone(&service_account_repository),
+                Arc::clone(&role_repository),
+                Arc::clone(&audit_log),
+            )),
+            unassign_service_account_role_use_case: Arc::new(UnassignServiceAccountRoleUseCase::new(
+                Arc::clone(&role_repository),
+                Arc::clone(&audit_log),
+            )),
+            create_api_key_use_case: Arc::new(CreateApiKeyUseCase::new(
+                Arc::clone(&service_account_repository),
+                Arc::clone(&permission_repository),
+                Arc::clone(&api_key_service),
+                Arc::clone(&audit_log),
+            )),
+            list_api_keys_use_case: Arc::new(ListApiKeysUseCase::new(
+                service_account_repository,
+                Arc::clone(&api_key_repository),
+            )),
+            rotate_api_key_use_case: Arc::new(RotateApiKeyUseCase::new(
+                Arc::clone(&api_key_repository),
+                Arc::clone(&api_key_service),
+                Arc::clone(&audit_log),
+            )),
+            revoke_api_key_use_case: Arc::new(RevokeApiKeyUseCase::new(
+                api_key_repository,
+                Arc::clone(&api_key_service),
+                Arc::clone(&audit_log),
+            )),
+            create_oauth_client_use_case: Arc::new(CreateOAuthClientUseCase::new(
+                Arc::clone(&oauth_client_repository),
+                Arc::clone(&permission_repository),
+            )),
+            list_oauth_clients_use_case: Arc::new(ListOAuthClientsUseCase::new(Arc::clone(&oauth_client_repository))),
+            delete_oauth_client_use_case: Arc::new(DeleteOAuthClientUseCase::new(oauth_client_repository)),
+            list_consents_use_case: Arc::new(ListConsentsUseCase::new(Arc::clone(&oauth_grant_repository))),
+            revoke_consent_use_case: Arc::new(RevokeConsentUseCase::new(
+                Arc::clone(&session_repository),
+                oauth_grant_repository,
+            )),
+            list_identity_providers_use_case: Arc::new(ListIdentityProvidersUseCase::new(identity_provider_repository)),
+            start_federated_login_use_case: Arc::new(StartFederatedLoginUseCase::new(Arc::clone(&federation_service))),
+            complete_federated_login_use_case: Arc::new(CompleteFederatedLoginUseCase::new(
+                Arc::clone(&user_repository),
+                Arc::clone(&password_service),
+                Arc::clone(&session_service),
+                Arc::clone(&authorization_service),
+                Arc::clone(&mfa_service),
+                federation_service,
+                Arc::clone(&audit_log),
+            )),
+            list_federated_identities_use_case: Arc::new(ListFederatedIdentitiesUseCase::new(federated_identity_repository)),
+            create_role_use_case: Arc::new(CreateRoleUseCase::new(Arc::clone(&role_repository))),
+            list_roles_use_case: Arc::new(ListRolesUseCase::new(Arc::clone(&role_repository))),
+            set_role_parent_use_case: Arc::new(SetRoleParentUseCase::new(Arc::clone(&role_repository))),
+            delete_role_use_case: Arc::new(DeleteRoleUseCase::new(Arc::clone(&role_repository))),
+            create_permission_use_case: Arc::new(CreatePermissionUseCase::new(Arc::clone(&permission_repository))),
+            list_permissions_use_case: Arc::new(ListPermissionsUseCase::new(Arc::clone(&permission_repository))),
+            grant_permission_use_case: Arc::new(GrantPermissionUseCase::new(
+                Arc::clone(&role_repository),
+                Arc::clone(&permission_repository),
+                Arc::clone(&audit_log),
+            )),
+            revoke_permission_use_case: Arc::new(RevokePermissionUseCase::new(permission_repository, Arc::clone(&audit_log))),
+            assign_role_use_case: Arc::new(AssignRoleUseCase::new(
+                Arc::clone(&user_repository),
+                Arc::clone(&role_repository),
+                Arc::clone(&audit_log),
+            )),
+            unassign_role_use_case: Arc::new(UnassignRoleUseCase::new(role_repository, Arc::clone(&audit_log))),
+            get_user_permissions_use_case: Arc::new(GetUserPermissionsUseCase::new(Arc::clone(&authorization_service))),
+            enroll_mfa_use_case: Arc::new(EnrollMfaUseCase::new(Arc::clone(&user_repository), Arc::clone(&mfa_service))),
+            confirm_mfa_use_case: Arc::new(ConfirmMfaUseCase::new(Arc::clone(&mfa_service))),
+            verify_mfa_use_case: Arc::new(VerifyMfaUseCase::new(
+                Arc::clone(&user_repository),
+                Arc::clone(&mfa_service),
+                session_service,
+                Arc::clone(&authorization_service),
+                login_throttle,
+                Arc::clone(&audit_log),
+            )),
+            disable_mfa_use_case: Arc::new(DisableMfaUseCase::new(
+                Arc::clone(&user_repository),
+                Arc::clone(&password_service),
+                mfa_service,
+            )),
+            request_email_verification_use_case: Arc::new(RequestEmailVerificationUseCase::new(
+                Arc::clone(&user_repository),
+                Arc::clone(&account_notifier),
+            )),
+            verify_email_use_case: Arc::new(VerifyEmailUseCase::new(
+                Arc::clone(&user_repository),
+                Arc::clone(&account_token_service),
+            )),
+            forgot_password_use_case: Arc::new(ForgotPasswordUseCase::new(
+                Arc::clone(&user_repository),
+                Arc::clone(&account_notifier),
+            )),
+            reset_password_use_case: Arc::new(ResetPasswordUseCase::new(
+                user_repository,
+                session_repository,
+                password_service,
+                password_policy_service,
+                account_token_service,
+                account_notifier,
+                Arc::clone(&audit_log),
+            )),
+            list_audit_events_use_case: Arc::new(ListAuditEventsUseCase::new(Arc::clone(&audit_log))),
+            export_audit_events_use_case: Arc::new(ExportAuditEventsUseCase::new(Arc::clone(&audit_log))),
+            verify_audit_chain_use_case: Arc::new(VerifyAuditChainUseCase::new(audit_log)),
+            trust_forwarded_for: config.server.trust_forwarded_for,
+            reauthentication_max_age: Duration::minutes(config.session.reauthentication_max_age_minutes as i64),
+            token_service,
+            revocation_service,
+            authorization_service,
+            api_key_service,
+        })
+    }
+}
@@ -0,0 +1,28 @@
+use crate::domain::value_objects::{AccountTokenPurpose, UserId};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Server-side record of an issued email verification or password reset
+/// token. The token itself is signed; this record makes it single-use.
+#[derive(Debug, Clone)]
+pub struct AccountToken {
+    pub id: Uuid,
+    pub user_id: UserId,
+    pub purpose: AccountTokenPurpose,
+    pub expires_at: DateTime<Utc>,
+    pub consumed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl AccountToken {
+    pub fn new(user_id: UserId, purpose: AccountTokenPurpose, expires_at: DateTime<Utc>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            purpose,
+            expires_at,
+            consumed_at: None,
+            created_at: Utc::now(),
+        }
+    }
+}
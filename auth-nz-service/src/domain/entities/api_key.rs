@@ -0,0 +1,87 @@
+use crate::domain::value_objects::{ApiKeyId, Scopes, ServiceAccountId};
+use chrono::{DateTime, Duration, Utc};
+use subtle::ConstantTimeEq;
+
+/// How stale `last_used_at` may get before a use is written back.
+const LAST_USED_RESOLUTION_SECONDS: i64 = 60;
+
+/// Credential of a service account. The key acts with the account's
+/// permissions, limited to its scopes.
+#[derive(Debug, Clone)]
+pub struct ApiKey {
+    pub id: ApiKeyId,
+    pub service_account_id: ServiceAccountId,
+    pub name: String,
+    /// Public part of the key, used to look it up.
+    pub prefix: String,
+    pub key_hash: String,
+    pub scopes: Scopes,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    /// The key this one replaced when it was rotated.
+    pub rotated_from: Option<ApiKeyId>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ApiKey {
+    pub fn new(
+        service_account_id: ServiceAccountId,
+        name: String,
+        prefix: String,
+        key_hash: String,
+        scopes: Scopes,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            id: ApiKeyId::new(),
+            service_account_id,
+            name,
+            prefix,
+            key_hash,
+            scopes,
+            expires_at,
+            last_used_at: None,
+            revoked_at: None,
+            rotated_from: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expires_at.is_some_and(|expires_at| Utc::now() >= expires_at)
+    }
+
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_at.is_some()
+    }
+
+    pub fn is_active(&self) -> bool {
+        !self.is_revoked() && !self.is_expired()
+    }
+
+    pub fn verify_hash(&self, key_hash: &str) -> bool {
+        self.key_hash.as_bytes().ct_eq(key_hash.as_bytes()).into()
+    }
+
+    pub fn revoke(&mut self) {
+        self.revoked_at = Some(Utc::now());
+    }
+
+    /// Keeps the key working for `grace` at most, so holders can switch to its successor.
+    pub fn retire(&mut self, grace: Duration) {
+        if grace <= Duration::zero() {
+            self.revoke();
+            return;
+        }
+        let until = Utc::now() + grace;
+        self.expires_at = Some(self.expires_at.map_or(until, |expires_at| expires_at.min(until)));
+    }
+
+    /// Whether a use should be recorded; uses within a minute of the last
+    /// recorded one are not, to keep authentication free of writes.
+    pub fn needs_touch(&self) -> bool {
+        self.last_used_at
+            .is_none_or(|last_used_at| Utc::now() - last_used_at >= Duration::seconds(LAST_USED_RESOLUTION_SECONDS))
+    }
+}
@@ -0,0 +1,111 @@
+use crate::domain::value_objects::{AuditEventType, UserId};
+use chrono::{DateTime, SecondsFormat, SubsecRound, Utc};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+
+/// `prev_hash` of the first event in the log.
+pub const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+/// An action about to be written to the audit log.
+#[derive(Debug, Clone)]
+pub struct NewAuditEvent {
+    pub event_type: AuditEventType,
+    /// Who acted: a user or service account id, or the id of an OAuth client
+    /// acting on its own.
+    /// Absent when nobody could be identified, e.g. a failed login.
+    pub actor: Option<String>,
+    /// The account the action concerns.
+    pub user_id: Option<UserId>,
+    pub ip_address: Option<String>,
+    pub details: Value,
+}
+
+impl NewAuditEvent {
+    pub fn new(event_type: AuditEventType) -> Self {
+        Self {
+            event_type,
+            actor: None,
+            user_id: None,
+            ip_address: None,
+            details: json!({}),
+        }
+    }
+
+    pub fn with_actor(mut self, actor: impl Into<String>) -> Self {
+        self.actor = Some(actor.into());
+        self
+    }
+
+    pub fn with_user(mut self, user_id: &UserId) -> Self {
+        self.user_id = Some(*user_id);
+        self
+    }
+
+    pub fn with_ip(mut self, ip_address: impl Into<String>) -> Self {
+        self.ip_address = Some(ip_address.into());
+        self
+    }
+
+    pub fn with_details(mut self, details: Value) -> Self {
+        self.details = details;
+        self
+    }
+}
+
+/// A record in the append-only audit log. Each record's hash covers its
+/// predecessor's, so altering, removing or reordering any record breaks the
+/// chain from that record on.
+#[derive(Debug, Clone)]
+pub struct AuditEvent {
+    /// Position in the log, starting at 1 and without gaps.
+    pub sequence: i64,
+    pub event_type: String,
+    pub actor: Option<String>,
+    pub user_id: Option<UserId>,
+    pub ip_address: Option<String>,
+    pub details: Value,
+    pub occurred_at: DateTime<Utc>,
+    pub prev_hash: String,
+    pub hash: String,
+}
+
+impl AuditEvent {
+    /// Appends `event` after the record with `prev_hash` at `sequence - 1`.
+    pub fn seal(event: NewAuditEvent, sequence: i64, prev_hash: String) -> Self {
+        let mut sealed = Self {
+            sequence,
+            event_type: event.event_type.as_str().to_string(),
+            actor: event.actor,
+            user_id: event.user_id,
+            ip_address: event.ip_address,
+            details: event.details,
+            // Stored with microsecond precision; the hash must survive the round trip
+            occurred_at: Utc::now().trunc_subsecs(6),
+            prev_hash,
+            hash: String::new(),
+        };
+        sealed.hash = sealed.compute_hash();
+        sealed
+    }
+
+    /// SHA-256 over the record's fields in a fixed order, `hash` itself excluded.
+    pub fn compute_hash(&self) -> String {
+        let canonical = json!([
+            self.sequence,
+            self.prev_hash,
+            self.event_type,
+            self.actor,
+            self.user_id.as_ref().map(|id| id.as_uuid().to_string()),
+            self.ip_address,
+            self.details,
+            self.occurred_at.to_rfc3339_opts(SecondsFormat::Micros, true),
+        ]);
+        hex::encode(Sha256::digest(canonical.to_string().as_bytes()))
+    }
+
+    /// Whether this record is intact and directly follows the record with
+    /// `prev_sequence` and `prev_hash` (0 and `GENESIS_HASH` for the first).
+    pub fn follows(&self, prev_sequence: i64, prev_hash: &str) -> bool {
+        self.sequence == prev_sequence + 1 && self.prev_hash == prev_hash && self.hash == self.compute_hash()
+    }
+}
@@ -0,0 +1,29 @@
+use crate::domain::value_objects::UserId;
+use chrono::{DateTime, Utc};
+
+/// Account at an external identity provider linked to a local user.
+#[derive(Debug, Clone)]
+pub struct FederatedIdentity {
+    pub provider: String,
+    /// The provider's stable `sub` for the account.
+    pub subject: String,
+    pub user_id: UserId,
+    /// Email the provider reported at the last login.
+    pub email: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub last_login_at: DateTime<Utc>,
+}
+
+impl FederatedIdentity {
+    pub fn new(provider: String, subject: String, user_id: UserId, email: Option<String>) -> Self {
+        let now = Utc::now();
+        Self {
+            provider,
+            subject,
+            user_id,
+            email,
+            created_at: now,
+            last_login_at: now,
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use chrono::{DateTime, Utc};
+
+/// Login redirected to an external provider and not yet called back.
+/// Looked up by the hash of the `state` parameter.
+#[derive(Debug, Clone)]
+pub struct FederatedLogin {
+    pub state_hash: String,
+    pub provider: String,
+    /// Must come back unchanged in the ID token.
+    pub nonce: String,
+    /// PKCE verifier sent with the code exchange.
+    pub code_verifier: String,
+    pub expires_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl FederatedLogin {
+    pub fn new(
+        state_hash: String,
+        provider: String,
+        nonce: String,
+        code_verifier: String,
+        expires_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            state_hash,
+            provider,
+            nonce,
+            code_verifier,
+            expires_at,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        Utc::now() > self.expires_at
+    }
+}
@@ -0,0 +1,21 @@
+/// External OpenID Connect provider users can sign in with.
+#[derive(Debug, Clone)]
+pub struct IdentityProvider {
+    /// Short name used in URLs and stored with linked identities, e.g. `google`.
+    pub name: String,
+    pub issuer: String,
+    pub client_id: String,
+    pub client_secret: Option<String>,
+    pub scopes: Vec<String>,
+    /// Callback registered with the provider.
+    pub redirect_uri: String,
+}
+
+/// Endpoints published in the provider's discovery document.
+#[derive(Debug, Clone)]
+pub struct IdentityProviderMetadata {
+    pub issuer: String,
+    pub authorization_endpoint: String,
+    pub token_endpoint: String,
+    pub jwks_uri: String,
+}
@@ -0,0 +1,28 @@
+use crate::domain::value_objects::UserId;
+use chrono::{DateTime, Utc};
+
+/// Pending second step of a login whose password was already verified.
+#[derive(Debug, Clone)]
+pub struct MfaChallenge {
+    pub token_hash: String,
+    pub user_id: UserId,
+    pub attempts: i32,
+    pub expires_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl MfaChallenge {
+    pub fn new(token_hash: String, user_id: UserId, expires_at: DateTime<Utc>) -> Self {
+        Self {
+            token_hash,
+            user_id,
+            attempts: 0,
+            expires_at,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        Utc::now() > self.expires_at
+    }
+}
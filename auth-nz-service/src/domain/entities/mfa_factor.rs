@@ -0,0 +1,34 @@
+use crate::domain::value_objects::{TotpSecret, UserId};
+use chrono::{DateTime, Utc};
+
+/// A user's TOTP authenticator. It only guards logins once confirmed.
+#[derive(Debug, Clone)]
+pub struct MfaFactor {
+    pub user_id: UserId,
+    pub secret: TotpSecret,
+    pub confirmed_at: Option<DateTime<Utc>>,
+    /// Last accepted time step, so a code cannot be replayed within its window.
+    pub last_used_step: Option<i64>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl MfaFactor {
+    pub fn new(user_id: UserId, secret: TotpSecret) -> Self {
+        Self {
+            user_id,
+            secret,
+            confirmed_at: None,
+            last_used_step: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn confirm(&mut self, step: i64) {
+        self.confirmed_at = Some(Utc::now());
+        self.last_used_step = Some(step);
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.confirmed_at.is_some()
+    }
+}
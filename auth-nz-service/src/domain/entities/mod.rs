@@ -2,4 +2,20 @@ pub mod user;
 pub mod session;
 pub mod role;
 pub mod permission;
-
+pub mod policy;
+pub mod mfa_factor;
+pub mod mfa_challenge;
+pub mod account_token;
+pub mod oauth_client;
+pub mod oauth_consent;
+pub mod oauth_authorization_code;
+pub mod identity_provider;
+pub mod federated_identity;
+pub mod federated_login;
+pub mod audit_event;
+pub mod service_account;
+pub mod api_key;
+pub mod passkey;
+pub mod webauthn_challenge;
+pub mod organization;
+pub mod organization_invitation;
@@ -0,0 +1,39 @@
+use crate::domain::value_objects::{Scopes, UserId};
+use chrono::{DateTime, Utc};
+
+/// What an authorization code stands for until the client exchanges it.
+#[derive(Debug, Clone)]
+pub struct OAuthAuthorizationCode {
+    pub code_hash: String,
+    pub client_id: String,
+    pub user_id: UserId,
+    pub redirect_uri: String,
+    pub scopes: Scopes,
+    /// S256 PKCE challenge the exchange must answer.
+    pub code_challenge: String,
+    pub expires_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl OAuthAuthorizationCode {
+    pub fn new(
+        code_hash: String,
+        client_id: String,
+        user_id: UserId,
+        redirect_uri: String,
+        scopes: Scopes,
+        code_challenge: String,
+        expires_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            code_hash,
+            client_id,
+            user_id,
+            redirect_uri,
+            scopes,
+            code_challenge,
+            expires_at,
+            created_at: Utc::now(),
+        }
+    }
+}
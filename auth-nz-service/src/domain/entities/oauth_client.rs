@@ -0,0 +1,57 @@
+use crate::domain::value_objects::{GrantType, Scopes};
+use chrono::{DateTime, Utc};
+use subtle::ConstantTimeEq;
+
+/// A registered OAuth client. Confidential clients authenticate with a
+/// secret; public clients such as the mobile app have none and must use PKCE.
+#[derive(Debug, Clone)]
+pub struct OAuthClient {
+    pub id: String,
+    pub name: String,
+    pub secret_hash: Option<String>,
+    pub redirect_uris: Vec<String>,
+    pub grant_types: Vec<GrantType>,
+    /// The most a client may ask for; each request can narrow it.
+    pub scopes: Scopes,
+    pub created_at: DateTime<Utc>,
+}
+
+impl OAuthClient {
+    pub fn new(
+        name: String,
+        secret_hash: Option<String>,
+        redirect_uris: Vec<String>,
+        grant_types: Vec<GrantType>,
+        scopes: Scopes,
+    ) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().simple().to_string(),
+            name,
+            secret_hash,
+            redirect_uris,
+            grant_types,
+            scopes,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn is_confidential(&self) -> bool {
+        self.secret_hash.is_some()
+    }
+
+    pub fn allows_grant(&self, grant_type: GrantType) -> bool {
+        self.grant_types.contains(&grant_type)
+    }
+
+    /// Redirect URIs are compared exactly; no prefix or wildcard matching.
+    pub fn allows_redirect_uri(&self, redirect_uri: &str) -> bool {
+        self.redirect_uris.iter().any(|uri| uri == redirect_uri)
+    }
+
+    pub fn verify_secret(&self, secret_hash: &str) -> bool {
+        match &self.secret_hash {
+            Some(expected) => expected.as_bytes().ct_eq(secret_hash.as_bytes()).into(),
+            None => false,
+        }
+    }
+}
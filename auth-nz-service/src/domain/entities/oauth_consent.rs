@@ -0,0 +1,23 @@
+use crate::domain::value_objects::{Scopes, UserId};
+use chrono::{DateTime, Utc};
+
+/// Scopes a user has agreed to let a client use. Authorization requests
+/// within these scopes skip the consent prompt.
+#[derive(Debug, Clone)]
+pub struct OAuthConsent {
+    pub user_id: UserId,
+    pub client_id: String,
+    pub scopes: Scopes,
+    pub granted_at: DateTime<Utc>,
+}
+
+impl OAuthConsent {
+    pub fn new(user_id: UserId, client_id: String, scopes: Scopes) -> Self {
+        Self {
+            user_id,
+            client_id,
+            scopes,
+            granted_at: Utc::now(),
+        }
+    }
+}
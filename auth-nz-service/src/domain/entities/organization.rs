@@ -0,0 +1,39 @@
+use crate::domain::value_objects::{OrganizationId, UserId};
+use chrono::{DateTime, Utc};
+
+/// A tenant: a customer company whose users hold roles within it.
+#[derive(Debug, Clone)]
+pub struct Organization {
+    pub id: OrganizationId,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Organization {
+    pub fn new(name: String) -> Self {
+        Self {
+            id: OrganizationId::new(),
+            name,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+/// A user's membership of an organization. Roles held in the organization
+/// are assigned to the membership and go away with it.
+#[derive(Debug, Clone)]
+pub struct OrganizationMember {
+    pub organization_id: OrganizationId,
+    pub user_id: UserId,
+    pub joined_at: DateTime<Utc>,
+}
+
+impl OrganizationMember {
+    pub fn new(organization_id: OrganizationId, user_id: UserId) -> Self {
+        Self {
+            organization_id,
+            user_id,
+            joined_at: Utc::now(),
+        }
+    }
+}
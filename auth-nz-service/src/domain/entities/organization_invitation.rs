@@ -0,0 +1,50 @@
+use crate::domain::value_objects::{Email, InvitationId, OrganizationId, RoleId, UserId};
+use chrono::{DateTime, Duration, Utc};
+
+/// An invitation emailed to someone to join an organization. It is accepted
+/// by a signed-in user with the invited email, at most once.
+#[derive(Debug, Clone)]
+pub struct OrganizationInvitation {
+    pub id: InvitationId,
+    pub organization_id: OrganizationId,
+    pub email: Email,
+    /// Role the invitee gets in the organization on joining.
+    pub role_id: Option<RoleId>,
+    pub token_hash: String,
+    pub invited_by: UserId,
+    pub expires_at: DateTime<Utc>,
+    pub accepted_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl OrganizationInvitation {
+    pub fn new(
+        organization_id: OrganizationId,
+        email: Email,
+        role_id: Option<RoleId>,
+        token_hash: String,
+        invited_by: UserId,
+        ttl: Duration,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: InvitationId::new(),
+            organization_id,
+            email,
+            role_id,
+            token_hash,
+            invited_by,
+            expires_at: now + ttl,
+            accepted_at: None,
+            created_at: now,
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        Utc::now() > self.expires_at
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.accepted_at.is_none() && !self.is_expired()
+    }
+}
@@ -0,0 +1,93 @@
+use crate::domain::value_objects::{AttestedCredential, PasskeyId, UserId};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// A WebAuthn credential registered by a user for passwordless login.
+#[derive(Debug, Clone)]
+pub struct Passkey {
+    pub id: PasskeyId,
+    pub user_id: UserId,
+    pub name: String,
+    /// Id the authenticator knows the credential by.
+    pub credential_id: Vec<u8>,
+    /// COSE_Key encoding of the public key.
+    pub public_key: Vec<u8>,
+    /// COSE algorithm of `public_key`.
+    pub algorithm: i64,
+    /// Highest signature counter seen. Stays 0 for authenticators that do not
+    /// keep one, such as synced passkeys.
+    pub sign_count: u32,
+    /// Authenticator model, all zeros when not disclosed.
+    pub aaguid: Uuid,
+    /// Attestation statement format the credential was registered with.
+    pub attestation_format: String,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Passkey {
+    pub fn new(
+        user_id: UserId,
+        name: String,
+        credential: AttestedCredential,
+        algorithm: i64,
+        sign_count: u32,
+        attestation_format: String,
+    ) -> Self {
+        Self {
+            id: PasskeyId::new(),
+            user_id,
+            name,
+            credential_id: credential.credential_id,
+            public_key: credential.public_key,
+            algorithm,
+            sign_count,
+            aaguid: credential.aaguid,
+            attestation_format,
+            last_used_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    /// Whether an assertion with `sign_count` may follow the last one seen.
+    /// A counter that does not move forward means the credential was copied
+    /// to another authenticator (WebAuthn Level 2, section 6.1.1).
+    pub fn accepts_sign_count(&self, sign_count: u32) -> bool {
+        (sign_count == 0 && self.sign_count == 0) || sign_count > self.sign_count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn passkey(sign_count: u32) -> Passkey {
+        let credential = AttestedCredential {
+            aaguid: Uuid::nil(),
+            credential_id: vec![1, 2, 3],
+            public_key: Vec::new(),
+        };
+        let mut passkey = Passkey::new(UserId::new(), "Laptop".to_string(), credential, -7, 0, "none".to_string());
+        passkey.sign_count = sign_count;
+        passkey
+    }
+
+    #[test]
+    fn accepts_a_counter_that_moves_forward() {
+        assert!(passkey(5).accepts_sign_count(6));
+        assert!(passkey(0).accepts_sign_count(1));
+    }
+
+    #[test]
+    fn rejects_a_counter_that_repeats_or_goes_backwards() {
+        assert!(!passkey(5).accepts_sign_count(5));
+        assert!(!passkey(5).accepts_sign_count(4));
+        // An authenticator that stops counting after it did is a clone too
+        assert!(!passkey(5).accepts_sign_count(0));
+    }
+
+    #[test]
+    fn accepts_authenticators_that_never_count() {
+        assert!(passkey(0).accepts_sign_count(0));
+    }
+}
@@ -20,5 +20,15 @@ impl Permission {
             created_at: Utc::now(),
         }
     }
-}
 
+    /// Whether this permission covers `action` on `resource`. Either side may be `*`.
+    pub fn matches(&self, resource: &str, action: &str) -> bool {
+        (self.resource == "*" || self.resource == resource)
+            && (self.action == "*" || self.action == action)
+    }
+
+    /// The `resource:action` form embedded in access tokens.
+    pub fn scope(&self) -> String {
+        format!("{}:{}", self.resource, self.action)
+    }
+}
@@ -0,0 +1,37 @@
+use crate::domain::value_objects::PolicyCondition;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PolicyEffect {
+    Allow,
+    Deny,
+}
+
+/// Attribute-based authorization rule.
+///
+/// A policy applies when the request's resource type and action match its
+/// target (`*` matches anything); its effect is taken when the condition holds.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Policy {
+    pub id: String,
+    #[serde(default)]
+    pub description: String,
+    pub effect: PolicyEffect,
+    #[serde(default)]
+    pub priority: i32,
+    pub resource_type: String,
+    pub actions: Vec<String>,
+    #[serde(default)]
+    pub condition: Option<PolicyCondition>,
+    #[serde(default = "Utc::now")]
+    pub created_at: DateTime<Utc>,
+}
+
+impl Policy {
+    pub fn applies_to(&self, resource_type: &str, action: &str) -> bool {
+        (self.resource_type == "*" || self.resource_type == resource_type)
+            && self.actions.iter().any(|a| a == "*" || a == action)
+    }
+}
@@ -5,6 +5,8 @@ use chrono::{DateTime, Utc};
 pub struct Role {
     pub id: RoleId,
     pub name: RoleName,
+    /// Role whose permissions this role inherits.
+    pub parent_id: Option<RoleId>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -15,9 +17,14 @@ impl Role {
         Self {
             id,
             name,
+            parent_id: None,
             created_at: now,
             updated_at: now,
         }
     }
-}
 
+    pub fn set_parent(&mut self, parent_id: Option<RoleId>) {
+        self.parent_id = parent_id;
+        self.updated_at = Utc::now();
+    }
+}
@@ -0,0 +1,23 @@
+use crate::domain::value_objects::ServiceAccountId;
+use chrono::{DateTime, Utc};
+
+/// Non-human principal for machine-to-machine calls, such as batch jobs.
+/// It holds roles like a user and authenticates with API keys.
+#[derive(Debug, Clone)]
+pub struct ServiceAccount {
+    pub id: ServiceAccountId,
+    pub name: String,
+    pub description: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ServiceAccount {
+    pub fn new(name: String, description: Option<String>) -> Self {
+        Self {
+            id: ServiceAccountId::new(),
+            name,
+            description,
+            created_at: Utc::now(),
+        }
+    }
+}
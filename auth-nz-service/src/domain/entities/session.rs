@@ -1,28 +1,113 @@
-use crate::domain::value_objects::{SessionId, UserId, Token};
+use crate::domain::value_objects::{DeviceInfo, OAuthDelegation, OrganizationId, SessionId, UserId, Token, RefreshTokenHash};
 use chrono::{DateTime, Utc};
 
 #[derive(Debug, Clone)]
 pub struct Session {
     pub id: SessionId,
     pub user_id: UserId,
+    /// Id of the session that started the refresh chain this session belongs to.
+    pub family_id: SessionId,
     pub token: Token,
+    pub refresh_token_hash: RefreshTokenHash,
     pub expires_at: DateTime<Utc>,
+    pub refresh_expires_at: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub device: DeviceInfo,
+    /// Last time the client used the session, i.e. signed in or refreshed.
+    pub last_seen_at: DateTime<Utc>,
+    /// Set when the session was opened through an OAuth client.
+    pub delegation: Option<OAuthDelegation>,
+    /// Set when the session was opened within an organization; its tokens
+    /// carry the user's roles there.
+    pub organization_id: Option<OrganizationId>,
+    /// When the user last proved who they are, i.e. signed in. Kept when the
+    /// session is refreshed or switched to an organization.
+    pub authenticated_at: DateTime<Utc>,
     pub created_at: DateTime<Utc>,
 }
 
 impl Session {
-    pub fn new(id: SessionId, user_id: UserId, token: Token, expires_at: DateTime<Utc>) -> Self {
+    pub fn new(
+        id: SessionId,
+        user_id: UserId,
+        token: Token,
+        expires_at: DateTime<Utc>,
+        refresh_token_hash: RefreshTokenHash,
+        refresh_expires_at: DateTime<Utc>,
+        device: DeviceInfo,
+    ) -> Self {
+        let now = Utc::now();
         Self {
             id,
             user_id,
+            family_id: id,
             token,
+            refresh_token_hash,
             expires_at,
-            created_at: Utc::now(),
+            refresh_expires_at,
+            revoked_at: None,
+            device,
+            last_seen_at: now,
+            delegation: None,
+            organization_id: None,
+            authenticated_at: now,
+            created_at: now,
         }
     }
 
+    /// Builds the session that replaces this one when its refresh token is used,
+    /// recording the client that refreshed it.
+    pub fn next_in_family(
+        &self,
+        id: SessionId,
+        token: Token,
+        expires_at: DateTime<Utc>,
+        refresh_token_hash: RefreshTokenHash,
+        refresh_expires_at: DateTime<Utc>,
+        device: DeviceInfo,
+    ) -> Self {
+        Self {
+            family_id: self.family_id,
+            ..Self::new(
+                id,
+                self.user_id,
+                token,
+                expires_at,
+                refresh_token_hash,
+                refresh_expires_at,
+                device,
+            )
+            .with_delegation(self.delegation.clone())
+            .with_organization(self.organization_id)
+            .with_authenticated_at(self.authenticated_at)
+        }
+    }
+
+    pub fn with_delegation(mut self, delegation: Option<OAuthDelegation>) -> Self {
+        self.delegation = delegation;
+        self
+    }
+
+    pub fn with_organization(mut self, organization_id: Option<OrganizationId>) -> Self {
+        self.organization_id = organization_id;
+        self
+    }
+
+    pub fn with_authenticated_at(mut self, authenticated_at: DateTime<Utc>) -> Self {
+        self.authenticated_at = authenticated_at;
+        self
+    }
+
+    #[allow(dead_code)]
     pub fn is_expired(&self) -> bool {
         Utc::now() > self.expires_at
     }
-}
 
+    pub fn is_refresh_expired(&self) -> bool {
+        Utc::now() > self.refresh_expires_at
+    }
+
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_at.is_some()
+    }
+}
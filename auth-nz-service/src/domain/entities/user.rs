@@ -7,6 +7,7 @@ pub struct User {
     pub email: Email,
     pub password_hash: PasswordHash,
     pub is_active: bool,
+    pub email_verified_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -19,21 +20,35 @@ impl User {
             email,
             password_hash,
             is_active: true,
+            email_verified_at: None,
             created_at: now,
             updated_at: now,
         }
     }
 
+    // Not called yet; kept for account administration
+    #[allow(dead_code)]
     pub fn deactivate(&mut self) {
         self.is_active = false;
         self.updated_at = Utc::now();
     }
 
+    #[allow(dead_code)]
     pub fn activate(&mut self) {
         self.is_active = true;
         self.updated_at = Utc::now();
     }
 
+    pub fn verify_email(&mut self) {
+        let now = Utc::now();
+        self.email_verified_at.get_or_insert(now);
+        self.updated_at = now;
+    }
+
+    pub fn is_email_verified(&self) -> bool {
+        self.email_verified_at.is_some()
+    }
+
     pub fn update_password(&mut self, new_hash: PasswordHash) {
         self.password_hash = new_hash;
         self.updated_at = Utc::now();
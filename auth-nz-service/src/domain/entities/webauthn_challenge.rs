@@ -0,0 +1,39 @@
+use crate::domain::value_objects::{UserId, WebAuthnCeremony};
+use chrono::{DateTime, Utc};
+use rand::rngs::OsRng;
+use rand::RngCore;
+use uuid::Uuid;
+
+/// Random challenge handed to the browser for one WebAuthn ceremony. It is
+/// consumed when the response comes back, successful or not.
+#[derive(Debug, Clone)]
+pub struct WebAuthnChallenge {
+    pub id: Uuid,
+    pub ceremony: WebAuthnCeremony,
+    /// The user registering a passkey, or the user expected to log in.
+    /// Absent for logins with discoverable credentials, where the
+    /// authenticator tells who is logging in.
+    pub user_id: Option<UserId>,
+    pub challenge: Vec<u8>,
+    pub expires_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl WebAuthnChallenge {
+    pub fn new(ceremony: WebAuthnCeremony, user_id: Option<UserId>, expires_at: DateTime<Utc>) -> Self {
+        let mut challenge = vec![0u8; 32];
+        OsRng.fill_bytes(&mut challenge);
+        Self {
+            id: Uuid::new_v4(),
+            ceremony,
+            user_id,
+            challenge,
+            expires_at,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        Utc::now() > self.expires_at
+    }
+}
@@ -1,5 +1,32 @@
+use serde::Serialize;
 use thiserror::Error;
 
+/// A problem with one input field, in a form clients can show next to it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FieldError {
+    pub field: String,
+    /// Stable identifier of the rule, e.g. `too_short`.
+    pub code: String,
+    pub message: String,
+}
+
+impl FieldError {
+    pub fn new(field: &str, code: &str, message: impl Into<String>) -> Self {
+        Self {
+            field: field.to_string(),
+            code: code.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+fn describe(errors: &[FieldError]) -> String {
+    errors.iter()
+        .map(|error| format!("{}: {}", error.field, error.message))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
 #[derive(Debug, Error, PartialEq, Eq)]
 pub enum DomainError {
     #[error("Invalid email: {0}")]
@@ -8,6 +35,17 @@ pub enum DomainError {
     #[error("Invalid password")]
     InvalidPassword,
 
+    /// Any failed password login, so the response does not tell whether the
+    /// email is registered.
+    #[error("Invalid credentials")]
+    InvalidCredentials,
+
+    #[error("{}", describe(.0))]
+    InvalidFields(Vec<FieldError>),
+
+    #[error("Password hashing error: {0}")]
+    PasswordHashing(String),
+
     #[error("User not found")]
     UserNotFound,
 
@@ -23,18 +61,153 @@ pub enum DomainError {
     #[error("Invalid token")]
     InvalidToken,
 
+    #[error("Token expired")]
+    TokenExpired,
+
+    #[error("Refresh token reused")]
+    RefreshTokenReused,
+
+    #[error("Token signing error: {0}")]
+    TokenSigning(String),
+
     #[error("Invalid role name: {0}")]
     InvalidRoleName(String),
 
     #[error("Invalid permission name: {0}")]
     InvalidPermissionName(String),
 
+    #[error("Role not found")]
+    RoleNotFound,
+
+    #[error("Role already exists")]
+    RoleAlreadyExists,
+
+    #[error("Invalid role hierarchy: {0}")]
+    InvalidRoleHierarchy(String),
+
+    #[error("Permission not found")]
+    PermissionNotFound,
+
+    #[error("Permission already exists")]
+    PermissionAlreadyExists,
+
+    #[error("Policy not found")]
+    PolicyNotFound,
+
+    #[error("Invalid policy: {0}")]
+    InvalidPolicy(String),
+
+    #[error("MFA is already enabled")]
+    MfaAlreadyEnabled,
+
+    #[error("MFA is not enabled")]
+    MfaNotEnabled,
+
+    #[error("Invalid MFA code")]
+    InvalidMfaCode,
+
+    #[error("Invalid or expired MFA challenge")]
+    InvalidMfaChallenge,
+
+    #[error("Too many failed login attempts, try again in {0} seconds")]
+    AccountLocked(i64),
+
+    #[error("Email already verified")]
+    EmailAlreadyVerified,
+
+    #[error("Event publishing error: {0}")]
+    EventPublishing(String),
+
+    #[error("OAuth client not found")]
+    OAuthClientNotFound,
+
+    #[error("Client authentication failed")]
+    InvalidClient,
+
+    #[error("Invalid grant: {0}")]
+    InvalidGrant(String),
+
+    #[error("Invalid scope: {0}")]
+    InvalidScope(String),
+
+    #[error("Client not allowed: {0}")]
+    UnauthorizedClient(String),
+
+    #[error("Unsupported grant type: {0}")]
+    UnsupportedGrantType(String),
+
+    #[error("Invalid OAuth request: {0}")]
+    InvalidOAuthRequest(String),
+
+    #[error("Unsupported response type: {0}")]
+    UnsupportedResponseType(String),
+
+    #[error("OAuth consent not found")]
+    OAuthConsentNotFound,
+
+    #[error("Identity provider not found")]
+    IdentityProviderNotFound,
+
+    #[error("Identity provider unavailable: {0}")]
+    IdentityProviderUnavailable(String),
+
+    #[error("External login failed: {0}")]
+    FederatedLoginFailed(String),
+
+    #[error("Service account not found")]
+    ServiceAccountNotFound,
+
+    #[error("Service account already exists")]
+    ServiceAccountAlreadyExists,
+
+    #[error("API key not found")]
+    ApiKeyNotFound,
+
+    #[error("Invalid or expired API key")]
+    InvalidApiKey,
+
+    #[error("Passkey not found")]
+    PasskeyNotFound,
+
+    #[error("Passkey already registered")]
+    PasskeyAlreadyRegistered,
+
+    #[error("Organization not found")]
+    OrganizationNotFound,
+
+    #[error("Organization already exists")]
+    OrganizationAlreadyExists,
+
+    #[error("User is not a member of the organization")]
+    OrganizationMemberNotFound,
+
+    #[error("User is already a member of the organization")]
+    OrganizationMemberAlreadyExists,
+
+    #[error("Invitation not found")]
+    InvitationNotFound,
+
+    #[error("Invalid invitation: {0}")]
+    InvalidInvitation(String),
+
+    #[error("Invalid or expired WebAuthn challenge")]
+    InvalidWebAuthnChallenge,
+
+    #[error("Invalid attestation: {0}")]
+    InvalidAttestation(String),
+
+    #[error("Invalid assertion: {0}")]
+    InvalidAssertion(String),
+
     #[error("Unauthorized")]
     Unauthorized,
 
     #[error("Forbidden")]
     Forbidden,
 
+    #[error("Repository error: {0}")]
+    Repository(String),
+
     #[error("Domain validation error: {0}")]
     ValidationError(String),
 }
@@ -0,0 +1,66 @@
+use serde::Serialize;
+
+/// Events other services react to, e.g. notification-service delivering the
+/// links in verification, reset and login emails.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AccountEvent {
+    EmailVerificationRequested {
+        user_id: String,
+        email: String,
+        link: String,
+        expires_at: String,
+    },
+    PasswordResetRequested {
+        user_id: String,
+        email: String,
+        link: String,
+        expires_at: String,
+    },
+    MagicLinkRequested {
+        user_id: String,
+        email: String,
+        link: String,
+        expires_at: String,
+    },
+    PasswordChanged {
+        user_id: String,
+        email: String,
+    },
+    /// `email` may not belong to any account yet.
+    OrganizationInvitationSent {
+        organization_id: String,
+        organization_name: String,
+        email: String,
+        link: String,
+        expires_at: String,
+    },
+    /// Audit record of a lockout. `user_id` is absent when the attempts were
+    /// made against an email with no account.
+    AccountLocked {
+        user_id: Option<String>,
+        email: String,
+        ip: String,
+        failed_attempts: u32,
+        locked_until: String,
+    },
+    IpLocked {
+        ip: String,
+        failed_attempts: u32,
+        locked_until: String,
+    },
+}
+
+impl AccountEvent {
+    pub fn event_type(&self) -> &str {
+        match self {
+            AccountEvent::EmailVerificationRequested { .. } => "email_verification_requested",
+            AccountEvent::PasswordResetRequested { .. } => "password_reset_requested",
+            AccountEvent::MagicLinkRequested { .. } => "magic_link_requested",
+            AccountEvent::PasswordChanged { .. } => "password_changed",
+            AccountEvent::OrganizationInvitationSent { .. } => "organization_invitation_sent",
+            AccountEvent::AccountLocked { .. } => "account_locked",
+            AccountEvent::IpLocked { .. } => "ip_locked",
+        }
+    }
+}
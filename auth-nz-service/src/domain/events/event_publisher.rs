@@ -0,0 +1,16 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use crate::domain::events::AccountEvent;
+use crate::domain::errors::DomainError;
+
+#[async_trait]
+pub trait EventPublisher: Send + Sync {
+    async fn publish(&self, event: &AccountEvent) -> Result<(), DomainError>;
+}
+
+#[async_trait]
+impl<P: EventPublisher> EventPublisher for Arc<P> {
+    async fn publish(&self, event: &AccountEvent) -> Result<(), DomainError> {
+        (**self).publish(event).await
+    }
+}
@@ -0,0 +1,5 @@
+pub mod account_event;
+pub mod event_publisher;
+
+pub use account_event::AccountEvent;
+pub use event_publisher::EventPublisher;
@@ -1,6 +1,6 @@
 pub mod entities;
+pub mod events;
 pub mod repositories;
 pub mod services;
 pub mod value_objects;
 pub mod errors;
-
@@ -0,0 +1,32 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use uuid::Uuid;
+use crate::domain::entities::account_token::AccountToken;
+use crate::domain::value_objects::{AccountTokenPurpose, UserId};
+use crate::domain::errors::DomainError;
+
+#[async_trait]
+pub trait AccountTokenRepository: Send + Sync {
+    async fn create(&self, token: &AccountToken) -> Result<(), DomainError>;
+    /// Marks the token consumed. Returns `false` if it was already consumed
+    /// or no token with that id was issued to the user for that purpose, so
+    /// a link cannot be used twice or redirected to another account.
+    async fn consume(&self, id: &Uuid, user_id: &UserId, purpose: AccountTokenPurpose) -> Result<bool, DomainError>;
+    /// Consumes every outstanding token of that purpose, e.g. when a newer one is issued.
+    async fn invalidate_for_user(&self, user_id: &UserId, purpose: AccountTokenPurpose) -> Result<(), DomainError>;
+}
+
+#[async_trait]
+impl<R: AccountTokenRepository> AccountTokenRepository for Arc<R> {
+    async fn create(&self, token: &AccountToken) -> Result<(), DomainError> {
+        (**self).create(token).await
+    }
+
+    async fn consume(&self, id: &Uuid, user_id: &UserId, purpose: AccountTokenPurpose) -> Result<bool, DomainError> {
+        (**self).consume(id, user_id, purpose).await
+    }
+
+    async fn invalidate_for_user(&self, user_id: &UserId, purpose: AccountTokenPurpose) -> Result<(), DomainError> {
+        (**self).invalidate_for_user(user_id, purpose).await
+    }
+}
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::domain::entities::api_key::ApiKey;
+use crate::domain::value_objects::{ApiKeyId, ServiceAccountId};
+use crate::domain::errors::DomainError;
+
+#[async_trait]
+pub trait ApiKeyRepository: Send + Sync {
+    async fn create(&self, api_key: &ApiKey) -> Result<(), DomainError>;
+    async fn find_by_id(&self, id: &ApiKeyId) -> Result<Option<ApiKey>, DomainError>;
+    async fn find_by_prefix(&self, prefix: &str) -> Result<Option<ApiKey>, DomainError>;
+    async fn find_by_service_account_id(&self, service_account_id: &ServiceAccountId) -> Result<Vec<ApiKey>, DomainError>;
+    /// Persists the key's expiry and revocation.
+    async fn update(&self, api_key: &ApiKey) -> Result<(), DomainError>;
+    /// Stores `successor` and the retired key it replaces in one transaction.
+    async fn replace(&self, retired: &ApiKey, successor: &ApiKey) -> Result<(), DomainError>;
+    async fn touch(&self, id: &ApiKeyId, used_at: DateTime<Utc>) -> Result<(), DomainError>;
+}
+
+#[async_trait]
+impl<R: ApiKeyRepository> ApiKeyRepository for Arc<R> {
+    async fn create(&self, api_key: &ApiKey) -> Result<(), DomainError> {
+        (**self).create(api_key).await
+    }
+
+    async fn find_by_id(&self, id: &ApiKeyId) -> Result<Option<ApiKey>, DomainError> {
+        (**self).find_by_id(id).await
+    }
+
+    async fn find_by_prefix(&self, prefix: &str) -> Result<Option<ApiKey>, DomainError> {
+        (**self).find_by_prefix(prefix).await
+    }
+
+    async fn find_by_service_account_id(&self, service_account_id: &ServiceAccountId) -> Result<Vec<ApiKey>, DomainError> {
+        (**self).find_by_service_account_id(service_account_id).await
+    }
+
+    async fn update(&self, api_key: &ApiKey) -> Result<(), DomainError> {
+        (**self).update(api_key).await
+    }
+
+    async fn replace(&self, retired: &ApiKey, successor: &ApiKey) -> Result<(), DomainError> {
+        (**self).replace(retired, successor).await
+    }
+
+    async fn touch(&self, id: &ApiKeyId, used_at: DateTime<Utc>) -> Result<(), DomainError> {
+        (**self).touch(id, used_at).await
+    }
+}
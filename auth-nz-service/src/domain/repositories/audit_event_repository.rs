@@ -0,0 +1,42 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::domain::entities::audit_event::{AuditEvent, NewAuditEvent};
+use crate::domain::value_objects::{AuditEventType, UserId};
+use crate::domain::errors::DomainError;
+
+/// Filters for reading the audit log. Results are ordered by sequence,
+/// oldest first unless `newest_first` is set.
+#[derive(Debug, Clone, Default)]
+pub struct AuditEventQuery {
+    pub user_id: Option<UserId>,
+    pub actor: Option<String>,
+    pub event_type: Option<AuditEventType>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub after_sequence: Option<i64>,
+    pub before_sequence: Option<i64>,
+    pub newest_first: bool,
+    pub limit: i64,
+}
+
+/// The append-only audit log. There is deliberately no way to change or
+/// remove a record once written.
+#[async_trait]
+pub trait AuditEventRepository: Send + Sync {
+    /// Seals the event onto the end of the chain and stores it. Appends are
+    /// serialized so that every record links to the one before it.
+    async fn append(&self, event: NewAuditEvent) -> Result<AuditEvent, DomainError>;
+    async fn find(&self, query: &AuditEventQuery) -> Result<Vec<AuditEvent>, DomainError>;
+}
+
+#[async_trait]
+impl<R: AuditEventRepository> AuditEventRepository for Arc<R> {
+    async fn append(&self, event: NewAuditEvent) -> Result<AuditEvent, DomainError> {
+        (**self).append(event).await
+    }
+
+    async fn find(&self, query: &AuditEventQuery) -> Result<Vec<AuditEvent>, DomainError> {
+        (**self).find(query).await
+    }
+}
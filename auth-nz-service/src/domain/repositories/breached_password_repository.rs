@@ -0,0 +1,20 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use crate::domain::errors::DomainError;
+
+/// Passwords known from data breaches, looked up by k-anonymity: callers
+/// send only the first 5 hex characters of the password's SHA-1 and match
+/// the returned suffixes themselves, as with the Pwned Passwords range API.
+#[async_trait]
+pub trait BreachedPasswordRepository: Send + Sync {
+    /// Uppercase 35 character SHA-1 suffixes under `prefix`, each with the
+    /// number of times it was seen in breaches.
+    async fn find_range(&self, prefix: &str) -> Result<Vec<(String, u64)>, DomainError>;
+}
+
+#[async_trait]
+impl<R: BreachedPasswordRepository> BreachedPasswordRepository for Arc<R> {
+    async fn find_range(&self, prefix: &str) -> Result<Vec<(String, u64)>, DomainError> {
+        (**self).find_range(prefix).await
+    }
+}
@@ -0,0 +1,47 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use crate::domain::entities::federated_identity::FederatedIdentity;
+use crate::domain::entities::federated_login::FederatedLogin;
+use crate::domain::value_objects::UserId;
+use crate::domain::errors::DomainError;
+
+/// Pending external logins and the provider accounts linked to users.
+#[async_trait]
+pub trait FederatedIdentityRepository: Send + Sync {
+    async fn create_login(&self, login: &FederatedLogin) -> Result<(), DomainError>;
+    /// Deletes the pending login and returns it. Returns `None` if it does not
+    /// exist or was already used.
+    async fn consume_login(&self, state_hash: &str) -> Result<Option<FederatedLogin>, DomainError>;
+    async fn find_identity(&self, provider: &str, subject: &str) -> Result<Option<FederatedIdentity>, DomainError>;
+    async fn find_identities_by_user_id(&self, user_id: &UserId) -> Result<Vec<FederatedIdentity>, DomainError>;
+    async fn create_identity(&self, identity: &FederatedIdentity) -> Result<(), DomainError>;
+    /// Updates the reported email and the last login time.
+    async fn record_login(&self, identity: &FederatedIdentity) -> Result<(), DomainError>;
+}
+
+#[async_trait]
+impl<R: FederatedIdentityRepository> FederatedIdentityRepository for Arc<R> {
+    async fn create_login(&self, login: &FederatedLogin) -> Result<(), DomainError> {
+        (**self).create_login(login).await
+    }
+
+    async fn consume_login(&self, state_hash: &str) -> Result<Option<FederatedLogin>, DomainError> {
+        (**self).consume_login(state_hash).await
+    }
+
+    async fn find_identity(&self, provider: &str, subject: &str) -> Result<Option<FederatedIdentity>, DomainError> {
+        (**self).find_identity(provider, subject).await
+    }
+
+    async fn find_identities_by_user_id(&self, user_id: &UserId) -> Result<Vec<FederatedIdentity>, DomainError> {
+        (**self).find_identities_by_user_id(user_id).await
+    }
+
+    async fn create_identity(&self, identity: &FederatedIdentity) -> Result<(), DomainError> {
+        (**self).create_identity(identity).await
+    }
+
+    async fn record_login(&self, identity: &FederatedIdentity) -> Result<(), DomainError> {
+        (**self).record_login(identity).await
+    }
+}
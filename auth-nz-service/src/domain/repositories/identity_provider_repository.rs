@@ -0,0 +1,53 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use jsonwebtoken::jwk::JwkSet;
+use crate::domain::entities::identity_provider::{IdentityProvider, IdentityProviderMetadata};
+use crate::domain::errors::DomainError;
+
+/// Configured external OpenID Connect providers and the back-channel calls
+/// made to them.
+#[async_trait]
+pub trait IdentityProviderRepository: Send + Sync {
+    async fn find_by_name(&self, name: &str) -> Result<Option<IdentityProvider>, DomainError>;
+    async fn find_all(&self) -> Result<Vec<IdentityProvider>, DomainError>;
+    /// The provider's discovery document.
+    async fn metadata(&self, provider: &IdentityProvider) -> Result<IdentityProviderMetadata, DomainError>;
+    /// The provider's signing keys. `refresh` skips any cached copy, for when
+    /// a token names a key that is not known yet.
+    async fn signing_keys(&self, provider: &IdentityProvider, refresh: bool) -> Result<JwkSet, DomainError>;
+    /// Exchanges an authorization code and returns the raw ID token.
+    async fn exchange_code(
+        &self,
+        provider: &IdentityProvider,
+        code: &str,
+        code_verifier: &str,
+    ) -> Result<String, DomainError>;
+}
+
+#[async_trait]
+impl<R: IdentityProviderRepository> IdentityProviderRepository for Arc<R> {
+    async fn find_by_name(&self, name: &str) -> Result<Option<IdentityProvider>, DomainError> {
+        (**self).find_by_name(name).await
+    }
+
+    async fn find_all(&self) -> Result<Vec<IdentityProvider>, DomainError> {
+        (**self).find_all().await
+    }
+
+    async fn metadata(&self, provider: &IdentityProvider) -> Result<IdentityProviderMetadata, DomainError> {
+        (**self).metadata(provider).await
+    }
+
+    async fn signing_keys(&self, provider: &IdentityProvider, refresh: bool) -> Result<JwkSet, DomainError> {
+        (**self).signing_keys(provider, refresh).await
+    }
+
+    async fn exchange_code(
+        &self,
+        provider: &IdentityProvider,
+        code: &str,
+        code_verifier: &str,
+    ) -> Result<String, DomainError> {
+        (**self).exchange_code(provider, code, code_verifier).await
+    }
+}
@@ -0,0 +1,41 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use crate::domain::errors::DomainError;
+
+/// Failed login counters and lockouts, keyed by whatever is being throttled
+/// (an account, a client IP). Entries expire on their own.
+#[async_trait]
+pub trait LoginAttemptRepository: Send + Sync {
+    /// Counts a failure and returns the number of failures in the current
+    /// window. The window starts with the first failure and lasts `window`.
+    async fn record_failure(&self, key: &str, window: Duration) -> Result<u32, DomainError>;
+    async fn failure_count(&self, key: &str) -> Result<u32, DomainError>;
+    async fn clear_failures(&self, key: &str) -> Result<(), DomainError>;
+    async fn lock(&self, key: &str, until: DateTime<Utc>) -> Result<(), DomainError>;
+    /// End of the active lockout, if any.
+    async fn locked_until(&self, key: &str) -> Result<Option<DateTime<Utc>>, DomainError>;
+}
+
+#[async_trait]
+impl<R: LoginAttemptRepository> LoginAttemptRepository for Arc<R> {
+    async fn record_failure(&self, key: &str, window: Duration) -> Result<u32, DomainError> {
+        (**self).record_failure(key, window).await
+    }
+
+    async fn failure_count(&self, key: &str) -> Result<u32, DomainError> {
+        (**self).failure_count(key).await
+    }
+
+    async fn clear_failures(&self, key: &str) -> Result<(), DomainError> {
+        (**self).clear_failures(key).await
+    }
+
+    async fn lock(&self, key: &str, until: DateTime<Utc>) -> Result<(), DomainError> {
+        (**self).lock(key, until).await
+    }
+
+    async fn locked_until(&self, key: &str) -> Result<Option<DateTime<Utc>>, DomainError> {
+        (**self).locked_until(key).await
+    }
+}
@@ -0,0 +1,69 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use crate::domain::entities::mfa_challenge::MfaChallenge;
+use crate::domain::entities::mfa_factor::MfaFactor;
+use crate::domain::value_objects::UserId;
+use crate::domain::errors::DomainError;
+
+#[async_trait]
+pub trait MfaRepository: Send + Sync {
+    async fn find_factor(&self, user_id: &UserId) -> Result<Option<MfaFactor>, DomainError>;
+    /// Inserts the factor or replaces the user's existing one.
+    async fn save_factor(&self, factor: &MfaFactor) -> Result<(), DomainError>;
+    /// Removes the factor together with its recovery codes.
+    async fn delete_factor(&self, user_id: &UserId) -> Result<(), DomainError>;
+    /// Records `step` as used. Returns `false` if it is not newer than the last
+    /// used step, which means the code was already accepted once.
+    async fn record_used_step(&self, user_id: &UserId, step: i64) -> Result<bool, DomainError>;
+    async fn replace_recovery_codes(&self, user_id: &UserId, code_hashes: &[String]) -> Result<(), DomainError>;
+    /// Marks an unused recovery code as used. Returns `false` if there was none.
+    async fn consume_recovery_code(&self, user_id: &UserId, code_hash: &str) -> Result<bool, DomainError>;
+    async fn create_challenge(&self, challenge: &MfaChallenge) -> Result<(), DomainError>;
+    async fn find_challenge(&self, token_hash: &str) -> Result<Option<MfaChallenge>, DomainError>;
+    async fn record_challenge_failure(&self, token_hash: &str) -> Result<(), DomainError>;
+    /// Deletes the challenge. Returns `false` if it was already consumed.
+    async fn consume_challenge(&self, token_hash: &str) -> Result<bool, DomainError>;
+}
+
+#[async_trait]
+impl<R: MfaRepository> MfaRepository for Arc<R> {
+    async fn find_factor(&self, user_id: &UserId) -> Result<Option<MfaFactor>, DomainError> {
+        (**self).find_factor(user_id).await
+    }
+
+    async fn save_factor(&self, factor: &MfaFactor) -> Result<(), DomainError> {
+        (**self).save_factor(factor).await
+    }
+
+    async fn delete_factor(&self, user_id: &UserId) -> Result<(), DomainError> {
+        (**self).delete_factor(user_id).await
+    }
+
+    async fn record_used_step(&self, user_id: &UserId, step: i64) -> Result<bool, DomainError> {
+        (**self).record_used_step(user_id, step).await
+    }
+
+    async fn replace_recovery_codes(&self, user_id: &UserId, code_hashes: &[String]) -> Result<(), DomainError> {
+        (**self).replace_recovery_codes(user_id, code_hashes).await
+    }
+
+    async fn consume_recovery_code(&self, user_id: &UserId, code_hash: &str) -> Result<bool, DomainError> {
+        (**self).consume_recovery_code(user_id, code_hash).await
+    }
+
+    async fn create_challenge(&self, challenge: &MfaChallenge) -> Result<(), DomainError> {
+        (**self).create_challenge(challenge).await
+    }
+
+    async fn find_challenge(&self, token_hash: &str) -> Result<Option<MfaChallenge>, DomainError> {
+        (**self).find_challenge(token_hash).await
+    }
+
+    async fn record_challenge_failure(&self, token_hash: &str) -> Result<(), DomainError> {
+        (**self).record_challenge_failure(token_hash).await
+    }
+
+    async fn consume_challenge(&self, token_hash: &str) -> Result<bool, DomainError> {
+        (**self).consume_challenge(token_hash).await
+    }
+}
@@ -2,9 +2,43 @@ pub mod user_repository;
 pub mod session_repository;
 pub mod role_repository;
 pub mod permission_repository;
+pub mod policy_repository;
+pub mod mfa_repository;
+pub mod account_token_repository;
+pub mod login_attempt_repository;
+pub mod revocation_list_repository;
+pub mod oauth_client_repository;
+pub mod oauth_grant_repository;
+pub mod identity_provider_repository;
+pub mod federated_identity_repository;
+pub mod audit_event_repository;
+pub mod service_account_repository;
+pub mod api_key_repository;
+pub mod passkey_repository;
+pub mod password_history_repository;
+pub mod breached_password_repository;
+pub mod organization_repository;
+pub mod organization_invitation_repository;
 
 pub use user_repository::UserRepository;
 pub use session_repository::SessionRepository;
 pub use role_repository::RoleRepository;
 pub use permission_repository::PermissionRepository;
+pub use policy_repository::PolicyRepository;
+pub use mfa_repository::MfaRepository;
+pub use account_token_repository::AccountTokenRepository;
+pub use login_attempt_repository::LoginAttemptRepository;
+pub use revocation_list_repository::RevocationListRepository;
+pub use oauth_client_repository::OAuthClientRepository;
+pub use oauth_grant_repository::OAuthGrantRepository;
+pub use identity_provider_repository::IdentityProviderRepository;
+pub use federated_identity_repository::FederatedIdentityRepository;
+pub use service_account_repository::ServiceAccountRepository;
+pub use api_key_repository::ApiKeyRepository;
+pub use passkey_repository::PasskeyRepository;
+pub use password_history_repository::PasswordHistoryRepository;
+pub use breached_password_repository::BreachedPasswordRepository;
+pub use organization_repository::OrganizationRepository;
+pub use organization_invitation_repository::OrganizationInvitationRepository;
 
+pub use audit_event_repository::{AuditEventQuery, AuditEventRepository};
@@ -0,0 +1,31 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use crate::domain::entities::oauth_client::OAuthClient;
+use crate::domain::errors::DomainError;
+
+#[async_trait]
+pub trait OAuthClientRepository: Send + Sync {
+    async fn create(&self, client: &OAuthClient) -> Result<(), DomainError>;
+    async fn find_by_id(&self, id: &str) -> Result<Option<OAuthClient>, DomainError>;
+    async fn find_all(&self) -> Result<Vec<OAuthClient>, DomainError>;
+    async fn delete(&self, id: &str) -> Result<(), DomainError>;
+}
+
+#[async_trait]
+impl<R: OAuthClientRepository> OAuthClientRepository for Arc<R> {
+    async fn create(&self, client: &OAuthClient) -> Result<(), DomainError> {
+        (**self).create(client).await
+    }
+
+    async fn find_by_id(&self, id: &str) -> Result<Option<OAuthClient>, DomainError> {
+        (**self).find_by_id(id).await
+    }
+
+    async fn find_all(&self) -> Result<Vec<OAuthClient>, DomainError> {
+        (**self).find_all().await
+    }
+
+    async fn delete(&self, id: &str) -> Result<(), DomainError> {
+        (**self).delete(id).await
+    }
+}
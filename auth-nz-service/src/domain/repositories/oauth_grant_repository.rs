@@ -0,0 +1,48 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use crate::domain::entities::oauth_authorization_code::OAuthAuthorizationCode;
+use crate::domain::entities::oauth_consent::OAuthConsent;
+use crate::domain::value_objects::UserId;
+use crate::domain::errors::DomainError;
+
+/// Authorization codes and the consents users gave to clients.
+#[async_trait]
+pub trait OAuthGrantRepository: Send + Sync {
+    async fn create_code(&self, code: &OAuthAuthorizationCode) -> Result<(), DomainError>;
+    /// Marks an unexpired code consumed and returns it. Returns `None` if it
+    /// does not exist, expired or was already exchanged.
+    async fn consume_code(&self, code_hash: &str) -> Result<Option<OAuthAuthorizationCode>, DomainError>;
+    async fn find_consent(&self, user_id: &UserId, client_id: &str) -> Result<Option<OAuthConsent>, DomainError>;
+    /// Inserts the consent or replaces the user's existing one for that client.
+    async fn save_consent(&self, consent: &OAuthConsent) -> Result<(), DomainError>;
+    async fn find_consents_by_user_id(&self, user_id: &UserId) -> Result<Vec<OAuthConsent>, DomainError>;
+    /// Returns `false` if there was no consent to delete.
+    async fn delete_consent(&self, user_id: &UserId, client_id: &str) -> Result<bool, DomainError>;
+}
+
+#[async_trait]
+impl<R: OAuthGrantRepository> OAuthGrantRepository for Arc<R> {
+    async fn create_code(&self, code: &OAuthAuthorizationCode) -> Result<(), DomainError> {
+        (**self).create_code(code).await
+    }
+
+    async fn consume_code(&self, code_hash: &str) -> Result<Option<OAuthAuthorizationCode>, DomainError> {
+        (**self).consume_code(code_hash).await
+    }
+
+    async fn find_consent(&self, user_id: &UserId, client_id: &str) -> Result<Option<OAuthConsent>, DomainError> {
+        (**self).find_consent(user_id, client_id).await
+    }
+
+    async fn save_consent(&self, consent: &OAuthConsent) -> Result<(), DomainError> {
+        (**self).save_consent(consent).await
+    }
+
+    async fn find_consents_by_user_id(&self, user_id: &UserId) -> Result<Vec<OAuthConsent>, DomainError> {
+        (**self).find_consents_by_user_id(user_id).await
+    }
+
+    async fn delete_consent(&self, user_id: &UserId, client_id: &str) -> Result<bool, DomainError> {
+        (**self).delete_consent(user_id, client_id).await
+    }
+}
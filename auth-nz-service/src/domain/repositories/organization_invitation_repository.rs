@@ -0,0 +1,45 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use crate::domain::entities::organization_invitation::OrganizationInvitation;
+use crate::domain::value_objects::{InvitationId, OrganizationId};
+use crate::domain::errors::DomainError;
+
+#[async_trait]
+pub trait OrganizationInvitationRepository: Send + Sync {
+    async fn create(&self, invitation: &OrganizationInvitation) -> Result<(), DomainError>;
+    async fn find_by_id(&self, id: &InvitationId) -> Result<Option<OrganizationInvitation>, DomainError>;
+    async fn find_by_token_hash(&self, token_hash: &str) -> Result<Option<OrganizationInvitation>, DomainError>;
+    /// Invitations of the organization, newest first.
+    async fn find_by_organization_id(&self, organization_id: &OrganizationId) -> Result<Vec<OrganizationInvitation>, DomainError>;
+    /// Marks the invitation accepted. Returns `false` if it already was, so
+    /// that of two concurrent attempts only one succeeds.
+    async fn accept(&self, id: &InvitationId) -> Result<bool, DomainError>;
+    async fn delete(&self, id: &InvitationId) -> Result<(), DomainError>;
+}
+
+#[async_trait]
+impl<R: OrganizationInvitationRepository> OrganizationInvitationRepository for Arc<R> {
+    async fn create(&self, invitation: &OrganizationInvitation) -> Result<(), DomainError> {
+        (**self).create(invitation).await
+    }
+
+    async fn find_by_id(&self, id: &InvitationId) -> Result<Option<OrganizationInvitation>, DomainError> {
+        (**self).find_by_id(id).await
+    }
+
+    async fn find_by_token_hash(&self, token_hash: &str) -> Result<Option<OrganizationInvitation>, DomainError> {
+        (**self).find_by_token_hash(token_hash).await
+    }
+
+    async fn find_by_organization_id(&self, organization_id: &OrganizationId) -> Result<Vec<OrganizationInvitation>, DomainError> {
+        (**self).find_by_organization_id(organization_id).await
+    }
+
+    async fn accept(&self, id: &InvitationId) -> Result<bool, DomainError> {
+        (**self).accept(id).await
+    }
+
+    async fn delete(&self, id: &InvitationId) -> Result<(), DomainError> {
+        (**self).delete(id).await
+    }
+}
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use crate::domain::entities::organization::{Organization, OrganizationMember};
+use crate::domain::value_objects::{OrganizationId, UserId};
+use crate::domain::errors::DomainError;
+
+#[async_trait]
+pub trait OrganizationRepository: Send + Sync {
+    /// Stores the organization together with the membership of its founder.
+    async fn create(&self, organization: &Organization, founder: &OrganizationMember) -> Result<(), DomainError>;
+    async fn find_by_id(&self, id: &OrganizationId) -> Result<Option<Organization>, DomainError>;
+    /// Organizations the user is a member of.
+    async fn find_by_user_id(&self, user_id: &UserId) -> Result<Vec<Organization>, DomainError>;
+    async fn add_member(&self, member: &OrganizationMember) -> Result<(), DomainError>;
+    async fn find_member(&self, organization_id: &OrganizationId, user_id: &UserId) -> Result<Option<OrganizationMember>, DomainError>;
+    async fn find_members(&self, organization_id: &OrganizationId) -> Result<Vec<OrganizationMember>, DomainError>;
+    /// Removes the membership together with the roles held through it.
+    async fn remove_member(&self, organization_id: &OrganizationId, user_id: &UserId) -> Result<(), DomainError>;
+}
+
+#[async_trait]
+impl<R: OrganizationRepository> OrganizationRepository for Arc<R> {
+    async fn create(&self, organization: &Organization, founder: &OrganizationMember) -> Result<(), DomainError> {
+        (**self).create(organization, founder).await
+    }
+
+    async fn find_by_id(&self, id: &OrganizationId) -> Result<Option<Organization>, DomainError> {
+        (**self).find_by_id(id).await
+    }
+
+    async fn find_by_user_id(&self, user_id: &UserId) -> Result<Vec<Organization>, DomainError> {
+        (**self).find_by_user_id(user_id).await
+    }
+
+    async fn add_member(&self, member: &OrganizationMember) -> Result<(), DomainError> {
+        (**self).add_member(member).await
+    }
+
+    async fn find_member(&self, organization_id: &OrganizationId, user_id: &UserId) -> Result<Option<OrganizationMember>, DomainError> {
+        (**self).find_member(organization_id, user_id).await
+    }
+
+    async fn find_members(&self, organization_id: &OrganizationId) -> Result<Vec<OrganizationMember>, DomainError> {
+        (**self).find_members(organization_id).await
+    }
+
+    async fn remove_member(&self, organization_id: &OrganizationId, user_id: &UserId) -> Result<(), DomainError> {
+        (**self).remove_member(organization_id, user_id).await
+    }
+}
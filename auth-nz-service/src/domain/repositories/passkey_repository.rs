@@ -0,0 +1,58 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use crate::domain::entities::passkey::Passkey;
+use crate::domain::entities::webauthn_challenge::WebAuthnChallenge;
+use crate::domain::value_objects::{PasskeyId, UserId};
+use crate::domain::errors::DomainError;
+
+#[async_trait]
+pub trait PasskeyRepository: Send + Sync {
+    async fn create(&self, passkey: &Passkey) -> Result<(), DomainError>;
+    async fn find_by_id(&self, id: &PasskeyId) -> Result<Option<Passkey>, DomainError>;
+    async fn find_by_credential_id(&self, credential_id: &[u8]) -> Result<Option<Passkey>, DomainError>;
+    async fn find_by_user_id(&self, user_id: &UserId) -> Result<Vec<Passkey>, DomainError>;
+    /// Stores the counter of an accepted assertion. Returns `false` if another
+    /// assertion already moved the counter to or past `sign_count`.
+    async fn record_use(&self, id: &PasskeyId, sign_count: u32, used_at: DateTime<Utc>) -> Result<bool, DomainError>;
+    async fn delete(&self, id: &PasskeyId) -> Result<(), DomainError>;
+    async fn create_challenge(&self, challenge: &WebAuthnChallenge) -> Result<(), DomainError>;
+    /// Deletes and returns the challenge, so each one is answered at most once.
+    async fn consume_challenge(&self, id: &Uuid) -> Result<Option<WebAuthnChallenge>, DomainError>;
+}
+
+#[async_trait]
+impl<R: PasskeyRepository> PasskeyRepository for Arc<R> {
+    async fn create(&self, passkey: &Passkey) -> Result<(), DomainError> {
+        (**self).create(passkey).await
+    }
+
+    async fn find_by_id(&self, id: &PasskeyId) -> Result<Option<Passkey>, DomainError> {
+        (**self).find_by_id(id).await
+    }
+
+    async fn find_by_credential_id(&self, credential_id: &[u8]) -> Result<Option<Passkey>, DomainError> {
+        (**self).find_by_credential_id(credential_id).await
+    }
+
+    async fn find_by_user_id(&self, user_id: &UserId) -> Result<Vec<Passkey>, DomainError> {
+        (**self).find_by_user_id(user_id).await
+    }
+
+    async fn record_use(&self, id: &PasskeyId, sign_count: u32, used_at: DateTime<Utc>) -> Result<bool, DomainError> {
+        (**self).record_use(id, sign_count, used_at).await
+    }
+
+    async fn delete(&self, id: &PasskeyId) -> Result<(), DomainError> {
+        (**self).delete(id).await
+    }
+
+    async fn create_challenge(&self, challenge: &WebAuthnChallenge) -> Result<(), DomainError> {
+        (**self).create_challenge(challenge).await
+    }
+
+    async fn consume_challenge(&self, id: &Uuid) -> Result<Option<WebAuthnChallenge>, DomainError> {
+        (**self).consume_challenge(id).await
+    }
+}
@@ -0,0 +1,24 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use crate::domain::value_objects::{PasswordHash, UserId};
+use crate::domain::errors::DomainError;
+
+/// Hashes of the passwords a user had before the current one.
+#[async_trait]
+pub trait PasswordHistoryRepository: Send + Sync {
+    /// Records a replaced password, keeping only the `keep` most recent.
+    async fn add(&self, user_id: &UserId, password_hash: &PasswordHash, keep: usize) -> Result<(), DomainError>;
+    /// Most recent first.
+    async fn find_recent(&self, user_id: &UserId, limit: usize) -> Result<Vec<PasswordHash>, DomainError>;
+}
+
+#[async_trait]
+impl<R: PasswordHistoryRepository> PasswordHistoryRepository for Arc<R> {
+    async fn add(&self, user_id: &UserId, password_hash: &PasswordHash, keep: usize) -> Result<(), DomainError> {
+        (**self).add(user_id, password_hash, keep).await
+    }
+
+    async fn find_recent(&self, user_id: &UserId, limit: usize) -> Result<Vec<PasswordHash>, DomainError> {
+        (**self).find_recent(user_id, limit).await
+    }
+}
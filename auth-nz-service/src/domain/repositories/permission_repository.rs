@@ -1,7 +1,7 @@
 use std::sync::Arc;
 use async_trait::async_trait;
 use crate::domain::entities::permission::Permission;
-use crate::domain::value_objects::{PermissionId, PermissionName};
+use crate::domain::value_objects::{PermissionId, PermissionName, RoleId};
 use crate::domain::errors::DomainError;
 
 #[async_trait]
@@ -10,6 +10,10 @@ pub trait PermissionRepository: Send + Sync {
     async fn find_by_id(&self, id: &PermissionId) -> Result<Option<Permission>, DomainError>;
     async fn find_by_name(&self, name: &PermissionName) -> Result<Option<Permission>, DomainError>;
     async fn find_all(&self) -> Result<Vec<Permission>, DomainError>;
+    /// Permissions granted directly to the role, without inherited ones.
+    async fn find_by_role_id(&self, role_id: &RoleId) -> Result<Vec<Permission>, DomainError>;
+    async fn grant_to_role(&self, role_id: &RoleId, permission_id: &PermissionId) -> Result<(), DomainError>;
+    async fn revoke_from_role(&self, role_id: &RoleId, permission_id: &PermissionId) -> Result<(), DomainError>;
 }
 
 #[async_trait]
@@ -29,5 +33,16 @@ impl<R: PermissionRepository> PermissionRepository for Arc<R> {
     async fn find_all(&self) -> Result<Vec<Permission>, DomainError> {
         (**self).find_all().await
     }
-}
 
+    async fn find_by_role_id(&self, role_id: &RoleId) -> Result<Vec<Permission>, DomainError> {
+        (**self).find_by_role_id(role_id).await
+    }
+
+    async fn grant_to_role(&self, role_id: &RoleId, permission_id: &PermissionId) -> Result<(), DomainError> {
+        (**self).grant_to_role(role_id, permission_id).await
+    }
+
+    async fn revoke_from_role(&self, role_id: &RoleId, permission_id: &PermissionId) -> Result<(), DomainError> {
+        (**self).revoke_from_role(role_id, permission_id).await
+    }
+}
@@ -0,0 +1,16 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use crate::domain::entities::policy::Policy;
+use crate::domain::errors::DomainError;
+
+#[async_trait]
+pub trait PolicyRepository: Send + Sync {
+    async fn find_all(&self) -> Result<Vec<Policy>, DomainError>;
+}
+
+#[async_trait]
+impl<R: PolicyRepository> PolicyRepository for Arc<R> {
+    async fn find_all(&self) -> Result<Vec<Policy>, DomainError> {
+        (**self).find_all().await
+    }
+}
@@ -0,0 +1,23 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::domain::errors::DomainError;
+
+/// Revoked token and session ids. An entry only has to outlive the access
+/// tokens it refers to, after which they fail verification on their own.
+#[async_trait]
+pub trait RevocationListRepository: Send + Sync {
+    async fn add(&self, key: &str, until: DateTime<Utc>) -> Result<(), DomainError>;
+    async fn contains(&self, key: &str) -> Result<bool, DomainError>;
+}
+
+#[async_trait]
+impl<R: RevocationListRepository> RevocationListRepository for Arc<R> {
+    async fn add(&self, key: &str, until: DateTime<Utc>) -> Result<(), DomainError> {
+        (**self).add(key, until).await
+    }
+
+    async fn contains(&self, key: &str) -> Result<bool, DomainError> {
+        (**self).contains(key).await
+    }
+}
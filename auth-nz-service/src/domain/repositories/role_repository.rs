@@ -1,7 +1,7 @@
 use std::sync::Arc;
 use async_trait::async_trait;
 use crate::domain::entities::role::Role;
-use crate::domain::value_objects::{RoleId, RoleName};
+use crate::domain::value_objects::{OrganizationId, RoleId, RoleName, ServiceAccountId, UserId};
 use crate::domain::errors::DomainError;
 
 #[async_trait]
@@ -10,6 +10,22 @@ pub trait RoleRepository: Send + Sync {
     async fn find_by_id(&self, id: &RoleId) -> Result<Option<Role>, DomainError>;
     async fn find_by_name(&self, name: &RoleName) -> Result<Option<Role>, DomainError>;
     async fn find_all(&self) -> Result<Vec<Role>, DomainError>;
+    async fn update(&self, role: &Role) -> Result<(), DomainError>;
+    async fn delete(&self, id: &RoleId) -> Result<(), DomainError>;
+    /// Roles assigned directly to the user, without inherited ones.
+    async fn find_by_user_id(&self, user_id: &UserId) -> Result<Vec<Role>, DomainError>;
+    async fn assign_to_user(&self, user_id: &UserId, role_id: &RoleId) -> Result<(), DomainError>;
+    async fn unassign_from_user(&self, user_id: &UserId, role_id: &RoleId) -> Result<(), DomainError>;
+    /// Roles assigned directly to the service account, without inherited ones.
+    async fn find_by_service_account_id(&self, service_account_id: &ServiceAccountId) -> Result<Vec<Role>, DomainError>;
+    async fn assign_to_service_account(&self, service_account_id: &ServiceAccountId, role_id: &RoleId) -> Result<(), DomainError>;
+    async fn unassign_from_service_account(&self, service_account_id: &ServiceAccountId, role_id: &RoleId) -> Result<(), DomainError>;
+    /// Roles the user holds directly in the organization, without inherited
+    /// ones. Empty for users who are not members.
+    async fn find_by_member(&self, organization_id: &OrganizationId, user_id: &UserId) -> Result<Vec<Role>, DomainError>;
+    /// Assigns a role within the organization; the user must be a member.
+    async fn assign_to_member(&self, organization_id: &OrganizationId, user_id: &UserId, role_id: &RoleId) -> Result<(), DomainError>;
+    async fn unassign_from_member(&self, organization_id: &OrganizationId, user_id: &UserId, role_id: &RoleId) -> Result<(), DomainError>;
 }
 
 #[async_trait]
@@ -29,5 +45,48 @@ impl<R: RoleRepository> RoleRepository for Arc<R> {
     async fn find_all(&self) -> Result<Vec<Role>, DomainError> {
         (**self).find_all().await
     }
-}
 
+    async fn update(&self, role: &Role) -> Result<(), DomainError> {
+        (**self).update(role).await
+    }
+
+    async fn delete(&self, id: &RoleId) -> Result<(), DomainError> {
+        (**self).delete(id).await
+    }
+
+    async fn find_by_user_id(&self, user_id: &UserId) -> Result<Vec<Role>, DomainError> {
+        (**self).find_by_user_id(user_id).await
+    }
+
+    async fn assign_to_user(&self, user_id: &UserId, role_id: &RoleId) -> Result<(), DomainError> {
+        (**self).assign_to_user(user_id, role_id).await
+    }
+
+    async fn unassign_from_user(&self, user_id: &UserId, role_id: &RoleId) -> Result<(), DomainError> {
+        (**self).unassign_from_user(user_id, role_id).await
+    }
+
+    async fn find_by_service_account_id(&self, service_account_id: &ServiceAccountId) -> Result<Vec<Role>, DomainError> {
+        (**self).find_by_service_account_id(service_account_id).await
+    }
+
+    async fn assign_to_service_account(&self, service_account_id: &ServiceAccountId, role_id: &RoleId) -> Result<(), DomainError> {
+        (**self).assign_to_service_account(service_account_id, role_id).await
+    }
+
+    async fn unassign_from_service_account(&self, service_account_id: &ServiceAccountId, role_id: &RoleId) -> Result<(), DomainError> {
+        (**self).unassign_from_service_account(service_account_id, role_id).await
+    }
+
+    async fn find_by_member(&self, organization_id: &OrganizationId, user_id: &UserId) -> Result<Vec<Role>, DomainError> {
+        (**self).find_by_member(organization_id, user_id).await
+    }
+
+    async fn assign_to_member(&self, organization_id: &OrganizationId, user_id: &UserId, role_id: &RoleId) -> Result<(), DomainError> {
+        (**self).assign_to_member(organization_id, user_id, role_id).await
+    }
+
+    async fn unassign_from_member(&self, organization_id: &OrganizationId, user_id: &UserId, role_id: &RoleId) -> Result<(), DomainError> {
+        (**self).unassign_from_member(organization_id, user_id, role_id).await
+    }
+}
@@ -0,0 +1,33 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use crate::domain::entities::service_account::ServiceAccount;
+use crate::domain::value_objects::ServiceAccountId;
+use crate::domain::errors::DomainError;
+
+#[async_trait]
+pub trait ServiceAccountRepository: Send + Sync {
+    async fn create(&self, service_account: &ServiceAccount) -> Result<(), DomainError>;
+    async fn find_by_id(&self, id: &ServiceAccountId) -> Result<Option<ServiceAccount>, DomainError>;
+    async fn find_all(&self) -> Result<Vec<ServiceAccount>, DomainError>;
+    /// Deletes the account together with its role assignments and API keys.
+    async fn delete(&self, id: &ServiceAccountId) -> Result<(), DomainError>;
+}
+
+#[async_trait]
+impl<R: ServiceAccountRepository> ServiceAccountRepository for Arc<R> {
+    async fn create(&self, service_account: &ServiceAccount) -> Result<(), DomainError> {
+        (**self).create(service_account).await
+    }
+
+    async fn find_by_id(&self, id: &ServiceAccountId) -> Result<Option<ServiceAccount>, DomainError> {
+        (**self).find_by_id(id).await
+    }
+
+    async fn find_all(&self) -> Result<Vec<ServiceAccount>, DomainError> {
+        (**self).find_all().await
+    }
+
+    async fn delete(&self, id: &ServiceAccountId) -> Result<(), DomainError> {
+        (**self).delete(id).await
+    }
+}
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 use async_trait::async_trait;
 use crate::domain::entities::session::Session;
-use crate::domain::value_objects::{SessionId, Token, UserId};
+use crate::domain::value_objects::{RefreshTokenHash, SessionId, Token, UserId};
 use crate::domain::errors::DomainError;
 
 #[async_trait]
@@ -9,7 +9,19 @@ pub trait SessionRepository: Send + Sync {
     async fn create(&self, session: &Session) -> Result<(), DomainError>;
     async fn find_by_id(&self, id: &SessionId) -> Result<Option<Session>, DomainError>;
     async fn find_by_token(&self, token: &Token) -> Result<Option<Session>, DomainError>;
+    async fn find_by_refresh_token_hash(&self, hash: &RefreshTokenHash) -> Result<Option<Session>, DomainError>;
+    #[allow(dead_code)]
     async fn find_by_user_id(&self, user_id: &UserId) -> Result<Vec<Session>, DomainError>;
+    /// The live session of each of the user's refresh families, most recently seen first.
+    async fn find_active_by_user_id(&self, user_id: &UserId) -> Result<Vec<Session>, DomainError>;
+    /// Marks the session revoked. Returns `false` if it was already revoked,
+    /// so concurrent refreshes cannot both consume the same refresh token.
+    async fn revoke(&self, id: &SessionId) -> Result<bool, DomainError>;
+    async fn revoke_family(&self, family_id: &SessionId) -> Result<(), DomainError>;
+    /// Revokes every session of the user, except those in `keep_family` if given.
+    async fn revoke_by_user_id(&self, user_id: &UserId, keep_family: Option<&SessionId>) -> Result<(), DomainError>;
+    /// Revokes the sessions the user opened through an OAuth client.
+    async fn revoke_by_client_id(&self, user_id: &UserId, client_id: &str) -> Result<(), DomainError>;
     async fn delete(&self, id: &SessionId) -> Result<(), DomainError>;
     async fn delete_by_user_id(&self, user_id: &UserId) -> Result<(), DomainError>;
 }
@@ -28,10 +40,34 @@ impl<R: SessionRepository> SessionRepository for Arc<R> {
         (**self).find_by_token(token).await
     }
 
+    async fn find_by_refresh_token_hash(&self, hash: &RefreshTokenHash) -> Result<Option<Session>, DomainError> {
+        (**self).find_by_refresh_token_hash(hash).await
+    }
+
     async fn find_by_user_id(&self, user_id: &UserId) -> Result<Vec<Session>, DomainError> {
         (**self).find_by_user_id(user_id).await
     }
 
+    async fn find_active_by_user_id(&self, user_id: &UserId) -> Result<Vec<Session>, DomainError> {
+        (**self).find_active_by_user_id(user_id).await
+    }
+
+    async fn revoke(&self, id: &SessionId) -> Result<bool, DomainError> {
+        (**self).revoke(id).await
+    }
+
+    async fn revoke_family(&self, family_id: &SessionId) -> Result<(), DomainError> {
+        (**self).revoke_family(family_id).await
+    }
+
+    async fn revoke_by_user_id(&self, user_id: &UserId, keep_family: Option<&SessionId>) -> Result<(), DomainError> {
+        (**self).revoke_by_user_id(user_id, keep_family).await
+    }
+
+    async fn revoke_by_client_id(&self, user_id: &UserId, client_id: &str) -> Result<(), DomainError> {
+        (**self).revoke_by_client_id(user_id, client_id).await
+    }
+
     async fn delete(&self, id: &SessionId) -> Result<(), DomainError> {
         (**self).delete(id).await
     }
@@ -40,4 +76,3 @@ impl<R: SessionRepository> SessionRepository for Arc<R> {
         (**self).delete_by_user_id(user_id).await
     }
 }
-
@@ -0,0 +1,85 @@
+use std::sync::Arc;
+use crate::domain::entities::organization::Organization;
+use crate::domain::entities::organization_invitation::OrganizationInvitation;
+use crate::domain::entities::user::User;
+use crate::domain::events::{AccountEvent, EventPublisher};
+use crate::domain::repositories::AccountTokenRepository;
+use crate::domain::services::AccountTokenService;
+use crate::domain::value_objects::{AccountTokenPurpose, InvitationToken};
+use crate::domain::errors::DomainError;
+
+/// Issues account tokens and publishes the events carrying the links built
+/// from them. Delivery is up to whoever consumes the events.
+pub struct AccountNotifier<R: AccountTokenRepository, P: EventPublisher> {
+    account_token_service: Arc<AccountTokenService<R>>,
+    event_publisher: Arc<P>,
+    link_base_url: String,
+}
+
+impl<R: AccountTokenRepository, P: EventPublisher> AccountNotifier<R, P> {
+    pub fn new(account_token_service: Arc<AccountTokenService<R>>, event_publisher: Arc<P>, link_base_url: String) -> Self {
+        Self {
+            account_token_service,
+            event_publisher,
+            link_base_url: link_base_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    pub async fn send_email_verification(&self, user: &User) -> Result<(), DomainError> {
+        let (token, expires_at) = self.account_token_service
+            .issue(&user.id, AccountTokenPurpose::EmailVerification).await?;
+
+        self.event_publisher.publish(&AccountEvent::EmailVerificationRequested {
+            user_id: user.id.as_uuid().to_string(),
+            email: user.email.as_str().to_string(),
+            link: format!("{}/verify-email?token={}", self.link_base_url, token),
+            expires_at: expires_at.to_rfc3339(),
+        }).await
+    }
+
+    pub async fn send_password_reset(&self, user: &User) -> Result<(), DomainError> {
+        let (token, expires_at) = self.account_token_service
+            .issue(&user.id, AccountTokenPurpose::PasswordReset).await?;
+
+        self.event_publisher.publish(&AccountEvent::PasswordResetRequested {
+            user_id: user.id.as_uuid().to_string(),
+            email: user.email.as_str().to_string(),
+            link: format!("{}/reset-password?token={}", self.link_base_url, token),
+            expires_at: expires_at.to_rfc3339(),
+        }).await
+    }
+
+    pub async fn send_magic_link(&self, user: &User) -> Result<(), DomainError> {
+        let (token, expires_at) = self.account_token_service
+            .issue(&user.id, AccountTokenPurpose::MagicLink).await?;
+
+        self.event_publisher.publish(&AccountEvent::MagicLinkRequested {
+            user_id: user.id.as_uuid().to_string(),
+            email: user.email.as_str().to_string(),
+            link: format!("{}/login/magic-link?token={}", self.link_base_url, token),
+            expires_at: expires_at.to_rfc3339(),
+        }).await
+    }
+
+    pub async fn send_organization_invitation(
+        &self,
+        organization: &Organization,
+        invitation: &OrganizationInvitation,
+        token: &InvitationToken,
+    ) -> Result<(), DomainError> {
+        self.event_publisher.publish(&AccountEvent::OrganizationInvitationSent {
+            organization_id: organization.id.as_uuid().to_string(),
+            organization_name: organization.name.clone(),
+            email: invitation.email.as_str().to_string(),
+            link: format!("{}/organizations/invitations/accept?token={}", self.link_base_url, token.as_str()),
+            expires_at: invitation.expires_at.to_rfc3339(),
+        }).await
+    }
+
+    pub async fn password_changed(&self, user: &User) -> Result<(), DomainError> {
+        self.event_publisher.publish(&AccountEvent::PasswordChanged {
+            user_id: user.id.as_uuid().to_string(),
+            email: user.email.as_str().to_string(),
+        }).await
+    }
+}
@@ -0,0 +1,109 @@
+use std::sync::Arc;
+use chrono::{DateTime, Duration, Utc};
+use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use serde::{Deserialize, Serialize};
+use crate::domain::entities::account_token::AccountToken;
+use crate::domain::repositories::AccountTokenRepository;
+use crate::domain::value_objects::{AccountTokenPurpose, UserId};
+use crate::domain::errors::DomainError;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct AccountTokenClaims {
+    sub: String,
+    jti: String,
+    purpose: AccountTokenPurpose,
+    iat: i64,
+    exp: i64,
+}
+
+/// Issues and redeems the tokens embedded in email verification, password
+/// reset and magic login links. Tokens are HMAC-signed so forged or expired ones are rejected
+/// without a lookup, and recorded so each one can be redeemed only once.
+pub struct AccountTokenService<R: AccountTokenRepository> {
+    account_token_repository: Arc<R>,
+    encoding_key: EncodingKey,
+    decoding_key: DecodingKey,
+    email_verification_ttl: Duration,
+    password_reset_ttl: Duration,
+    magic_link_ttl: Duration,
+}
+
+impl<R: AccountTokenRepository> AccountTokenService<R> {
+    pub fn new(
+        account_token_repository: Arc<R>,
+        secret: &str,
+        email_verification_ttl: Duration,
+        password_reset_ttl: Duration,
+        magic_link_ttl: Duration,
+    ) -> Self {
+        Self {
+            account_token_repository,
+            encoding_key: EncodingKey::from_secret(secret.as_bytes()),
+            decoding_key: DecodingKey::from_secret(secret.as_bytes()),
+            email_verification_ttl,
+            password_reset_ttl,
+            magic_link_ttl,
+        }
+    }
+
+    /// Issues a token, invalidating earlier ones of the same purpose so only
+    /// the most recent link works.
+    pub async fn issue(&self, user_id: &UserId, purpose: AccountTokenPurpose) -> Result<(String, DateTime<Utc>), DomainError> {
+        let ttl = match purpose {
+            AccountTokenPurpose::EmailVerification => self.email_verification_ttl,
+            AccountTokenPurpose::PasswordReset => self.password_reset_ttl,
+            AccountTokenPurpose::MagicLink => self.magic_link_ttl,
+        };
+        let record = AccountToken::new(*user_id, purpose, Utc::now() + ttl);
+
+        let claims = AccountTokenClaims {
+            sub: user_id.as_uuid().to_string(),
+            jti: record.id.to_string(),
+            purpose,
+            iat: record.created_at.timestamp(),
+            exp: record.expires_at.timestamp(),
+        };
+        let token = encode(&Header::new(Algorithm::HS256), &claims, &self.encoding_key)
+            .map_err(|e| DomainError::TokenSigning(e.to_string()))?;
+
+        self.account_token_repository.invalidate_for_user(user_id, purpose).await?;
+        self.account_token_repository.create(&record).await?;
+
+        Ok((token, record.expires_at))
+    }
+
+    /// Verifies and consumes a token, returning the user it was issued to.
+    pub async fn redeem(&self, token: &str, purpose: AccountTokenPurpose) -> Result<UserId, DomainError> {
+        let (id, user_id) = self.verify(token, purpose)?;
+
+        if !self.account_token_repository.consume(&id, &user_id, purpose).await? {
+            return Err(DomainError::InvalidToken);
+        }
+
+        Ok(user_id)
+    }
+
+    /// Returns the user a token was issued to without consuming it, so a
+    /// request rejected for other reasons can be retried with the same link.
+    /// Says nothing about whether the token was already used.
+    pub fn peek(&self, token: &str, purpose: AccountTokenPurpose) -> Result<UserId, DomainError> {
+        self.verify(token, purpose).map(|(_, user_id)| user_id)
+    }
+
+    fn verify(&self, token: &str, purpose: AccountTokenPurpose) -> Result<(uuid::Uuid, UserId), DomainError> {
+        let mut validation = Validation::new(Algorithm::HS256);
+        validation.leeway = 0;
+
+        let claims = decode::<AccountTokenClaims>(token, &self.decoding_key, &validation)
+            .map_err(|_| DomainError::InvalidToken)?
+            .claims;
+        if claims.purpose != purpose {
+            return Err(DomainError::InvalidToken);
+        }
+
+        let id = uuid::Uuid::parse_str(&claims.jti).map_err(|_| DomainError::InvalidToken)?;
+        let user_id = uuid::Uuid::parse_str(&claims.sub).map_err(|_| DomainError::InvalidToken)?;
+
+        Ok((id, UserId::from_uuid(user_id)))
+    }
+}
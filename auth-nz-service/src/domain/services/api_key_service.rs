@@ -0,0 +1,131 @@
+use std::sync::Arc;
+use chrono::{DateTime, Duration, Utc};
+use crate::domain::entities::api_key::ApiKey;
+use crate::domain::entities::service_account::ServiceAccount;
+use crate::domain::repositories::{ApiKeyRepository, PermissionRepository, RoleRepository, ServiceAccountRepository};
+use crate::domain::services::{AccessGrants, AuthorizationService};
+use crate::domain::value_objects::{ApiKeyToken, Scopes};
+use crate::domain::errors::DomainError;
+
+/// A request authenticated with an API key.
+#[derive(Debug, Clone)]
+pub struct AuthenticatedApiKey {
+    pub key: ApiKey,
+    pub service_account: ServiceAccount,
+    /// The account's current permissions limited to the key's scopes.
+    pub grants: AccessGrants,
+}
+
+/// Issues, rotates and authenticates service account API keys.
+pub struct ApiKeyService<SAR, KR, RR, PR>
+where
+    SAR: ServiceAccountRepository,
+    KR: ApiKeyRepository,
+    RR: RoleRepository,
+    PR: PermissionRepository,
+{
+    service_account_repository: Arc<SAR>,
+    api_key_repository: Arc<KR>,
+    authorization_service: Arc<AuthorizationService<RR, PR>>,
+}
+
+impl<SAR, KR, RR, PR> ApiKeyService<SAR, KR, RR, PR>
+where
+    SAR: ServiceAccountRepository,
+    KR: ApiKeyRepository,
+    RR: RoleRepository,
+    PR: PermissionRepository,
+{
+    pub fn new(
+        service_account_repository: Arc<SAR>,
+        api_key_repository: Arc<KR>,
+        authorization_service: Arc<AuthorizationService<RR, PR>>,
+    ) -> Self {
+        Self {
+            service_account_repository,
+            api_key_repository,
+            authorization_service,
+        }
+    }
+
+    /// Creates a key for the account. The returned token is the only copy of the key.
+    pub async fn issue(
+        &self,
+        service_account: &ServiceAccount,
+        name: String,
+        scopes: Scopes,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Result<(ApiKey, ApiKeyToken), DomainError> {
+        let (key, token) = generate(service_account, name, scopes, expires_at);
+        self.api_key_repository.create(&key).await?;
+        Ok((key, token))
+    }
+
+    /// Replaces the key with a new one that has the same name, scopes and
+    /// lifetime. The old key keeps working for `grace`, or stops right away
+    /// when there is none.
+    pub async fn rotate(&self, key: &ApiKey, grace: Duration) -> Result<(ApiKey, ApiKeyToken), DomainError> {
+        if !key.is_active() {
+            return Err(DomainError::ValidationError("API key is revoked or expired".to_string()));
+        }
+        let service_account = self.service_account_repository.find_by_id(&key.service_account_id).await?
+            .ok_or(DomainError::ServiceAccountNotFound)?;
+
+        let expires_at = key.expires_at.map(|expires_at| Utc::now() + (expires_at - key.created_at));
+        let (mut successor, token) = generate(&service_account, key.name.clone(), key.scopes.clone(), expires_at);
+        successor.rotated_from = Some(key.id);
+
+        let mut retired = key.clone();
+        retired.retire(grace);
+        self.api_key_repository.replace(&retired, &successor).await?;
+
+        Ok((successor, token))
+    }
+
+    pub async fn revoke(&self, key: &ApiKey) -> Result<(), DomainError> {
+        let mut key = key.clone();
+        key.revoke();
+        self.api_key_repository.update(&key).await
+    }
+
+    /// Checks the presented key and resolves what it may do. Unknown, revoked
+    /// and expired keys are all rejected the same way.
+    pub async fn authenticate(&self, token: &ApiKeyToken) -> Result<AuthenticatedApiKey, DomainError> {
+        let prefix = token.prefix().ok_or(DomainError::InvalidApiKey)?;
+        let key = self.api_key_repository.find_by_prefix(prefix).await?
+            .ok_or(DomainError::InvalidApiKey)?;
+        if !key.verify_hash(&token.hash()) || !key.is_active() {
+            return Err(DomainError::InvalidApiKey);
+        }
+
+        let service_account = self.service_account_repository.find_by_id(&key.service_account_id).await?
+            .ok_or(DomainError::InvalidApiKey)?;
+
+        if key.needs_touch() {
+            // Usage tracking is informational; a failed write must not reject the call
+            if let Err(e) = self.api_key_repository.touch(&key.id, Utc::now()).await {
+                tracing::warn!("Failed to record use of API key {}: {}", key.id.as_uuid(), e);
+            }
+        }
+
+        let grants = self.authorization_service
+            .service_account_grants(&service_account.id).await?
+            .restricted_to(&key.scopes);
+
+        Ok(AuthenticatedApiKey { key, service_account, grants })
+    }
+}
+
+fn generate(
+    service_account: &ServiceAccount,
+    name: String,
+    scopes: Scopes,
+    expires_at: Option<DateTime<Utc>>,
+) -> (ApiKey, ApiKeyToken) {
+    let token = ApiKeyToken::generate();
+    let prefix = token.prefix()
+        .expect("generated API keys are well-formed")
+        .to_string();
+    let key = ApiKey::new(service_account.id, name, prefix, token.hash(), scopes, expires_at);
+    (key, token)
+}
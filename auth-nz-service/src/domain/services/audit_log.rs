@@ -0,0 +1,167 @@
+use std::sync::Arc;
+use crate::domain::entities::audit_event::{AuditEvent, NewAuditEvent, GENESIS_HASH};
+use crate::domain::repositories::{AuditEventQuery, AuditEventRepository};
+use crate::domain::errors::DomainError;
+
+const VERIFY_BATCH_SIZE: i64 = 500;
+
+/// Result of walking the audit chain from its first record.
+#[derive(Debug, Clone)]
+pub struct ChainVerification {
+    pub valid: bool,
+    pub events_checked: u64,
+    /// The first record that was altered or does not follow its predecessor.
+    pub first_invalid_sequence: Option<i64>,
+    /// Last record of the intact part of the chain. The chain cannot reveal
+    /// records cut off its end; comparing against a head kept elsewhere can.
+    pub head_sequence: i64,
+    pub head_hash: String,
+}
+
+/// Records security-relevant actions in the hash-chained audit log.
+pub struct AuditLog<R: AuditEventRepository> {
+    repository: Arc<R>,
+}
+
+impl<R: AuditEventRepository> AuditLog<R> {
+    pub fn new(repository: Arc<R>) -> Self {
+        Self { repository }
+    }
+
+    /// Writes the event. A failed write is logged rather than failing the
+    /// action it describes; sequences are assigned on write, so it leaves no gap.
+    pub async fn record(&self, event: NewAuditEvent) {
+        let event_type = event.event_type;
+        if let Err(e) = self.repository.append(event).await {
+            tracing::error!("Failed to write {} audit event: {}", event_type.as_str(), e);
+        }
+    }
+
+    pub async fn search(&self, query: &AuditEventQuery) -> Result<Vec<AuditEvent>, DomainError> {
+        self.repository.find(query).await
+    }
+
+    /// Recomputes every hash and checks each record links to the one before it.
+    pub async fn verify(&self) -> Result<ChainVerification, DomainError> {
+        let mut head_sequence = 0;
+        let mut head_hash = GENESIS_HASH.to_string();
+        let mut events_checked = 0;
+
+        loop {
+            let batch = self.repository.find(&AuditEventQuery {
+                after_sequence: Some(head_sequence),
+                limit: VERIFY_BATCH_SIZE,
+                ..Default::default()
+            }).await?;
+
+            for event in &batch {
+                events_checked += 1;
+                if !event.follows(head_sequence, &head_hash) {
+                    tracing::error!("Audit chain is broken at sequence {}", event.sequence);
+                    return Ok(ChainVerification {
+                        valid: false,
+                        events_checked,
+                        first_invalid_sequence: Some(event.sequence),
+                        head_sequence,
+                        head_hash,
+                    });
+                }
+                head_sequence = event.sequence;
+                head_hash = event.hash.clone();
+            }
+
+            if (batch.len() as i64) < VERIFY_BATCH_SIZE {
+                return Ok(ChainVerification {
+                    valid: true,
+                    events_checked,
+                    first_invalid_sequence: None,
+                    head_sequence,
+                    head_hash,
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+    use async_trait::async_trait;
+    use serde_json::json;
+    use super::*;
+    use crate::domain::value_objects::{AuditEventType, UserId};
+
+    /// Unlike the real store, lets tests tamper with written records.
+    #[derive(Default)]
+    struct InMemoryAuditEvents {
+        events: Mutex<Vec<AuditEvent>>,
+    }
+
+    #[async_trait]
+    impl AuditEventRepository for InMemoryAuditEvents {
+        async fn append(&self, event: NewAuditEvent) -> Result<AuditEvent, DomainError> {
+            let mut events = self.events.lock().unwrap();
+            let (sequence, prev_hash) = events.last()
+                .map(|last| (last.sequence + 1, last.hash.clone()))
+                .unwrap_or((1, GENESIS_HASH.to_string()));
+            let sealed = AuditEvent::seal(event, sequence, prev_hash);
+            events.push(sealed.clone());
+            Ok(sealed)
+        }
+
+        async fn find(&self, query: &AuditEventQuery) -> Result<Vec<AuditEvent>, DomainError> {
+            Ok(self.events.lock().unwrap().iter()
+                .filter(|event| query.after_sequence.is_none_or(|after| event.sequence > after))
+                .take(query.limit as usize)
+                .cloned()
+                .collect())
+        }
+    }
+
+    async fn log_with_events(count: usize) -> (Arc<InMemoryAuditEvents>, AuditLog<InMemoryAuditEvents>) {
+        let repository = Arc::new(InMemoryAuditEvents::default());
+        let log = AuditLog::new(repository.clone());
+        for _ in 0..count {
+            log.record(NewAuditEvent::new(AuditEventType::LoginSucceeded)
+                .with_user(&UserId::new())
+                .with_details(json!({"method": "password"})))
+                .await;
+        }
+        (repository, log)
+    }
+
+    #[tokio::test]
+    async fn intact_chain_verifies() {
+        let (_, log) = log_with_events(3).await;
+
+        let verification = log.verify().await.unwrap();
+
+        assert!(verification.valid);
+        assert_eq!(verification.events_checked, 3);
+        assert_eq!(verification.head_sequence, 3);
+    }
+
+    #[tokio::test]
+    async fn altered_payload_breaks_the_chain_at_that_record() {
+        let (repository, log) = log_with_events(3).await;
+        repository.events.lock().unwrap()[1].details = json!({"method": "passkey"});
+
+        let verification = log.verify().await.unwrap();
+
+        assert!(!verification.valid);
+        assert_eq!(verification.first_invalid_sequence, Some(2));
+        assert_eq!(verification.head_sequence, 1);
+    }
+
+    #[tokio::test]
+    async fn deleted_record_breaks_the_chain_at_its_successor() {
+        let (repository, log) = log_with_events(3).await;
+        repository.events.lock().unwrap().remove(1);
+
+        let verification = log.verify().await.unwrap();
+
+        assert!(!verification.valid);
+        assert_eq!(verification.first_invalid_sequence, Some(3));
+        assert_eq!(verification.head_sequence, 1);
+    }
+}
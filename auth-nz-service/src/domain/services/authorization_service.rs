@@ -0,0 +1,229 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+use serde::Serialize;
+use crate::domain::entities::permission::Permission;
+use crate::domain::entities::role::Role;
+use crate::domain::repositories::{RoleRepository, PermissionRepository};
+use crate::domain::value_objects::{OrganizationId, Scopes, ServiceAccountId, UserId};
+use crate::domain::errors::DomainError;
+
+/// Role names and `resource:action` permissions embedded in access tokens.
+#[derive(Debug, Clone, Default)]
+pub struct AccessGrants {
+    pub roles: Vec<String>,
+    pub permissions: Vec<String>,
+    /// The organization the roles are held in; `None` for roles held outside
+    /// of any organization.
+    pub organization_id: Option<OrganizationId>,
+}
+
+impl AccessGrants {
+    /// The grants of a token issued to an OAuth client: each requested scope
+    /// the user's permissions cover, and no roles, since a role would let
+    /// downstream checks reach past the scopes.
+    pub fn restricted_to(&self, scopes: &Scopes) -> AccessGrants {
+        let permissions = scopes.iter()
+            .filter(|scope| self.permissions.iter().any(|permission| covers(permission, scope)))
+            .map(str::to_string)
+            .collect();
+
+        AccessGrants {
+            roles: Vec::new(),
+            permissions,
+            organization_id: self.organization_id,
+        }
+    }
+
+    /// Whether any permission covers `action` on `resource`.
+    pub fn allows(&self, resource: &str, action: &str) -> bool {
+        let scope = format!("{}:{}", resource, action);
+        self.permissions.iter().any(|permission| covers(permission, &scope))
+    }
+}
+
+fn covers(permission: &str, scope: &str) -> bool {
+    match (permission.split_once(':'), scope.split_once(':')) {
+        (Some((resource, action)), Some((scope_resource, scope_action))) => {
+            (resource == "*" || resource == scope_resource) && (action == "*" || action == scope_action)
+        }
+        _ => false,
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AuthorizationDecision {
+    pub allowed: bool,
+    pub reason: String,
+}
+
+impl AuthorizationDecision {
+    pub fn allow(reason: String) -> Self {
+        Self { allowed: true, reason }
+    }
+
+    pub fn deny(reason: String) -> Self {
+        Self { allowed: false, reason }
+    }
+}
+
+pub struct AuthorizationService<RR: RoleRepository, PR: PermissionRepository> {
+    role_repository: Arc<RR>,
+    permission_repository: Arc<PR>,
+}
+
+impl<RR: RoleRepository, PR: PermissionRepository> AuthorizationService<RR, PR> {
+    pub fn new(role_repository: Arc<RR>, permission_repository: Arc<PR>) -> Self {
+        Self {
+            role_repository,
+            permission_repository,
+        }
+    }
+
+    /// Roles assigned to the user followed by every role they inherit from.
+    pub async fn effective_roles(&self, user_id: &UserId) -> Result<Vec<Role>, DomainError> {
+        let assigned = self.role_repository.find_by_user_id(user_id).await?;
+        self.with_inherited(assigned).await
+    }
+
+    #[allow(dead_code)]
+    pub async fn effective_permissions(&self, user_id: &UserId) -> Result<Vec<Permission>, DomainError> {
+        let roles = self.effective_roles(user_id).await?;
+        self.permissions_of(&roles).await
+    }
+
+    pub async fn grants(&self, user_id: &UserId) -> Result<AccessGrants, DomainError> {
+        let roles = self.effective_roles(user_id).await?;
+        self.grants_of(&roles).await
+    }
+
+    /// Roles the user holds in the organization followed by every role they
+    /// inherit from. Roles held outside the organization do not count.
+    pub async fn organization_roles(&self, user_id: &UserId, organization_id: &OrganizationId) -> Result<Vec<Role>, DomainError> {
+        let assigned = self.role_repository.find_by_member(organization_id, user_id).await?;
+        self.with_inherited(assigned).await
+    }
+
+    /// Grants for a token scoped to the organization. Membership is not
+    /// checked here; a non-member simply has no grants.
+    pub async fn organization_grants(&self, user_id: &UserId, organization_id: &OrganizationId) -> Result<AccessGrants, DomainError> {
+        let roles = self.organization_roles(user_id, organization_id).await?;
+        Ok(AccessGrants {
+            organization_id: Some(*organization_id),
+            ..self.grants_of(&roles).await?
+        })
+    }
+
+    /// Roles assigned to the service account followed by every role they inherit from.
+    pub async fn service_account_roles(&self, service_account_id: &ServiceAccountId) -> Result<Vec<Role>, DomainError> {
+        let assigned = self.role_repository.find_by_service_account_id(service_account_id).await?;
+        self.with_inherited(assigned).await
+    }
+
+    pub async fn service_account_grants(&self, service_account_id: &ServiceAccountId) -> Result<AccessGrants, DomainError> {
+        let roles = self.service_account_roles(service_account_id).await?;
+        self.grants_of(&roles).await
+    }
+
+    async fn with_inherited(&self, assigned: Vec<Role>) -> Result<Vec<Role>, DomainError> {
+        let mut roles = Vec::new();
+        let mut seen = HashSet::new();
+
+        for assigned in assigned {
+            let mut current = Some(assigned);
+            while let Some(role) = current {
+                if !seen.insert(role.id) {
+                    break;
+                }
+                current = match role.parent_id {
+                    Some(parent_id) => self.role_repository.find_by_id(&parent_id).await?,
+                    None => None,
+                };
+                roles.push(role);
+            }
+        }
+
+        Ok(roles)
+    }
+
+    async fn permissions_of(&self, roles: &[Role]) -> Result<Vec<Permission>, DomainError> {
+        let mut permissions = Vec::new();
+        let mut seen = HashSet::new();
+
+        for role in roles {
+            for permission in self.permission_repository.find_by_role_id(&role.id).await? {
+                if seen.insert(permission.id) {
+                    permissions.push(permission);
+                }
+            }
+        }
+
+        Ok(permissions)
+    }
+
+    async fn grants_of(&self, roles: &[Role]) -> Result<AccessGrants, DomainError> {
+        let permissions = self.permissions_of(roles).await?;
+
+        Ok(AccessGrants {
+            roles: roles.iter().map(|role| role.name.as_str().to_string()).collect(),
+            permissions: permissions.iter().map(Permission::scope).collect(),
+            organization_id: None,
+        })
+    }
+
+    pub async fn check(&self, user_id: &UserId, resource: &str, action: &str) -> Result<AuthorizationDecision, DomainError> {
+        let roles = self.effective_roles(user_id).await?;
+        if roles.is_empty() {
+            return Ok(AuthorizationDecision::deny("User has no roles".to_string()));
+        }
+        self.decide(&roles, resource, action).await
+    }
+
+    /// Like `check`, but against the roles the user holds in the organization.
+    pub async fn check_in_organization(
+        &self,
+        user_id: &UserId,
+        organization_id: &OrganizationId,
+        resource: &str,
+        action: &str,
+    ) -> Result<AuthorizationDecision, DomainError> {
+        let roles = self.organization_roles(user_id, organization_id).await?;
+        if roles.is_empty() {
+            return Ok(AuthorizationDecision::deny("User has no roles in the organization".to_string()));
+        }
+        self.decide(&roles, resource, action).await
+    }
+
+    /// Fails with `Forbidden` unless `check_in_organization` allows the action.
+    pub async fn require_in_organization(
+        &self,
+        user_id: &UserId,
+        organization_id: &OrganizationId,
+        resource: &str,
+        action: &str,
+    ) -> Result<(), DomainError> {
+        let decision = self.check_in_organization(user_id, organization_id, resource, action).await?;
+        if !decision.allowed {
+            return Err(DomainError::Forbidden);
+        }
+        Ok(())
+    }
+
+    async fn decide(&self, roles: &[Role], resource: &str, action: &str) -> Result<AuthorizationDecision, DomainError> {
+        for role in roles {
+            for permission in self.permission_repository.find_by_role_id(&role.id).await? {
+                if permission.matches(resource, action) {
+                    return Ok(AuthorizationDecision::allow(format!(
+                        "Permission '{}' granted by role '{}'",
+                        permission.name.as_str(),
+                        role.name.as_str()
+                    )));
+                }
+            }
+        }
+
+        Ok(AuthorizationDecision::deny(format!(
+            "No role grants '{}' on '{}'",
+            action, resource
+        )))
+    }
+}
@@ -0,0 +1,121 @@
+use std::sync::Arc;
+use chrono::{Duration, Utc};
+use crate::domain::events::{AccountEvent, EventPublisher};
+use crate::domain::repositories::LoginAttemptRepository;
+use crate::domain::value_objects::{Email, UserId};
+use crate::domain::errors::DomainError;
+
+#[derive(Debug, Clone)]
+pub struct LoginThrottlePolicy {
+    pub max_account_failures: u32,
+    pub max_ip_failures: u32,
+    pub failure_window: Duration,
+    pub lockout: Duration,
+    /// Delay before the second attempt; it doubles with every further failure.
+    pub base_delay: std::time::Duration,
+    pub max_delay: std::time::Duration,
+}
+
+/// Slows down and then locks out repeated failed logins, both per account and
+/// per client IP, so neither guessing one password nor spraying many accounts
+/// from one address is unbounded.
+pub struct LoginThrottle<R: LoginAttemptRepository, P: EventPublisher> {
+    attempts: Arc<R>,
+    event_publisher: Arc<P>,
+    policy: LoginThrottlePolicy,
+}
+
+impl<R: LoginAttemptRepository, P: EventPublisher> LoginThrottle<R, P> {
+    pub fn new(attempts: Arc<R>, event_publisher: Arc<P>, policy: LoginThrottlePolicy) -> Self {
+        Self {
+            attempts,
+            event_publisher,
+            policy,
+        }
+    }
+
+    /// Rejects the attempt while the account or IP is locked, and otherwise
+    /// waits out the delay earned by previous failures.
+    pub async fn check(&self, email: &Email, ip: &str) -> Result<(), DomainError> {
+        let account_key = Self::account_key(email);
+        let ip_key = Self::ip_key(ip);
+
+        for key in [&account_key, &ip_key] {
+            if let Some(until) = self.attempts.locked_until(key).await? {
+                let remaining = (until - Utc::now()).num_seconds().max(1);
+                return Err(DomainError::AccountLocked(remaining));
+            }
+        }
+
+        let failures = self.attempts.failure_count(&account_key).await?
+            .max(self.attempts.failure_count(&ip_key).await?);
+        let delay = self.delay_for(failures);
+        if !delay.is_zero() {
+            tokio::time::sleep(delay).await;
+        }
+        Ok(())
+    }
+
+    /// Counts a failed attempt and locks the account or IP once it reaches its limit.
+    pub async fn record_failure(&self, email: &Email, ip: &str, user_id: Option<&UserId>) -> Result<(), DomainError> {
+        let account_key = Self::account_key(email);
+        let account_failures = self.attempts.record_failure(&account_key, self.policy.failure_window).await?;
+        if account_failures >= self.policy.max_account_failures {
+            let until = Utc::now() + self.policy.lockout;
+            self.attempts.lock(&account_key, until).await?;
+            self.attempts.clear_failures(&account_key).await?;
+            tracing::warn!("Locked logins for {} after {} failed attempts", email.as_str(), account_failures);
+            self.audit(AccountEvent::AccountLocked {
+                user_id: user_id.map(|id| id.as_uuid().to_string()),
+                email: email.as_str().to_string(),
+                ip: ip.to_string(),
+                failed_attempts: account_failures,
+                locked_until: until.to_rfc3339(),
+            }).await;
+        }
+
+        let ip_key = Self::ip_key(ip);
+        let ip_failures = self.attempts.record_failure(&ip_key, self.policy.failure_window).await?;
+        if ip_failures >= self.policy.max_ip_failures {
+            let until = Utc::now() + self.policy.lockout;
+            self.attempts.lock(&ip_key, until).await?;
+            self.attempts.clear_failures(&ip_key).await?;
+            tracing::warn!("Locked logins from {} after {} failed attempts", ip, ip_failures);
+            self.audit(AccountEvent::IpLocked {
+                ip: ip.to_string(),
+                failed_attempts: ip_failures,
+                locked_until: until.to_rfc3339(),
+            }).await;
+        }
+
+        Ok(())
+    }
+
+    /// A successful login resets the account's counter. The IP counter keeps
+    /// running, otherwise one valid account would launder a spraying attack.
+    pub async fn record_success(&self, email: &Email) -> Result<(), DomainError> {
+        self.attempts.clear_failures(&Self::account_key(email)).await
+    }
+
+    fn delay_for(&self, failures: u32) -> std::time::Duration {
+        if failures == 0 {
+            return std::time::Duration::ZERO;
+        }
+        let factor = 2u32.saturating_pow(failures - 1);
+        self.policy.base_delay.saturating_mul(factor).min(self.policy.max_delay)
+    }
+
+    async fn audit(&self, event: AccountEvent) {
+        if let Err(e) = self.event_publisher.publish(&event).await {
+            tracing::warn!("Failed to publish {} event: {}", event.event_type(), e);
+        }
+    }
+
+    fn account_key(email: &Email) -> String {
+        format!("account:{}", email.as_str().trim().to_lowercase())
+    }
+
+    fn ip_key(ip: &str) -> String {
+        format!("ip:{}", ip)
+    }
+}
@@ -0,0 +1,249 @@
+use std::sync::Arc;
+use chrono::{DateTime, Duration, Utc};
+use crate::domain::entities::mfa_challenge::MfaChallenge;
+use crate::domain::entities::mfa_factor::MfaFactor;
+use crate::domain::repositories::MfaRepository;
+use crate::domain::services::TotpService;
+use crate::domain::value_objects::{MfaChallengeToken, RecoveryCode, TotpSecret, UserId};
+use crate::domain::errors::DomainError;
+
+const RECOVERY_CODE_COUNT: usize = 10;
+/// Wrong codes tolerated per challenge before the user has to log in again.
+const MAX_CHALLENGE_ATTEMPTS: i32 = 5;
+
+pub struct MfaService<R: MfaRepository> {
+    mfa_repository: Arc<R>,
+    totp_service: TotpService,
+    challenge_ttl: Duration,
+}
+
+impl<R: MfaRepository> MfaService<R> {
+    pub fn new(mfa_repository: Arc<R>, totp_service: TotpService, challenge_ttl: Duration) -> Self {
+        Self {
+            mfa_repository,
+            totp_service,
+            challenge_ttl,
+        }
+    }
+
+    pub async fn is_enabled(&self, user_id: &UserId) -> Result<bool, DomainError> {
+        Ok(self.mfa_repository.find_factor(user_id).await?
+            .is_some_and(|factor| factor.is_enabled()))
+    }
+
+    /// Creates a fresh, unconfirmed secret and returns it with its `otpauth://` URI.
+    /// Restarting enrolment replaces any previous unconfirmed secret.
+    pub async fn enroll(&self, user_id: &UserId, account: &str) -> Result<(TotpSecret, String), DomainError> {
+        if self.is_enabled(user_id).await? {
+            return Err(DomainError::MfaAlreadyEnabled);
+        }
+
+        let factor = MfaFactor::new(*user_id, TotpSecret::generate());
+        self.mfa_repository.save_factor(&factor).await?;
+
+        let uri = self.totp_service.provisioning_uri(&factor.secret, account);
+        Ok((factor.secret, uri))
+    }
+
+    /// Enables MFA once the user proves their authenticator produces valid codes,
+    /// and issues the recovery codes.
+    pub async fn confirm(&self, user_id: &UserId, code: &str) -> Result<Vec<RecoveryCode>, DomainError> {
+        let mut factor = self.mfa_repository.find_factor(user_id).await?
+            .ok_or(DomainError::MfaNotEnabled)?;
+        if factor.is_enabled() {
+            return Err(DomainError::MfaAlreadyEnabled);
+        }
+
+        let step = self.totp_service.verify(&factor.secret, code)
+            .ok_or(DomainError::InvalidMfaCode)?;
+        factor.confirm(step);
+        self.mfa_repository.save_factor(&factor).await?;
+
+        self.regenerate_recovery_codes(user_id).await
+    }
+
+    pub async fn regenerate_recovery_codes(&self, user_id: &UserId) -> Result<Vec<RecoveryCode>, DomainError> {
+        let codes: Vec<RecoveryCode> = (0..RECOVERY_CODE_COUNT).map(|_| RecoveryCode::generate()).collect();
+        let hashes: Vec<String> = codes.iter().map(RecoveryCode::hash).collect();
+        self.mfa_repository.replace_recovery_codes(user_id, &hashes).await?;
+        Ok(codes)
+    }
+
+    /// Accepts either a current TOTP code or an unused recovery code.
+    pub async fn verify(&self, user_id: &UserId, code: &str) -> Result<(), DomainError> {
+        let factor = self.mfa_repository.find_factor(user_id).await?
+            .filter(MfaFactor::is_enabled)
+            .ok_or(DomainError::MfaNotEnabled)?;
+
+        if let Some(step) = self.totp_service.verify(&factor.secret, code) {
+            return match self.mfa_repository.record_used_step(user_id, step).await? {
+                true => Ok(()),
+                false => Err(DomainError::InvalidMfaCode),
+            };
+        }
+
+        if self.mfa_repository.consume_recovery_code(user_id, &RecoveryCode::new(code.to_string()).hash()).await? {
+            tracing::info!("Recovery code used by user {}", user_id.as_uuid());
+            return Ok(());
+        }
+
+        Err(DomainError::InvalidMfaCode)
+    }
+
+    pub async fn disable(&self, user_id: &UserId) -> Result<(), DomainError> {
+        self.mfa_repository.delete_factor(user_id).await
+    }
+
+    pub async fn start_challenge(&self, user_id: &UserId) -> Result<(MfaChallengeToken, DateTime<Utc>), DomainError> {
+        let token = MfaChallengeToken::generate();
+        let challenge = MfaChallenge::new(token.hash(), *user_id, Utc::now() + self.challenge_ttl);
+        self.mfa_repository.create_challenge(&challenge).await?;
+
+        Ok((token, challenge.expires_at))
+    }
+
+    /// The user a pending login challenge belongs to, while it can still be answered.
+    pub async fn challenge_user(&self, token: &MfaChallengeToken) -> Result<Option<UserId>, DomainError> {
+        Ok(self.mfa_repository.find_challenge(&token.hash()).await?
+            .filter(|challenge| !challenge.is_expired() && challenge.attempts < MAX_CHALLENGE_ATTEMPTS)
+            .map(|challenge| challenge.user_id))
+    }
+
+    /// Checks the second factor for a pending login and consumes the challenge.
+    /// Returns the user the login belongs to.
+    pub async fn complete_challenge(&self, token: &MfaChallengeToken, code: &str) -> Result<UserId, DomainError> {
+        let token_hash = token.hash();
+        let challenge = self.mfa_repository.find_challenge(&token_hash).await?
+            .ok_or(DomainError::InvalidMfaChallenge)?;
+
+        if challenge.is_expired() || challenge.attempts >= MAX_CHALLENGE_ATTEMPTS {
+            self.mfa_repository.consume_challenge(&token_hash).await?;
+            return Err(DomainError::InvalidMfaChallenge);
+        }
+
+        if let Err(e) = self.verify(&challenge.user_id, code).await {
+            self.mfa_repository.record_challenge_failure(&token_hash).await?;
+            return Err(e);
+        }
+
+        if !self.mfa_repository.consume_challenge(&token_hash).await? {
+            return Err(DomainError::InvalidMfaChallenge);
+        }
+
+        Ok(challenge.user_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use async_trait::async_trait;
+    use super::*;
+
+    #[derive(Default)]
+    struct InMemoryMfa {
+        factors: Mutex<HashMap<UserId, MfaFactor>>,
+        recovery_codes: Mutex<Vec<(UserId, String)>>,
+        challenges: Mutex<Vec<MfaChallenge>>,
+    }
+
+    #[async_trait]
+    impl MfaRepository for InMemoryMfa {
+        async fn find_factor(&self, user_id: &UserId) -> Result<Option<MfaFactor>, DomainError> {
+            Ok(self.factors.lock().unwrap().get(user_id).cloned())
+        }
+
+        async fn save_factor(&self, factor: &MfaFactor) -> Result<(), DomainError> {
+            self.factors.lock().unwrap().insert(factor.user_id, factor.clone());
+            Ok(())
+        }
+
+        async fn delete_factor(&self, user_id: &UserId) -> Result<(), DomainError> {
+            self.factors.lock().unwrap().remove(user_id);
+            self.recovery_codes.lock().unwrap().retain(|(owner, _)| owner != user_id);
+            Ok(())
+        }
+
+        async fn record_used_step(&self, user_id: &UserId, step: i64) -> Result<bool, DomainError> {
+            let mut factors = self.factors.lock().unwrap();
+            let Some(factor) = factors.get_mut(user_id) else {
+                return Ok(false);
+            };
+            if factor.last_used_step.is_some_and(|last| last >= step) {
+                return Ok(false);
+            }
+            factor.last_used_step = Some(step);
+            Ok(true)
+        }
+
+        async fn replace_recovery_codes(&self, user_id: &UserId, code_hashes: &[String]) -> Result<(), DomainError> {
+            let mut codes = self.recovery_codes.lock().unwrap();
+            codes.retain(|(owner, _)| owner != user_id);
+            codes.extend(code_hashes.iter().map(|hash| (*user_id, hash.clone())));
+            Ok(())
+        }
+
+        async fn consume_recovery_code(&self, user_id: &UserId, code_hash: &str) -> Result<bool, DomainError> {
+            let mut codes = self.recovery_codes.lock().unwrap();
+            let before = codes.len();
+            codes.retain(|(owner, hash)| !(owner == user_id && hash == code_hash));
+            Ok(codes.len() != before)
+        }
+
+        async fn create_challenge(&self, challenge: &MfaChallenge) -> Result<(), DomainError> {
+            self.challenges.lock().unwrap().push(challenge.clone());
+            Ok(())
+        }
+
+        async fn find_challenge(&self, token_hash: &str) -> Result<Option<MfaChallenge>, DomainError> {
+            Ok(self.challenges.lock().unwrap().iter().find(|challenge| challenge.token_hash == token_hash).cloned())
+        }
+
+        async fn record_challenge_failure(&self, token_hash: &str) -> Result<(), DomainError> {
+            for challenge in self.challenges.lock().unwrap().iter_mut().filter(|challenge| challenge.token_hash == token_hash) {
+                challenge.attempts += 1;
+            }
+            Ok(())
+        }
+
+        async fn consume_challenge(&self, token_hash: &str) -> Result<bool, DomainError> {
+            let mut challenges = self.challenges.lock().unwrap();
+            let before = challenges.len();
+            challenges.retain(|challenge| challenge.token_hash != token_hash);
+            Ok(challenges.len() != before)
+        }
+    }
+
+    /// A service with MFA enabled for the returned user, no step used yet.
+    async fn enabled() -> (MfaService<InMemoryMfa>, UserId, TotpSecret) {
+        let repository = Arc::new(InMemoryMfa::default());
+        let user_id = UserId::new();
+        let secret = TotpSecret::generate();
+        let mut factor = MfaFactor::new(user_id, secret.clone());
+        factor.confirmed_at = Some(Utc::now());
+        repository.save_factor(&factor).await.unwrap();
+
+        let service = MfaService::new(repository, TotpService::new("TikTok Clone".to_string()), Duration::minutes(5));
+        (service, user_id, secret)
+    }
+
+    #[tokio::test]
+    async fn totp_code_cannot_be_replayed() {
+        let (service, user_id, secret) = enabled().await;
+        let code = TotpService::current_code(&secret);
+
+        assert_eq!(service.verify(&user_id, &code).await, Ok(()));
+        assert_eq!(service.verify(&user_id, &code).await, Err(DomainError::InvalidMfaCode));
+    }
+
+    #[tokio::test]
+    async fn recovery_code_is_single_use() {
+        let (service, user_id, _) = enabled().await;
+        let codes = service.regenerate_recovery_codes(&user_id).await.unwrap();
+
+        assert_eq!(service.verify(&user_id, codes[0].as_str()).await, Ok(()));
+        assert_eq!(service.verify(&user_id, codes[0].as_str()).await, Err(DomainError::InvalidMfaCode));
+        assert_eq!(service.verify(&user_id, codes[1].as_str()).await, Ok(()));
+    }
+}
@@ -1,4 +1,41 @@
+pub mod account_notifier;
+pub mod account_token_service;
+pub mod api_key_service;
+pub mod audit_log;
 pub mod auth_service;
+pub mod authorization_service;
+pub mod federation_service;
+pub mod login_throttle;
+pub mod mfa_service;
+pub mod oauth_service;
+pub mod organization_service;
+pub mod password_policy_service;
+pub mod password_service;
+pub mod policy_engine;
+pub mod revocation_service;
+pub mod role_service;
+pub mod session_service;
+pub mod token_service;
+pub mod totp_service;
+pub mod webauthn_service;
 
+pub use account_notifier::AccountNotifier;
+pub use account_token_service::AccountTokenService;
+pub use api_key_service::{ApiKeyService, AuthenticatedApiKey};
+pub use audit_log::{AuditLog, ChainVerification};
 pub use auth_service::AuthService;
-
+pub use authorization_service::{AccessGrants, AuthorizationService};
+pub use federation_service::{ExternalIdentity, FederationService};
+pub use login_throttle::{LoginThrottle, LoginThrottlePolicy};
+pub use mfa_service::MfaService;
+pub use oauth_service::OAuthService;
+pub use organization_service::OrganizationService;
+pub use password_policy_service::{PasswordPolicy, PasswordPolicyService};
+pub use password_service::PasswordService;
+pub use policy_engine::{AuthorizationRequest, PolicyEngine};
+pub use revocation_service::RevocationService;
+pub use role_service::RoleService;
+pub use session_service::{IssuedSession, SessionService};
+pub use token_service::{AccessTokenClaims, TokenKey, TokenService};
+pub use totp_service::TotpService;
+pub use webauthn_service::{AssertionResponse, AttestationResponse, WebAuthnService};
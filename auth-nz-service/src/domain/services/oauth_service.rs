@@ -0,0 +1,339 @@
+use std::sync::Arc;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{Duration, Utc};
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+use crate::domain::entities::oauth_authorization_code::OAuthAuthorizationCode;
+use crate::domain::entities::oauth_client::OAuthClient;
+use crate::domain::entities::oauth_consent::OAuthConsent;
+use crate::domain::repositories::{OAuthClientRepository, OAuthGrantRepository};
+use crate::domain::value_objects::{AuthorizationCode, ClientSecret, GrantType, Scopes, UserId};
+use crate::domain::errors::DomainError;
+
+/// Client authentication, authorization codes with PKCE, and consent.
+pub struct OAuthService<CR: OAuthClientRepository, GR: OAuthGrantRepository> {
+    client_repository: Arc<CR>,
+    grant_repository: Arc<GR>,
+    code_ttl: Duration,
+}
+
+impl<CR: OAuthClientRepository, GR: OAuthGrantRepository> OAuthService<CR, GR> {
+    pub fn new(client_repository: Arc<CR>, grant_repository: Arc<GR>, code_ttl: Duration) -> Self {
+        Self {
+            client_repository,
+            grant_repository,
+            code_ttl,
+        }
+    }
+
+    /// Authenticates the client at the token endpoint. Confidential clients
+    /// must present their secret; public clients must not have one to present.
+    pub async fn authenticate_client(&self, client_id: &str, secret: Option<&str>) -> Result<OAuthClient, DomainError> {
+        let client = self.client_repository.find_by_id(client_id).await?
+            .ok_or(DomainError::InvalidClient)?;
+
+        let authenticated = match secret {
+            Some(secret) => client.verify_secret(&ClientSecret::new(secret.to_string()).hash()),
+            None => !client.is_confidential(),
+        };
+        if !authenticated {
+            return Err(DomainError::InvalidClient);
+        }
+        Ok(client)
+    }
+
+    /// Looks up the client of an authorization request and checks the
+    /// redirect URI. Until both are known good, errors must not be sent to
+    /// the redirect URI.
+    pub async fn authorization_client(&self, client_id: &str, redirect_uri: &str) -> Result<OAuthClient, DomainError> {
+        let client = self.client_repository.find_by_id(client_id).await?
+            .ok_or_else(|| DomainError::InvalidOAuthRequest("Unknown client_id".to_string()))?;
+
+        if !client.allows_redirect_uri(redirect_uri) {
+            return Err(DomainError::InvalidOAuthRequest("redirect_uri is not registered for this client".to_string()));
+        }
+        Ok(client)
+    }
+
+    /// Validates the rest of an authorization request and returns the scopes it asks for.
+    pub fn validate_authorization(
+        &self,
+        client: &OAuthClient,
+        response_type: &str,
+        scope: Option<&str>,
+        code_challenge: Option<&str>,
+        code_challenge_method: Option<&str>,
+    ) -> Result<Scopes, DomainError> {
+        if response_type != "code" {
+            return Err(DomainError::UnsupportedResponseType(response_type.to_string()));
+        }
+        if !client.allows_grant(GrantType::AuthorizationCode) {
+            return Err(DomainError::UnauthorizedClient("authorization_code grant is not allowed".to_string()));
+        }
+        match (code_challenge, code_challenge_method) {
+            (Some(challenge), Some("S256")) if !challenge.is_empty() => {}
+            (None, _) => return Err(DomainError::InvalidOAuthRequest("code_challenge is required".to_string())),
+            _ => return Err(DomainError::InvalidOAuthRequest("code_challenge_method must be S256".to_string())),
+        }
+        self.resolve_scopes(client, scope)
+    }
+
+    /// The requested scopes, or every scope of the client when none are requested.
+    pub fn resolve_scopes(&self, client: &OAuthClient, scope: Option<&str>) -> Result<Scopes, DomainError> {
+        let scopes = match scope.filter(|scope| !scope.trim().is_empty()) {
+            Some(scope) => Scopes::parse(scope)?,
+            None => client.scopes.clone(),
+        };
+
+        if scopes.is_empty() {
+            return Err(DomainError::InvalidScope("No scope requested".to_string()));
+        }
+        if !client.scopes.contains_all(&scopes) {
+            return Err(DomainError::InvalidScope("Scope exceeds what the client may request".to_string()));
+        }
+        Ok(scopes)
+    }
+
+    pub async fn has_consent(&self, user_id: &UserId, client_id: &str, scopes: &Scopes) -> Result<bool, DomainError> {
+        Ok(self.grant_repository.find_consent(user_id, client_id).await?
+            .map(|consent| consent.scopes.contains_all(scopes))
+            .unwrap_or(false))
+    }
+
+    /// Records consent for `scopes` on top of whatever the user already granted the client.
+    pub async fn grant_consent(&self, user_id: &UserId, client_id: &str, scopes: &Scopes) -> Result<(), DomainError> {
+        let scopes = match self.grant_repository.find_consent(user_id, client_id).await? {
+            Some(existing) => existing.scopes.union(scopes),
+            None => scopes.clone(),
+        };
+        self.grant_repository.save_consent(&OAuthConsent::new(*user_id, client_id.to_string(), scopes)).await
+    }
+
+    pub async fn issue_code(
+        &self,
+        user_id: &UserId,
+        client: &OAuthClient,
+        redirect_uri: &str,
+        scopes: Scopes,
+        code_challenge: &str,
+    ) -> Result<AuthorizationCode, DomainError> {
+        let code = AuthorizationCode::generate();
+        self.grant_repository.create_code(&OAuthAuthorizationCode::new(
+            code.hash(),
+            client.id.clone(),
+            *user_id,
+            redirect_uri.to_string(),
+            scopes,
+            code_challenge.to_string(),
+            Utc::now() + self.code_ttl,
+        )).await?;
+        Ok(code)
+    }
+
+    /// Exchanges a code for what it was issued for. The code is consumed
+    /// before anything else is checked, so a failed exchange cannot be retried.
+    pub async fn redeem_code(
+        &self,
+        client: &OAuthClient,
+        code: &str,
+        redirect_uri: &str,
+        code_verifier: &str,
+    ) -> Result<OAuthAuthorizationCode, DomainError> {
+        let grant = self.grant_repository.consume_code(&AuthorizationCode::new(code.to_string()).hash()).await?
+            .ok_or_else(|| DomainError::InvalidGrant("Authorization code is invalid, expired or already used".to_string()))?;
+
+        if grant.client_id != client.id {
+            return Err(DomainError::InvalidGrant("Authorization code was issued to another client".to_string()));
+        }
+        if grant.redirect_uri != redirect_uri {
+            return Err(DomainError::InvalidGrant("redirect_uri does not match the authorization request".to_string()));
+        }
+        if !verify_pkce(code_verifier, &grant.code_challenge) {
+            return Err(DomainError::InvalidGrant("PKCE verification failed".to_string()));
+        }
+        Ok(grant)
+    }
+}
+
+/// RFC 7636 S256: the challenge is the unpadded base64url SHA-256 of the verifier.
+fn verify_pkce(code_verifier: &str, code_challenge: &str) -> bool {
+    let well_formed = (43..=128).contains(&code_verifier.len())
+        && code_verifier.chars().all(|c| c.is_ascii_alphanumeric() || "-._~".contains(c));
+    if !well_formed {
+        return false;
+    }
+    let computed = URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes()));
+    computed.as_bytes().ct_eq(code_challenge.as_bytes()).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+    use async_trait::async_trait;
+    use super::*;
+
+    // RFC 7636 Appendix B
+    const VERIFIER: &str = "dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk";
+    const CHALLENGE: &str = "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM";
+    const REDIRECT_URI: &str = "tiktokclone://oauth/callback";
+
+    #[derive(Default)]
+    struct InMemoryClients {
+        clients: Mutex<Vec<OAuthClient>>,
+    }
+
+    #[async_trait]
+    impl OAuthClientRepository for InMemoryClients {
+        async fn create(&self, client: &OAuthClient) -> Result<(), DomainError> {
+            self.clients.lock().unwrap().push(client.clone());
+            Ok(())
+        }
+
+        async fn find_by_id(&self, id: &str) -> Result<Option<OAuthClient>, DomainError> {
+            Ok(self.clients.lock().unwrap().iter().find(|client| client.id == id).cloned())
+        }
+
+        async fn find_all(&self) -> Result<Vec<OAuthClient>, DomainError> {
+            Ok(self.clients.lock().unwrap().clone())
+        }
+
+        async fn delete(&self, id: &str) -> Result<(), DomainError> {
+            self.clients.lock().unwrap().retain(|client| client.id != id);
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct InMemoryGrants {
+        codes: Mutex<Vec<OAuthAuthorizationCode>>,
+        consents: Mutex<Vec<OAuthConsent>>,
+    }
+
+    #[async_trait]
+    impl OAuthGrantRepository for InMemoryGrants {
+        async fn create_code(&self, code: &OAuthAuthorizationCode) -> Result<(), DomainError> {
+            self.codes.lock().unwrap().push(code.clone());
+            Ok(())
+        }
+
+        async fn consume_code(&self, code_hash: &str) -> Result<Option<OAuthAuthorizationCode>, DomainError> {
+            let mut codes = self.codes.lock().unwrap();
+            let position = codes.iter().position(|code| code.code_hash == code_hash && code.expires_at > Utc::now());
+            Ok(position.map(|position| codes.remove(position)))
+        }
+
+        async fn find_consent(&self, user_id: &UserId, client_id: &str) -> Result<Option<OAuthConsent>, DomainError> {
+            Ok(self.consents.lock().unwrap().iter()
+                .find(|consent| consent.user_id == *user_id && consent.client_id == client_id)
+                .cloned())
+        }
+
+        async fn save_consent(&self, consent: &OAuthConsent) -> Result<(), DomainError> {
+            let mut consents = self.consents.lock().unwrap();
+            consents.retain(|existing| !(existing.user_id == consent.user_id && existing.client_id == consent.client_id));
+            consents.push(consent.clone());
+            Ok(())
+        }
+
+        async fn find_consents_by_user_id(&self, user_id: &UserId) -> Result<Vec<OAuthConsent>, DomainError> {
+            Ok(self.consents.lock().unwrap().iter().filter(|consent| consent.user_id == *user_id).cloned().collect())
+        }
+
+        async fn delete_consent(&self, user_id: &UserId, client_id: &str) -> Result<bool, DomainError> {
+            let mut consents = self.consents.lock().unwrap();
+            let before = consents.len();
+            consents.retain(|consent| !(consent.user_id == *user_id && consent.client_id == client_id));
+            Ok(consents.len() != before)
+        }
+    }
+
+    fn service() -> OAuthService<InMemoryClients, InMemoryGrants> {
+        OAuthService::new(Arc::new(InMemoryClients::default()), Arc::new(InMemoryGrants::default()), Duration::minutes(5))
+    }
+
+    fn public_client() -> OAuthClient {
+        OAuthClient::new(
+            "Mobile app".to_string(),
+            None,
+            vec![REDIRECT_URI.to_string()],
+            vec![GrantType::AuthorizationCode],
+            Scopes::parse("profile:read").unwrap(),
+        )
+    }
+
+    async fn issued_code(service: &OAuthService<InMemoryClients, InMemoryGrants>, client: &OAuthClient) -> String {
+        service.issue_code(&UserId::new(), client, REDIRECT_URI, client.scopes.clone(), CHALLENGE).await
+            .unwrap()
+            .as_str()
+            .to_string()
+    }
+
+    fn invalid_grant(result: Result<OAuthAuthorizationCode, DomainError>) -> bool {
+        matches!(result, Err(DomainError::InvalidGrant(_)))
+    }
+
+    #[test]
+    fn pkce_accepts_rfc_7636_vector() {
+        assert!(verify_pkce(VERIFIER, CHALLENGE));
+    }
+
+    #[test]
+    fn pkce_rejects_wrong_verifier() {
+        assert!(!verify_pkce("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXj", CHALLENGE));
+    }
+
+    #[test]
+    fn pkce_rejects_malformed_verifier() {
+        let with_invalid_character = "dBjftJeZ4CVP+mB92K27uhbUJU1p1r_wW1gFWFOEjXk";
+        let challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(with_invalid_character.as_bytes()));
+        assert!(!verify_pkce(with_invalid_character, &challenge));
+
+        for length in [42, 129] {
+            let verifier = "a".repeat(length);
+            let challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(verifier.as_bytes()));
+            assert!(!verify_pkce(&verifier, &challenge));
+        }
+    }
+
+    #[tokio::test]
+    async fn code_is_exchanged_by_the_client_it_was_issued_to() {
+        let service = service();
+        let client = public_client();
+        let code = issued_code(&service, &client).await;
+
+        let grant = service.redeem_code(&client, &code, REDIRECT_URI, VERIFIER).await.unwrap();
+
+        assert_eq!(grant.client_id, client.id);
+    }
+
+    #[tokio::test]
+    async fn code_redeemed_by_another_client_is_rejected() {
+        let service = service();
+        let client = public_client();
+        let code = issued_code(&service, &client).await;
+
+        assert!(invalid_grant(service.redeem_code(&public_client(), &code, REDIRECT_URI, VERIFIER).await));
+        // The failed exchange consumed the code
+        assert!(invalid_grant(service.redeem_code(&client, &code, REDIRECT_URI, VERIFIER).await));
+    }
+
+    #[tokio::test]
+    async fn code_with_mismatched_redirect_uri_is_rejected() {
+        let service = service();
+        let client = public_client();
+        let code = issued_code(&service, &client).await;
+
+        assert!(invalid_grant(service.redeem_code(&client, &code, "https://evil.example/callback", VERIFIER).await));
+    }
+
+    #[tokio::test]
+    async fn code_cannot_be_reused() {
+        let service = service();
+        let client = public_client();
+        let code = issued_code(&service, &client).await;
+
+        assert!(service.redeem_code(&client, &code, REDIRECT_URI, VERIFIER).await.is_ok());
+        assert!(invalid_grant(service.redeem_code(&client, &code, REDIRECT_URI, VERIFIER).await));
+    }
+}
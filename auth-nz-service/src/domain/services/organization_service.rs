@@ -0,0 +1,128 @@
+use std::sync::Arc;
+use chrono::Duration;
+use crate::domain::entities::organization::{Organization, OrganizationMember};
+use crate::domain::entities::organization_invitation::OrganizationInvitation;
+use crate::domain::entities::user::User;
+use crate::domain::repositories::{OrganizationInvitationRepository, OrganizationRepository};
+use crate::domain::value_objects::{Email, InvitationId, InvitationToken, OrganizationId, RoleId, UserId};
+use crate::domain::errors::DomainError;
+
+/// Manages organizations, who belongs to them and invitations to join.
+/// Roles within an organization are assigned through `RoleRepository`.
+pub struct OrganizationService<OR: OrganizationRepository, IR: OrganizationInvitationRepository> {
+    organization_repository: Arc<OR>,
+    invitation_repository: Arc<IR>,
+    invitation_ttl: Duration,
+}
+
+impl<OR: OrganizationRepository, IR: OrganizationInvitationRepository> OrganizationService<OR, IR> {
+    pub fn new(organization_repository: Arc<OR>, invitation_repository: Arc<IR>, invitation_ttl: Duration) -> Self {
+        Self {
+            organization_repository,
+            invitation_repository,
+            invitation_ttl,
+        }
+    }
+
+    /// Creates the organization with `founder` as its first member.
+    pub async fn create(&self, name: String, founder: &UserId) -> Result<Organization, DomainError> {
+        let organization = Organization::new(name);
+        let member = OrganizationMember::new(organization.id, *founder);
+        self.organization_repository.create(&organization, &member).await?;
+        Ok(organization)
+    }
+
+    pub async fn find(&self, organization_id: &OrganizationId) -> Result<Organization, DomainError> {
+        self.organization_repository.find_by_id(organization_id).await?
+            .ok_or(DomainError::OrganizationNotFound)
+    }
+
+    /// The organization, provided the user belongs to it.
+    pub async fn membership(&self, organization_id: &OrganizationId, user_id: &UserId) -> Result<Organization, DomainError> {
+        let organization = self.find(organization_id).await?;
+        self.organization_repository.find_member(organization_id, user_id).await?
+            .ok_or(DomainError::OrganizationMemberNotFound)?;
+        Ok(organization)
+    }
+
+    pub async fn organizations_of(&self, user_id: &UserId) -> Result<Vec<Organization>, DomainError> {
+        self.organization_repository.find_by_user_id(user_id).await
+    }
+
+    pub async fn members(&self, organization_id: &OrganizationId) -> Result<Vec<OrganizationMember>, DomainError> {
+        self.organization_repository.find_members(organization_id).await
+    }
+
+    pub async fn remove_member(&self, organization_id: &OrganizationId, user_id: &UserId) -> Result<(), DomainError> {
+        self.organization_repository.find_member(organization_id, user_id).await?
+            .ok_or(DomainError::OrganizationMemberNotFound)?;
+        self.organization_repository.remove_member(organization_id, user_id).await
+    }
+
+    /// Records an invitation and returns it with the token for the link;
+    /// only the token's hash is kept.
+    pub async fn invite(
+        &self,
+        organization_id: &OrganizationId,
+        email: Email,
+        role_id: Option<RoleId>,
+        invited_by: &UserId,
+    ) -> Result<(OrganizationInvitation, InvitationToken), DomainError> {
+        let token = InvitationToken::generate();
+        let invitation = OrganizationInvitation::new(
+            *organization_id,
+            email,
+            role_id,
+            token.hash(),
+            *invited_by,
+            self.invitation_ttl,
+        );
+        self.invitation_repository.create(&invitation).await?;
+        Ok((invitation, token))
+    }
+
+    pub async fn invitations(&self, organization_id: &OrganizationId) -> Result<Vec<OrganizationInvitation>, DomainError> {
+        self.invitation_repository.find_by_organization_id(organization_id).await
+    }
+
+    /// Deletes an invitation of the organization so its link stops working.
+    pub async fn revoke_invitation(
+        &self,
+        organization_id: &OrganizationId,
+        invitation_id: &InvitationId,
+    ) -> Result<OrganizationInvitation, DomainError> {
+        let invitation = self.invitation_repository.find_by_id(invitation_id).await?
+            .filter(|invitation| invitation.organization_id == *organization_id)
+            .ok_or(DomainError::InvitationNotFound)?;
+        self.invitation_repository.delete(&invitation.id).await?;
+        Ok(invitation)
+    }
+
+    /// Makes `user` a member of the organization they were invited to. The
+    /// invitation must be pending and addressed to the user's verified email.
+    pub async fn accept(&self, token: &InvitationToken, user: &User) -> Result<OrganizationInvitation, DomainError> {
+        let invitation = self.invitation_repository.find_by_token_hash(&token.hash()).await?
+            .ok_or_else(|| DomainError::InvalidInvitation("Unknown invitation".to_string()))?;
+
+        if !invitation.is_pending() {
+            return Err(DomainError::InvalidInvitation("Invitation was already used or has expired".to_string()));
+        }
+        if !invitation.email.as_str().eq_ignore_ascii_case(user.email.as_str()) {
+            return Err(DomainError::InvalidInvitation("Invitation was sent to another email".to_string()));
+        }
+        if user.email_verified_at.is_none() {
+            return Err(DomainError::InvalidInvitation("Verify your email before accepting invitations".to_string()));
+        }
+        if self.organization_repository.find_member(&invitation.organization_id, &user.id).await?.is_some() {
+            return Err(DomainError::OrganizationMemberAlreadyExists);
+        }
+
+        if !self.invitation_repository.accept(&invitation.id).await? {
+            return Err(DomainError::InvalidInvitation("Invitation was already used or has expired".to_string()));
+        }
+        self.organization_repository
+            .add_member(&OrganizationMember::new(invitation.organization_id, user.id)).await?;
+
+        Ok(invitation)
+    }
+}
@@ -0,0 +1,138 @@
+use std::sync::Arc;
+use sha1::{Digest, Sha1};
+use crate::domain::entities::user::User;
+use crate::domain::repositories::{BreachedPasswordRepository, PasswordHistoryRepository};
+use crate::domain::services::PasswordService;
+use crate::domain::errors::{DomainError, FieldError};
+
+#[derive(Debug, Clone)]
+pub struct PasswordPolicy {
+    /// In characters, not bytes.
+    pub min_length: usize,
+    /// Bounds the work of hashing an attacker-supplied password.
+    pub max_length: usize,
+    pub require_lowercase: bool,
+    pub require_uppercase: bool,
+    pub require_digit: bool,
+    /// Anything that is not a letter or digit, spaces included.
+    pub require_symbol: bool,
+    /// How many of the user's most recent passwords, the current one included,
+    /// may not be chosen again. 0 allows any reuse.
+    pub history_size: usize,
+}
+
+/// Decides whether a new password is acceptable: the configured rules, no
+/// match in the breach corpus, and no reuse of a recent password.
+pub struct PasswordPolicyService<HR: PasswordHistoryRepository, BR: BreachedPasswordRepository> {
+    history_repository: Arc<HR>,
+    breached_password_repository: Arc<BR>,
+    password_service: Arc<PasswordService>,
+    policy: PasswordPolicy,
+}
+
+impl<HR: PasswordHistoryRepository, BR: BreachedPasswordRepository> PasswordPolicyService<HR, BR> {
+    pub fn new(
+        history_repository: Arc<HR>,
+        breached_password_repository: Arc<BR>,
+        password_service: Arc<PasswordService>,
+        policy: PasswordPolicy,
+    ) -> Self {
+        Self {
+            history_repository,
+            breached_password_repository,
+            password_service,
+            policy,
+        }
+    }
+
+    /// Checks `password`, submitted in `field`, as the new password of `user`
+    /// (`None` while registering). Every violation found is reported at once
+    /// as `DomainError::InvalidFields`.
+    pub async fn check(&self, field: &str, password: &str, user: Option<&User>) -> Result<(), DomainError> {
+        let length = password.chars().count();
+        if length > self.policy.max_length {
+            // Reported alone, without hashing anything
+            return Err(DomainError::InvalidFields(vec![FieldError::new(
+                field,
+                "too_long",
+                format!("must be at most {} characters", self.policy.max_length),
+            )]));
+        }
+
+        let mut errors = Vec::new();
+        if length < self.policy.min_length {
+            errors.push(FieldError::new(
+                field,
+                "too_short",
+                format!("must be at least {} characters", self.policy.min_length),
+            ));
+        }
+        let classes = [
+            (self.policy.require_lowercase, "missing_lowercase", "must contain a lowercase letter", char::is_lowercase as fn(char) -> bool),
+            (self.policy.require_uppercase, "missing_uppercase", "must contain an uppercase letter", char::is_uppercase),
+            (self.policy.require_digit, "missing_digit", "must contain a digit", char::is_numeric),
+            (self.policy.require_symbol, "missing_symbol", "must contain a symbol", |c: char| !c.is_alphanumeric()),
+        ];
+        for (required, code, message, matches) in classes {
+            if required && !password.chars().any(matches) {
+                errors.push(FieldError::new(field, code, message));
+            }
+        }
+
+        if self.is_breached(password).await? {
+            errors.push(FieldError::new(
+                field,
+                "breached",
+                "has appeared in a data breach and cannot be used",
+            ));
+        }
+        if let Some(user) = user {
+            if self.is_recent(password, user).await? {
+                errors.push(FieldError::new(
+                    field,
+                    "reused",
+                    format!("must differ from your last {} passwords", self.policy.history_size),
+                ));
+            }
+        }
+
+        match errors.is_empty() {
+            true => Ok(()),
+            false => Err(DomainError::InvalidFields(errors)),
+        }
+    }
+
+    /// Remembers the password `user` had before it was replaced.
+    pub async fn remember(&self, user: &User) -> Result<(), DomainError> {
+        if self.policy.history_size < 2 {
+            return Ok(());
+        }
+        self.history_repository
+            .add(&user.id, &user.password_hash, self.policy.history_size - 1).await
+    }
+
+    async fn is_breached(&self, password: &str) -> Result<bool, DomainError> {
+        let digest = hex::encode_upper(Sha1::digest(password.as_bytes()));
+        let (prefix, suffix) = digest.split_at(5);
+
+        Ok(self.breached_password_repository.find_range(prefix).await?
+            .iter()
+            .any(|(candidate, count)| candidate == suffix && *count > 0))
+    }
+
+    async fn is_recent(&self, password: &str, user: &User) -> Result<bool, DomainError> {
+        if self.policy.history_size == 0 {
+            return Ok(false);
+        }
+        if self.password_service.verify(password, &user.password_hash)? {
+            return Ok(true);
+        }
+
+        for previous in self.history_repository.find_recent(&user.id, self.policy.history_size - 1).await? {
+            if self.password_service.verify(password, &previous)? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+}
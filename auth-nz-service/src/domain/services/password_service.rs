@@ -0,0 +1,87 @@
+use argon2::password_hash::{self, PasswordHash as PhcString, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::{Algorithm, Argon2, Params, Version};
+use rand::rngs::OsRng;
+use rand::RngCore;
+use subtle::ConstantTimeEq;
+use crate::domain::value_objects::PasswordHash;
+use crate::domain::errors::DomainError;
+
+pub struct PasswordService {
+    params: Params,
+    /// Hash of a random password, verified against when there is no account
+    /// so that lookups for unknown emails take as long as real ones.
+    dummy_hash: PasswordHash,
+}
+
+impl PasswordService {
+    pub fn new(memory_cost_kib: u32, time_cost: u32, parallelism: u32) -> Result<Self, DomainError> {
+        let params = Params::new(memory_cost_kib, time_cost, parallelism, None)
+            .map_err(|e| DomainError::PasswordHashing(e.to_string()))?;
+        let mut service = Self { params, dummy_hash: PasswordHash::new(String::new()) };
+        service.dummy_hash = service.unusable_hash()?;
+        Ok(service)
+    }
+
+    fn argon2(&self) -> Argon2<'_> {
+        Argon2::new(Algorithm::Argon2id, Version::V0x13, self.params.clone())
+    }
+
+    pub fn hash(&self, password: &str) -> Result<PasswordHash, DomainError> {
+        let salt = SaltString::generate(&mut OsRng);
+        let hash = self.argon2()
+            .hash_password(password.as_bytes(), &salt)
+            .map_err(|e| DomainError::PasswordHashing(e.to_string()))?;
+        Ok(PasswordHash::new(hash.to_string()))
+    }
+
+    /// Hash of a random password nobody knows, for accounts created through an
+    /// external provider. A password can still be set with a reset.
+    pub fn unusable_hash(&self) -> Result<PasswordHash, DomainError> {
+        let mut password = [0u8; 32];
+        OsRng.fill_bytes(&mut password);
+        self.hash(&hex::encode(password))
+    }
+
+    /// Verifies `password` against a stored hash using the algorithm and
+    /// parameters recorded in the hash itself.
+    pub fn verify(&self, password: &str, hash: &PasswordHash) -> Result<bool, DomainError> {
+        match PhcString::new(hash.as_str()) {
+            Ok(parsed) => match self.argon2().verify_password(password.as_bytes(), &parsed) {
+                Ok(()) => Ok(true),
+                Err(password_hash::Error::Password) => Ok(false),
+                Err(e) => Err(DomainError::PasswordHashing(e.to_string())),
+            },
+            // Accounts created before hashing was introduced store the raw password.
+            Err(_) => Ok(bool::from(hash.as_str().as_bytes().ct_eq(password.as_bytes()))),
+        }
+    }
+
+    /// Does the work of [`verify`](Self::verify) for a login without an
+    /// account, so response times do not reveal which emails are registered.
+    pub fn verify_dummy(&self, password: &str) {
+        let _ = self.verify(password, &self.dummy_hash);
+    }
+
+    /// Returns true when the stored hash is not Argon2id or was produced with
+    /// weaker parameters than the ones currently configured.
+    pub fn needs_rehash(&self, hash: &PasswordHash) -> bool {
+        let Ok(parsed) = PhcString::new(hash.as_str()) else {
+            return true;
+        };
+
+        if parsed.algorithm != Algorithm::Argon2id.ident()
+            || parsed.version != Some(Version::V0x13 as u32)
+        {
+            return true;
+        }
+
+        match Params::try_from(&parsed) {
+            Ok(params) => {
+                params.m_cost() < self.params.m_cost()
+                    || params.t_cost() < self.params.t_cost()
+                    || params.p_cost() < self.params.p_cost()
+            }
+            Err(_) => true,
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use std::sync::Arc;
+use serde_json::{json, Value};
+use crate::domain::entities::policy::{Policy, PolicyEffect};
+use crate::domain::repositories::PolicyRepository;
+use crate::domain::errors::DomainError;
+
+pub struct AuthorizationRequest {
+    pub subject: Value,
+    pub resource: Value,
+    pub action: String,
+    pub environment: Value,
+}
+
+pub struct RuleEvaluation {
+    pub policy_id: String,
+    pub effect: PolicyEffect,
+    pub applicable: bool,
+    pub matched: bool,
+    pub trace: Vec<String>,
+}
+
+pub struct PolicyDecision {
+    pub allowed: bool,
+    pub matched_policy: Option<String>,
+    pub reason: String,
+    /// Per-policy evaluation details, only collected in explain mode.
+    pub evaluations: Vec<RuleEvaluation>,
+}
+
+pub struct PolicyEngine<R: PolicyRepository> {
+    policy_repository: Arc<R>,
+}
+
+impl<R: PolicyRepository> PolicyEngine<R> {
+    pub fn new(policy_repository: Arc<R>) -> Self {
+        Self { policy_repository }
+    }
+
+    /// Evaluates every policy targeting the request's resource type and action.
+    ///
+    /// A matching deny always wins; otherwise the highest-priority matching
+    /// allow grants access. With no match the request is denied.
+    pub async fn evaluate(&self, request: &AuthorizationRequest, explain: bool) -> Result<PolicyDecision, DomainError> {
+        let mut policies = self.policy_repository.find_all().await?;
+        policies.sort_by(|a, b| b.priority.cmp(&a.priority).then_with(|| a.id.cmp(&b.id)));
+
+        let resource_type = request.resource.get("type").and_then(Value::as_str).unwrap_or_default();
+        let context = json!({
+            "subject": request.subject,
+            "resource": request.resource,
+            "action": request.action,
+            "environment": request.environment,
+        });
+
+        let mut allowed_by: Option<&Policy> = None;
+        let mut denied_by: Option<&Policy> = None;
+        let mut evaluations = Vec::new();
+
+        for policy in &policies {
+            let applicable = policy.applies_to(resource_type, &request.action);
+            let mut trace = Vec::new();
+            let matched = applicable && match &policy.condition {
+                Some(condition) if explain => condition.explain(&context, &mut trace),
+                Some(condition) => condition.evaluate(&context),
+                None => true,
+            };
+
+            if matched {
+                match policy.effect {
+                    PolicyEffect::Deny if denied_by.is_none() => denied_by = Some(policy),
+                    PolicyEffect::Allow if allowed_by.is_none() => allowed_by = Some(policy),
+                    _ => {}
+                }
+            }
+
+            if explain {
+                evaluations.push(RuleEvaluation {
+                    policy_id: policy.id.clone(),
+                    effect: policy.effect,
+                    applicable,
+                    matched,
+                    trace,
+                });
+            } else if denied_by.is_some() {
+                break;
+            }
+        }
+
+        let decision = match (denied_by, allowed_by) {
+            (Some(policy), _) => PolicyDecision {
+                allowed: false,
+                matched_policy: Some(policy.id.clone()),
+                reason: format!("Denied by policy '{}'", policy.id),
+                evaluations,
+            },
+            (None, Some(policy)) => PolicyDecision {
+                allowed: true,
+                matched_policy: Some(policy.id.clone()),
+                reason: format!("Allowed by policy '{}'", policy.id),
+                evaluations,
+            },
+            (None, None) => PolicyDecision {
+                allowed: false,
+                matched_policy: None,
+                reason: format!(
+                    "No policy allows '{}' on '{}'",
+                    request.action, resource_type
+                ),
+                evaluations,
+            },
+        };
+
+        Ok(decision)
+    }
+}
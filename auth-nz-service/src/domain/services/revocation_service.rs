@@ -0,0 +1,97 @@
+use std::sync::Arc;
+use chrono::{DateTime, TimeZone, Utc};
+use crate::domain::entities::session::Session;
+use crate::domain::repositories::{RevocationListRepository, SessionRepository};
+use crate::domain::services::AccessTokenClaims;
+use crate::domain::value_objects::{RefreshToken, SessionId};
+use crate::domain::errors::DomainError;
+
+/// Tells whether an issued token still stands. The session store is the
+/// source of truth; the revocation list in front of it answers for tokens
+/// already known to be dead without a database round trip.
+pub struct RevocationService<SR: SessionRepository, RL: RevocationListRepository> {
+    session_repository: Arc<SR>,
+    revocation_list: Arc<RL>,
+}
+
+impl<SR: SessionRepository, RL: RevocationListRepository> RevocationService<SR, RL> {
+    pub fn new(session_repository: Arc<SR>, revocation_list: Arc<RL>) -> Self {
+        Self {
+            session_repository,
+            revocation_list,
+        }
+    }
+
+    /// Whether a verified access token has neither been revoked itself nor
+    /// lost its session to a logout or revocation. A token naming a session
+    /// of another user is treated as inactive.
+    pub async fn is_access_token_active(&self, claims: &AccessTokenClaims) -> Result<bool, DomainError> {
+        if self.revocation_list.contains(&token_key(&claims.jti)).await? {
+            return Ok(false);
+        }
+
+        let Some(sid) = &claims.sid else {
+            return Ok(true);
+        };
+        if self.revocation_list.contains(&session_key(sid)).await? {
+            return Ok(false);
+        }
+
+        let session = self.session(claims).await?;
+        if session.as_ref().is_some_and(|session| session.user_id.as_uuid().to_string() != claims.sub) {
+            // Not cached: the session itself may still be live for its owner
+            return Ok(false);
+        }
+
+        let live = session.is_some_and(|session| !session.is_revoked());
+        if !live {
+            // Remember the answer for as long as the token could be presented
+            self.revocation_list.add(&session_key(sid), expiry(claims)).await?;
+        }
+        Ok(live)
+    }
+
+    /// The session a refresh token belongs to, if it can still be redeemed.
+    pub async fn refresh_session(&self, refresh_token: &RefreshToken) -> Result<Option<Session>, DomainError> {
+        let session = self.session_repository.find_by_refresh_token_hash(&refresh_token.hash()).await?;
+        Ok(session.filter(|session| !session.is_revoked() && !session.is_refresh_expired()))
+    }
+
+    /// Revokes the token and, for user tokens, its whole refresh chain.
+    pub async fn revoke_access_token(&self, claims: &AccessTokenClaims) -> Result<(), DomainError> {
+        self.revocation_list.add(&token_key(&claims.jti), expiry(claims)).await?;
+
+        let session = self.session(claims).await?
+            .filter(|session| session.user_id.as_uuid().to_string() == claims.sub);
+        if let Some(session) = session {
+            self.revoke_session(&session).await?;
+        }
+        Ok(())
+    }
+
+    /// Revokes the session's refresh chain and every access token issued in it.
+    pub async fn revoke_session(&self, session: &Session) -> Result<(), DomainError> {
+        self.session_repository.revoke_family(&session.family_id).await?;
+        // Earlier sessions of the chain are caught by the session store lookup
+        self.revocation_list.add(&session_key(&session.id.as_uuid().to_string()), session.expires_at).await
+    }
+
+    async fn session(&self, claims: &AccessTokenClaims) -> Result<Option<Session>, DomainError> {
+        match claims.sid.as_deref().and_then(|sid| uuid::Uuid::parse_str(sid).ok()) {
+            Some(id) => self.session_repository.find_by_id(&SessionId::from_uuid(id)).await,
+            None => Ok(None),
+        }
+    }
+}
+
+fn token_key(jti: &str) -> String {
+    format!("jti:{}", jti)
+}
+
+fn session_key(sid: &str) -> String {
+    format!("sid:{}", sid)
+}
+
+fn expiry(claims: &AccessTokenClaims) -> DateTime<Utc> {
+    Utc.timestamp_opt(claims.exp, 0).single().unwrap_or_else(Utc::now)
+}
@@ -0,0 +1,38 @@
+use std::sync::Arc;
+use crate::domain::repositories::RoleRepository;
+use crate::domain::value_objects::{RoleId, RoleName};
+use crate::domain::errors::DomainError;
+
+pub struct RoleService<R: RoleRepository> {
+    role_repository: Arc<R>,
+}
+
+impl<R: RoleRepository> RoleService<R> {
+    pub fn new(role_repository: Arc<R>) -> Self {
+        Self { role_repository }
+    }
+
+    pub async fn validate_role_creation(&self, name: &RoleName) -> Result<(), DomainError> {
+        if self.role_repository.find_by_name(name).await?.is_some() {
+            return Err(DomainError::RoleAlreadyExists);
+        }
+        Ok(())
+    }
+
+    /// Ensures `parent_id` exists and that making it the parent of `role_id`
+    /// would not create an inheritance cycle.
+    pub async fn validate_parent(&self, role_id: &RoleId, parent_id: &RoleId) -> Result<(), DomainError> {
+        let mut current = Some(*parent_id);
+        while let Some(id) = current {
+            if id == *role_id {
+                return Err(DomainError::InvalidRoleHierarchy(
+                    "Role cannot inherit from itself or its descendants".to_string(),
+                ));
+            }
+            let role = self.role_repository.find_by_id(&id).await?
+                .ok_or(DomainError::RoleNotFound)?;
+            current = role.parent_id;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,330 @@
+use std::sync::Arc;
+use chrono::{DateTime, Duration, Utc};
+use crate::domain::entities::session::Session;
+use crate::domain::repositories::SessionRepository;
+use crate::domain::services::{AccessGrants, TokenService};
+use crate::domain::value_objects::{DeviceInfo, OAuthDelegation, RefreshToken, SessionId, UserId};
+use crate::domain::errors::DomainError;
+
+pub struct IssuedSession {
+    pub session: Session,
+    pub refresh_token: RefreshToken,
+}
+
+pub struct SessionService<R: SessionRepository> {
+    session_repository: Arc<R>,
+    token_service: Arc<TokenService>,
+    refresh_token_ttl: Duration,
+}
+
+impl<R: SessionRepository> SessionService<R> {
+    pub fn new(session_repository: Arc<R>, token_service: Arc<TokenService>, refresh_token_ttl: Duration) -> Self {
+        Self {
+            session_repository,
+            token_service,
+            refresh_token_ttl,
+        }
+    }
+
+    /// Starts a new refresh token family for the user.
+    pub async fn start(&self, user_id: &UserId, grants: &AccessGrants, device: DeviceInfo) -> Result<IssuedSession, DomainError> {
+        self.open(user_id, grants, device, None, Utc::now()).await
+    }
+
+    /// Starts a new refresh token family for a user who did not sign in again,
+    /// keeping the time they last did.
+    pub async fn start_continued(
+        &self,
+        user_id: &UserId,
+        grants: &AccessGrants,
+        device: DeviceInfo,
+        authenticated_at: DateTime<Utc>,
+    ) -> Result<IssuedSession, DomainError> {
+        self.open(user_id, grants, device, None, authenticated_at).await
+    }
+
+    /// Starts a session on behalf of an OAuth client, limited to the delegated scopes.
+    pub async fn start_delegated(
+        &self,
+        user_id: &UserId,
+        grants: &AccessGrants,
+        device: DeviceInfo,
+        delegation: OAuthDelegation,
+    ) -> Result<IssuedSession, DomainError> {
+        self.open(user_id, grants, device, Some(delegation), Utc::now()).await
+    }
+
+    async fn open(
+        &self,
+        user_id: &UserId,
+        grants: &AccessGrants,
+        device: DeviceInfo,
+        delegation: Option<OAuthDelegation>,
+        authenticated_at: DateTime<Utc>,
+    ) -> Result<IssuedSession, DomainError> {
+        let session_id = SessionId::new();
+        let grants = scoped(grants, delegation.as_ref());
+        let (token, expires_at) = self.token_service
+            .issue_access_token(user_id, &session_id, &grants, delegation.as_ref(), authenticated_at)?;
+        let refresh_token = RefreshToken::generate();
+        let session = Session::new(
+            session_id,
+            *user_id,
+            token,
+            expires_at,
+            refresh_token.hash(),
+            Utc::now() + self.refresh_token_ttl,
+            device,
+        )
+        .with_delegation(delegation)
+        .with_organization(grants.organization_id)
+        .with_authenticated_at(authenticated_at);
+
+        self.session_repository.create(&session).await?;
+
+        Ok(IssuedSession { session, refresh_token })
+    }
+
+    /// Consumes a refresh token and returns the session it belonged to.
+    /// `client_id` is the OAuth client redeeming it, `None` for first-party
+    /// refreshes; a token only works for the client it was issued to.
+    ///
+    /// Refresh tokens are single-use: presenting one that was already consumed
+    /// means it leaked, so every session in its family is revoked.
+    pub async fn redeem(&self, refresh_token: &RefreshToken, client_id: Option<&str>) -> Result<Session, DomainError> {
+        let session = self.session_repository.find_by_refresh_token_hash(&refresh_token.hash()).await?
+            .ok_or(DomainError::InvalidToken)?;
+
+        if session.delegation.as_ref().map(|d| d.client_id.as_str()) != client_id {
+            return Err(DomainError::InvalidToken);
+        }
+
+        if session.is_revoked() || !self.session_repository.revoke(&session.id).await? {
+            tracing::warn!(
+                "Refresh token reuse detected for user {}, revoking session family {}",
+                session.user_id.as_uuid(),
+                session.family_id.as_uuid()
+            );
+            self.session_repository.revoke_family(&session.family_id).await?;
+            return Err(DomainError::RefreshTokenReused);
+        }
+
+        if session.is_refresh_expired() {
+            return Err(DomainError::SessionExpired);
+        }
+
+        Ok(session)
+    }
+
+    /// Issues the successor of a redeemed session within the same family.
+    pub async fn rotate(&self, previous: &Session, grants: &AccessGrants, device: DeviceInfo) -> Result<IssuedSession, DomainError> {
+        let session_id = SessionId::new();
+        let grants = scoped(grants, previous.delegation.as_ref());
+        let (token, expires_at) = self.token_service
+            .issue_access_token(
+                &previous.user_id,
+                &session_id,
+                &grants,
+                previous.delegation.as_ref(),
+                previous.authenticated_at,
+            )?;
+        let refresh_token = RefreshToken::generate();
+        let session = previous.next_in_family(
+            session_id,
+            token,
+            expires_at,
+            refresh_token.hash(),
+            Utc::now() + self.refresh_token_ttl,
+            device,
+        );
+
+        self.session_repository.create(&session).await?;
+
+        Ok(IssuedSession { session, refresh_token })
+    }
+
+    /// Deletes every session of the user, signing them out everywhere.
+    pub async fn end_all(&self, user_id: &UserId) -> Result<(), DomainError> {
+        self.session_repository.delete_by_user_id(user_id).await
+    }
+}
+
+fn scoped(grants: &AccessGrants, delegation: Option<&OAuthDelegation>) -> AccessGrants {
+    match delegation {
+        Some(delegation) => grants.restricted_to(&delegation.scopes),
+        None => grants.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+    use async_trait::async_trait;
+    use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey};
+    use super::*;
+    use crate::domain::services::TokenKey;
+    use crate::domain::value_objects::{RefreshTokenHash, Scopes, Token};
+
+    #[derive(Default)]
+    struct InMemorySessions {
+        sessions: Mutex<Vec<Session>>,
+    }
+
+    impl InMemorySessions {
+        fn get(&self, id: &SessionId) -> Session {
+            self.sessions.lock().unwrap().iter().find(|session| session.id == *id).cloned().unwrap()
+        }
+    }
+
+    #[async_trait]
+    impl SessionRepository for InMemorySessions {
+        async fn create(&self, session: &Session) -> Result<(), DomainError> {
+            self.sessions.lock().unwrap().push(session.clone());
+            Ok(())
+        }
+
+        async fn find_by_id(&self, id: &SessionId) -> Result<Option<Session>, DomainError> {
+            Ok(self.sessions.lock().unwrap().iter().find(|session| session.id == *id).cloned())
+        }
+
+        async fn find_by_token(&self, token: &Token) -> Result<Option<Session>, DomainError> {
+            Ok(self.sessions.lock().unwrap().iter().find(|session| session.token == *token).cloned())
+        }
+
+        async fn find_by_refresh_token_hash(&self, hash: &RefreshTokenHash) -> Result<Option<Session>, DomainError> {
+            Ok(self.sessions.lock().unwrap().iter().find(|session| session.refresh_token_hash == *hash).cloned())
+        }
+
+        async fn find_by_user_id(&self, user_id: &UserId) -> Result<Vec<Session>, DomainError> {
+            Ok(self.sessions.lock().unwrap().iter().filter(|session| session.user_id == *user_id).cloned().collect())
+        }
+
+        async fn find_active_by_user_id(&self, user_id: &UserId) -> Result<Vec<Session>, DomainError> {
+            Ok(self.sessions.lock().unwrap().iter()
+                .filter(|session| session.user_id == *user_id && !session.is_revoked())
+                .cloned()
+                .collect())
+        }
+
+        async fn revoke(&self, id: &SessionId) -> Result<bool, DomainError> {
+            let mut sessions = self.sessions.lock().unwrap();
+            match sessions.iter_mut().find(|session| session.id == *id && !session.is_revoked()) {
+                Some(session) => {
+                    session.revoked_at = Some(Utc::now());
+                    Ok(true)
+                }
+                None => Ok(false),
+            }
+        }
+
+        async fn revoke_family(&self, family_id: &SessionId) -> Result<(), DomainError> {
+            for session in self.sessions.lock().unwrap().iter_mut().filter(|session| session.family_id == *family_id) {
+                session.revoked_at.get_or_insert_with(Utc::now);
+            }
+            Ok(())
+        }
+
+        async fn revoke_by_user_id(&self, user_id: &UserId, keep_family: Option<&SessionId>) -> Result<(), DomainError> {
+            for session in self.sessions.lock().unwrap().iter_mut()
+                .filter(|session| session.user_id == *user_id && Some(&session.family_id) != keep_family)
+            {
+                session.revoked_at.get_or_insert_with(Utc::now);
+            }
+            Ok(())
+        }
+
+        async fn revoke_by_client_id(&self, user_id: &UserId, client_id: &str) -> Result<(), DomainError> {
+            for session in self.sessions.lock().unwrap().iter_mut().filter(|session| {
+                session.user_id == *user_id && session.delegation.as_ref().is_some_and(|d| d.client_id == client_id)
+            }) {
+                session.revoked_at.get_or_insert_with(Utc::now);
+            }
+            Ok(())
+        }
+
+        async fn delete(&self, id: &SessionId) -> Result<(), DomainError> {
+            self.sessions.lock().unwrap().retain(|session| session.id != *id);
+            Ok(())
+        }
+
+        async fn delete_by_user_id(&self, user_id: &UserId) -> Result<(), DomainError> {
+            self.sessions.lock().unwrap().retain(|session| session.user_id != *user_id);
+            Ok(())
+        }
+    }
+
+    fn service(refresh_token_ttl: Duration) -> (Arc<InMemorySessions>, SessionService<InMemorySessions>) {
+        let secret = b"0123456789abcdef0123456789abcdef";
+        let key = TokenKey {
+            kid: "test".to_string(),
+            algorithm: Algorithm::HS256,
+            encoding_key: Some(EncodingKey::from_secret(secret)),
+            decoding_key: DecodingKey::from_secret(secret),
+            jwk: None,
+        };
+        let token_service = TokenService::new(vec![key], "test".to_string(), "auth-nz".to_string(), Duration::minutes(15))
+            .unwrap();
+        let sessions = Arc::new(InMemorySessions::default());
+        let service = SessionService::new(sessions.clone(), Arc::new(token_service), refresh_token_ttl);
+        (sessions, service)
+    }
+
+    fn grants() -> AccessGrants {
+        AccessGrants {
+            roles: vec!["user".to_string()],
+            permissions: vec!["profile:read".to_string()],
+            organization_id: None,
+        }
+    }
+
+    fn device() -> DeviceInfo {
+        DeviceInfo::new(None, None)
+    }
+
+    #[tokio::test]
+    async fn reusing_a_refresh_token_revokes_the_family() {
+        let (sessions, service) = service(Duration::days(30));
+        let user_id = UserId::new();
+        let issued = service.start(&user_id, &grants(), device()).await.unwrap();
+
+        let redeemed = service.redeem(&issued.refresh_token, None).await.unwrap();
+        let successor = service.rotate(&redeemed, &grants(), device()).await.unwrap();
+        assert!(!sessions.get(&successor.session.id).is_revoked());
+
+        let reused = service.redeem(&issued.refresh_token, None).await;
+
+        assert_eq!(reused.unwrap_err(), DomainError::RefreshTokenReused);
+        assert!(sessions.get(&successor.session.id).is_revoked());
+        assert_eq!(
+            service.redeem(&successor.refresh_token, None).await.unwrap_err(),
+            DomainError::RefreshTokenReused
+        );
+    }
+
+    #[tokio::test]
+    async fn refresh_token_only_works_for_its_client() {
+        let (sessions, service) = service(Duration::days(30));
+        let delegation = OAuthDelegation {
+            client_id: "app-a".to_string(),
+            scopes: Scopes::parse("profile:read").unwrap(),
+        };
+        let issued = service.start_delegated(&UserId::new(), &grants(), device(), delegation).await.unwrap();
+
+        assert_eq!(
+            service.redeem(&issued.refresh_token, Some("app-b")).await.unwrap_err(),
+            DomainError::InvalidToken
+        );
+        assert_eq!(service.redeem(&issued.refresh_token, None).await.unwrap_err(), DomainError::InvalidToken);
+        // A rejected attempt does not consume the token
+        assert!(!sessions.get(&issued.session.id).is_revoked());
+        assert!(service.redeem(&issued.refresh_token, Some("app-a")).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn expired_refresh_token_is_rejected() {
+        let (_, service) = service(Duration::seconds(-1));
+        let issued = service.start(&UserId::new(), &grants(), device()).await.unwrap();
+
+        assert_eq!(service.redeem(&issued.refresh_token, None).await.unwrap_err(), DomainError::SessionExpired);
+    }
+}
@@ -0,0 +1,191 @@
+use std::collections::HashMap;
+use chrono::{DateTime, Duration, Utc};
+use jsonwebtoken::errors::ErrorKind;
+use jsonwebtoken::jwk::{Jwk, JwkSet};
+use jsonwebtoken::{decode, decode_header, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use serde::{Deserialize, Serialize};
+use crate::domain::services::AccessGrants;
+use crate::domain::value_objects::{OAuthDelegation, Scopes, SessionId, Token, UserId};
+use crate::domain::errors::DomainError;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccessTokenClaims {
+    pub iss: String,
+    pub sub: String,
+    /// Absent from client credentials tokens, which have no user session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sid: Option<String>,
+    pub roles: Vec<String>,
+    #[serde(default)]
+    pub permissions: Vec<String>,
+    /// Set on tokens issued to an OAuth client.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    /// Set on tokens scoped to an organization, whose roles and permissions
+    /// are the ones the user holds there.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub org_id: Option<String>,
+    /// When the user signed in to the session, carried across refreshes.
+    /// Absent from client credentials tokens.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth_time: Option<i64>,
+    pub iat: i64,
+    pub exp: i64,
+    pub jti: String,
+}
+
+pub struct TokenKey {
+    pub kid: String,
+    pub algorithm: Algorithm,
+    /// `None` for retired keys that are only kept to verify outstanding tokens.
+    pub encoding_key: Option<EncodingKey>,
+    pub decoding_key: DecodingKey,
+    /// Public key published through JWKS; `None` for symmetric keys.
+    pub jwk: Option<Jwk>,
+}
+
+pub struct TokenService {
+    keys: HashMap<String, TokenKey>,
+    signing_kid: String,
+    issuer: String,
+    access_token_ttl: Duration,
+}
+
+impl TokenService {
+    pub fn new(
+        keys: Vec<TokenKey>,
+        signing_kid: String,
+        issuer: String,
+        access_token_ttl: Duration,
+    ) -> Result<Self, DomainError> {
+        let keys: HashMap<String, TokenKey> = keys.into_iter()
+            .map(|key| (key.kid.clone(), key))
+            .collect();
+
+        match keys.get(&signing_kid) {
+            Some(key) if key.encoding_key.is_some() => {}
+            _ => {
+                return Err(DomainError::TokenSigning(format!(
+                    "No private key available for signing key id {}",
+                    signing_kid
+                )))
+            }
+        }
+
+        Ok(Self {
+            keys,
+            signing_kid,
+            issuer,
+            access_token_ttl,
+        })
+    }
+
+    pub fn issuer(&self) -> &str {
+        &self.issuer
+    }
+
+    pub fn signing_algorithm(&self) -> Algorithm {
+        self.keys[&self.signing_kid].algorithm
+    }
+
+    /// Public keys of every loaded key pair, including retired ones, so that
+    /// tokens they signed can still be verified downstream.
+    pub fn jwks(&self) -> JwkSet {
+        let mut keys: Vec<Jwk> = self.keys.values()
+            .filter_map(|key| key.jwk.clone())
+            .collect();
+        keys.sort_by(|a, b| a.common.key_id.cmp(&b.common.key_id));
+        JwkSet { keys }
+    }
+
+    pub fn access_token_ttl(&self) -> Duration {
+        self.access_token_ttl
+    }
+
+    pub fn issue_access_token(
+        &self,
+        user_id: &UserId,
+        session_id: &SessionId,
+        grants: &AccessGrants,
+        delegation: Option<&OAuthDelegation>,
+        authenticated_at: DateTime<Utc>,
+    ) -> Result<(Token, DateTime<Utc>), DomainError> {
+        let issued_at = Utc::now();
+        let expires_at = issued_at + self.access_token_ttl;
+        self.sign(AccessTokenClaims {
+            iss: self.issuer.clone(),
+            sub: user_id.as_uuid().to_string(),
+            sid: Some(session_id.as_uuid().to_string()),
+            roles: grants.roles.clone(),
+            permissions: grants.permissions.clone(),
+            client_id: delegation.map(|d| d.client_id.clone()),
+            scope: delegation.map(|d| d.scopes.to_string()),
+            org_id: grants.organization_id.map(|id| id.as_uuid().to_string()),
+            auth_time: Some(authenticated_at.timestamp()),
+            iat: issued_at.timestamp(),
+            exp: expires_at.timestamp(),
+            jti: uuid::Uuid::new_v4().to_string(),
+        })
+        .map(|token| (token, expires_at))
+    }
+
+    /// Token for a client acting on its own behalf (client credentials grant).
+    /// The client is the subject and its scopes are its permissions.
+    pub fn issue_client_token(&self, client_id: &str, scopes: &Scopes) -> Result<(Token, DateTime<Utc>), DomainError> {
+        let issued_at = Utc::now();
+        let expires_at = issued_at + self.access_token_ttl;
+        self.sign(AccessTokenClaims {
+            iss: self.issuer.clone(),
+            sub: client_id.to_string(),
+            sid: None,
+            roles: Vec::new(),
+            permissions: scopes.to_vec(),
+            client_id: Some(client_id.to_string()),
+            scope: Some(scopes.to_string()),
+            org_id: None,
+            auth_time: None,
+            iat: issued_at.timestamp(),
+            exp: expires_at.timestamp(),
+            jti: uuid::Uuid::new_v4().to_string(),
+        })
+        .map(|token| (token, expires_at))
+    }
+
+    fn sign(&self, claims: AccessTokenClaims) -> Result<Token, DomainError> {
+        let key = &self.keys[&self.signing_kid];
+        let encoding_key = key.encoding_key.as_ref()
+            .ok_or_else(|| DomainError::TokenSigning(format!("Key {} cannot sign", key.kid)))?;
+
+        let mut header = Header::new(key.algorithm);
+        header.kid = Some(key.kid.clone());
+
+        encode(&header, &claims, encoding_key)
+            .map(Token::new)
+            .map_err(|e| DomainError::TokenSigning(e.to_string()))
+    }
+
+    /// Verifies a token with the key named by its `kid` header, so tokens
+    /// signed by a rotated-out key stay valid while that key is still loaded.
+    pub fn verify_access_token(&self, token: &Token) -> Result<AccessTokenClaims, DomainError> {
+        let header = decode_header(token.as_str()).map_err(|_| DomainError::InvalidToken)?;
+        let key = header.kid.as_ref()
+            .and_then(|kid| self.keys.get(kid))
+            .ok_or(DomainError::InvalidToken)?;
+
+        if header.alg != key.algorithm {
+            return Err(DomainError::InvalidToken);
+        }
+
+        let mut validation = Validation::new(key.algorithm);
+        validation.set_issuer(&[&self.issuer]);
+
+        decode::<AccessTokenClaims>(token.as_str(), &key.decoding_key, &validation)
+            .map(|data| data.claims)
+            .map_err(|e| match e.kind() {
+                ErrorKind::ExpiredSignature => DomainError::TokenExpired,
+                _ => DomainError::InvalidToken,
+            })
+    }
+}
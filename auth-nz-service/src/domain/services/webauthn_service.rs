@@ -0,0 +1,768 @@
+use std::sync::Arc;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{Duration, Utc};
+use ciborium::Value;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+use uuid::Uuid;
+use x509_cert::der::asn1::{ObjectIdentifier, OctetString};
+use x509_cert::der::Decode;
+use x509_cert::ext::pkix::BasicConstraints;
+use x509_cert::Certificate;
+use crate::domain::entities::passkey::Passkey;
+use crate::domain::entities::webauthn_challenge::WebAuthnChallenge;
+use crate::domain::repositories::PasskeyRepository;
+use crate::domain::value_objects::{AuthenticatorData, CredentialPublicKey, PasskeyId, UserId, WebAuthnCeremony};
+use crate::domain::errors::DomainError;
+
+const BASIC_CONSTRAINTS_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.5.29.19");
+/// FIDO extension carrying the authenticator model in attestation certificates.
+const AAGUID_EXTENSION_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.6.1.4.1.45724.1.1.4");
+
+/// A registration ceremony to hand to `navigator.credentials.create()`.
+pub struct RegistrationChallenge {
+    pub challenge: WebAuthnChallenge,
+    pub rp_id: String,
+    pub rp_name: String,
+    /// Credentials the user already has, so the same authenticator is not registered twice.
+    pub excluded_credentials: Vec<Vec<u8>>,
+}
+
+/// A login ceremony to hand to `navigator.credentials.get()`.
+pub struct AuthenticationChallenge {
+    pub challenge: WebAuthnChallenge,
+    pub rp_id: String,
+    /// Empty when any discoverable credential may answer.
+    pub allowed_credentials: Vec<Vec<u8>>,
+}
+
+/// The browser's answer to a registration ceremony.
+pub struct AttestationResponse {
+    pub client_data_json: Vec<u8>,
+    pub attestation_object: Vec<u8>,
+}
+
+/// The browser's answer to a login ceremony.
+pub struct AssertionResponse {
+    pub credential_id: Vec<u8>,
+    pub client_data_json: Vec<u8>,
+    pub authenticator_data: Vec<u8>,
+    pub signature: Vec<u8>,
+    /// The user id the credential was registered with; always present for
+    /// discoverable credentials.
+    pub user_handle: Option<Vec<u8>>,
+}
+
+/// The CBOR attestation object returned on registration.
+struct AttestationObject {
+    format: String,
+    statement: Vec<(Value, Value)>,
+    auth_data: Vec<u8>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ClientData {
+    #[serde(rename = "type")]
+    ceremony_type: String,
+    challenge: String,
+    origin: String,
+    #[serde(rename = "crossOrigin", default)]
+    cross_origin: bool,
+}
+
+/// Runs the WebAuthn relying party side of passkey registration and login.
+/// Both ceremonies require user verification, so a passkey login counts as
+/// multi-factor on its own.
+///
+/// Attestation statements in the `none` and `packed` formats are accepted.
+/// Packed certificates are checked for well-formedness and their signature,
+/// but not chained to a vendor root; the format is stored with the passkey so
+/// a stricter policy can be applied later.
+pub struct WebAuthnService<R: PasskeyRepository> {
+    passkey_repository: Arc<R>,
+    rp_id: String,
+    rp_name: String,
+    origins: Vec<String>,
+    challenge_ttl: Duration,
+}
+
+impl<R: PasskeyRepository> WebAuthnService<R> {
+    pub fn new(
+        passkey_repository: Arc<R>,
+        rp_id: String,
+        rp_name: String,
+        origins: Vec<String>,
+        challenge_ttl: Duration,
+    ) -> Self {
+        Self {
+            passkey_repository,
+            rp_id,
+            rp_name,
+            origins,
+            challenge_ttl,
+        }
+    }
+
+    pub async fn start_registration(&self, user_id: &UserId) -> Result<RegistrationChallenge, DomainError> {
+        let challenge = WebAuthnChallenge::new(
+            WebAuthnCeremony::Registration,
+            Some(*user_id),
+            Utc::now() + self.challenge_ttl,
+        );
+        self.passkey_repository.create_challenge(&challenge).await?;
+
+        let excluded_credentials = self.passkey_repository.find_by_user_id(user_id).await?
+            .into_iter()
+            .map(|passkey| passkey.credential_id)
+            .collect();
+
+        Ok(RegistrationChallenge {
+            challenge,
+            rp_id: self.rp_id.clone(),
+            rp_name: self.rp_name.clone(),
+            excluded_credentials,
+        })
+    }
+
+    /// Verifies the attestation (WebAuthn Level 2, section 7.1) and stores the
+    /// new passkey.
+    pub async fn finish_registration(
+        &self,
+        user_id: &UserId,
+        challenge_id: &Uuid,
+        name: String,
+        response: &AttestationResponse,
+    ) -> Result<Passkey, DomainError> {
+        let challenge = self.take_challenge(challenge_id, WebAuthnCeremony::Registration).await?;
+        if challenge.user_id != Some(*user_id) {
+            return Err(DomainError::InvalidWebAuthnChallenge);
+        }
+        self.verify_client_data(&challenge, &response.client_data_json, DomainError::InvalidAttestation)?;
+
+        let attestation = parse_attestation_object(&response.attestation_object)?;
+        let auth_data = AuthenticatorData::parse(&attestation.auth_data)?;
+        self.verify_authenticator_data(&auth_data, DomainError::InvalidAttestation)?;
+        let credential = auth_data.attested_credential.clone()
+            .ok_or_else(|| DomainError::InvalidAttestation("No credential in authenticator data".to_string()))?;
+        let public_key = CredentialPublicKey::from_cose(&credential.public_key)?;
+
+        let signed = signed_data(&attestation.auth_data, &response.client_data_json);
+        match attestation.format.as_str() {
+            "none" if attestation.statement.is_empty() => {}
+            "none" => {
+                return Err(DomainError::InvalidAttestation("Statement of format none must be empty".to_string()));
+            }
+            "packed" => verify_packed(&attestation.statement, &signed, &public_key, &credential.aaguid)?,
+            other => {
+                return Err(DomainError::InvalidAttestation(format!("Unsupported attestation format {}", other)));
+            }
+        }
+
+        let passkey = Passkey::new(*user_id, name, credential, public_key.algorithm(), auth_data.sign_count, attestation.format);
+        self.passkey_repository.create(&passkey).await?;
+
+        Ok(passkey)
+    }
+
+    /// Starts a login. With `user_id`, only that user's passkeys are offered;
+    /// without it, the authenticator picks a discoverable credential.
+    pub async fn start_authentication(&self, user_id: Option<&UserId>) -> Result<AuthenticationChallenge, DomainError> {
+        let challenge = WebAuthnChallenge::new(
+            WebAuthnCeremony::Authentication,
+            user_id.copied(),
+            Utc::now() + self.challenge_ttl,
+        );
+        self.passkey_repository.create_challenge(&challenge).await?;
+
+        let allowed_credentials = match user_id {
+            Some(user_id) => self.passkey_repository.find_by_user_id(user_id).await?
+                .into_iter()
+                .map(|passkey| passkey.credential_id)
+                .collect(),
+            None => Vec::new(),
+        };
+
+        Ok(AuthenticationChallenge {
+            challenge,
+            rp_id: self.rp_id.clone(),
+            allowed_credentials,
+        })
+    }
+
+    /// Verifies the assertion (WebAuthn Level 2, section 7.2) and returns the
+    /// passkey that signed it, with its counter advanced.
+    pub async fn finish_authentication(&self, challenge_id: &Uuid, response: &AssertionResponse) -> Result<Passkey, DomainError> {
+        let challenge = self.take_challenge(challenge_id, WebAuthnCeremony::Authentication).await?;
+
+        let mut passkey = self.passkey_repository.find_by_credential_id(&response.credential_id).await?
+            .ok_or_else(|| DomainError::InvalidAssertion("Unknown credential".to_string()))?;
+        if challenge.user_id.is_some_and(|user_id| user_id != passkey.user_id) {
+            return Err(DomainError::InvalidAssertion("Credential belongs to another user".to_string()));
+        }
+        if response.user_handle.as_ref().is_some_and(|handle| handle.as_slice() != passkey.user_id.as_uuid().as_bytes()) {
+            return Err(DomainError::InvalidAssertion("User handle does not match the credential".to_string()));
+        }
+
+        self.verify_client_data(&challenge, &response.client_data_json, DomainError::InvalidAssertion)?;
+        let auth_data = AuthenticatorData::parse(&response.authenticator_data)?;
+        self.verify_authenticator_data(&auth_data, DomainError::InvalidAssertion)?;
+
+        let public_key = CredentialPublicKey::from_cose(&passkey.public_key)
+            .map_err(|e| DomainError::Repository(format!("Stored passkey {} is unusable: {}", passkey.id.as_uuid(), e)))?;
+        let signed = signed_data(&response.authenticator_data, &response.client_data_json);
+        if !public_key.verify(&signed, &response.signature) {
+            return Err(DomainError::InvalidAssertion("Signature verification failed".to_string()));
+        }
+
+        let used_at = Utc::now();
+        if !passkey.accepts_sign_count(auth_data.sign_count)
+            || !self.passkey_repository.record_use(&passkey.id, auth_data.sign_count, used_at).await?
+        {
+            tracing::warn!(
+                "Signature counter of passkey {} went from {} to {}, it may have been cloned",
+                passkey.id.as_uuid(),
+                passkey.sign_count,
+                auth_data.sign_count
+            );
+            return Err(DomainError::InvalidAssertion("Signature counter did not increase".to_string()));
+        }
+        passkey.sign_count = auth_data.sign_count;
+        passkey.last_used_at = Some(used_at);
+
+        Ok(passkey)
+    }
+
+    pub async fn list(&self, user_id: &UserId) -> Result<Vec<Passkey>, DomainError> {
+        self.passkey_repository.find_by_user_id(user_id).await
+    }
+
+    /// Removes one of the user's passkeys and returns it.
+    pub async fn remove(&self, user_id: &UserId, id: &PasskeyId) -> Result<Passkey, DomainError> {
+        let passkey = self.passkey_repository.find_by_id(id).await?
+            .filter(|passkey| passkey.user_id == *user_id)
+            .ok_or(DomainError::PasskeyNotFound)?;
+        self.passkey_repository.delete(id).await?;
+        Ok(passkey)
+    }
+
+    async fn take_challenge(&self, id: &Uuid, ceremony: WebAuthnCeremony) -> Result<WebAuthnChallenge, DomainError> {
+        self.passkey_repository.consume_challenge(id).await?
+            .filter(|challenge| challenge.ceremony == ceremony && !challenge.is_expired())
+            .ok_or(DomainError::InvalidWebAuthnChallenge)
+    }
+
+    fn verify_client_data(
+        &self,
+        challenge: &WebAuthnChallenge,
+        client_data_json: &[u8],
+        error: fn(String) -> DomainError,
+    ) -> Result<(), DomainError> {
+        let client_data: ClientData = serde_json::from_slice(client_data_json)
+            .map_err(|_| DomainError::ValidationError("Malformed client data".to_string()))?;
+
+        if client_data.ceremony_type != challenge.ceremony.client_data_type() {
+            return Err(error(format!("Unexpected client data type {}", client_data.ceremony_type)));
+        }
+        let received = URL_SAFE_NO_PAD.decode(client_data.challenge.trim_end_matches('='))
+            .map_err(|_| error("Malformed challenge".to_string()))?;
+        if !bool::from(received.ct_eq(&challenge.challenge)) {
+            return Err(error("Challenge does not match".to_string()));
+        }
+        if !self.origins.contains(&client_data.origin) {
+            return Err(error(format!("Unexpected origin {}", client_data.origin)));
+        }
+        if client_data.cross_origin {
+            return Err(error("Cross-origin ceremonies are not accepted".to_string()));
+        }
+        Ok(())
+    }
+
+    fn verify_authenticator_data(&self, auth_data: &AuthenticatorData, error: fn(String) -> DomainError) -> Result<(), DomainError> {
+        if auth_data.rp_id_hash[..] != Sha256::digest(self.rp_id.as_bytes())[..] {
+            return Err(error("Credential is scoped to another relying party".to_string()));
+        }
+        if !auth_data.user_present() {
+            return Err(error("User presence was not confirmed".to_string()));
+        }
+        if !auth_data.user_verified() {
+            return Err(error("User verification is required".to_string()));
+        }
+        Ok(())
+    }
+}
+
+/// What attestation and assertion signatures cover: the authenticator data
+/// followed by the SHA-256 of the client data.
+fn signed_data(authenticator_data: &[u8], client_data_json: &[u8]) -> Vec<u8> {
+    let mut signed = authenticator_data.to_vec();
+    signed.extend_from_slice(&Sha256::digest(client_data_json));
+    signed
+}
+
+fn parse_attestation_object(bytes: &[u8]) -> Result<AttestationObject, DomainError> {
+    let malformed = || DomainError::ValidationError("Malformed attestation object".to_string());
+
+    let Value::Map(entries) = ciborium::de::from_reader::<Value, _>(bytes).map_err(|_| malformed())? else {
+        return Err(malformed());
+    };
+
+    let (mut format, mut statement, mut auth_data) = (None, None, None);
+    for (key, value) in entries {
+        match (key.as_text(), value) {
+            (Some("fmt"), Value::Text(value)) => format = Some(value),
+            (Some("attStmt"), Value::Map(value)) => statement = Some(value),
+            (Some("authData"), Value::Bytes(value)) => auth_data = Some(value),
+            _ => {}
+        }
+    }
+
+    match (format, statement, auth_data) {
+        (Some(format), Some(statement), Some(auth_data)) => Ok(AttestationObject { format, statement, auth_data }),
+        _ => Err(malformed()),
+    }
+}
+
+/// Packed attestation (WebAuthn Level 2, section 8.2): signed by an
+/// attestation certificate, or by the credential itself (self attestation).
+fn verify_packed(
+    statement: &[(Value, Value)],
+    signed: &[u8],
+    credential_key: &CredentialPublicKey,
+    aaguid: &Uuid,
+) -> Result<(), DomainError> {
+    let invalid = |message: &str| DomainError::InvalidAttestation(message.to_string());
+    let field = |name: &str| statement.iter()
+        .find(|(key, _)| key.as_text() == Some(name))
+        .map(|(_, value)| value);
+
+    let algorithm = field("alg")
+        .and_then(Value::as_integer)
+        .and_then(|value| i64::try_from(value).ok())
+        .ok_or_else(|| invalid("Packed statement has no alg"))?;
+    let signature = field("sig")
+        .and_then(Value::as_bytes)
+        .ok_or_else(|| invalid("Packed statement has no sig"))?;
+
+    let Some(x5c) = field("x5c") else {
+        if algorithm != credential_key.algorithm() {
+            return Err(invalid("Self attestation algorithm does not match the credential"));
+        }
+        return match credential_key.verify(signed, signature) {
+            true => Ok(()),
+            false => Err(invalid("Attestation signature verification failed")),
+        };
+    };
+
+    let certificate = x5c.as_array()
+        .and_then(|chain| chain.first())
+        .and_then(Value::as_bytes)
+        .ok_or_else(|| invalid("Empty attestation certificate chain"))?;
+    let certificate = Certificate::from_der(certificate)
+        .map_err(|_| invalid("Malformed attestation certificate"))?;
+    verify_attestation_certificate(&certificate, aaguid)?;
+
+    let key = CredentialPublicKey::from_subject_public_key(
+        algorithm,
+        certificate.tbs_certificate.subject_public_key_info.subject_public_key.raw_bytes(),
+    )?;
+    match key.verify(signed, signature) {
+        true => Ok(()),
+        false => Err(invalid("Attestation signature verification failed")),
+    }
+}
+
+/// Requirements on packed attestation certificates (section 8.2.1).
+fn verify_attestation_certificate(certificate: &Certificate, aaguid: &Uuid) -> Result<(), DomainError> {
+    let invalid = |message: &str| DomainError::InvalidAttestation(message.to_string());
+    let tbs = &certificate.tbs_certificate;
+
+    if tbs.version != x509_cert::Version::V3 {
+        return Err(invalid("Attestation certificate must be X.509 v3"));
+    }
+    if !tbs.subject.to_string().contains("OU=Authenticator Attestation") {
+        return Err(invalid("Attestation certificate subject lacks OU=Authenticator Attestation"));
+    }
+
+    for extension in tbs.extensions.iter().flatten() {
+        if extension.extn_id == BASIC_CONSTRAINTS_OID {
+            let constraints = BasicConstraints::from_der(extension.extn_value.as_bytes())
+                .map_err(|_| invalid("Malformed basic constraints"))?;
+            if constraints.ca {
+                return Err(invalid("Attestation certificate must not be a CA"));
+            }
+        } else if extension.extn_id == AAGUID_EXTENSION_OID {
+            if extension.critical {
+                return Err(invalid("AAGUID extension must not be critical"));
+            }
+            let value = OctetString::from_der(extension.extn_value.as_bytes())
+                .map_err(|_| invalid("Malformed AAGUID extension"))?;
+            if value.as_bytes() != aaguid.as_bytes() {
+                return Err(invalid("Attestation certificate is for another authenticator model"));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use async_trait::async_trait;
+    use chrono::DateTime;
+    use super::*;
+    use crate::domain::value_objects::AttestedCredential;
+
+    // Fixed vectors for rp id `localhost` and origin `http://localhost:3000`,
+    // made with an ES256 credential whose attestation key is certified by a
+    // test root. Registration answers the challenge 0x01..=0x20, login 0x21..=0x40.
+    const RP_ID: &str = "localhost";
+    const ORIGIN: &str = "http://localhost:3000";
+    const USER_ID: &str = "4a1f0c2e-5b6d-4e7f-8a9b-0c1d2e3f4a5b";
+    const AAGUID: &str = "6e7a7c4a-1b2c-4d3e-8f90-a1b2c3d4e5f6";
+    const CREDENTIAL_ID: &str = "c7ed1d0000000000000000000000beef";
+    const REGISTRATION_CLIENT_DATA: &str = r#"{"type":"webauthn.create","challenge":"AQIDBAUGBwgJCgsMDQ4PEBESExQVFhcYGRobHB0eHyA","origin":"http://localhost:3000","crossOrigin":false}"#;
+    const ASSERTION_CLIENT_DATA: &str = r#"{"type":"webauthn.get","challenge":"ISIjJCUmJygpKissLS4vMDEyMzQ1Njc4OTo7PD0-P0A","origin":"http://localhost:3000","crossOrigin":false}"#;
+    /// COSE_Key of the ES256 credential the vectors were made with.
+    const CREDENTIAL_PUBLIC_KEY: &str = concat!(
+        "a50102032620012158208c564006d4860f0847b0cec971529eb6ebf7772d505f9e07a7bfd2a7aa5756e42258208a2c40",
+        "fe1de71a590216befa6fa3e1f8dcb1f36f572b186371f1f99b35d44d00",
+    );
+    /// Attestation object in the `none` format, flags UP | UV | AT, counter 0.
+    const NONE_ATTESTATION: &str = concat!(
+        "a363666d74646e6f6e656761747453746d74a0686175746844617461589449960de5880e8c687434170f6476605b8fe4",
+        "aeb9a28632c7995cf3ba831d976345000000006e7a7c4a1b2c4d3e8f90a1b2c3d4e5f60010c7ed1d0000000000000000",
+        "000000beefa50102032620012158208c564006d4860f0847b0cec971529eb6ebf7772d505f9e07a7bfd2a7aa5756e422",
+        "58208a2c40fe1de71a590216befa6fa3e1f8dcb1f36f572b186371f1f99b35d44d00",
+    );
+    /// The same credential in the `packed` format, signed by a leaf attestation certificate.
+    const PACKED_ATTESTATION: &str = concat!(
+        "a363666d74667061636b65646761747453746d74a363616c67266373696758483046022100b5139520e1ac40cf34d2c2",
+        "fff2c61612f1af2c99f9c1643f0445e20ae0bd4603022100d4b97e60b45c25c5da8e7df19a025c55191631a5714417b1",
+        "a645fa8ab594460163783563815901b0308201ac30820152a00302010202021000300a06082a8648ce3d040302302031",
+        "1e301c06035504030c1554657374204174746573746174696f6e20526f6f74301e170d3235303130313030303030305a",
+        "170d3435303130313030303030305a3069310b3009060355040613025553311b3019060355040a0c1254657374204175",
+        "7468656e74696361746f7231223020060355040b0c1941757468656e74696361746f72204174746573746174696f6e31",
+        "19301706035504030c1054657374204174746573746174696f6e3059301306072a8648ce3d020106082a8648ce3d0301",
+        "07034200049d863785ce056d918ba9b8fbaa9da9562254d65a01aef651649cac9dacfe67f847d58722c6f89b52a14398",
+        "09e60655116b615397b6b8d16d38bb92aa02a19d92a3333031300c0603551d130101ff040230003021060b2b06010401",
+        "82e51c010104041204106e7a7c4a1b2c4d3e8f90a1b2c3d4e5f6300a06082a8648ce3d040302034800304502207419c0",
+        "84a72991a834ddd0ab454c69c1a8caf445e2c02cc68e6ee840f9be743c022100f8ca46b754bd8117d2bec4e17cc203f2",
+        "6e3ee8754ed9ef6ad08afee04ae44b92686175746844617461589449960de5880e8c687434170f6476605b8fe4aeb9a2",
+        "8632c7995cf3ba831d976345000000006e7a7c4a1b2c4d3e8f90a1b2c3d4e5f60010c7ed1d0000000000000000000000",
+        "beefa50102032620012158208c564006d4860f0847b0cec971529eb6ebf7772d505f9e07a7bfd2a7aa5756e42258208a",
+        "2c40fe1de71a590216befa6fa3e1f8dcb1f36f572b186371f1f99b35d44d00",
+    );
+    /// As above, but the certificate has basic constraints `CA:TRUE`.
+    const PACKED_CA_ATTESTATION: &str = concat!(
+        "a363666d74667061636b65646761747453746d74a363616c67266373696758473045022100e33cc9f523dd148a7bbbeb",
+        "91f2ad6fa72191a33249684282fea5f9fcc68e410a022033e403b38326a5b70a5c219f781a31f730df4a6b884226e048",
+        "0cab3a45eb6e8663783563815901b3308201af30820155a00302010202021001300a06082a8648ce3d0403023020311e",
+        "301c06035504030c1554657374204174746573746174696f6e20526f6f74301e170d3235303130313030303030305a17",
+        "0d3435303130313030303030305a3069310b3009060355040613025553311b3019060355040a0c125465737420417574",
+        "68656e74696361746f7231223020060355040b0c1941757468656e74696361746f72204174746573746174696f6e3119",
+        "301706035504030c1054657374204174746573746174696f6e3059301306072a8648ce3d020106082a8648ce3d030107",
+        "034200049d863785ce056d918ba9b8fbaa9da9562254d65a01aef651649cac9dacfe67f847d58722c6f89b52a1439809",
+        "e60655116b615397b6b8d16d38bb92aa02a19d92a3363034300f0603551d130101ff040530030101ff3021060b2b0601",
+        "040182e51c010104041204106e7a7c4a1b2c4d3e8f90a1b2c3d4e5f6300a06082a8648ce3d040302034800304502200a",
+        "6252068768885cc06b8e057656486740eb6d1696a0457b33dbdf7e7e05aaa70221008afaecaaa75f81efaea50efc7a9c",
+        "4069328e06fff9abfb1939b47d9594f618c3686175746844617461589449960de5880e8c687434170f6476605b8fe4ae",
+        "b9a28632c7995cf3ba831d976345000000006e7a7c4a1b2c4d3e8f90a1b2c3d4e5f60010c7ed1d000000000000000000",
+        "0000beefa50102032620012158208c564006d4860f0847b0cec971529eb6ebf7772d505f9e07a7bfd2a7aa5756e42258",
+        "208a2c40fe1de71a590216befa6fa3e1f8dcb1f36f572b186371f1f99b35d44d00",
+    );
+    /// Authenticator data of a login, flags UP | UV, counter 5.
+    const ASSERTION_AUTHENTICATOR_DATA: &str = "49960de5880e8c687434170f6476605b8fe4aeb9a28632c7995cf3ba831d97630500000005";
+    /// Signature of the credential over the assertion.
+    const ASSERTION_SIGNATURE: &str = concat!(
+        "304402202c2ee94d6e5bdbce1081d2c1f0a160dc6d58222b9b6570981c837d5e076f36f802206bdb01ea7a645eb93ff9",
+        "9eb28dcaa195f5b575374826a44c3a627c8230770572",
+    );
+    #[derive(Default)]
+    struct InMemoryPasskeys {
+        passkeys: Mutex<Vec<Passkey>>,
+        challenges: Mutex<HashMap<Uuid, WebAuthnChallenge>>,
+    }
+
+    #[async_trait]
+    impl PasskeyRepository for InMemoryPasskeys {
+        async fn create(&self, passkey: &Passkey) -> Result<(), DomainError> {
+            self.passkeys.lock().unwrap().push(passkey.clone());
+            Ok(())
+        }
+
+        async fn find_by_id(&self, id: &PasskeyId) -> Result<Option<Passkey>, DomainError> {
+            Ok(self.passkeys.lock().unwrap().iter().find(|passkey| passkey.id == *id).cloned())
+        }
+
+        async fn find_by_credential_id(&self, credential_id: &[u8]) -> Result<Option<Passkey>, DomainError> {
+            Ok(self.passkeys.lock().unwrap().iter().find(|passkey| passkey.credential_id == credential_id).cloned())
+        }
+
+        async fn find_by_user_id(&self, user_id: &UserId) -> Result<Vec<Passkey>, DomainError> {
+            Ok(self.passkeys.lock().unwrap().iter().filter(|passkey| passkey.user_id == *user_id).cloned().collect())
+        }
+
+        async fn record_use(&self, id: &PasskeyId, sign_count: u32, used_at: DateTime<Utc>) -> Result<bool, DomainError> {
+            let mut passkeys = self.passkeys.lock().unwrap();
+            let Some(passkey) = passkeys.iter_mut().find(|passkey| passkey.id == *id) else {
+                return Ok(false);
+            };
+            if !passkey.accepts_sign_count(sign_count) {
+                return Ok(false);
+            }
+            passkey.sign_count = sign_count;
+            passkey.last_used_at = Some(used_at);
+            Ok(true)
+        }
+
+        async fn delete(&self, id: &PasskeyId) -> Result<(), DomainError> {
+            self.passkeys.lock().unwrap().retain(|passkey| passkey.id != *id);
+            Ok(())
+        }
+
+        async fn create_challenge(&self, challenge: &WebAuthnChallenge) -> Result<(), DomainError> {
+            self.challenges.lock().unwrap().insert(challenge.id, challenge.clone());
+            Ok(())
+        }
+
+        async fn consume_challenge(&self, id: &Uuid) -> Result<Option<WebAuthnChallenge>, DomainError> {
+            Ok(self.challenges.lock().unwrap().remove(id))
+        }
+    }
+
+    struct Fixture {
+        repository: Arc<InMemoryPasskeys>,
+        service: WebAuthnService<InMemoryPasskeys>,
+    }
+
+    impl Fixture {
+        fn new(rp_id: &str) -> Self {
+            let repository = Arc::new(InMemoryPasskeys::default());
+            let service = WebAuthnService::new(
+                Arc::clone(&repository),
+                rp_id.to_string(),
+                "TikTok Clone".to_string(),
+                vec![ORIGIN.to_string()],
+                Duration::minutes(5),
+            );
+            Self { repository, service }
+        }
+
+        /// Stores a challenge with the bytes the vectors answer.
+        async fn challenge(&self, ceremony: WebAuthnCeremony, challenge: Vec<u8>) -> Uuid {
+            let mut stored = WebAuthnChallenge::new(ceremony, Some(user_id()), Utc::now() + Duration::minutes(5));
+            stored.challenge = challenge;
+            self.repository.create_challenge(&stored).await.unwrap();
+            stored.id
+        }
+
+        async fn register(&self, client_data_json: &str, attestation_object: Vec<u8>) -> Result<Passkey, DomainError> {
+            let challenge_id = self.challenge(WebAuthnCeremony::Registration, (0x01..=0x20).collect()).await;
+            let response = AttestationResponse {
+                client_data_json: client_data_json.as_bytes().to_vec(),
+                attestation_object,
+            };
+            self.service.finish_registration(&user_id(), &challenge_id, "Laptop".to_string(), &response).await
+        }
+
+        /// Stores the vectors' credential as a passkey that has seen `sign_count`.
+        async fn with_passkey(self, sign_count: u32) -> Self {
+            let credential = AttestedCredential {
+                aaguid: Uuid::parse_str(AAGUID).unwrap(),
+                credential_id: bytes(CREDENTIAL_ID),
+                public_key: bytes(CREDENTIAL_PUBLIC_KEY),
+            };
+            let mut passkey = Passkey::new(user_id(), "Laptop".to_string(), credential, -7, 0, "none".to_string());
+            passkey.sign_count = sign_count;
+            self.repository.create(&passkey).await.unwrap();
+            self
+        }
+
+        async fn authenticate(&self, client_data_json: &str, authenticator_data: Vec<u8>, signature: Vec<u8>) -> Result<Passkey, DomainError> {
+            let challenge_id = self.challenge(WebAuthnCeremony::Authentication, (0x21..=0x40).collect()).await;
+            let response = AssertionResponse {
+                credential_id: bytes(CREDENTIAL_ID),
+                client_data_json: client_data_json.as_bytes().to_vec(),
+                authenticator_data,
+                signature,
+                user_handle: Some(user_id().as_uuid().as_bytes().to_vec()),
+            };
+            self.service.finish_authentication(&challenge_id, &response).await
+        }
+    }
+
+    fn user_id() -> UserId {
+        UserId::from_uuid(Uuid::parse_str(USER_ID).unwrap())
+    }
+
+    fn bytes(hex: &str) -> Vec<u8> {
+        hex::decode(hex).unwrap()
+    }
+
+    /// Re-encodes an attestation object after changing its authenticator data.
+    fn with_auth_data(attestation_object: &str, change: impl FnOnce(&mut [u8])) -> Vec<u8> {
+        let Value::Map(mut entries) = ciborium::de::from_reader::<Value, _>(bytes(attestation_object).as_slice()).unwrap() else {
+            panic!("attestation object is not a map");
+        };
+        let auth_data = entries.iter_mut()
+            .find_map(|(key, value)| match (key.as_text(), value) {
+                (Some("authData"), Value::Bytes(auth_data)) => Some(auth_data),
+                _ => None,
+            })
+            .unwrap();
+        change(auth_data.as_mut_slice());
+        let mut encoded = Vec::new();
+        ciborium::ser::into_writer(&Value::Map(entries), &mut encoded).unwrap();
+        encoded
+    }
+
+    fn without_user_verification(auth_data: &mut [u8]) {
+        auth_data[32] &= !0x04;
+    }
+
+    fn assert_rejected<T: std::fmt::Debug>(result: Result<T, DomainError>, reason: &str) {
+        match result {
+            Err(DomainError::InvalidAttestation(message) | DomainError::InvalidAssertion(message)) => {
+                assert!(message.contains(reason), "expected {:?}, got {:?}", reason, message)
+            }
+            other => panic!("expected rejection {:?}, got {:?}", reason, other),
+        }
+    }
+
+    #[tokio::test]
+    async fn registers_a_passkey_with_none_attestation() {
+        let fixture = Fixture::new(RP_ID);
+
+        let passkey = fixture.register(REGISTRATION_CLIENT_DATA, bytes(NONE_ATTESTATION)).await.unwrap();
+
+        assert_eq!(passkey.credential_id, bytes(CREDENTIAL_ID));
+        assert_eq!(passkey.public_key, bytes(CREDENTIAL_PUBLIC_KEY));
+        assert_eq!(passkey.aaguid, Uuid::parse_str(AAGUID).unwrap());
+        assert_eq!(passkey.algorithm, -7);
+        assert_eq!(passkey.sign_count, 0);
+        assert_eq!(passkey.attestation_format, "none");
+        assert_eq!(fixture.repository.passkeys.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn registers_a_passkey_with_packed_attestation() {
+        let fixture = Fixture::new(RP_ID);
+
+        let passkey = fixture.register(REGISTRATION_CLIENT_DATA, bytes(PACKED_ATTESTATION)).await.unwrap();
+
+        assert_eq!(passkey.attestation_format, "packed");
+        assert_eq!(passkey.credential_id, bytes(CREDENTIAL_ID));
+    }
+
+    #[tokio::test]
+    async fn rejects_a_packed_certificate_flagged_as_a_ca() {
+        let fixture = Fixture::new(RP_ID);
+
+        assert_rejected(fixture.register(REGISTRATION_CLIENT_DATA, bytes(PACKED_CA_ATTESTATION)).await, "must not be a CA");
+    }
+
+    #[tokio::test]
+    async fn rejects_a_packed_signature_over_other_client_data() {
+        let fixture = Fixture::new(RP_ID);
+        let client_data = REGISTRATION_CLIENT_DATA.replace(r#""crossOrigin":false"#, r#""crossOrigin":false,"other":1"#);
+
+        assert_rejected(fixture.register(&client_data, bytes(PACKED_ATTESTATION)).await, "Attestation signature verification failed");
+        assert!(fixture.repository.passkeys.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn rejects_registration_from_another_origin() {
+        let fixture = Fixture::new(RP_ID);
+        let client_data = REGISTRATION_CLIENT_DATA.replace(ORIGIN, "https://attacker.example.com");
+
+        assert_rejected(fixture.register(&client_data, bytes(NONE_ATTESTATION)).await, "Unexpected origin");
+    }
+
+    #[tokio::test]
+    async fn rejects_registration_for_another_relying_party() {
+        let fixture = Fixture::new("example.com");
+
+        assert_rejected(fixture.register(REGISTRATION_CLIENT_DATA, bytes(NONE_ATTESTATION)).await, "another relying party");
+    }
+
+    #[tokio::test]
+    async fn rejects_registration_without_user_verification() {
+        let fixture = Fixture::new(RP_ID);
+        let attestation = with_auth_data(NONE_ATTESTATION, without_user_verification);
+
+        assert_rejected(fixture.register(REGISTRATION_CLIENT_DATA, attestation).await, "User verification is required");
+    }
+
+    #[tokio::test]
+    async fn authenticates_with_a_valid_assertion() {
+        let fixture = Fixture::new(RP_ID).with_passkey(0).await;
+
+        let passkey = fixture
+            .authenticate(ASSERTION_CLIENT_DATA, bytes(ASSERTION_AUTHENTICATOR_DATA), bytes(ASSERTION_SIGNATURE))
+            .await
+            .unwrap();
+
+        assert_eq!(passkey.sign_count, 5);
+        assert!(passkey.last_used_at.is_some());
+        assert_eq!(fixture.repository.passkeys.lock().unwrap()[0].sign_count, 5);
+    }
+
+    #[tokio::test]
+    async fn rejects_a_signature_counter_that_goes_backwards() {
+        let fixture = Fixture::new(RP_ID).with_passkey(9).await;
+
+        let result = fixture
+            .authenticate(ASSERTION_CLIENT_DATA, bytes(ASSERTION_AUTHENTICATOR_DATA), bytes(ASSERTION_SIGNATURE))
+            .await;
+
+        assert_rejected(result, "Signature counter did not increase");
+        assert_eq!(fixture.repository.passkeys.lock().unwrap()[0].sign_count, 9);
+    }
+
+    #[tokio::test]
+    async fn rejects_a_tampered_assertion_signature() {
+        let fixture = Fixture::new(RP_ID).with_passkey(0).await;
+        let mut signature = bytes(ASSERTION_SIGNATURE);
+        let last = signature.len() - 1;
+        signature[last] ^= 0x01;
+
+        let result = fixture.authenticate(ASSERTION_CLIENT_DATA, bytes(ASSERTION_AUTHENTICATOR_DATA), signature).await;
+
+        assert_rejected(result, "Signature verification failed");
+    }
+
+    #[tokio::test]
+    async fn rejects_an_assertion_from_another_origin() {
+        let fixture = Fixture::new(RP_ID).with_passkey(0).await;
+        let client_data = ASSERTION_CLIENT_DATA.replace(ORIGIN, "https://attacker.example.com");
+
+        let result = fixture.authenticate(&client_data, bytes(ASSERTION_AUTHENTICATOR_DATA), bytes(ASSERTION_SIGNATURE)).await;
+
+        assert_rejected(result, "Unexpected origin");
+    }
+
+    #[tokio::test]
+    async fn rejects_an_assertion_with_another_rp_id_hash() {
+        let fixture = Fixture::new(RP_ID).with_passkey(0).await;
+        let mut authenticator_data = bytes(ASSERTION_AUTHENTICATOR_DATA);
+        authenticator_data[..32].copy_from_slice(&Sha256::digest(b"example.com"));
+
+        let result = fixture.authenticate(ASSERTION_CLIENT_DATA, authenticator_data, bytes(ASSERTION_SIGNATURE)).await;
+
+        assert_rejected(result, "another relying party");
+    }
+
+    #[tokio::test]
+    async fn rejects_an_assertion_without_user_verification() {
+        let fixture = Fixture::new(RP_ID).with_passkey(0).await;
+        let mut authenticator_data = bytes(ASSERTION_AUTHENTICATOR_DATA);
+        without_user_verification(&mut authenticator_data);
+
+        let result = fixture.authenticate(ASSERTION_CLIENT_DATA, authenticator_data, bytes(ASSERTION_SIGNATURE)).await;
+
+        assert_rejected(result, "User verification is required");
+    }
+}
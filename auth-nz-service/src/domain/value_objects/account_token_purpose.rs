@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+/// What a single-use account token may be redeemed for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AccountTokenPurpose {
+    EmailVerification,
+    PasswordReset,
+    MagicLink,
+}
+
+impl AccountTokenPurpose {
+    pub fn as_str(&self) -> &str {
+        match self {
+            AccountTokenPurpose::EmailVerification => "email_verification",
+            AccountTokenPurpose::PasswordReset => "password_reset",
+            AccountTokenPurpose::MagicLink => "magic_link",
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use rand::rngs::OsRng;
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+
+const SCHEME: &str = "ak_";
+const PREFIX_LEN: usize = 12;
+
+/// Raw API key of a service account, `ak_<prefix>_<secret>`, shown once when
+/// issued. The prefix identifies the key and may be displayed; only a hash of
+/// the whole key is persisted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiKeyToken(String);
+
+impl ApiKeyToken {
+    pub fn generate() -> Self {
+        let mut prefix = [0u8; PREFIX_LEN / 2];
+        OsRng.fill_bytes(&mut prefix);
+        let mut secret = [0u8; 32];
+        OsRng.fill_bytes(&mut secret);
+        Self(format!("{}{}_{}", SCHEME, hex::encode(prefix), URL_SAFE_NO_PAD.encode(secret)))
+    }
+
+    pub fn new(value: String) -> Self {
+        Self(value)
+    }
+
+    /// Whether a presented credential is an API key rather than a JWT or refresh token.
+    pub fn is_api_key(value: &str) -> bool {
+        value.starts_with(SCHEME)
+    }
+
+    /// The lookup prefix, or `None` if the value is not shaped like a key.
+    pub fn prefix(&self) -> Option<&str> {
+        let (prefix, secret) = self.0.strip_prefix(SCHEME)?.split_once('_')?;
+        (prefix.len() == PREFIX_LEN && !secret.is_empty()).then_some(prefix)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn hash(&self) -> String {
+        hex::encode(Sha256::digest(self.0.as_bytes()))
+    }
+}
+
+impl From<ApiKeyToken> for String {
+    fn from(token: ApiKeyToken) -> Self {
+        token.0
+    }
+}
@@ -0,0 +1,79 @@
+use crate::domain::errors::DomainError;
+
+/// Security-relevant actions written to the audit log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AuditEventType {
+    LoginSucceeded,
+    LoginFailed,
+    RoleAssigned,
+    RoleUnassigned,
+    PermissionGranted,
+    PermissionRevoked,
+    PasswordChanged,
+    SessionRevoked,
+    ServiceAccountCreated,
+    ServiceAccountDeleted,
+    ApiKeyCreated,
+    ApiKeyRotated,
+    ApiKeyRevoked,
+    PasskeyRegistered,
+    PasskeyDeleted,
+    OrganizationCreated,
+    OrganizationMemberAdded,
+    OrganizationMemberRemoved,
+    OrganizationInvitationSent,
+    OrganizationInvitationRevoked,
+}
+
+impl AuditEventType {
+    pub fn parse(value: &str) -> Result<Self, DomainError> {
+        match value {
+            "login_succeeded" => Ok(AuditEventType::LoginSucceeded),
+            "login_failed" => Ok(AuditEventType::LoginFailed),
+            "role_assigned" => Ok(AuditEventType::RoleAssigned),
+            "role_unassigned" => Ok(AuditEventType::RoleUnassigned),
+            "permission_granted" => Ok(AuditEventType::PermissionGranted),
+            "permission_revoked" => Ok(AuditEventType::PermissionRevoked),
+            "password_changed" => Ok(AuditEventType::PasswordChanged),
+            "session_revoked" => Ok(AuditEventType::SessionRevoked),
+            "service_account_created" => Ok(AuditEventType::ServiceAccountCreated),
+            "service_account_deleted" => Ok(AuditEventType::ServiceAccountDeleted),
+            "api_key_created" => Ok(AuditEventType::ApiKeyCreated),
+            "api_key_rotated" => Ok(AuditEventType::ApiKeyRotated),
+            "api_key_revoked" => Ok(AuditEventType::ApiKeyRevoked),
+            "passkey_registered" => Ok(AuditEventType::PasskeyRegistered),
+            "passkey_deleted" => Ok(AuditEventType::PasskeyDeleted),
+            "organization_created" => Ok(AuditEventType::OrganizationCreated),
+            "organization_member_added" => Ok(AuditEventType::OrganizationMemberAdded),
+            "organization_member_removed" => Ok(AuditEventType::OrganizationMemberRemoved),
+            "organization_invitation_sent" => Ok(AuditEventType::OrganizationInvitationSent),
+            "organization_invitation_revoked" => Ok(AuditEventType::OrganizationInvitationRevoked),
+            other => Err(DomainError::ValidationError(format!("Unknown audit event type: {}", other))),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            AuditEventType::LoginSucceeded => "login_succeeded",
+            AuditEventType::LoginFailed => "login_failed",
+            AuditEventType::RoleAssigned => "role_assigned",
+            AuditEventType::RoleUnassigned => "role_unassigned",
+            AuditEventType::PermissionGranted => "permission_granted",
+            AuditEventType::PermissionRevoked => "permission_revoked",
+            AuditEventType::PasswordChanged => "password_changed",
+            AuditEventType::SessionRevoked => "session_revoked",
+            AuditEventType::ServiceAccountCreated => "service_account_created",
+            AuditEventType::ServiceAccountDeleted => "service_account_deleted",
+            AuditEventType::ApiKeyCreated => "api_key_created",
+            AuditEventType::ApiKeyRotated => "api_key_rotated",
+            AuditEventType::ApiKeyRevoked => "api_key_revoked",
+            AuditEventType::PasskeyRegistered => "passkey_registered",
+            AuditEventType::PasskeyDeleted => "passkey_deleted",
+            AuditEventType::OrganizationCreated => "organization_created",
+            AuditEventType::OrganizationMemberAdded => "organization_member_added",
+            AuditEventType::OrganizationMemberRemoved => "organization_member_removed",
+            AuditEventType::OrganizationInvitationSent => "organization_invitation_sent",
+            AuditEventType::OrganizationInvitationRevoked => "organization_invitation_revoked",
+        }
+    }
+}
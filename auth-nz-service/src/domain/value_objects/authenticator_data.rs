@@ -0,0 +1,81 @@
+use crate::domain::errors::DomainError;
+use uuid::Uuid;
+
+const FLAG_USER_PRESENT: u8 = 0x01;
+const FLAG_USER_VERIFIED: u8 = 0x04;
+const FLAG_ATTESTED_CREDENTIAL: u8 = 0x40;
+
+/// Credential created during registration, as reported by the authenticator.
+#[derive(Debug, Clone)]
+pub struct AttestedCredential {
+    pub aaguid: Uuid,
+    pub credential_id: Vec<u8>,
+    /// COSE_Key encoding of the credential's public key.
+    pub public_key: Vec<u8>,
+}
+
+/// The authenticator data structure signed in every WebAuthn ceremony
+/// (WebAuthn Level 2, section 6.1).
+#[derive(Debug, Clone)]
+pub struct AuthenticatorData {
+    pub rp_id_hash: [u8; 32],
+    pub flags: u8,
+    pub sign_count: u32,
+    pub attested_credential: Option<AttestedCredential>,
+}
+
+impl AuthenticatorData {
+    pub fn parse(bytes: &[u8]) -> Result<Self, DomainError> {
+        let malformed = |what: &str| DomainError::ValidationError(format!("Malformed authenticator data: {}", what));
+
+        if bytes.len() < 37 {
+            return Err(malformed("too short"));
+        }
+        let mut rp_id_hash = [0u8; 32];
+        rp_id_hash.copy_from_slice(&bytes[..32]);
+        let flags = bytes[32];
+        let sign_count = u32::from_be_bytes([bytes[33], bytes[34], bytes[35], bytes[36]]);
+
+        let attested_credential = if flags & FLAG_ATTESTED_CREDENTIAL != 0 {
+            let rest = &bytes[37..];
+            if rest.len() < 18 {
+                return Err(malformed("truncated attested credential data"));
+            }
+            let aaguid = Uuid::from_slice(&rest[..16]).map_err(|_| malformed("invalid AAGUID"))?;
+            let id_length = u16::from_be_bytes([rest[16], rest[17]]) as usize;
+            let rest = &rest[18..];
+            if rest.len() < id_length {
+                return Err(malformed("truncated credential id"));
+            }
+            let credential_id = rest[..id_length].to_vec();
+
+            // The key is a single CBOR item; extensions may follow it
+            let mut key_bytes = &rest[id_length..];
+            let available = key_bytes.len();
+            ciborium::de::from_reader::<ciborium::Value, _>(&mut key_bytes)
+                .map_err(|_| malformed("invalid credential public key"))?;
+            let key_length = available - key_bytes.len();
+            let public_key = rest[id_length..id_length + key_length].to_vec();
+
+            Some(AttestedCredential { aaguid, credential_id, public_key })
+        } else {
+            None
+        };
+
+        Ok(Self {
+            rp_id_hash,
+            flags,
+            sign_count,
+            attested_credential,
+        })
+    }
+
+    pub fn user_present(&self) -> bool {
+        self.flags & FLAG_USER_PRESENT != 0
+    }
+
+    /// The authenticator checked a PIN or biometric, not just a touch.
+    pub fn user_verified(&self) -> bool {
+        self.flags & FLAG_USER_VERIFIED != 0
+    }
+}
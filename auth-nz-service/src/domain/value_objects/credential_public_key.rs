@@ -0,0 +1,125 @@
+use crate::domain::errors::DomainError;
+use ciborium::Value;
+use p256::ecdsa::signature::Verifier;
+use rsa::pkcs1::DecodeRsaPublicKey;
+use rsa::{BigUint, RsaPublicKey};
+use sha2::Sha256;
+
+/// COSE algorithm identifiers (RFC 9053) accepted for passkeys, in order of preference.
+pub const COSE_ES256: i64 = -7;
+pub const COSE_EDDSA: i64 = -8;
+pub const COSE_RS256: i64 = -257;
+pub const SUPPORTED_COSE_ALGORITHMS: [i64; 3] = [COSE_ES256, COSE_EDDSA, COSE_RS256];
+
+/// Key that verifies WebAuthn signatures: a passkey's own key, or the key of
+/// an attestation certificate.
+#[derive(Debug, Clone)]
+pub enum CredentialPublicKey {
+    Es256(p256::ecdsa::VerifyingKey),
+    EdDsa(ed25519_dalek::VerifyingKey),
+    Rs256(RsaPublicKey),
+}
+
+impl CredentialPublicKey {
+    /// Parses a COSE_Key (RFC 9052, section 7) as found in authenticator data.
+    pub fn from_cose(bytes: &[u8]) -> Result<Self, DomainError> {
+        let malformed = |what: &str| DomainError::ValidationError(format!("Malformed credential public key: {}", what));
+
+        let value: Value = ciborium::de::from_reader(bytes).map_err(|_| malformed("not CBOR"))?;
+        let Value::Map(entries) = value else {
+            return Err(malformed("not a map"));
+        };
+        let parameter = |label: i64| {
+            entries.iter()
+                .find(|(key, _)| key.as_integer() == Some(label.into()))
+                .map(|(_, value)| value)
+        };
+        let integer = |label: i64| {
+            parameter(label)
+                .and_then(Value::as_integer)
+                .and_then(|value| i64::try_from(value).ok())
+        };
+        let bytes = |label: i64| parameter(label).and_then(Value::as_bytes);
+
+        let algorithm = integer(3).ok_or_else(|| malformed("missing alg"))?;
+        match (integer(1), algorithm) {
+            // kty EC2, crv P-256
+            (Some(2), COSE_ES256) if integer(-1) == Some(1) => {
+                let (Some(x), Some(y)) = (bytes(-2), bytes(-3)) else {
+                    return Err(malformed("missing coordinates"));
+                };
+                let mut point = Vec::with_capacity(1 + x.len() + y.len());
+                point.push(0x04);
+                point.extend_from_slice(x);
+                point.extend_from_slice(y);
+                p256::ecdsa::VerifyingKey::from_sec1_bytes(&point)
+                    .map(CredentialPublicKey::Es256)
+                    .map_err(|_| malformed("invalid P-256 point"))
+            }
+            // kty OKP, crv Ed25519
+            (Some(1), COSE_EDDSA) if integer(-1) == Some(6) => {
+                let x: [u8; 32] = bytes(-2)
+                    .and_then(|x| x.as_slice().try_into().ok())
+                    .ok_or_else(|| malformed("invalid Ed25519 key"))?;
+                ed25519_dalek::VerifyingKey::from_bytes(&x)
+                    .map(CredentialPublicKey::EdDsa)
+                    .map_err(|_| malformed("invalid Ed25519 key"))
+            }
+            // kty RSA
+            (Some(3), COSE_RS256) => {
+                let (Some(n), Some(e)) = (bytes(-1), bytes(-2)) else {
+                    return Err(malformed("missing modulus or exponent"));
+                };
+                RsaPublicKey::new(BigUint::from_bytes_be(n), BigUint::from_bytes_be(e))
+                    .map(CredentialPublicKey::Rs256)
+                    .map_err(|_| malformed("invalid RSA key"))
+            }
+            _ => Err(DomainError::ValidationError(format!("Unsupported credential algorithm {}", algorithm))),
+        }
+    }
+
+    /// Builds the key for `algorithm` from the raw subject public key of an
+    /// X.509 certificate.
+    pub fn from_subject_public_key(algorithm: i64, raw: &[u8]) -> Result<Self, DomainError> {
+        let invalid = || DomainError::ValidationError("Unusable attestation certificate key".to_string());
+        match algorithm {
+            COSE_ES256 => p256::ecdsa::VerifyingKey::from_sec1_bytes(raw)
+                .map(CredentialPublicKey::Es256)
+                .map_err(|_| invalid()),
+            COSE_EDDSA => {
+                let raw: [u8; 32] = raw.try_into().map_err(|_| invalid())?;
+                ed25519_dalek::VerifyingKey::from_bytes(&raw)
+                    .map(CredentialPublicKey::EdDsa)
+                    .map_err(|_| invalid())
+            }
+            COSE_RS256 => RsaPublicKey::from_pkcs1_der(raw)
+                .map(CredentialPublicKey::Rs256)
+                .map_err(|_| invalid()),
+            other => Err(DomainError::ValidationError(format!("Unsupported attestation algorithm {}", other))),
+        }
+    }
+
+    pub fn algorithm(&self) -> i64 {
+        match self {
+            CredentialPublicKey::Es256(_) => COSE_ES256,
+            CredentialPublicKey::EdDsa(_) => COSE_EDDSA,
+            CredentialPublicKey::Rs256(_) => COSE_RS256,
+        }
+    }
+
+    /// Checks a signature in the encoding WebAuthn uses for the algorithm:
+    /// DER for ECDSA, raw for EdDSA, PKCS#1 v1.5 for RSA.
+    pub fn verify(&self, message: &[u8], signature: &[u8]) -> bool {
+        match self {
+            CredentialPublicKey::Es256(key) => p256::ecdsa::Signature::from_der(signature)
+                .is_ok_and(|signature| key.verify(message, &signature).is_ok()),
+            CredentialPublicKey::EdDsa(key) => ed25519_dalek::Signature::from_slice(signature)
+                .is_ok_and(|signature| key.verify(message, &signature).is_ok()),
+            CredentialPublicKey::Rs256(key) => {
+                let key = rsa::pkcs1v15::VerifyingKey::<Sha256>::new(key.clone());
+                rsa::pkcs1v15::Signature::try_from(signature)
+                    .is_ok_and(|signature| key.verify(message, &signature).is_ok())
+            }
+        }
+    }
+}
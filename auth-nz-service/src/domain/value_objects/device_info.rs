@@ -0,0 +1,81 @@
+/// The client a session was opened from, kept so users can tell their
+/// sessions apart when deciding which ones to revoke.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeviceInfo {
+    user_agent: Option<String>,
+    ip_address: Option<String>,
+    label: String,
+}
+
+const MAX_USER_AGENT_LEN: usize = 512;
+
+impl DeviceInfo {
+    pub fn new(user_agent: Option<String>, ip_address: Option<String>) -> Self {
+        let user_agent = user_agent
+            .map(|ua| ua.trim().chars().take(MAX_USER_AGENT_LEN).collect::<String>())
+            .filter(|ua| !ua.is_empty());
+        let label = user_agent.as_deref().map(describe).unwrap_or_else(|| "Unknown device".to_string());
+        Self {
+            user_agent,
+            ip_address,
+            label,
+        }
+    }
+
+    /// Rebuilds a stored value without re-deriving the label.
+    pub fn from_parts(user_agent: Option<String>, ip_address: Option<String>, label: String) -> Self {
+        Self {
+            user_agent,
+            ip_address,
+            label,
+        }
+    }
+
+    pub fn user_agent(&self) -> Option<&str> {
+        self.user_agent.as_deref()
+    }
+
+    pub fn ip_address(&self) -> Option<&str> {
+        self.ip_address.as_deref()
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+}
+
+/// A coarse "<client> on <platform>" description. Order matters: Edge and
+/// Chrome both claim to be Safari, and Android claims to be Linux.
+fn describe(user_agent: &str) -> String {
+    const CLIENTS: &[(&str, &str)] = &[
+        ("Edg/", "Edge"),
+        ("OPR/", "Opera"),
+        ("Firefox/", "Firefox"),
+        ("Chrome/", "Chrome"),
+        ("Safari/", "Safari"),
+        ("okhttp", "Android app"),
+        ("CFNetwork", "iOS app"),
+        ("Dart/", "Mobile app"),
+        ("curl/", "curl"),
+    ];
+    const PLATFORMS: &[(&str, &str)] = &[
+        ("iPhone", "iPhone"),
+        ("iPad", "iPad"),
+        ("Android", "Android"),
+        ("Windows", "Windows"),
+        ("Mac OS X", "macOS"),
+        ("Macintosh", "macOS"),
+        ("CrOS", "ChromeOS"),
+        ("Linux", "Linux"),
+    ];
+
+    let client = CLIENTS.iter().find(|(marker, _)| user_agent.contains(marker)).map(|(_, name)| *name);
+    let platform = PLATFORMS.iter().find(|(marker, _)| user_agent.contains(marker)).map(|(_, name)| *name);
+
+    match (client, platform) {
+        (Some(client), Some(platform)) => format!("{} on {}", client, platform),
+        (Some(client), None) => client.to_string(),
+        (None, Some(platform)) => platform.to_string(),
+        (None, None) => "Unknown device".to_string(),
+    }
+}
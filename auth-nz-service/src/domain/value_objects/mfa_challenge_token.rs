@@ -0,0 +1,36 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use rand::rngs::OsRng;
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+
+/// Opaque token returned by a password login that still needs a second factor.
+/// Only its hash is persisted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MfaChallengeToken(String);
+
+impl MfaChallengeToken {
+    pub fn generate() -> Self {
+        let mut bytes = [0u8; 32];
+        OsRng.fill_bytes(&mut bytes);
+        Self(URL_SAFE_NO_PAD.encode(bytes))
+    }
+
+    pub fn new(token: String) -> Self {
+        Self(token)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn hash(&self) -> String {
+        hex::encode(Sha256::digest(self.0.as_bytes()))
+    }
+}
+
+impl From<MfaChallengeToken> for String {
+    fn from(token: MfaChallengeToken) -> Self {
+        token.0
+    }
+}
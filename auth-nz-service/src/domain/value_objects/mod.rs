@@ -3,18 +3,66 @@ pub mod user_id;
 pub mod password_hash;
 pub mod session_id;
 pub mod token;
+pub mod refresh_token;
+pub mod refresh_token_hash;
 pub mod role_id;
 pub mod role_name;
 pub mod permission_id;
 pub mod permission_name;
+pub mod policy_condition;
+pub mod totp_secret;
+pub mod recovery_code;
+pub mod mfa_challenge_token;
+pub mod account_token_purpose;
+pub mod device_info;
+pub mod scopes;
+pub mod grant_type;
+pub mod client_secret;
+pub mod authorization_code;
+pub mod oauth_delegation;
+pub mod audit_event_type;
+pub mod service_account_id;
+pub mod api_key_id;
+pub mod api_key_token;
+pub mod passkey_id;
+pub mod webauthn_ceremony;
+pub mod authenticator_data;
+pub mod credential_public_key;
+pub mod organization_id;
+pub mod invitation_id;
+pub mod invitation_token;
 
 pub use email::Email;
 pub use user_id::UserId;
 pub use password_hash::PasswordHash;
 pub use session_id::SessionId;
 pub use token::Token;
+pub use refresh_token::RefreshToken;
+pub use refresh_token_hash::RefreshTokenHash;
 pub use role_id::RoleId;
 pub use role_name::RoleName;
 pub use permission_id::PermissionId;
 pub use permission_name::PermissionName;
+pub use policy_condition::PolicyCondition;
+pub use totp_secret::TotpSecret;
+pub use recovery_code::RecoveryCode;
+pub use mfa_challenge_token::MfaChallengeToken;
+pub use account_token_purpose::AccountTokenPurpose;
+pub use device_info::DeviceInfo;
+pub use scopes::Scopes;
+pub use grant_type::GrantType;
+pub use client_secret::ClientSecret;
+pub use authorization_code::AuthorizationCode;
+pub use oauth_delegation::OAuthDelegation;
+pub use audit_event_type::AuditEventType;
+pub use service_account_id::ServiceAccountId;
+pub use api_key_id::ApiKeyId;
+pub use api_key_token::ApiKeyToken;
+pub use passkey_id::PasskeyId;
+pub use webauthn_ceremony::WebAuthnCeremony;
+pub use authenticator_data::{AttestedCredential, AuthenticatorData};
+pub use credential_public_key::{CredentialPublicKey, SUPPORTED_COSE_ALGORITHMS};
+pub use organization_id::OrganizationId;
+pub use invitation_id::InvitationId;
+pub use invitation_token::InvitationToken;
 
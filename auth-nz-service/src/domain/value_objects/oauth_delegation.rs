@@ -0,0 +1,9 @@
+use crate::domain::value_objects::Scopes;
+
+/// Marks a session as opened on behalf of an OAuth client: its tokens carry
+/// the client id and only the permissions the granted scopes select.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OAuthDelegation {
+    pub client_id: String,
+    pub scopes: Scopes,
+}
@@ -0,0 +1,154 @@
+use std::cmp::Ordering;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Boolean expression evaluated against a request context of the form
+/// `{"subject": {..}, "resource": {..}, "action": "..", "environment": {..}}`.
+///
+/// Operands are JSON literals, except strings starting with `$`, which are
+/// dotted paths into the context such as `$subject.id` or `$resource.owner_id`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PolicyCondition {
+    All(Vec<PolicyCondition>),
+    Any(Vec<PolicyCondition>),
+    Not(Box<PolicyCondition>),
+    Eq(Value, Value),
+    Ne(Value, Value),
+    Gt(Value, Value),
+    Gte(Value, Value),
+    Lt(Value, Value),
+    Lte(Value, Value),
+    /// Left operand is one of the elements of the right-hand array.
+    In(Value, Value),
+    /// Left array contains the right operand, or left string contains it as a substring.
+    Contains(Value, Value),
+    Exists(String),
+}
+
+impl PolicyCondition {
+    pub fn evaluate(&self, context: &Value) -> bool {
+        self.evaluate_traced(context, None)
+    }
+
+    /// Evaluates the condition and records one line per comparison in `trace`.
+    pub fn explain(&self, context: &Value, trace: &mut Vec<String>) -> bool {
+        self.evaluate_traced(context, Some(trace))
+    }
+
+    fn evaluate_traced(&self, context: &Value, trace: Option<&mut Vec<String>>) -> bool {
+        match self {
+            // When explaining, every child is evaluated so the trace covers all of them
+            Self::All(conditions) => match trace {
+                Some(trace) => conditions.iter()
+                    .map(|c| c.evaluate_traced(context, Some(&mut *trace)))
+                    .collect::<Vec<_>>()
+                    .into_iter()
+                    .all(|result| result),
+                None => conditions.iter().all(|c| c.evaluate(context)),
+            },
+            Self::Any(conditions) => match trace {
+                Some(trace) => conditions.iter()
+                    .map(|c| c.evaluate_traced(context, Some(&mut *trace)))
+                    .collect::<Vec<_>>()
+                    .into_iter()
+                    .any(|result| result),
+                None => conditions.iter().any(|c| c.evaluate(context)),
+            },
+            Self::Not(condition) => !condition.evaluate_traced(context, trace),
+            Self::Exists(path) => {
+                let result = !lookup(context, path.trim_start_matches('$')).is_null();
+                if let Some(trace) = trace {
+                    trace.push(format!("exists({}) = {}", path, result));
+                }
+                result
+            }
+            Self::Eq(l, r) => self.apply(context, l, r, trace, values_equal),
+            Self::Ne(l, r) => self.apply(context, l, r, trace, |l, r| !values_equal(l, r)),
+            Self::Gt(l, r) => self.apply(context, l, r, trace, |l, r| {
+                compare(l, r) == Some(Ordering::Greater)
+            }),
+            Self::Gte(l, r) => self.apply(context, l, r, trace, |l, r| {
+                matches!(compare(l, r), Some(Ordering::Greater | Ordering::Equal))
+            }),
+            Self::Lt(l, r) => self.apply(context, l, r, trace, |l, r| {
+                compare(l, r) == Some(Ordering::Less)
+            }),
+            Self::Lte(l, r) => self.apply(context, l, r, trace, |l, r| {
+                matches!(compare(l, r), Some(Ordering::Less | Ordering::Equal))
+            }),
+            Self::In(l, r) => self.apply(context, l, r, trace, |l, r| {
+                r.as_array().is_some_and(|items| items.iter().any(|item| values_equal(l, item)))
+            }),
+            Self::Contains(l, r) => self.apply(context, l, r, trace, |l, r| match (l, r) {
+                (Value::Array(items), _) => items.iter().any(|item| values_equal(item, r)),
+                (Value::String(haystack), Value::String(needle)) => haystack.contains(needle.as_str()),
+                _ => false,
+            }),
+        }
+    }
+
+    fn apply(
+        &self,
+        context: &Value,
+        left: &Value,
+        right: &Value,
+        trace: Option<&mut Vec<String>>,
+        operator: fn(&Value, &Value) -> bool,
+    ) -> bool {
+        let left = resolve(context, left);
+        let right = resolve(context, right);
+        let result = operator(&left, &right);
+        if let Some(trace) = trace {
+            trace.push(format!("{}({}, {}) = {}", self.name(), left, right, result));
+        }
+        result
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::All(_) => "all",
+            Self::Any(_) => "any",
+            Self::Not(_) => "not",
+            Self::Eq(..) => "eq",
+            Self::Ne(..) => "ne",
+            Self::Gt(..) => "gt",
+            Self::Gte(..) => "gte",
+            Self::Lt(..) => "lt",
+            Self::Lte(..) => "lte",
+            Self::In(..) => "in",
+            Self::Contains(..) => "contains",
+            Self::Exists(_) => "exists",
+        }
+    }
+}
+
+fn resolve(context: &Value, operand: &Value) -> Value {
+    match operand {
+        Value::String(s) if s.starts_with('$') => lookup(context, &s[1..]),
+        other => other.clone(),
+    }
+}
+
+fn lookup(context: &Value, path: &str) -> Value {
+    path.split('.')
+        .try_fold(context, |value, key| value.get(key))
+        .cloned()
+        .unwrap_or(Value::Null)
+}
+
+fn values_equal(left: &Value, right: &Value) -> bool {
+    match (left.as_f64(), right.as_f64()) {
+        (Some(l), Some(r)) => l == r,
+        _ => left == right,
+    }
+}
+
+fn compare(left: &Value, right: &Value) -> Option<Ordering> {
+    match (left, right) {
+        (Value::Number(l), Value::Number(r)) => l.as_f64()?.partial_cmp(&r.as_f64()?),
+        // RFC 3339 timestamps in the same offset order correctly as strings
+        (Value::String(l), Value::String(r)) => Some(l.cmp(r)),
+        _ => None,
+    }
+}
@@ -0,0 +1,41 @@
+use data_encoding::BASE32_NOPAD;
+use rand::rngs::OsRng;
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+
+/// One-time MFA recovery code, shown to the user once. Only its hash is persisted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecoveryCode(String);
+
+impl RecoveryCode {
+    /// Ten base32 characters split in two groups, e.g. `k3j9q-7xw2m`.
+    pub fn generate() -> Self {
+        let mut bytes = [0u8; 10];
+        OsRng.fill_bytes(&mut bytes);
+        let encoded = BASE32_NOPAD.encode(&bytes).to_lowercase();
+        Self(format!("{}-{}", &encoded[..5], &encoded[5..10]))
+    }
+
+    pub fn new(code: String) -> Self {
+        Self(code)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Hash of the normalized code, so case and separators do not matter when redeeming.
+    pub fn hash(&self) -> String {
+        let normalized: String = self.0.chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .map(|c| c.to_ascii_lowercase())
+            .collect();
+        hex::encode(Sha256::digest(normalized.as_bytes()))
+    }
+}
+
+impl From<RecoveryCode> for String {
+    fn from(code: RecoveryCode) -> Self {
+        code.0
+    }
+}
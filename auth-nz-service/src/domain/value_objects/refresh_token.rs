@@ -0,0 +1,36 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use rand::rngs::OsRng;
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+use crate::domain::value_objects::RefreshTokenHash;
+
+/// Opaque refresh token handed to the client. Only its hash is persisted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RefreshToken(String);
+
+impl RefreshToken {
+    pub fn generate() -> Self {
+        let mut bytes = [0u8; 32];
+        OsRng.fill_bytes(&mut bytes);
+        Self(URL_SAFE_NO_PAD.encode(bytes))
+    }
+
+    pub fn new(token: String) -> Self {
+        Self(token)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn hash(&self) -> RefreshTokenHash {
+        RefreshTokenHash::new(hex::encode(Sha256::digest(self.0.as_bytes())))
+    }
+}
+
+impl From<RefreshToken> for String {
+    fn from(token: RefreshToken) -> Self {
+        token.0
+    }
+}
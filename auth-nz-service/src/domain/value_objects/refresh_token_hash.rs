@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct RefreshTokenHash(String);
+
+impl RefreshTokenHash {
+    pub fn new(hash: String) -> Self {
+        Self(hash)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for RefreshTokenHash {
+    fn from(hash: String) -> Self {
+        Self(hash)
+    }
+}
+
+impl From<RefreshTokenHash> for String {
+    fn from(hash: RefreshTokenHash) -> Self {
+        hash.0
+    }
+}
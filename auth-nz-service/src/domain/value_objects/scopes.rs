@@ -0,0 +1,55 @@
+use std::collections::BTreeSet;
+use std::fmt;
+use crate::domain::errors::DomainError;
+
+/// A set of OAuth scopes. Scopes use the `resource:action` form of
+/// permissions, so a scope selects which of the user's permissions a client
+/// may act with.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Scopes(BTreeSet<String>);
+
+impl Scopes {
+    /// Parses the space-delimited form used on the wire.
+    pub fn parse(value: &str) -> Result<Self, DomainError> {
+        Self::from_vec(value.split_whitespace().map(str::to_string).collect())
+    }
+
+    pub fn from_vec(scopes: Vec<String>) -> Result<Self, DomainError> {
+        let mut set = BTreeSet::new();
+        for scope in scopes {
+            match scope.split_once(':') {
+                Some((resource, action)) if !resource.is_empty() && !action.is_empty() => {
+                    set.insert(scope);
+                }
+                _ => return Err(DomainError::InvalidScope(format!("'{}' is not of the form resource:action", scope))),
+            }
+        }
+        Ok(Self(set))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().map(String::as_str)
+    }
+
+    pub fn contains_all(&self, other: &Scopes) -> bool {
+        other.0.is_subset(&self.0)
+    }
+
+    pub fn union(&self, other: &Scopes) -> Scopes {
+        Scopes(self.0.union(&other.0).cloned().collect())
+    }
+
+    pub fn to_vec(&self) -> Vec<String> {
+        self.0.iter().cloned().collect()
+    }
+}
+
+impl fmt::Display for Scopes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.iter().cloned().collect::<Vec<_>>().join(" "))
+    }
+}
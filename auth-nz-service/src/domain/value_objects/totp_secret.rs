@@ -0,0 +1,37 @@
+use data_encoding::BASE32_NOPAD;
+use rand::rngs::OsRng;
+use rand::RngCore;
+use crate::domain::errors::DomainError;
+
+/// Shared TOTP key, kept in the base32 form authenticator apps expect.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TotpSecret(String);
+
+impl TotpSecret {
+    /// 160-bit key, the size RFC 4226 recommends for HMAC-SHA1.
+    pub fn generate() -> Self {
+        let mut bytes = [0u8; 20];
+        OsRng.fill_bytes(&mut bytes);
+        Self(BASE32_NOPAD.encode(&bytes))
+    }
+
+    pub fn new(secret: String) -> Result<Self, DomainError> {
+        BASE32_NOPAD.decode(secret.as_bytes())
+            .map_err(|_| DomainError::ValidationError("TOTP secret must be base32".to_string()))?;
+        Ok(Self(secret))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn key(&self) -> Vec<u8> {
+        BASE32_NOPAD.decode(self.0.as_bytes()).unwrap_or_default()
+    }
+}
+
+impl From<TotpSecret> for String {
+    fn from(secret: TotpSecret) -> Self {
+        secret.0
+    }
+}
@@ -0,0 +1,33 @@
+use crate::domain::errors::DomainError;
+
+/// The WebAuthn ceremony a challenge was issued for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WebAuthnCeremony {
+    Registration,
+    Authentication,
+}
+
+impl WebAuthnCeremony {
+    pub fn parse(value: &str) -> Result<Self, DomainError> {
+        match value {
+            "registration" => Ok(WebAuthnCeremony::Registration),
+            "authentication" => Ok(WebAuthnCeremony::Authentication),
+            other => Err(DomainError::ValidationError(format!("Unknown WebAuthn ceremony: {}", other))),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            WebAuthnCeremony::Registration => "registration",
+            WebAuthnCeremony::Authentication => "authentication",
+        }
+    }
+
+    /// `type` the browser puts in the client data of this ceremony.
+    pub fn client_data_type(&self) -> &str {
+        match self {
+            WebAuthnCeremony::Registration => "webauthn.create",
+            WebAuthnCeremony::Authentication => "webauthn.get",
+        }
+    }
+}
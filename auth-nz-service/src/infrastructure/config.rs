@@ -5,12 +5,27 @@ pub struct Config {
     pub server: ServerConfig,
     pub database: DatabaseConfig,
     pub jwt: JwtConfig,
+    pub password: PasswordConfig,
+    pub password_policy: PasswordPolicyConfig,
+    pub session: SessionConfig,
+    pub policy: PolicyConfig,
+    pub mfa: MfaConfig,
+    pub redis: RedisConfig,
+    pub events: EventsConfig,
+    pub account_tokens: AccountTokenConfig,
+    pub login_throttle: LoginThrottleConfig,
+    pub oauth: OAuthConfig,
+    pub federation: FederationConfig,
+    pub webauthn: WebAuthnConfig,
+    pub organizations: OrganizationConfig,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ServerConfig {
     pub host: String,
     pub port: u16,
+    /// Take the client IP from `X-Forwarded-For`; only safe behind a proxy that sets it.
+    pub trust_forwarded_for: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,11 +37,186 @@ pub struct DatabaseConfig {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct JwtConfig {
     pub secret: String,
-    pub expiration_hours: u64,
+    pub access_token_ttl_minutes: u64,
+    pub algorithm: String,
+    pub signing_kid: String,
+    pub keys_dir: Option<String>,
+    pub issuer: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PasswordConfig {
+    pub memory_cost_kib: u32,
+    pub time_cost: u32,
+    pub parallelism: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PasswordPolicyConfig {
+    pub min_length: usize,
+    pub max_length: usize,
+    pub require_lowercase: bool,
+    pub require_uppercase: bool,
+    pub require_digit: bool,
+    pub require_symbol: bool,
+    pub history_size: usize,
+    /// File of breached password SHA-1 hashes; no breach check without one.
+    pub breach_corpus_path: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionConfig {
+    pub refresh_token_ttl_days: u64,
+    /// How long after signing in a user may manage their passkeys and MFA.
+    pub reauthentication_max_age_minutes: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PolicyConfig {
+    pub dir: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MfaConfig {
+    pub issuer: String,
+    pub challenge_ttl_minutes: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RedisConfig {
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventsConfig {
+    pub stream: String,
+    pub stream_max_len: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountTokenConfig {
+    pub secret: String,
+    pub email_verification_ttl_hours: u64,
+    pub password_reset_ttl_minutes: u64,
+    pub magic_link_ttl_minutes: u64,
+    /// Frontend origin the emailed links point at.
+    pub link_base_url: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoginThrottleConfig {
+    pub max_account_failures: u32,
+    pub max_ip_failures: u32,
+    pub failure_window_minutes: u64,
+    pub lockout_minutes: u64,
+    pub base_delay_ms: u64,
+    pub max_delay_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OAuthConfig {
+    pub authorization_code_ttl_seconds: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FederationConfig {
+    pub providers: Vec<IdentityProviderConfig>,
+    /// Public origin of this service; callbacks go to `/login/oidc/<name>/callback` under it.
+    pub redirect_base_url: String,
+    pub login_ttl_minutes: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebAuthnConfig {
+    /// Domain passkeys are scoped to; the frontend origins must be on it or a subdomain.
+    pub rp_id: String,
+    pub rp_name: String,
+    /// Origins the browser may report in client data, e.g. `https://app.example.com`.
+    pub origins: Vec<String>,
+    pub challenge_ttl_minutes: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrganizationConfig {
+    pub invitation_ttl_hours: u64,
+    /// Role the creator of an organization gets in it.
+    pub founder_role: String,
+}
+
+/// Read from `OIDC_<NAME>_*` for every name listed in `OIDC_PROVIDERS`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IdentityProviderConfig {
+    pub name: String,
+    pub issuer: String,
+    pub client_id: String,
+    pub client_secret: Option<String>,
+    pub scopes: Vec<String>,
+}
+
+impl FederationConfig {
+    fn load(default_redirect_base_url: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut providers = Vec::new();
+        let names = std::env::var("OIDC_PROVIDERS").unwrap_or_default();
+        for name in names.split(',').map(str::trim).filter(|name| !name.is_empty()) {
+            let prefix = format!("OIDC_{}", name.to_uppercase().replace('-', "_"));
+            let required = |suffix: &str| {
+                std::env::var(format!("{}_{}", prefix, suffix))
+                    .map_err(|_| format!("{}_{} is required for identity provider {}", prefix, suffix, name))
+            };
+            providers.push(IdentityProviderConfig {
+                name: name.to_lowercase(),
+                issuer: required("ISSUER")?,
+                client_id: required("CLIENT_ID")?,
+                client_secret: std::env::var(format!("{}_CLIENT_SECRET", prefix)).ok(),
+                scopes: std::env::var(format!("{}_SCOPES", prefix))
+                    .unwrap_or_else(|_| "openid email profile".to_string())
+                    .split_whitespace()
+                    .map(str::to_string)
+                    .collect(),
+            });
+        }
+
+        Ok(Self {
+            providers,
+            redirect_base_url: std::env::var("OIDC_REDIRECT_BASE_URL")
+                .unwrap_or_else(|_| default_redirect_base_url.to_string()),
+            login_ttl_minutes: std::env::var("OIDC_LOGIN_TTL_MINUTES")
+                .unwrap_or_else(|_| "10".to_string())
+                .parse()
+                .unwrap_or(10),
+        })
+    }
+}
+
+/// Shortest HMAC secret accepted for signing tokens, per RFC 7518 §3.2 for HS256.
+pub const MIN_SECRET_BYTES: usize = 32;
+
 impl Config {
     pub fn load() -> Result<Self, Box<dyn std::error::Error>> {
+        let jwt_algorithm = std::env::var("JWT_ALGORITHM").unwrap_or_else(|_| "EdDSA".to_string());
+        let jwt_keys_dir = std::env::var("JWT_KEYS_DIR").ok();
+        let jwt_secret = std::env::var("JWT_SECRET").unwrap_or_default();
+        // Without a keys directory, HS* tokens are signed with JWT_SECRET itself
+        if jwt_algorithm.starts_with("HS") && jwt_keys_dir.is_none() && jwt_secret.len() < MIN_SECRET_BYTES {
+            return Err(format!(
+                "JWT_SECRET must be set to at least {} bytes when JWT_ALGORITHM is {}",
+                MIN_SECRET_BYTES, jwt_algorithm
+            ).into());
+        }
+        // Signs email verification, password reset and magic links; sharing the
+        // access token key would let anyone holding that key mint these links
+        let account_token_secret = std::env::var("ACCOUNT_TOKEN_SECRET")
+            .map_err(|_| "ACCOUNT_TOKEN_SECRET must be set")?;
+        if account_token_secret.len() < MIN_SECRET_BYTES {
+            return Err(format!("ACCOUNT_TOKEN_SECRET must be at least {} bytes", MIN_SECRET_BYTES).into());
+        }
+        if account_token_secret == jwt_secret {
+            return Err("ACCOUNT_TOKEN_SECRET must differ from JWT_SECRET".into());
+        }
+        let jwt_issuer = std::env::var("JWT_ISSUER").unwrap_or_else(|_| "http://localhost:3001".to_string());
+        let link_base_url = std::env::var("ACCOUNT_LINK_BASE_URL")
+            .unwrap_or_else(|_| "http://localhost:3000".to_string());
+
         Ok(Self {
             server: ServerConfig {
                 host: std::env::var("SERVER_HOST").unwrap_or_else(|_| "0.0.0.0".to_string()),
@@ -34,6 +224,10 @@ impl Config {
                     .unwrap_or_else(|_| "3001".to_string())
                     .parse()
                     .unwrap_or(3001),
+                trust_forwarded_for: std::env::var("TRUST_X_FORWARDED_FOR")
+                    .unwrap_or_else(|_| "false".to_string())
+                    .parse()
+                    .unwrap_or(false),
             },
             database: DatabaseConfig {
                 url: std::env::var("DATABASE_URL")
@@ -41,8 +235,160 @@ impl Config {
                 max_connections: 10,
             },
             jwt: JwtConfig {
-                secret: std::env::var("JWT_SECRET").unwrap_or_else(|_| "secret".to_string()),
-                expiration_hours: 24,
+                secret: jwt_secret.clone(),
+                access_token_ttl_minutes: std::env::var("JWT_ACCESS_TOKEN_TTL_MINUTES")
+                    .unwrap_or_else(|_| "15".to_string())
+                    .parse()
+                    .unwrap_or(15),
+                algorithm: jwt_algorithm,
+                signing_kid: std::env::var("JWT_SIGNING_KID").unwrap_or_else(|_| "default".to_string()),
+                keys_dir: jwt_keys_dir,
+                issuer: jwt_issuer.clone(),
+            },
+            password: PasswordConfig {
+                memory_cost_kib: std::env::var("ARGON2_MEMORY_COST_KIB")
+                    .unwrap_or_else(|_| "19456".to_string())
+                    .parse()
+                    .unwrap_or(19456),
+                time_cost: std::env::var("ARGON2_TIME_COST")
+                    .unwrap_or_else(|_| "2".to_string())
+                    .parse()
+                    .unwrap_or(2),
+                parallelism: std::env::var("ARGON2_PARALLELISM")
+                    .unwrap_or_else(|_| "1".to_string())
+                    .parse()
+                    .unwrap_or(1),
+            },
+            password_policy: PasswordPolicyConfig {
+                min_length: std::env::var("PASSWORD_MIN_LENGTH")
+                    .unwrap_or_else(|_| "10".to_string())
+                    .parse()
+                    .unwrap_or(10),
+                max_length: std::env::var("PASSWORD_MAX_LENGTH")
+                    .unwrap_or_else(|_| "128".to_string())
+                    .parse()
+                    .unwrap_or(128),
+                require_lowercase: std::env::var("PASSWORD_REQUIRE_LOWERCASE")
+                    .unwrap_or_else(|_| "true".to_string())
+                    .parse()
+                    .unwrap_or(true),
+                require_uppercase: std::env::var("PASSWORD_REQUIRE_UPPERCASE")
+                    .unwrap_or_else(|_| "true".to_string())
+                    .parse()
+                    .unwrap_or(true),
+                require_digit: std::env::var("PASSWORD_REQUIRE_DIGIT")
+                    .unwrap_or_else(|_| "true".to_string())
+                    .parse()
+                    .unwrap_or(true),
+                require_symbol: std::env::var("PASSWORD_REQUIRE_SYMBOL")
+                    .unwrap_or_else(|_| "false".to_string())
+                    .parse()
+                    .unwrap_or(false),
+                history_size: std::env::var("PASSWORD_HISTORY_SIZE")
+                    .unwrap_or_else(|_| "5".to_string())
+                    .parse()
+                    .unwrap_or(5),
+                breach_corpus_path: std::env::var("PASSWORD_BREACH_CORPUS").ok(),
+            },
+            session: SessionConfig {
+                refresh_token_ttl_days: std::env::var("REFRESH_TOKEN_TTL_DAYS")
+                    .unwrap_or_else(|_| "30".to_string())
+                    .parse()
+                    .unwrap_or(30),
+                reauthentication_max_age_minutes: std::env::var("REAUTHENTICATION_MAX_AGE_MINUTES")
+                    .unwrap_or_else(|_| "10".to_string())
+                    .parse()
+                    .unwrap_or(10),
+            },
+            policy: PolicyConfig {
+                dir: std::env::var("POLICY_DIR").unwrap_or_else(|_| "policies".to_string()),
+            },
+            mfa: MfaConfig {
+                issuer: std::env::var("MFA_ISSUER").unwrap_or_else(|_| "TikTok Clone".to_string()),
+                challenge_ttl_minutes: std::env::var("MFA_CHALLENGE_TTL_MINUTES")
+                    .unwrap_or_else(|_| "5".to_string())
+                    .parse()
+                    .unwrap_or(5),
+            },
+            redis: RedisConfig {
+                url: std::env::var("REDIS_URL").ok(),
+            },
+            events: EventsConfig {
+                stream: std::env::var("AUTH_EVENTS_STREAM").unwrap_or_else(|_| "auth:events".to_string()),
+                stream_max_len: std::env::var("AUTH_EVENTS_STREAM_MAX_LEN")
+                    .unwrap_or_else(|_| "100000".to_string())
+                    .parse()
+                    .unwrap_or(100000),
+            },
+            account_tokens: AccountTokenConfig {
+                secret: account_token_secret,
+                email_verification_ttl_hours: std::env::var("EMAIL_VERIFICATION_TTL_HOURS")
+                    .unwrap_or_else(|_| "24".to_string())
+                    .parse()
+                    .unwrap_or(24),
+                password_reset_ttl_minutes: std::env::var("PASSWORD_RESET_TTL_MINUTES")
+                    .unwrap_or_else(|_| "30".to_string())
+                    .parse()
+                    .unwrap_or(30),
+                magic_link_ttl_minutes: std::env::var("MAGIC_LINK_TTL_MINUTES")
+                    .unwrap_or_else(|_| "15".to_string())
+                    .parse()
+                    .unwrap_or(15),
+                link_base_url: link_base_url.clone(),
+            },
+            login_throttle: LoginThrottleConfig {
+                max_account_failures: std::env::var("LOGIN_MAX_ACCOUNT_FAILURES")
+                    .unwrap_or_else(|_| "5".to_string())
+                    .parse()
+                    .unwrap_or(5),
+                max_ip_failures: std::env::var("LOGIN_MAX_IP_FAILURES")
+                    .unwrap_or_else(|_| "50".to_string())
+                    .parse()
+                    .unwrap_or(50),
+                failure_window_minutes: std::env::var("LOGIN_FAILURE_WINDOW_MINUTES")
+                    .unwrap_or_else(|_| "15".to_string())
+                    .parse()
+                    .unwrap_or(15),
+                lockout_minutes: std::env::var("LOGIN_LOCKOUT_MINUTES")
+                    .unwrap_or_else(|_| "15".to_string())
+                    .parse()
+                    .unwrap_or(15),
+                base_delay_ms: std::env::var("LOGIN_BASE_DELAY_MS")
+                    .unwrap_or_else(|_| "250".to_string())
+                    .parse()
+                    .unwrap_or(250),
+                max_delay_ms: std::env::var("LOGIN_MAX_DELAY_MS")
+                    .unwrap_or_else(|_| "4000".to_string())
+                    .parse()
+                    .unwrap_or(4000),
+            },
+            oauth: OAuthConfig {
+                authorization_code_ttl_seconds: std::env::var("OAUTH_AUTHORIZATION_CODE_TTL_SECONDS")
+                    .unwrap_or_else(|_| "300".to_string())
+                    .parse()
+                    .unwrap_or(300),
+            },
+            federation: FederationConfig::load(&jwt_issuer)?,
+            webauthn: WebAuthnConfig {
+                rp_id: std::env::var("WEBAUTHN_RP_ID").unwrap_or_else(|_| "localhost".to_string()),
+                rp_name: std::env::var("WEBAUTHN_RP_NAME").unwrap_or_else(|_| "TikTok Clone".to_string()),
+                origins: std::env::var("WEBAUTHN_ORIGINS")
+                    .unwrap_or(link_base_url)
+                    .split(',')
+                    .map(|origin| origin.trim().trim_end_matches('/').to_string())
+                    .filter(|origin| !origin.is_empty())
+                    .collect(),
+                challenge_ttl_minutes: std::env::var("WEBAUTHN_CHALLENGE_TTL_MINUTES")
+                    .unwrap_or_else(|_| "5".to_string())
+                    .parse()
+                    .unwrap_or(5),
+            },
+            organizations: OrganizationConfig {
+                invitation_ttl_hours: std::env::var("ORGANIZATION_INVITATION_TTL_HOURS")
+                    .unwrap_or_else(|_| "72".to_string())
+                    .parse()
+                    .unwrap_or(72),
+                founder_role: std::env::var("ORGANIZATION_FOUNDER_ROLE").unwrap_or_else(|_| "org-admin".to_string()),
             },
         })
     }
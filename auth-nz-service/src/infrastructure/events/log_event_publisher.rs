@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+use crate::domain::events::{AccountEvent, EventPublisher};
+use crate::domain::errors::DomainError;
+
+/// Used when no broker is configured, so local setups can pick the links up from the log.
+pub struct LogEventPublisher;
+
+#[async_trait]
+impl EventPublisher for LogEventPublisher {
+    async fn publish(&self, event: &AccountEvent) -> Result<(), DomainError> {
+        let payload = serde_json::to_string(event)
+            .map_err(|e| DomainError::EventPublishing(e.to_string()))?;
+        tracing::info!("Account event {}: {}", event.event_type(), payload);
+        Ok(())
+    }
+}
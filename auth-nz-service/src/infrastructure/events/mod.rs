@@ -0,0 +1,39 @@
+pub mod log_event_publisher;
+pub mod redis_event_publisher;
+
+pub use log_event_publisher::LogEventPublisher;
+pub use redis_event_publisher::RedisEventPublisher;
+
+use async_trait::async_trait;
+use crate::domain::events::{AccountEvent, EventPublisher};
+use crate::domain::errors::DomainError;
+use crate::infrastructure::config::{EventsConfig, RedisConfig};
+
+/// The publisher selected by configuration: Redis when `REDIS_URL` is set,
+/// the log otherwise.
+pub enum ConfiguredEventPublisher {
+    Redis(RedisEventPublisher),
+    Log(LogEventPublisher),
+}
+
+impl ConfiguredEventPublisher {
+    pub fn from_config(redis: &RedisConfig, events: &EventsConfig) -> Result<Self, redis::RedisError> {
+        match &redis.url {
+            Some(url) => Ok(Self::Redis(RedisEventPublisher::new(url, events.stream.clone(), events.stream_max_len)?)),
+            None => {
+                tracing::warn!("REDIS_URL is not set, account events are only logged");
+                Ok(Self::Log(LogEventPublisher))
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl EventPublisher for ConfiguredEventPublisher {
+    async fn publish(&self, event: &AccountEvent) -> Result<(), DomainError> {
+        match self {
+            Self::Redis(publisher) => publisher.publish(event).await,
+            Self::Log(publisher) => publisher.publish(event).await,
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use async_trait::async_trait;
+use crate::domain::events::{AccountEvent, EventPublisher};
+use crate::domain::errors::DomainError;
+
+/// Appends events to a Redis stream. Consumers such as notification-service
+/// read it with a consumer group, so events survive while they are down.
+pub struct RedisEventPublisher {
+    client: redis::Client,
+    stream: String,
+    max_len: usize,
+}
+
+impl RedisEventPublisher {
+    pub fn new(url: &str, stream: String, max_len: usize) -> Result<Self, redis::RedisError> {
+        Ok(Self {
+            client: redis::Client::open(url)?,
+            stream,
+            max_len,
+        })
+    }
+}
+
+#[async_trait]
+impl EventPublisher for RedisEventPublisher {
+    async fn publish(&self, event: &AccountEvent) -> Result<(), DomainError> {
+        let payload = serde_json::to_string(event)
+            .map_err(|e| DomainError::EventPublishing(e.to_string()))?;
+
+        let mut conn = self.client.get_multiplexed_async_connection().await
+            .map_err(|e| DomainError::EventPublishing(format!("Redis error: {}", e)))?;
+
+        redis::cmd("XADD")
+            .arg(&self.stream)
+            .arg("MAXLEN")
+            .arg("~")
+            .arg(self.max_len)
+            .arg("*")
+            .arg("type")
+            .arg(event.event_type())
+            .arg("payload")
+            .arg(payload)
+            .query_async::<_, String>(&mut conn)
+            .await
+            .map_err(|e| DomainError::EventPublishing(format!("Redis error: {}", e)))?;
+
+        Ok(())
+    }
+}
@@ -1,8 +1,8 @@
 pub mod config;
+pub mod events;
 pub mod persistence;
 pub mod repositories;
+pub mod token_keys;
 
 pub use config::Config;
-pub use persistence::*;
-pub use repositories::*;
 
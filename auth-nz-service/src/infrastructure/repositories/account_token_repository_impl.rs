@@ -0,0 +1,75 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use crate::domain::entities::account_token::AccountToken;
+use crate::domain::repositories::AccountTokenRepository;
+use crate::domain::value_objects::{AccountTokenPurpose, UserId};
+use crate::domain::errors::DomainError;
+use crate::infrastructure::persistence::PostgresPool;
+
+pub struct PostgresAccountTokenRepository {
+    pool: PostgresPool,
+}
+
+impl PostgresAccountTokenRepository {
+    pub fn new(pool: PostgresPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AccountTokenRepository for PostgresAccountTokenRepository {
+    async fn create(&self, token: &AccountToken) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO account_tokens (id, user_id, purpose, expires_at, consumed_at, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+        )
+        .bind(token.id)
+        .bind(token.user_id.as_uuid())
+        .bind(token.purpose.as_str())
+        .bind(token.expires_at)
+        .bind(token.consumed_at)
+        .bind(token.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn consume(&self, id: &Uuid, user_id: &UserId, purpose: AccountTokenPurpose) -> Result<bool, DomainError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE account_tokens
+            SET consumed_at = NOW()
+            WHERE id = $1 AND user_id = $2 AND purpose = $3 AND consumed_at IS NULL AND expires_at > NOW()
+            "#,
+        )
+        .bind(id)
+        .bind(user_id.as_uuid())
+        .bind(purpose.as_str())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn invalidate_for_user(&self, user_id: &UserId, purpose: AccountTokenPurpose) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            UPDATE account_tokens
+            SET consumed_at = NOW()
+            WHERE user_id = $1 AND purpose = $2 AND consumed_at IS NULL
+            "#,
+        )
+        .bind(user_id.as_uuid())
+        .bind(purpose.as_str())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,156 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::postgres::PgRow;
+use sqlx::{Postgres, Row, Transaction};
+use uuid::Uuid;
+use crate::domain::entities::api_key::ApiKey;
+use crate::domain::repositories::ApiKeyRepository;
+use crate::domain::value_objects::{ApiKeyId, Scopes, ServiceAccountId};
+use crate::domain::errors::DomainError;
+use crate::infrastructure::persistence::PostgresPool;
+
+const API_KEY_COLUMNS: &str =
+    "id, service_account_id, name, prefix, key_hash, scopes, expires_at, last_used_at, revoked_at, rotated_from, created_at";
+
+pub struct PostgresApiKeyRepository {
+    pool: PostgresPool,
+}
+
+impl PostgresApiKeyRepository {
+    pub fn new(pool: PostgresPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn map_api_key(row: &PgRow) -> Result<ApiKey, DomainError> {
+    Ok(ApiKey {
+        id: ApiKeyId::from_uuid(row.get("id")),
+        service_account_id: ServiceAccountId::from_uuid(row.get("service_account_id")),
+        name: row.get("name"),
+        prefix: row.get("prefix"),
+        key_hash: row.get("key_hash"),
+        scopes: Scopes::from_vec(row.get("scopes"))?,
+        expires_at: row.get("expires_at"),
+        last_used_at: row.get("last_used_at"),
+        revoked_at: row.get("revoked_at"),
+        rotated_from: row.get::<Option<Uuid>, _>("rotated_from").map(ApiKeyId::from_uuid),
+        created_at: row.get("created_at"),
+    })
+}
+
+async fn insert(tx: &mut Transaction<'_, Postgres>, api_key: &ApiKey) -> Result<(), DomainError> {
+    sqlx::query(
+        r#"
+        INSERT INTO api_keys (id, service_account_id, name, prefix, key_hash, scopes, expires_at, rotated_from, created_at)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        "#,
+    )
+    .bind(api_key.id.as_uuid())
+    .bind(api_key.service_account_id.as_uuid())
+    .bind(&api_key.name)
+    .bind(&api_key.prefix)
+    .bind(&api_key.key_hash)
+    .bind(api_key.scopes.to_vec())
+    .bind(api_key.expires_at)
+    .bind(api_key.rotated_from.map(|id| id.as_uuid()))
+    .bind(api_key.created_at)
+    .execute(&mut **tx)
+    .await
+    .map_err(|e| match e {
+        sqlx::Error::Database(ref db) if db.is_foreign_key_violation() => DomainError::ServiceAccountNotFound,
+        _ => DomainError::Repository(format!("PostgreSQL error: {}", e)),
+    })?;
+
+    Ok(())
+}
+
+#[async_trait]
+impl ApiKeyRepository for PostgresApiKeyRepository {
+    async fn create(&self, api_key: &ApiKey) -> Result<(), DomainError> {
+        let mut tx = self.pool.begin().await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        insert(&mut tx, api_key).await?;
+
+        tx.commit().await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &ApiKeyId) -> Result<Option<ApiKey>, DomainError> {
+        let row = sqlx::query(&format!("SELECT {} FROM api_keys WHERE id = $1", API_KEY_COLUMNS))
+            .bind(id.as_uuid())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        row.as_ref().map(map_api_key).transpose()
+    }
+
+    async fn find_by_prefix(&self, prefix: &str) -> Result<Option<ApiKey>, DomainError> {
+        let row = sqlx::query(&format!("SELECT {} FROM api_keys WHERE prefix = $1", API_KEY_COLUMNS))
+            .bind(prefix)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        row.as_ref().map(map_api_key).transpose()
+    }
+
+    async fn find_by_service_account_id(&self, service_account_id: &ServiceAccountId) -> Result<Vec<ApiKey>, DomainError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM api_keys WHERE service_account_id = $1 ORDER BY created_at DESC",
+            API_KEY_COLUMNS
+        ))
+        .bind(service_account_id.as_uuid())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        rows.iter().map(map_api_key).collect()
+    }
+
+    async fn update(&self, api_key: &ApiKey) -> Result<(), DomainError> {
+        sqlx::query("UPDATE api_keys SET expires_at = $2, revoked_at = $3 WHERE id = $1")
+            .bind(api_key.id.as_uuid())
+            .bind(api_key.expires_at)
+            .bind(api_key.revoked_at)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn replace(&self, retired: &ApiKey, successor: &ApiKey) -> Result<(), DomainError> {
+        let mut tx = self.pool.begin().await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        sqlx::query("UPDATE api_keys SET expires_at = $2, revoked_at = $3 WHERE id = $1")
+            .bind(retired.id.as_uuid())
+            .bind(retired.expires_at)
+            .bind(retired.revoked_at)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        insert(&mut tx, successor).await?;
+
+        tx.commit().await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn touch(&self, id: &ApiKeyId, used_at: DateTime<Utc>) -> Result<(), DomainError> {
+        sqlx::query("UPDATE api_keys SET last_used_at = $2 WHERE id = $1")
+            .bind(id.as_uuid())
+            .bind(used_at)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+}
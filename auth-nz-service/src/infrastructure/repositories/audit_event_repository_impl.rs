@@ -0,0 +1,117 @@
+use async_trait::async_trait;
+use serde_json::Value;
+use sqlx::postgres::PgRow;
+use sqlx::Row;
+use crate::domain::entities::audit_event::{AuditEvent, NewAuditEvent, GENESIS_HASH};
+use crate::domain::repositories::{AuditEventQuery, AuditEventRepository};
+use crate::domain::value_objects::UserId;
+use crate::domain::errors::DomainError;
+use crate::infrastructure::persistence::PostgresPool;
+
+pub struct PostgresAuditEventRepository {
+    pool: PostgresPool,
+}
+
+impl PostgresAuditEventRepository {
+    pub fn new(pool: PostgresPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn map_audit_event(row: &PgRow) -> AuditEvent {
+    let details: String = row.get("details");
+    AuditEvent {
+        sequence: row.get("sequence"),
+        event_type: row.get("event_type"),
+        actor: row.get("actor"),
+        user_id: row.get::<Option<uuid::Uuid>, _>("user_id").map(UserId::from_uuid),
+        ip_address: row.get("ip_address"),
+        // Text that no longer parses was tampered with; keeping it as a string lets verification flag it
+        details: serde_json::from_str(&details).unwrap_or(Value::String(details)),
+        occurred_at: row.get("occurred_at"),
+        prev_hash: row.get("prev_hash"),
+        hash: row.get("hash"),
+    }
+}
+
+#[async_trait]
+impl AuditEventRepository for PostgresAuditEventRepository {
+    async fn append(&self, event: NewAuditEvent) -> Result<AuditEvent, DomainError> {
+        let mut tx = self.pool.begin().await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        // Conflicts with itself but not with readers, so appends queue up behind each other
+        sqlx::query("LOCK TABLE audit_events IN SHARE ROW EXCLUSIVE MODE")
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        let last = sqlx::query("SELECT sequence, hash FROM audit_events ORDER BY sequence DESC LIMIT 1")
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+        let (prev_sequence, prev_hash) = match last {
+            Some(row) => (row.get::<i64, _>("sequence"), row.get::<String, _>("hash")),
+            None => (0, GENESIS_HASH.to_string()),
+        };
+
+        let sealed = AuditEvent::seal(event, prev_sequence + 1, prev_hash);
+
+        sqlx::query(
+            r#"
+            INSERT INTO audit_events (sequence, event_type, actor, user_id, ip_address, details, occurred_at, prev_hash, hash)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            "#,
+        )
+        .bind(sealed.sequence)
+        .bind(&sealed.event_type)
+        .bind(&sealed.actor)
+        .bind(sealed.user_id.map(|id| id.as_uuid()))
+        .bind(&sealed.ip_address)
+        .bind(sealed.details.to_string())
+        .bind(sealed.occurred_at)
+        .bind(&sealed.prev_hash)
+        .bind(&sealed.hash)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        tx.commit().await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(sealed)
+    }
+
+    async fn find(&self, query: &AuditEventQuery) -> Result<Vec<AuditEvent>, DomainError> {
+        let order = if query.newest_first { "DESC" } else { "ASC" };
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT sequence, event_type, actor, user_id, ip_address, details, occurred_at, prev_hash, hash
+            FROM audit_events
+            WHERE ($1::uuid IS NULL OR user_id = $1)
+              AND ($2::text IS NULL OR actor = $2)
+              AND ($3::text IS NULL OR event_type = $3)
+              AND ($4::timestamptz IS NULL OR occurred_at >= $4)
+              AND ($5::timestamptz IS NULL OR occurred_at <= $5)
+              AND ($6::bigint IS NULL OR sequence > $6)
+              AND ($7::bigint IS NULL OR sequence < $7)
+            ORDER BY sequence {}
+            LIMIT $8
+            "#,
+            order
+        ))
+        .bind(query.user_id.map(|id| id.as_uuid()))
+        .bind(&query.actor)
+        .bind(query.event_type.map(|event_type| event_type.as_str().to_string()))
+        .bind(query.from)
+        .bind(query.to)
+        .bind(query.after_sequence)
+        .bind(query.before_sequence)
+        .bind(query.limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(rows.iter().map(map_audit_event).collect())
+    }
+}
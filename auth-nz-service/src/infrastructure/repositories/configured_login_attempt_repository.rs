@@ -0,0 +1,87 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use crate::domain::repositories::LoginAttemptRepository;
+use crate::domain::errors::DomainError;
+use crate::infrastructure::config::RedisConfig;
+use super::{InMemoryLoginAttemptRepository, RedisLoginAttemptRepository};
+
+/// Redis when `REDIS_URL` is set, with the in-memory store taking over for
+/// any call Redis fails, so an outage weakens throttling instead of blocking logins.
+pub struct ConfiguredLoginAttemptRepository {
+    redis: Option<RedisLoginAttemptRepository>,
+    fallback: InMemoryLoginAttemptRepository,
+}
+
+impl ConfiguredLoginAttemptRepository {
+    pub fn from_config(redis: &RedisConfig) -> Result<Self, redis::RedisError> {
+        let redis = match &redis.url {
+            Some(url) => Some(RedisLoginAttemptRepository::new(url)?),
+            None => {
+                tracing::warn!("REDIS_URL is not set, login attempts are counted per instance");
+                None
+            }
+        };
+        Ok(Self {
+            redis,
+            fallback: InMemoryLoginAttemptRepository::new(),
+        })
+    }
+
+    fn degrade(e: DomainError) {
+        tracing::warn!("Login attempt store unavailable, using in-memory counters: {}", e);
+    }
+}
+
+#[async_trait]
+impl LoginAttemptRepository for ConfiguredLoginAttemptRepository {
+    async fn record_failure(&self, key: &str, window: Duration) -> Result<u32, DomainError> {
+        if let Some(redis) = &self.redis {
+            match redis.record_failure(key, window).await {
+                Ok(count) => return Ok(count),
+                Err(e) => Self::degrade(e),
+            }
+        }
+        self.fallback.record_failure(key, window).await
+    }
+
+    async fn failure_count(&self, key: &str) -> Result<u32, DomainError> {
+        if let Some(redis) = &self.redis {
+            match redis.failure_count(key).await {
+                Ok(count) => return Ok(count),
+                Err(e) => Self::degrade(e),
+            }
+        }
+        self.fallback.failure_count(key).await
+    }
+
+    async fn clear_failures(&self, key: &str) -> Result<(), DomainError> {
+        if let Some(redis) = &self.redis {
+            if let Err(e) = redis.clear_failures(key).await {
+                Self::degrade(e);
+            }
+        }
+        self.fallback.clear_failures(key).await
+    }
+
+    async fn lock(&self, key: &str, until: DateTime<Utc>) -> Result<(), DomainError> {
+        if let Some(redis) = &self.redis {
+            match redis.lock(key, until).await {
+                Ok(()) => return Ok(()),
+                Err(e) => Self::degrade(e),
+            }
+        }
+        self.fallback.lock(key, until).await
+    }
+
+    async fn locked_until(&self, key: &str) -> Result<Option<DateTime<Utc>>, DomainError> {
+        if let Some(redis) = &self.redis {
+            match redis.locked_until(key).await {
+                Ok(Some(until)) => return Ok(Some(until)),
+                // A lock taken while Redis was down only lives in memory
+                Ok(None) => {}
+                Err(e) => Self::degrade(e),
+            }
+        }
+        self.fallback.locked_until(key).await
+    }
+}
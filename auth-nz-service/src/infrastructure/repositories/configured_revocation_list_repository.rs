@@ -0,0 +1,59 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::domain::repositories::RevocationListRepository;
+use crate::domain::errors::DomainError;
+use crate::infrastructure::config::RedisConfig;
+use super::{InMemoryRevocationListRepository, RedisRevocationListRepository};
+
+/// Redis when `REDIS_URL` is set, with the in-memory list taking over for any
+/// call Redis fails. The list is only a cache in front of the session store,
+/// so an outage costs speed, not correctness.
+pub struct ConfiguredRevocationListRepository {
+    redis: Option<RedisRevocationListRepository>,
+    fallback: InMemoryRevocationListRepository,
+}
+
+impl ConfiguredRevocationListRepository {
+    pub fn from_config(redis: &RedisConfig) -> Result<Self, redis::RedisError> {
+        let redis = match &redis.url {
+            Some(url) => Some(RedisRevocationListRepository::new(url)?),
+            None => {
+                tracing::warn!("REDIS_URL is not set, revoked tokens are cached per instance");
+                None
+            }
+        };
+        Ok(Self {
+            redis,
+            fallback: InMemoryRevocationListRepository::new(),
+        })
+    }
+
+    fn degrade(e: DomainError) {
+        tracing::warn!("Revocation list unavailable, using in-memory list: {}", e);
+    }
+}
+
+#[async_trait]
+impl RevocationListRepository for ConfiguredRevocationListRepository {
+    async fn add(&self, key: &str, until: DateTime<Utc>) -> Result<(), DomainError> {
+        if let Some(redis) = &self.redis {
+            match redis.add(key, until).await {
+                Ok(()) => return Ok(()),
+                Err(e) => Self::degrade(e),
+            }
+        }
+        self.fallback.add(key, until).await
+    }
+
+    async fn contains(&self, key: &str) -> Result<bool, DomainError> {
+        if let Some(redis) = &self.redis {
+            match redis.contains(key).await {
+                Ok(true) => return Ok(true),
+                // An entry added while Redis was down only lives in memory
+                Ok(false) => {}
+                Err(e) => Self::degrade(e),
+            }
+        }
+        self.fallback.contains(key).await
+    }
+}
@@ -0,0 +1,149 @@
+use async_trait::async_trait;
+use sqlx::postgres::PgRow;
+use sqlx::Row;
+use crate::domain::entities::federated_identity::FederatedIdentity;
+use crate::domain::entities::federated_login::FederatedLogin;
+use crate::domain::repositories::FederatedIdentityRepository;
+use crate::domain::value_objects::UserId;
+use crate::domain::errors::DomainError;
+use crate::infrastructure::persistence::PostgresPool;
+
+pub struct PostgresFederatedIdentityRepository {
+    pool: PostgresPool,
+}
+
+impl PostgresFederatedIdentityRepository {
+    pub fn new(pool: PostgresPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn map_identity(row: &PgRow) -> FederatedIdentity {
+    FederatedIdentity {
+        provider: row.get("provider"),
+        subject: row.get("subject"),
+        user_id: UserId::from_uuid(row.get("user_id")),
+        email: row.get("email"),
+        created_at: row.get("created_at"),
+        last_login_at: row.get("last_login_at"),
+    }
+}
+
+#[async_trait]
+impl FederatedIdentityRepository for PostgresFederatedIdentityRepository {
+    async fn create_login(&self, login: &FederatedLogin) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO federated_logins (state_hash, provider, nonce, code_verifier, expires_at, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+        )
+        .bind(&login.state_hash)
+        .bind(&login.provider)
+        .bind(&login.nonce)
+        .bind(&login.code_verifier)
+        .bind(login.expires_at)
+        .bind(login.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn consume_login(&self, state_hash: &str) -> Result<Option<FederatedLogin>, DomainError> {
+        let row = sqlx::query(
+            r#"
+            DELETE FROM federated_logins
+            WHERE state_hash = $1
+            RETURNING state_hash, provider, nonce, code_verifier, expires_at, created_at
+            "#,
+        )
+        .bind(state_hash)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(row.map(|r| FederatedLogin {
+            state_hash: r.get("state_hash"),
+            provider: r.get("provider"),
+            nonce: r.get("nonce"),
+            code_verifier: r.get("code_verifier"),
+            expires_at: r.get("expires_at"),
+            created_at: r.get("created_at"),
+        }))
+    }
+
+    async fn find_identity(&self, provider: &str, subject: &str) -> Result<Option<FederatedIdentity>, DomainError> {
+        let row = sqlx::query(
+            r#"
+            SELECT provider, subject, user_id, email, created_at, last_login_at
+            FROM federated_identities
+            WHERE provider = $1 AND subject = $2
+            "#,
+        )
+        .bind(provider)
+        .bind(subject)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(row.as_ref().map(map_identity))
+    }
+
+    async fn find_identities_by_user_id(&self, user_id: &UserId) -> Result<Vec<FederatedIdentity>, DomainError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT provider, subject, user_id, email, created_at, last_login_at
+            FROM federated_identities
+            WHERE user_id = $1
+            ORDER BY created_at
+            "#,
+        )
+        .bind(user_id.as_uuid())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(rows.iter().map(map_identity).collect())
+    }
+
+    async fn create_identity(&self, identity: &FederatedIdentity) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO federated_identities (provider, subject, user_id, email, created_at, last_login_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+        )
+        .bind(&identity.provider)
+        .bind(&identity.subject)
+        .bind(identity.user_id.as_uuid())
+        .bind(&identity.email)
+        .bind(identity.created_at)
+        .bind(identity.last_login_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn record_login(&self, identity: &FederatedIdentity) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            UPDATE federated_identities
+            SET email = $3, last_login_at = $4
+            WHERE provider = $1 AND subject = $2
+            "#,
+        )
+        .bind(&identity.provider)
+        .bind(&identity.subject)
+        .bind(&identity.email)
+        .bind(identity.last_login_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+}
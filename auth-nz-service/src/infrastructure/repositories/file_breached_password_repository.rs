@@ -0,0 +1,61 @@
+use std::collections::HashMap;
+use std::path::Path;
+use async_trait::async_trait;
+use crate::domain::repositories::BreachedPasswordRepository;
+use crate::domain::errors::DomainError;
+
+/// Breach corpus loaded once from a file in the Pwned Passwords download
+/// format: one uppercase or lowercase SHA-1 per line, optionally followed by
+/// `:count`. Blank lines and lines starting with `#` are skipped.
+pub struct FileBreachedPasswordRepository {
+    ranges: HashMap<String, Vec<(String, u64)>>,
+}
+
+impl FileBreachedPasswordRepository {
+    /// A corpus with no entries, for when none is configured.
+    pub fn empty() -> Self {
+        Self { ranges: HashMap::new() }
+    }
+
+    pub async fn load(path: &Path) -> Result<Self, DomainError> {
+        let invalid = |line: usize, reason: &str| {
+            DomainError::ValidationError(format!("{}:{}: {}", path.display(), line, reason))
+        };
+
+        let contents = tokio::fs::read_to_string(path).await
+            .map_err(|e| DomainError::ValidationError(format!("{}: {}", path.display(), e)))?;
+
+        let mut ranges: HashMap<String, Vec<(String, u64)>> = HashMap::new();
+        let mut hashes = 0;
+        for (index, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (hash, count) = match line.split_once(':') {
+                Some((hash, count)) => (hash, count.trim().parse().map_err(|_| invalid(index + 1, "invalid count"))?),
+                None => (line, 1),
+            };
+            if hash.len() != 40 || !hash.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Err(invalid(index + 1, "expected a hex SHA-1 hash"));
+            }
+
+            let hash = hash.to_ascii_uppercase();
+            let (prefix, suffix) = hash.split_at(5);
+            ranges.entry(prefix.to_string()).or_default().push((suffix.to_string(), count));
+            hashes += 1;
+        }
+
+        tracing::info!("Loaded {} breached password hashes from {}", hashes, path.display());
+
+        Ok(Self { ranges })
+    }
+}
+
+#[async_trait]
+impl BreachedPasswordRepository for FileBreachedPasswordRepository {
+    async fn find_range(&self, prefix: &str) -> Result<Vec<(String, u64)>, DomainError> {
+        Ok(self.ranges.get(&prefix.to_ascii_uppercase()).cloned().unwrap_or_default())
+    }
+}
@@ -0,0 +1,53 @@
+use std::path::Path;
+use async_trait::async_trait;
+use crate::domain::entities::policy::Policy;
+use crate::domain::repositories::PolicyRepository;
+use crate::domain::errors::DomainError;
+
+/// Policies loaded once from a directory of JSON files.
+///
+/// Each `*.json` file holds either a single policy or an array of policies.
+pub struct FilePolicyRepository {
+    policies: Vec<Policy>,
+}
+
+impl FilePolicyRepository {
+    pub async fn load(dir: &Path) -> Result<Self, DomainError> {
+        let mut policies = Vec::new();
+
+        let mut entries = tokio::fs::read_dir(dir).await
+            .map_err(|e| DomainError::InvalidPolicy(format!("{}: {}", dir.display(), e)))?;
+        while let Some(entry) = entries.next_entry().await
+            .map_err(|e| DomainError::InvalidPolicy(format!("{}: {}", dir.display(), e)))?
+        {
+            let path = entry.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                continue;
+            }
+
+            let contents = tokio::fs::read_to_string(&path).await
+                .map_err(|e| DomainError::InvalidPolicy(format!("{}: {}", path.display(), e)))?;
+            let value: serde_json::Value = serde_json::from_str(&contents)
+                .map_err(|e| DomainError::InvalidPolicy(format!("{}: {}", path.display(), e)))?;
+            let parsed: Vec<Policy> = if value.is_array() {
+                serde_json::from_value(value)
+            } else {
+                serde_json::from_value(value).map(|policy| vec![policy])
+            }
+            .map_err(|e| DomainError::InvalidPolicy(format!("{}: {}", path.display(), e)))?;
+
+            policies.extend(parsed);
+        }
+
+        tracing::info!("Loaded {} authorization policies from {}", policies.len(), dir.display());
+
+        Ok(Self { policies })
+    }
+}
+
+#[async_trait]
+impl PolicyRepository for FilePolicyRepository {
+    async fn find_all(&self) -> Result<Vec<Policy>, DomainError> {
+        Ok(self.policies.clone())
+    }
+}
@@ -0,0 +1,171 @@
+use std::collections::HashMap;
+use std::time::Duration;
+use async_trait::async_trait;
+use jsonwebtoken::jwk::JwkSet;
+use serde::Deserialize;
+use tokio::sync::RwLock;
+use crate::domain::entities::identity_provider::{IdentityProvider, IdentityProviderMetadata};
+use crate::domain::repositories::IdentityProviderRepository;
+use crate::domain::errors::DomainError;
+use crate::infrastructure::config::FederationConfig;
+
+#[derive(Deserialize)]
+struct DiscoveryDocument {
+    issuer: String,
+    authorization_endpoint: String,
+    token_endpoint: String,
+    jwks_uri: String,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    id_token: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct TokenErrorResponse {
+    error: String,
+    #[serde(default)]
+    error_description: Option<String>,
+}
+
+/// Providers configured through `FederationConfig`. Discovery documents and
+/// key sets are fetched on first use and kept for the life of the process.
+pub struct HttpIdentityProviderRepository {
+    client: reqwest::Client,
+    providers: Vec<IdentityProvider>,
+    metadata: RwLock<HashMap<String, IdentityProviderMetadata>>,
+    keys: RwLock<HashMap<String, JwkSet>>,
+}
+
+impl HttpIdentityProviderRepository {
+    pub fn new(config: &FederationConfig) -> Result<Self, reqwest::Error> {
+        let base_url = config.redirect_base_url.trim_end_matches('/');
+        let providers = config.providers.iter()
+            .map(|provider| IdentityProvider {
+                name: provider.name.clone(),
+                issuer: provider.issuer.clone(),
+                client_id: provider.client_id.clone(),
+                client_secret: provider.client_secret.clone(),
+                scopes: provider.scopes.clone(),
+                redirect_uri: format!("{}/login/oidc/{}/callback", base_url, provider.name),
+            })
+            .collect();
+
+        Ok(Self {
+            client: reqwest::Client::builder()
+                .timeout(Duration::from_secs(10))
+                .build()?,
+            providers,
+            metadata: RwLock::new(HashMap::new()),
+            keys: RwLock::new(HashMap::new()),
+        })
+    }
+}
+
+fn unavailable(provider: &IdentityProvider, e: impl std::fmt::Display) -> DomainError {
+    DomainError::IdentityProviderUnavailable(format!("{}: {}", provider.name, e))
+}
+
+#[async_trait]
+impl IdentityProviderRepository for HttpIdentityProviderRepository {
+    async fn find_by_name(&self, name: &str) -> Result<Option<IdentityProvider>, DomainError> {
+        Ok(self.providers.iter().find(|provider| provider.name == name).cloned())
+    }
+
+    async fn find_all(&self) -> Result<Vec<IdentityProvider>, DomainError> {
+        Ok(self.providers.clone())
+    }
+
+    async fn metadata(&self, provider: &IdentityProvider) -> Result<IdentityProviderMetadata, DomainError> {
+        if let Some(metadata) = self.metadata.read().await.get(&provider.name) {
+            return Ok(metadata.clone());
+        }
+
+        let url = format!("{}/.well-known/openid-configuration", provider.issuer.trim_end_matches('/'));
+        let document: DiscoveryDocument = self.client.get(&url)
+            .send().await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| unavailable(provider, e))?
+            .json().await
+            .map_err(|e| unavailable(provider, e))?;
+
+        // Tokens are checked against this issuer, so it must be the configured one
+        if document.issuer != provider.issuer {
+            return Err(unavailable(provider, format!(
+                "discovery document names issuer {}, expected {}",
+                document.issuer, provider.issuer
+            )));
+        }
+
+        let metadata = IdentityProviderMetadata {
+            issuer: document.issuer,
+            authorization_endpoint: document.authorization_endpoint,
+            token_endpoint: document.token_endpoint,
+            jwks_uri: document.jwks_uri,
+        };
+        self.metadata.write().await.insert(provider.name.clone(), metadata.clone());
+        Ok(metadata)
+    }
+
+    async fn signing_keys(&self, provider: &IdentityProvider, refresh: bool) -> Result<JwkSet, DomainError> {
+        if !refresh {
+            if let Some(keys) = self.keys.read().await.get(&provider.name) {
+                return Ok(keys.clone());
+            }
+        }
+
+        let metadata = self.metadata(provider).await?;
+        let keys: JwkSet = self.client.get(&metadata.jwks_uri)
+            .send().await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| unavailable(provider, e))?
+            .json().await
+            .map_err(|e| unavailable(provider, e))?;
+
+        self.keys.write().await.insert(provider.name.clone(), keys.clone());
+        Ok(keys)
+    }
+
+    async fn exchange_code(
+        &self,
+        provider: &IdentityProvider,
+        code: &str,
+        code_verifier: &str,
+    ) -> Result<String, DomainError> {
+        let metadata = self.metadata(provider).await?;
+
+        let mut form = vec![
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", provider.redirect_uri.as_str()),
+            ("code_verifier", code_verifier),
+            ("client_id", provider.client_id.as_str()),
+        ];
+        if let Some(client_secret) = &provider.client_secret {
+            form.push(("client_secret", client_secret.as_str()));
+        }
+
+        let response = self.client.post(&metadata.token_endpoint)
+            .form(&form)
+            .send().await
+            .map_err(|e| unavailable(provider, e))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            return Err(match response.json::<TokenErrorResponse>().await {
+                Ok(error) => DomainError::FederatedLoginFailed(format!(
+                    "Code exchange rejected: {}{}",
+                    error.error,
+                    error.error_description.map(|d| format!(" ({})", d)).unwrap_or_default()
+                )),
+                Err(_) => unavailable(provider, format!("token endpoint returned {}", status)),
+            });
+        }
+
+        let tokens: TokenResponse = response.json().await
+            .map_err(|e| unavailable(provider, e))?;
+        tokens.id_token
+            .ok_or_else(|| DomainError::FederatedLoginFailed("Provider returned no ID token".to_string()))
+    }
+}
@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use crate::domain::repositories::LoginAttemptRepository;
+use crate::domain::errors::DomainError;
+
+struct FailureWindow {
+    count: u32,
+    expires_at: DateTime<Utc>,
+}
+
+/// Process-local counters, used when Redis is not configured or unreachable.
+/// Each instance counts on its own, so limits are per replica.
+#[derive(Default)]
+pub struct InMemoryLoginAttemptRepository {
+    failures: Mutex<HashMap<String, FailureWindow>>,
+    locks: Mutex<HashMap<String, DateTime<Utc>>>,
+}
+
+impl InMemoryLoginAttemptRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl LoginAttemptRepository for InMemoryLoginAttemptRepository {
+    async fn record_failure(&self, key: &str, window: Duration) -> Result<u32, DomainError> {
+        let now = Utc::now();
+        let mut failures = self.failures.lock().unwrap();
+        failures.retain(|_, entry| entry.expires_at > now);
+
+        let entry = failures.entry(key.to_string()).or_insert(FailureWindow {
+            count: 0,
+            expires_at: now + window,
+        });
+        entry.count += 1;
+        Ok(entry.count)
+    }
+
+    async fn failure_count(&self, key: &str) -> Result<u32, DomainError> {
+        let now = Utc::now();
+        Ok(self.failures.lock().unwrap()
+            .get(key)
+            .filter(|entry| entry.expires_at > now)
+            .map(|entry| entry.count)
+            .unwrap_or(0))
+    }
+
+    async fn clear_failures(&self, key: &str) -> Result<(), DomainError> {
+        self.failures.lock().unwrap().remove(key);
+        Ok(())
+    }
+
+    async fn lock(&self, key: &str, until: DateTime<Utc>) -> Result<(), DomainError> {
+        let now = Utc::now();
+        let mut locks = self.locks.lock().unwrap();
+        locks.retain(|_, locked_until| *locked_until > now);
+        locks.insert(key.to_string(), until);
+        Ok(())
+    }
+
+    async fn locked_until(&self, key: &str) -> Result<Option<DateTime<Utc>>, DomainError> {
+        let now = Utc::now();
+        Ok(self.locks.lock().unwrap()
+            .get(key)
+            .copied()
+            .filter(|until| *until > now))
+    }
+}
@@ -0,0 +1,38 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::domain::repositories::RevocationListRepository;
+use crate::domain::errors::DomainError;
+
+/// Process-local list, used when Redis is not configured or unreachable.
+/// Entries only reach the replica that recorded them; the others fall back
+/// to the session store.
+#[derive(Default)]
+pub struct InMemoryRevocationListRepository {
+    entries: Mutex<HashMap<String, DateTime<Utc>>>,
+}
+
+impl InMemoryRevocationListRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl RevocationListRepository for InMemoryRevocationListRepository {
+    async fn add(&self, key: &str, until: DateTime<Utc>) -> Result<(), DomainError> {
+        let now = Utc::now();
+        let mut entries = self.entries.lock().unwrap();
+        entries.retain(|_, expires_at| *expires_at > now);
+        entries.insert(key.to_string(), until);
+        Ok(())
+    }
+
+    async fn contains(&self, key: &str) -> Result<bool, DomainError> {
+        let now = Utc::now();
+        Ok(self.entries.lock().unwrap()
+            .get(key)
+            .is_some_and(|expires_at| *expires_at > now))
+    }
+}
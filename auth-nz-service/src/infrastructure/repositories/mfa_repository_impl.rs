@@ -0,0 +1,215 @@
+use async_trait::async_trait;
+use sqlx::Row;
+use crate::domain::entities::mfa_challenge::MfaChallenge;
+use crate::domain::entities::mfa_factor::MfaFactor;
+use crate::domain::repositories::MfaRepository;
+use crate::domain::value_objects::{TotpSecret, UserId};
+use crate::domain::errors::DomainError;
+use crate::infrastructure::persistence::PostgresPool;
+
+pub struct PostgresMfaRepository {
+    pool: PostgresPool,
+}
+
+impl PostgresMfaRepository {
+    pub fn new(pool: PostgresPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl MfaRepository for PostgresMfaRepository {
+    async fn find_factor(&self, user_id: &UserId) -> Result<Option<MfaFactor>, DomainError> {
+        let row = sqlx::query(
+            r#"
+            SELECT user_id, secret, confirmed_at, last_used_step, created_at
+            FROM mfa_factors
+            WHERE user_id = $1
+            "#,
+        )
+        .bind(user_id.as_uuid())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        row.map(|r| {
+            Ok(MfaFactor {
+                user_id: UserId::from_uuid(r.get("user_id")),
+                secret: TotpSecret::new(r.get("secret"))?,
+                confirmed_at: r.get("confirmed_at"),
+                last_used_step: r.get("last_used_step"),
+                created_at: r.get("created_at"),
+            })
+        })
+        .transpose()
+    }
+
+    async fn save_factor(&self, factor: &MfaFactor) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO mfa_factors (user_id, secret, confirmed_at, last_used_step, created_at)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (user_id) DO UPDATE
+            SET secret = EXCLUDED.secret,
+                confirmed_at = EXCLUDED.confirmed_at,
+                last_used_step = EXCLUDED.last_used_step,
+                created_at = EXCLUDED.created_at
+            "#,
+        )
+        .bind(factor.user_id.as_uuid())
+        .bind(factor.secret.as_str())
+        .bind(factor.confirmed_at)
+        .bind(factor.last_used_step)
+        .bind(factor.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn delete_factor(&self, user_id: &UserId) -> Result<(), DomainError> {
+        let mut tx = self.pool.begin().await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        sqlx::query("DELETE FROM mfa_recovery_codes WHERE user_id = $1")
+            .bind(user_id.as_uuid())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        sqlx::query("DELETE FROM mfa_factors WHERE user_id = $1")
+            .bind(user_id.as_uuid())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        tx.commit().await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn record_used_step(&self, user_id: &UserId, step: i64) -> Result<bool, DomainError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE mfa_factors
+            SET last_used_step = $2
+            WHERE user_id = $1 AND (last_used_step IS NULL OR last_used_step < $2)
+            "#,
+        )
+        .bind(user_id.as_uuid())
+        .bind(step)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn replace_recovery_codes(&self, user_id: &UserId, code_hashes: &[String]) -> Result<(), DomainError> {
+        let mut tx = self.pool.begin().await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        sqlx::query("DELETE FROM mfa_recovery_codes WHERE user_id = $1")
+            .bind(user_id.as_uuid())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO mfa_recovery_codes (user_id, code_hash)
+            SELECT $1, code_hash FROM UNNEST($2::TEXT[]) AS code_hash
+            "#,
+        )
+        .bind(user_id.as_uuid())
+        .bind(code_hashes)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        tx.commit().await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn consume_recovery_code(&self, user_id: &UserId, code_hash: &str) -> Result<bool, DomainError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE mfa_recovery_codes
+            SET used_at = NOW()
+            WHERE user_id = $1 AND code_hash = $2 AND used_at IS NULL
+            "#,
+        )
+        .bind(user_id.as_uuid())
+        .bind(code_hash)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn create_challenge(&self, challenge: &MfaChallenge) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO mfa_challenges (token_hash, user_id, attempts, expires_at, created_at)
+            VALUES ($1, $2, $3, $4, $5)
+            "#,
+        )
+        .bind(&challenge.token_hash)
+        .bind(challenge.user_id.as_uuid())
+        .bind(challenge.attempts)
+        .bind(challenge.expires_at)
+        .bind(challenge.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn find_challenge(&self, token_hash: &str) -> Result<Option<MfaChallenge>, DomainError> {
+        let row = sqlx::query(
+            r#"
+            SELECT token_hash, user_id, attempts, expires_at, created_at
+            FROM mfa_challenges
+            WHERE token_hash = $1
+            "#,
+        )
+        .bind(token_hash)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(row.map(|r| MfaChallenge {
+            token_hash: r.get("token_hash"),
+            user_id: UserId::from_uuid(r.get("user_id")),
+            attempts: r.get("attempts"),
+            expires_at: r.get("expires_at"),
+            created_at: r.get("created_at"),
+        }))
+    }
+
+    async fn record_challenge_failure(&self, token_hash: &str) -> Result<(), DomainError> {
+        sqlx::query("UPDATE mfa_challenges SET attempts = attempts + 1 WHERE token_hash = $1")
+            .bind(token_hash)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn consume_challenge(&self, token_hash: &str) -> Result<bool, DomainError> {
+        let result = sqlx::query("DELETE FROM mfa_challenges WHERE token_hash = $1")
+            .bind(token_hash)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+}
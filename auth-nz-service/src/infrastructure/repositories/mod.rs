@@ -1,3 +1,51 @@
-// Repository implementations will be added here
-// Similar structure to user-service
+pub mod user_repository_impl;
+pub mod session_repository_impl;
+pub mod role_repository_impl;
+pub mod permission_repository_impl;
+pub mod mfa_repository_impl;
+pub mod account_token_repository_impl;
+pub mod oauth_client_repository_impl;
+pub mod oauth_grant_repository_impl;
+pub mod federated_identity_repository_impl;
+pub mod audit_event_repository_impl;
+pub mod service_account_repository_impl;
+pub mod api_key_repository_impl;
+pub mod passkey_repository_impl;
+pub mod password_history_repository_impl;
+pub mod organization_repository_impl;
+pub mod organization_invitation_repository_impl;
+pub mod file_policy_repository;
+pub mod file_breached_password_repository;
+pub mod http_identity_provider_repository;
+pub mod in_memory_login_attempt_repository;
+pub mod redis_login_attempt_repository;
+pub mod configured_login_attempt_repository;
+pub mod in_memory_revocation_list_repository;
+pub mod redis_revocation_list_repository;
+pub mod configured_revocation_list_repository;
 
+pub use user_repository_impl::PostgresUserRepository;
+pub use session_repository_impl::PostgresSessionRepository;
+pub use role_repository_impl::PostgresRoleRepository;
+pub use permission_repository_impl::PostgresPermissionRepository;
+pub use mfa_repository_impl::PostgresMfaRepository;
+pub use account_token_repository_impl::PostgresAccountTokenRepository;
+pub use oauth_client_repository_impl::PostgresOAuthClientRepository;
+pub use oauth_grant_repository_impl::PostgresOAuthGrantRepository;
+pub use federated_identity_repository_impl::PostgresFederatedIdentityRepository;
+pub use audit_event_repository_impl::PostgresAuditEventRepository;
+pub use service_account_repository_impl::PostgresServiceAccountRepository;
+pub use api_key_repository_impl::PostgresApiKeyRepository;
+pub use passkey_repository_impl::PostgresPasskeyRepository;
+pub use password_history_repository_impl::PostgresPasswordHistoryRepository;
+pub use organization_repository_impl::PostgresOrganizationRepository;
+pub use organization_invitation_repository_impl::PostgresOrganizationInvitationRepository;
+pub use file_policy_repository::FilePolicyRepository;
+pub use file_breached_password_repository::FileBreachedPasswordRepository;
+pub use http_identity_provider_repository::HttpIdentityProviderRepository;
+pub use in_memory_login_attempt_repository::InMemoryLoginAttemptRepository;
+pub use redis_login_attempt_repository::RedisLoginAttemptRepository;
+pub use configured_login_attempt_repository::ConfiguredLoginAttemptRepository;
+pub use in_memory_revocation_list_repository::InMemoryRevocationListRepository;
+pub use redis_revocation_list_repository::RedisRevocationListRepository;
+pub use configured_revocation_list_repository::ConfiguredRevocationListRepository;
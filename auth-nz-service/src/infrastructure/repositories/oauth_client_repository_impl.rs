@@ -0,0 +1,90 @@
+use async_trait::async_trait;
+use sqlx::postgres::PgRow;
+use sqlx::Row;
+use crate::domain::entities::oauth_client::OAuthClient;
+use crate::domain::repositories::OAuthClientRepository;
+use crate::domain::value_objects::{GrantType, Scopes};
+use crate::domain::errors::DomainError;
+use crate::infrastructure::persistence::PostgresPool;
+
+const CLIENT_COLUMNS: &str = "id, name, secret_hash, redirect_uris, grant_types, scopes, created_at";
+
+pub struct PostgresOAuthClientRepository {
+    pool: PostgresPool,
+}
+
+impl PostgresOAuthClientRepository {
+    pub fn new(pool: PostgresPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn map_client(row: &PgRow) -> Result<OAuthClient, DomainError> {
+    Ok(OAuthClient {
+        id: row.get("id"),
+        name: row.get("name"),
+        secret_hash: row.get("secret_hash"),
+        redirect_uris: row.get("redirect_uris"),
+        grant_types: row.get::<Vec<String>, _>("grant_types")
+            .iter()
+            .map(|grant_type| GrantType::parse(grant_type))
+            .collect::<Result<_, _>>()?,
+        scopes: Scopes::from_vec(row.get("scopes"))?,
+        created_at: row.get("created_at"),
+    })
+}
+
+#[async_trait]
+impl OAuthClientRepository for PostgresOAuthClientRepository {
+    async fn create(&self, client: &OAuthClient) -> Result<(), DomainError> {
+        let grant_types: Vec<&str> = client.grant_types.iter().map(GrantType::as_str).collect();
+
+        sqlx::query(
+            r#"
+            INSERT INTO oauth_clients (id, name, secret_hash, redirect_uris, grant_types, scopes, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            "#,
+        )
+        .bind(&client.id)
+        .bind(&client.name)
+        .bind(&client.secret_hash)
+        .bind(&client.redirect_uris)
+        .bind(&grant_types)
+        .bind(client.scopes.to_vec())
+        .bind(client.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &str) -> Result<Option<OAuthClient>, DomainError> {
+        let row = sqlx::query(&format!("SELECT {} FROM oauth_clients WHERE id = $1", CLIENT_COLUMNS))
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        row.as_ref().map(map_client).transpose()
+    }
+
+    async fn find_all(&self) -> Result<Vec<OAuthClient>, DomainError> {
+        let rows = sqlx::query(&format!("SELECT {} FROM oauth_clients ORDER BY name", CLIENT_COLUMNS))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        rows.iter().map(map_client).collect()
+    }
+
+    async fn delete(&self, id: &str) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM oauth_clients WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,139 @@
+use async_trait::async_trait;
+use sqlx::postgres::PgRow;
+use sqlx::Row;
+use crate::domain::entities::oauth_authorization_code::OAuthAuthorizationCode;
+use crate::domain::entities::oauth_consent::OAuthConsent;
+use crate::domain::repositories::OAuthGrantRepository;
+use crate::domain::value_objects::{Scopes, UserId};
+use crate::domain::errors::DomainError;
+use crate::infrastructure::persistence::PostgresPool;
+
+pub struct PostgresOAuthGrantRepository {
+    pool: PostgresPool,
+}
+
+impl PostgresOAuthGrantRepository {
+    pub fn new(pool: PostgresPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn map_consent(row: &PgRow) -> Result<OAuthConsent, DomainError> {
+    Ok(OAuthConsent {
+        user_id: UserId::from_uuid(row.get("user_id")),
+        client_id: row.get("client_id"),
+        scopes: Scopes::from_vec(row.get("scopes"))?,
+        granted_at: row.get("granted_at"),
+    })
+}
+
+#[async_trait]
+impl OAuthGrantRepository for PostgresOAuthGrantRepository {
+    async fn create_code(&self, code: &OAuthAuthorizationCode) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO oauth_authorization_codes
+                (code_hash, client_id, user_id, redirect_uri, scopes, code_challenge, expires_at, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#,
+        )
+        .bind(&code.code_hash)
+        .bind(&code.client_id)
+        .bind(code.user_id.as_uuid())
+        .bind(&code.redirect_uri)
+        .bind(code.scopes.to_vec())
+        .bind(&code.code_challenge)
+        .bind(code.expires_at)
+        .bind(code.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn consume_code(&self, code_hash: &str) -> Result<Option<OAuthAuthorizationCode>, DomainError> {
+        let row = sqlx::query(
+            r#"
+            UPDATE oauth_authorization_codes SET consumed_at = NOW()
+            WHERE code_hash = $1 AND consumed_at IS NULL AND expires_at > NOW()
+            RETURNING code_hash, client_id, user_id, redirect_uri, scopes, code_challenge, expires_at, created_at
+            "#,
+        )
+        .bind(code_hash)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        row.map(|r| {
+            Ok(OAuthAuthorizationCode {
+                code_hash: r.get("code_hash"),
+                client_id: r.get("client_id"),
+                user_id: UserId::from_uuid(r.get("user_id")),
+                redirect_uri: r.get("redirect_uri"),
+                scopes: Scopes::from_vec(r.get("scopes"))?,
+                code_challenge: r.get("code_challenge"),
+                expires_at: r.get("expires_at"),
+                created_at: r.get("created_at"),
+            })
+        })
+        .transpose()
+    }
+
+    async fn find_consent(&self, user_id: &UserId, client_id: &str) -> Result<Option<OAuthConsent>, DomainError> {
+        let row = sqlx::query(
+            "SELECT user_id, client_id, scopes, granted_at FROM oauth_consents WHERE user_id = $1 AND client_id = $2",
+        )
+        .bind(user_id.as_uuid())
+        .bind(client_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        row.as_ref().map(map_consent).transpose()
+    }
+
+    async fn save_consent(&self, consent: &OAuthConsent) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO oauth_consents (user_id, client_id, scopes, granted_at)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (user_id, client_id) DO UPDATE
+            SET scopes = EXCLUDED.scopes,
+                granted_at = EXCLUDED.granted_at
+            "#,
+        )
+        .bind(consent.user_id.as_uuid())
+        .bind(&consent.client_id)
+        .bind(consent.scopes.to_vec())
+        .bind(consent.granted_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn find_consents_by_user_id(&self, user_id: &UserId) -> Result<Vec<OAuthConsent>, DomainError> {
+        let rows = sqlx::query(
+            "SELECT user_id, client_id, scopes, granted_at FROM oauth_consents WHERE user_id = $1 ORDER BY granted_at DESC",
+        )
+        .bind(user_id.as_uuid())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        rows.iter().map(map_consent).collect()
+    }
+
+    async fn delete_consent(&self, user_id: &UserId, client_id: &str) -> Result<bool, DomainError> {
+        let result = sqlx::query("DELETE FROM oauth_consents WHERE user_id = $1 AND client_id = $2")
+            .bind(user_id.as_uuid())
+            .bind(client_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+}
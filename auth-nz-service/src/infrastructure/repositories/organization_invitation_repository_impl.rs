@@ -0,0 +1,116 @@
+use async_trait::async_trait;
+use sqlx::postgres::PgRow;
+use sqlx::Row;
+use uuid::Uuid;
+use crate::domain::entities::organization_invitation::OrganizationInvitation;
+use crate::domain::repositories::OrganizationInvitationRepository;
+use crate::domain::value_objects::{Email, InvitationId, OrganizationId, RoleId, UserId};
+use crate::domain::errors::DomainError;
+use crate::infrastructure::persistence::PostgresPool;
+
+const INVITATION_COLUMNS: &str = "id, organization_id, email, role_id, token_hash, invited_by, expires_at, accepted_at, created_at";
+
+pub struct PostgresOrganizationInvitationRepository {
+    pool: PostgresPool,
+}
+
+impl PostgresOrganizationInvitationRepository {
+    pub fn new(pool: PostgresPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn map_invitation(row: &PgRow) -> Result<OrganizationInvitation, DomainError> {
+    Ok(OrganizationInvitation {
+        id: InvitationId::from_uuid(row.get("id")),
+        organization_id: OrganizationId::from_uuid(row.get("organization_id")),
+        email: Email::new(row.get("email"))?,
+        role_id: row.get::<Option<Uuid>, _>("role_id").map(RoleId::from_uuid),
+        token_hash: row.get("token_hash"),
+        invited_by: UserId::from_uuid(row.get("invited_by")),
+        expires_at: row.get("expires_at"),
+        accepted_at: row.get("accepted_at"),
+        created_at: row.get("created_at"),
+    })
+}
+
+#[async_trait]
+impl OrganizationInvitationRepository for PostgresOrganizationInvitationRepository {
+    async fn create(&self, invitation: &OrganizationInvitation) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO organization_invitations (
+                id, organization_id, email, role_id, token_hash, invited_by, expires_at, accepted_at, created_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            "#,
+        )
+        .bind(invitation.id.as_uuid())
+        .bind(invitation.organization_id.as_uuid())
+        .bind(invitation.email.as_str())
+        .bind(invitation.role_id.map(|id| id.as_uuid()))
+        .bind(&invitation.token_hash)
+        .bind(invitation.invited_by.as_uuid())
+        .bind(invitation.expires_at)
+        .bind(invitation.accepted_at)
+        .bind(invitation.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &InvitationId) -> Result<Option<OrganizationInvitation>, DomainError> {
+        let row = sqlx::query(&format!("SELECT {} FROM organization_invitations WHERE id = $1", INVITATION_COLUMNS))
+            .bind(id.as_uuid())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        row.as_ref().map(map_invitation).transpose()
+    }
+
+    async fn find_by_token_hash(&self, token_hash: &str) -> Result<Option<OrganizationInvitation>, DomainError> {
+        let row = sqlx::query(&format!("SELECT {} FROM organization_invitations WHERE token_hash = $1", INVITATION_COLUMNS))
+            .bind(token_hash)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        row.as_ref().map(map_invitation).transpose()
+    }
+
+    async fn find_by_organization_id(&self, organization_id: &OrganizationId) -> Result<Vec<OrganizationInvitation>, DomainError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM organization_invitations WHERE organization_id = $1 ORDER BY created_at DESC",
+            INVITATION_COLUMNS
+        ))
+        .bind(organization_id.as_uuid())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        rows.iter().map(map_invitation).collect()
+    }
+
+    async fn accept(&self, id: &InvitationId) -> Result<bool, DomainError> {
+        let result = sqlx::query("UPDATE organization_invitations SET accepted_at = NOW() WHERE id = $1 AND accepted_at IS NULL")
+            .bind(id.as_uuid())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn delete(&self, id: &InvitationId) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM organization_invitations WHERE id = $1")
+            .bind(id.as_uuid())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+}
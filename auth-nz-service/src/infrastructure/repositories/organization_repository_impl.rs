@@ -0,0 +1,145 @@
+use async_trait::async_trait;
+use sqlx::postgres::PgRow;
+use sqlx::Row;
+use crate::domain::entities::organization::{Organization, OrganizationMember};
+use crate::domain::repositories::OrganizationRepository;
+use crate::domain::value_objects::{OrganizationId, UserId};
+use crate::domain::errors::DomainError;
+use crate::infrastructure::persistence::PostgresPool;
+
+pub struct PostgresOrganizationRepository {
+    pool: PostgresPool,
+}
+
+impl PostgresOrganizationRepository {
+    pub fn new(pool: PostgresPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn map_organization(row: &PgRow) -> Organization {
+    Organization {
+        id: OrganizationId::from_uuid(row.get("id")),
+        name: row.get("name"),
+        created_at: row.get("created_at"),
+    }
+}
+
+fn map_member(row: &PgRow) -> OrganizationMember {
+    OrganizationMember {
+        organization_id: OrganizationId::from_uuid(row.get("organization_id")),
+        user_id: UserId::from_uuid(row.get("user_id")),
+        joined_at: row.get("joined_at"),
+    }
+}
+
+#[async_trait]
+impl OrganizationRepository for PostgresOrganizationRepository {
+    async fn create(&self, organization: &Organization, founder: &OrganizationMember) -> Result<(), DomainError> {
+        let mut tx = self.pool.begin().await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        sqlx::query("INSERT INTO organizations (id, name, created_at) VALUES ($1, $2, $3)")
+            .bind(organization.id.as_uuid())
+            .bind(&organization.name)
+            .bind(organization.created_at)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| match e {
+                sqlx::Error::Database(ref db) if db.is_unique_violation() => DomainError::OrganizationAlreadyExists,
+                _ => DomainError::Repository(format!("PostgreSQL error: {}", e)),
+            })?;
+
+        sqlx::query("INSERT INTO organization_members (organization_id, user_id, joined_at) VALUES ($1, $2, $3)")
+            .bind(founder.organization_id.as_uuid())
+            .bind(founder.user_id.as_uuid())
+            .bind(founder.joined_at)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        tx.commit().await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &OrganizationId) -> Result<Option<Organization>, DomainError> {
+        let row = sqlx::query("SELECT id, name, created_at FROM organizations WHERE id = $1")
+            .bind(id.as_uuid())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(row.as_ref().map(map_organization))
+    }
+
+    async fn find_by_user_id(&self, user_id: &UserId) -> Result<Vec<Organization>, DomainError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT o.id, o.name, o.created_at
+            FROM organizations o
+            JOIN organization_members m ON m.organization_id = o.id
+            WHERE m.user_id = $1
+            ORDER BY o.name
+            "#,
+        )
+        .bind(user_id.as_uuid())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(rows.iter().map(map_organization).collect())
+    }
+
+    async fn add_member(&self, member: &OrganizationMember) -> Result<(), DomainError> {
+        sqlx::query("INSERT INTO organization_members (organization_id, user_id, joined_at) VALUES ($1, $2, $3)")
+            .bind(member.organization_id.as_uuid())
+            .bind(member.user_id.as_uuid())
+            .bind(member.joined_at)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| match e {
+                sqlx::Error::Database(ref db) if db.is_unique_violation() => DomainError::OrganizationMemberAlreadyExists,
+                _ => DomainError::Repository(format!("PostgreSQL error: {}", e)),
+            })?;
+
+        Ok(())
+    }
+
+    async fn find_member(&self, organization_id: &OrganizationId, user_id: &UserId) -> Result<Option<OrganizationMember>, DomainError> {
+        let row = sqlx::query(
+            "SELECT organization_id, user_id, joined_at FROM organization_members WHERE organization_id = $1 AND user_id = $2",
+        )
+        .bind(organization_id.as_uuid())
+        .bind(user_id.as_uuid())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(row.as_ref().map(map_member))
+    }
+
+    async fn find_members(&self, organization_id: &OrganizationId) -> Result<Vec<OrganizationMember>, DomainError> {
+        let rows = sqlx::query(
+            "SELECT organization_id, user_id, joined_at FROM organization_members WHERE organization_id = $1 ORDER BY joined_at",
+        )
+        .bind(organization_id.as_uuid())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(rows.iter().map(map_member).collect())
+    }
+
+    async fn remove_member(&self, organization_id: &OrganizationId, user_id: &UserId) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM organization_members WHERE organization_id = $1 AND user_id = $2")
+            .bind(organization_id.as_uuid())
+            .bind(user_id.as_uuid())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+}
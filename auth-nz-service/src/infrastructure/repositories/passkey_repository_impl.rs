@@ -0,0 +1,191 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::postgres::PgRow;
+use sqlx::Row;
+use uuid::Uuid;
+use crate::domain::entities::passkey::Passkey;
+use crate::domain::entities::webauthn_challenge::WebAuthnChallenge;
+use crate::domain::repositories::PasskeyRepository;
+use crate::domain::value_objects::{PasskeyId, UserId, WebAuthnCeremony};
+use crate::domain::errors::DomainError;
+use crate::infrastructure::persistence::PostgresPool;
+
+const PASSKEY_COLUMNS: &str =
+    "id, user_id, name, credential_id, public_key, algorithm, sign_count, aaguid, attestation_format, last_used_at, created_at";
+
+pub struct PostgresPasskeyRepository {
+    pool: PostgresPool,
+}
+
+impl PostgresPasskeyRepository {
+    pub fn new(pool: PostgresPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn map_passkey(row: &PgRow) -> Passkey {
+    Passkey {
+        id: PasskeyId::from_uuid(row.get("id")),
+        user_id: UserId::from_uuid(row.get("user_id")),
+        name: row.get("name"),
+        credential_id: row.get("credential_id"),
+        public_key: row.get("public_key"),
+        algorithm: row.get::<i32, _>("algorithm") as i64,
+        sign_count: row.get::<i64, _>("sign_count") as u32,
+        aaguid: row.get("aaguid"),
+        attestation_format: row.get("attestation_format"),
+        last_used_at: row.get("last_used_at"),
+        created_at: row.get("created_at"),
+    }
+}
+
+fn map_challenge(row: &PgRow) -> Result<WebAuthnChallenge, DomainError> {
+    Ok(WebAuthnChallenge {
+        id: row.get("id"),
+        ceremony: WebAuthnCeremony::parse(row.get("ceremony"))?,
+        user_id: row.get::<Option<Uuid>, _>("user_id").map(UserId::from_uuid),
+        challenge: row.get("challenge"),
+        expires_at: row.get("expires_at"),
+        created_at: row.get("created_at"),
+    })
+}
+
+#[async_trait]
+impl PasskeyRepository for PostgresPasskeyRepository {
+    async fn create(&self, passkey: &Passkey) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO passkeys (id, user_id, name, credential_id, public_key, algorithm, sign_count, aaguid, attestation_format, last_used_at, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            "#,
+        )
+        .bind(passkey.id.as_uuid())
+        .bind(passkey.user_id.as_uuid())
+        .bind(&passkey.name)
+        .bind(&passkey.credential_id)
+        .bind(&passkey.public_key)
+        .bind(passkey.algorithm as i32)
+        .bind(passkey.sign_count as i64)
+        .bind(passkey.aaguid)
+        .bind(&passkey.attestation_format)
+        .bind(passkey.last_used_at)
+        .bind(passkey.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::Database(ref db) if db.is_unique_violation() => DomainError::PasskeyAlreadyRegistered,
+            sqlx::Error::Database(ref db) if db.is_foreign_key_violation() => DomainError::UserNotFound,
+            _ => DomainError::Repository(format!("PostgreSQL error: {}", e)),
+        })?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &PasskeyId) -> Result<Option<Passkey>, DomainError> {
+        let row = sqlx::query(&format!("SELECT {} FROM passkeys WHERE id = $1", PASSKEY_COLUMNS))
+            .bind(id.as_uuid())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(row.as_ref().map(map_passkey))
+    }
+
+    async fn find_by_credential_id(&self, credential_id: &[u8]) -> Result<Option<Passkey>, DomainError> {
+        let row = sqlx::query(&format!("SELECT {} FROM passkeys WHERE credential_id = $1", PASSKEY_COLUMNS))
+            .bind(credential_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(row.as_ref().map(map_passkey))
+    }
+
+    async fn find_by_user_id(&self, user_id: &UserId) -> Result<Vec<Passkey>, DomainError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM passkeys WHERE user_id = $1 ORDER BY created_at DESC",
+            PASSKEY_COLUMNS
+        ))
+        .bind(user_id.as_uuid())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(rows.iter().map(map_passkey).collect())
+    }
+
+    async fn record_use(&self, id: &PasskeyId, sign_count: u32, used_at: DateTime<Utc>) -> Result<bool, DomainError> {
+        // Counterless authenticators always report 0; anything else must move forward
+        let result = sqlx::query(
+            r#"
+            UPDATE passkeys
+            SET sign_count = $2, last_used_at = $3
+            WHERE id = $1 AND (sign_count < $2 OR ($2 = 0 AND sign_count = 0))
+            "#,
+        )
+        .bind(id.as_uuid())
+        .bind(sign_count as i64)
+        .bind(used_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn delete(&self, id: &PasskeyId) -> Result<(), DomainError> {
+        let result = sqlx::query("DELETE FROM passkeys WHERE id = $1")
+            .bind(id.as_uuid())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::PasskeyNotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn create_challenge(&self, challenge: &WebAuthnChallenge) -> Result<(), DomainError> {
+        // Piggybacks cleanup of abandoned ceremonies on every new one
+        sqlx::query("DELETE FROM webauthn_challenges WHERE expires_at < NOW()")
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO webauthn_challenges (id, user_id, ceremony, challenge, expires_at, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+        )
+        .bind(challenge.id)
+        .bind(challenge.user_id.map(|id| id.as_uuid()))
+        .bind(challenge.ceremony.as_str())
+        .bind(&challenge.challenge)
+        .bind(challenge.expires_at)
+        .bind(challenge.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn consume_challenge(&self, id: &Uuid) -> Result<Option<WebAuthnChallenge>, DomainError> {
+        let row = sqlx::query(
+            r#"
+            DELETE FROM webauthn_challenges
+            WHERE id = $1
+            RETURNING id, user_id, ceremony, challenge, expires_at, created_at
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        row.as_ref().map(map_challenge).transpose()
+    }
+}
@@ -0,0 +1,60 @@
+use async_trait::async_trait;
+use sqlx::Row;
+use crate::domain::repositories::PasswordHistoryRepository;
+use crate::domain::value_objects::{PasswordHash, UserId};
+use crate::domain::errors::DomainError;
+use crate::infrastructure::persistence::PostgresPool;
+
+pub struct PostgresPasswordHistoryRepository {
+    pool: PostgresPool,
+}
+
+impl PostgresPasswordHistoryRepository {
+    pub fn new(pool: PostgresPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl PasswordHistoryRepository for PostgresPasswordHistoryRepository {
+    async fn add(&self, user_id: &UserId, password_hash: &PasswordHash, keep: usize) -> Result<(), DomainError> {
+        let mut tx = self.pool.begin().await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        sqlx::query("INSERT INTO password_history (user_id, password_hash) VALUES ($1, $2)")
+            .bind(user_id.as_uuid())
+            .bind(password_hash.as_str())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        sqlx::query(
+            r#"
+            DELETE FROM password_history
+            WHERE user_id = $1
+              AND id NOT IN (SELECT id FROM password_history WHERE user_id = $1 ORDER BY id DESC LIMIT $2)
+            "#,
+        )
+        .bind(user_id.as_uuid())
+        .bind(keep as i64)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        tx.commit().await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn find_recent(&self, user_id: &UserId, limit: usize) -> Result<Vec<PasswordHash>, DomainError> {
+        let rows = sqlx::query("SELECT password_hash FROM password_history WHERE user_id = $1 ORDER BY id DESC LIMIT $2")
+            .bind(user_id.as_uuid())
+            .bind(limit as i64)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(rows.iter().map(|row| PasswordHash::new(row.get("password_hash"))).collect())
+    }
+}
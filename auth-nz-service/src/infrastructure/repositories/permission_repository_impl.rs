@@ -0,0 +1,122 @@
+use async_trait::async_trait;
+use sqlx::postgres::PgRow;
+use sqlx::Row;
+use crate::domain::entities::permission::Permission;
+use crate::domain::repositories::PermissionRepository;
+use crate::domain::value_objects::{PermissionId, PermissionName, RoleId};
+use crate::domain::errors::DomainError;
+use crate::infrastructure::persistence::PostgresPool;
+
+pub struct PostgresPermissionRepository {
+    pool: PostgresPool,
+}
+
+impl PostgresPermissionRepository {
+    pub fn new(pool: PostgresPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn map_permission(row: &PgRow) -> Result<Permission, DomainError> {
+    Ok(Permission {
+        id: PermissionId::from_uuid(row.get("id")),
+        name: PermissionName::new(row.get("name"))?,
+        resource: row.get("resource"),
+        action: row.get("action"),
+        created_at: row.get("created_at"),
+    })
+}
+
+#[async_trait]
+impl PermissionRepository for PostgresPermissionRepository {
+    async fn create(&self, permission: &Permission) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO permissions (id, name, resource, action, created_at)
+            VALUES ($1, $2, $3, $4, $5)
+            "#,
+        )
+        .bind(permission.id.as_uuid())
+        .bind(permission.name.as_str())
+        .bind(&permission.resource)
+        .bind(&permission.action)
+        .bind(permission.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::Database(ref db) if db.is_unique_violation() => DomainError::PermissionAlreadyExists,
+            _ => DomainError::Repository(format!("PostgreSQL error: {}", e)),
+        })?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &PermissionId) -> Result<Option<Permission>, DomainError> {
+        let row = sqlx::query("SELECT id, name, resource, action, created_at FROM permissions WHERE id = $1")
+            .bind(id.as_uuid())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        row.as_ref().map(map_permission).transpose()
+    }
+
+    async fn find_by_name(&self, name: &PermissionName) -> Result<Option<Permission>, DomainError> {
+        let row = sqlx::query("SELECT id, name, resource, action, created_at FROM permissions WHERE name = $1")
+            .bind(name.as_str())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        row.as_ref().map(map_permission).transpose()
+    }
+
+    async fn find_all(&self) -> Result<Vec<Permission>, DomainError> {
+        let rows = sqlx::query("SELECT id, name, resource, action, created_at FROM permissions ORDER BY name")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        rows.iter().map(map_permission).collect()
+    }
+
+    async fn find_by_role_id(&self, role_id: &RoleId) -> Result<Vec<Permission>, DomainError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT p.id, p.name, p.resource, p.action, p.created_at
+            FROM permissions p
+            JOIN role_permissions rp ON rp.permission_id = p.id
+            WHERE rp.role_id = $1
+            ORDER BY p.name
+            "#,
+        )
+        .bind(role_id.as_uuid())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        rows.iter().map(map_permission).collect()
+    }
+
+    async fn grant_to_role(&self, role_id: &RoleId, permission_id: &PermissionId) -> Result<(), DomainError> {
+        sqlx::query("INSERT INTO role_permissions (role_id, permission_id) VALUES ($1, $2) ON CONFLICT DO NOTHING")
+            .bind(role_id.as_uuid())
+            .bind(permission_id.as_uuid())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn revoke_from_role(&self, role_id: &RoleId, permission_id: &PermissionId) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM role_permissions WHERE role_id = $1 AND permission_id = $2")
+            .bind(role_id.as_uuid())
+            .bind(permission_id.as_uuid())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use redis::AsyncCommands;
+use redis::aio::MultiplexedConnection;
+use crate::domain::repositories::LoginAttemptRepository;
+use crate::domain::errors::DomainError;
+
+/// Counters shared by every replica. Keys carry a TTL, so counters and locks
+/// disappear on their own once the window or lockout is over.
+pub struct RedisLoginAttemptRepository {
+    client: redis::Client,
+}
+
+impl RedisLoginAttemptRepository {
+    pub fn new(url: &str) -> Result<Self, redis::RedisError> {
+        Ok(Self {
+            client: redis::Client::open(url)?,
+        })
+    }
+
+    async fn connection(&self) -> Result<MultiplexedConnection, DomainError> {
+        self.client.get_multiplexed_async_connection().await
+            .map_err(redis_error)
+    }
+
+    fn failures_key(key: &str) -> String {
+        format!("auth:login:failures:{}", key)
+    }
+
+    fn lock_key(key: &str) -> String {
+        format!("auth:login:lock:{}", key)
+    }
+}
+
+fn redis_error(e: redis::RedisError) -> DomainError {
+    DomainError::Repository(format!("Redis error: {}", e))
+}
+
+#[async_trait]
+impl LoginAttemptRepository for RedisLoginAttemptRepository {
+    async fn record_failure(&self, key: &str, window: Duration) -> Result<u32, DomainError> {
+        let mut conn = self.connection().await?;
+        let failures_key = Self::failures_key(key);
+
+        let count: u32 = conn.incr(&failures_key, 1).await.map_err(redis_error)?;
+        if count == 1 {
+            conn.expire::<_, ()>(&failures_key, window.num_seconds().max(1)).await
+                .map_err(redis_error)?;
+        }
+        Ok(count)
+    }
+
+    async fn failure_count(&self, key: &str) -> Result<u32, DomainError> {
+        let mut conn = self.connection().await?;
+        let count: Option<u32> = conn.get(Self::failures_key(key)).await.map_err(redis_error)?;
+        Ok(count.unwrap_or(0))
+    }
+
+    async fn clear_failures(&self, key: &str) -> Result<(), DomainError> {
+        let mut conn = self.connection().await?;
+        conn.del::<_, ()>(Self::failures_key(key)).await.map_err(redis_error)
+    }
+
+    async fn lock(&self, key: &str, until: DateTime<Utc>) -> Result<(), DomainError> {
+        let ttl = (until - Utc::now()).num_seconds().max(1) as u64;
+        let mut conn = self.connection().await?;
+        conn.set_ex::<_, _, ()>(Self::lock_key(key), until.timestamp(), ttl).await
+            .map_err(redis_error)
+    }
+
+    async fn locked_until(&self, key: &str) -> Result<Option<DateTime<Utc>>, DomainError> {
+        let mut conn = self.connection().await?;
+        let timestamp: Option<i64> = conn.get(Self::lock_key(key)).await.map_err(redis_error)?;
+        Ok(timestamp
+            .and_then(|ts| Utc.timestamp_opt(ts, 0).single())
+            .filter(|until| *until > Utc::now()))
+    }
+}
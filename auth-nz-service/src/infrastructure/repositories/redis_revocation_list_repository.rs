@@ -0,0 +1,48 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use redis::AsyncCommands;
+use redis::aio::MultiplexedConnection;
+use crate::domain::repositories::RevocationListRepository;
+use crate::domain::errors::DomainError;
+
+/// List shared by every replica. Each entry is a key with a TTL, so the list
+/// never holds more than the ids of still-unexpired tokens.
+pub struct RedisRevocationListRepository {
+    client: redis::Client,
+}
+
+impl RedisRevocationListRepository {
+    pub fn new(url: &str) -> Result<Self, redis::RedisError> {
+        Ok(Self {
+            client: redis::Client::open(url)?,
+        })
+    }
+
+    async fn connection(&self) -> Result<MultiplexedConnection, DomainError> {
+        self.client.get_multiplexed_async_connection().await
+            .map_err(redis_error)
+    }
+
+    fn entry_key(key: &str) -> String {
+        format!("auth:revoked:{}", key)
+    }
+}
+
+fn redis_error(e: redis::RedisError) -> DomainError {
+    DomainError::Repository(format!("Redis error: {}", e))
+}
+
+#[async_trait]
+impl RevocationListRepository for RedisRevocationListRepository {
+    async fn add(&self, key: &str, until: DateTime<Utc>) -> Result<(), DomainError> {
+        let ttl = (until - Utc::now()).num_seconds().max(1) as u64;
+        let mut conn = self.connection().await?;
+        conn.set_ex::<_, _, ()>(Self::entry_key(key), 1, ttl).await
+            .map_err(redis_error)
+    }
+
+    async fn contains(&self, key: &str) -> Result<bool, DomainError> {
+        let mut conn = self.connection().await?;
+        conn.exists(Self::entry_key(key)).await.map_err(redis_error)
+    }
+}
@@ -0,0 +1,240 @@
+use async_trait::async_trait;
+use sqlx::postgres::PgRow;
+use sqlx::Row;
+use uuid::Uuid;
+use crate::domain::entities::role::Role;
+use crate::domain::repositories::RoleRepository;
+use crate::domain::value_objects::{OrganizationId, RoleId, RoleName, ServiceAccountId, UserId};
+use crate::domain::errors::DomainError;
+use crate::infrastructure::persistence::PostgresPool;
+
+pub struct PostgresRoleRepository {
+    pool: PostgresPool,
+}
+
+impl PostgresRoleRepository {
+    pub fn new(pool: PostgresPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn map_role(row: &PgRow) -> Result<Role, DomainError> {
+    Ok(Role {
+        id: RoleId::from_uuid(row.get("id")),
+        name: RoleName::new(row.get("name"))?,
+        parent_id: row.get::<Option<Uuid>, _>("parent_id").map(RoleId::from_uuid),
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+    })
+}
+
+#[async_trait]
+impl RoleRepository for PostgresRoleRepository {
+    async fn create(&self, role: &Role) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO roles (id, name, parent_id, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5)
+            "#,
+        )
+        .bind(role.id.as_uuid())
+        .bind(role.name.as_str())
+        .bind(role.parent_id.map(|id| id.as_uuid()))
+        .bind(role.created_at)
+        .bind(role.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::Database(ref db) if db.is_unique_violation() => DomainError::RoleAlreadyExists,
+            _ => DomainError::Repository(format!("PostgreSQL error: {}", e)),
+        })?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &RoleId) -> Result<Option<Role>, DomainError> {
+        let row = sqlx::query("SELECT id, name, parent_id, created_at, updated_at FROM roles WHERE id = $1")
+            .bind(id.as_uuid())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        row.as_ref().map(map_role).transpose()
+    }
+
+    async fn find_by_name(&self, name: &RoleName) -> Result<Option<Role>, DomainError> {
+        let row = sqlx::query("SELECT id, name, parent_id, created_at, updated_at FROM roles WHERE name = $1")
+            .bind(name.as_str())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        row.as_ref().map(map_role).transpose()
+    }
+
+    async fn find_all(&self) -> Result<Vec<Role>, DomainError> {
+        let rows = sqlx::query("SELECT id, name, parent_id, created_at, updated_at FROM roles ORDER BY name")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        rows.iter().map(map_role).collect()
+    }
+
+    async fn update(&self, role: &Role) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            UPDATE roles
+            SET name = $2, parent_id = $3, updated_at = $4
+            WHERE id = $1
+            "#,
+        )
+        .bind(role.id.as_uuid())
+        .bind(role.name.as_str())
+        .bind(role.parent_id.map(|id| id.as_uuid()))
+        .bind(role.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: &RoleId) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM roles WHERE id = $1")
+            .bind(id.as_uuid())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn find_by_user_id(&self, user_id: &UserId) -> Result<Vec<Role>, DomainError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT r.id, r.name, r.parent_id, r.created_at, r.updated_at
+            FROM roles r
+            JOIN user_roles ur ON ur.role_id = r.id
+            WHERE ur.user_id = $1
+            ORDER BY r.name
+            "#,
+        )
+        .bind(user_id.as_uuid())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        rows.iter().map(map_role).collect()
+    }
+
+    async fn assign_to_user(&self, user_id: &UserId, role_id: &RoleId) -> Result<(), DomainError> {
+        sqlx::query("INSERT INTO user_roles (user_id, role_id) VALUES ($1, $2) ON CONFLICT DO NOTHING")
+            .bind(user_id.as_uuid())
+            .bind(role_id.as_uuid())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn unassign_from_user(&self, user_id: &UserId, role_id: &RoleId) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM user_roles WHERE user_id = $1 AND role_id = $2")
+            .bind(user_id.as_uuid())
+            .bind(role_id.as_uuid())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn find_by_service_account_id(&self, service_account_id: &ServiceAccountId) -> Result<Vec<Role>, DomainError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT r.id, r.name, r.parent_id, r.created_at, r.updated_at
+            FROM roles r
+            JOIN service_account_roles sr ON sr.role_id = r.id
+            WHERE sr.service_account_id = $1
+            ORDER BY r.name
+            "#,
+        )
+        .bind(service_account_id.as_uuid())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        rows.iter().map(map_role).collect()
+    }
+
+    async fn assign_to_service_account(&self, service_account_id: &ServiceAccountId, role_id: &RoleId) -> Result<(), DomainError> {
+        sqlx::query("INSERT INTO service_account_roles (service_account_id, role_id) VALUES ($1, $2) ON CONFLICT DO NOTHING")
+            .bind(service_account_id.as_uuid())
+            .bind(role_id.as_uuid())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn unassign_from_service_account(&self, service_account_id: &ServiceAccountId, role_id: &RoleId) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM service_account_roles WHERE service_account_id = $1 AND role_id = $2")
+            .bind(service_account_id.as_uuid())
+            .bind(role_id.as_uuid())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn find_by_member(&self, organization_id: &OrganizationId, user_id: &UserId) -> Result<Vec<Role>, DomainError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT r.id, r.name, r.parent_id, r.created_at, r.updated_at
+            FROM roles r
+            JOIN organization_member_roles mr ON mr.role_id = r.id
+            WHERE mr.organization_id = $1 AND mr.user_id = $2
+            ORDER BY r.name
+            "#,
+        )
+        .bind(organization_id.as_uuid())
+        .bind(user_id.as_uuid())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        rows.iter().map(map_role).collect()
+    }
+
+    async fn assign_to_member(&self, organization_id: &OrganizationId, user_id: &UserId, role_id: &RoleId) -> Result<(), DomainError> {
+        sqlx::query(
+            "INSERT INTO organization_member_roles (organization_id, user_id, role_id) VALUES ($1, $2, $3) ON CONFLICT DO NOTHING",
+        )
+        .bind(organization_id.as_uuid())
+        .bind(user_id.as_uuid())
+        .bind(role_id.as_uuid())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::Database(ref db) if db.is_foreign_key_violation() => DomainError::OrganizationMemberNotFound,
+            _ => DomainError::Repository(format!("PostgreSQL error: {}", e)),
+        })?;
+
+        Ok(())
+    }
+
+    async fn unassign_from_member(&self, organization_id: &OrganizationId, user_id: &UserId, role_id: &RoleId) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM organization_member_roles WHERE organization_id = $1 AND user_id = $2 AND role_id = $3")
+            .bind(organization_id.as_uuid())
+            .bind(user_id.as_uuid())
+            .bind(role_id.as_uuid())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+use async_trait::async_trait;
+use sqlx::postgres::PgRow;
+use sqlx::Row;
+use crate::domain::entities::service_account::ServiceAccount;
+use crate::domain::repositories::ServiceAccountRepository;
+use crate::domain::value_objects::ServiceAccountId;
+use crate::domain::errors::DomainError;
+use crate::infrastructure::persistence::PostgresPool;
+
+pub struct PostgresServiceAccountRepository {
+    pool: PostgresPool,
+}
+
+impl PostgresServiceAccountRepository {
+    pub fn new(pool: PostgresPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn map_service_account(row: &PgRow) -> ServiceAccount {
+    ServiceAccount {
+        id: ServiceAccountId::from_uuid(row.get("id")),
+        name: row.get("name"),
+        description: row.get("description"),
+        created_at: row.get("created_at"),
+    }
+}
+
+#[async_trait]
+impl ServiceAccountRepository for PostgresServiceAccountRepository {
+    async fn create(&self, service_account: &ServiceAccount) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO service_accounts (id, name, description, created_at)
+            VALUES ($1, $2, $3, $4)
+            "#,
+        )
+        .bind(service_account.id.as_uuid())
+        .bind(&service_account.name)
+        .bind(&service_account.description)
+        .bind(service_account.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::Database(ref db) if db.is_unique_violation() => DomainError::ServiceAccountAlreadyExists,
+            _ => DomainError::Repository(format!("PostgreSQL error: {}", e)),
+        })?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &ServiceAccountId) -> Result<Option<ServiceAccount>, DomainError> {
+        let row = sqlx::query("SELECT id, name, description, created_at FROM service_accounts WHERE id = $1")
+            .bind(id.as_uuid())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(row.as_ref().map(map_service_account))
+    }
+
+    async fn find_all(&self) -> Result<Vec<ServiceAccount>, DomainError> {
+        let rows = sqlx::query("SELECT id, name, description, created_at FROM service_accounts ORDER BY name")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(rows.iter().map(map_service_account).collect())
+    }
+
+    async fn delete(&self, id: &ServiceAccountId) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM service_accounts WHERE id = $1")
+            .bind(id.as_uuid())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+}
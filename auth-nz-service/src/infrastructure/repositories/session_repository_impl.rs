@@ -0,0 +1,207 @@
+use async_trait::async_trait;
+use sqlx::postgres::PgRow;
+use sqlx::Row;
+use crate::domain::entities::session::Session;
+use crate::domain::repositories::SessionRepository;
+use crate::domain::value_objects::{DeviceInfo, OAuthDelegation, OrganizationId, RefreshTokenHash, Scopes, SessionId, Token, UserId};
+use crate::domain::errors::DomainError;
+use crate::infrastructure::persistence::PostgresPool;
+
+const SESSION_COLUMNS: &str = "id, user_id, family_id, token, refresh_token_hash, expires_at, refresh_expires_at, revoked_at, \
+    user_agent, ip_address, device_label, last_seen_at, client_id, scope, organization_id, authenticated_at, created_at";
+
+pub struct PostgresSessionRepository {
+    pool: PostgresPool,
+}
+
+impl PostgresSessionRepository {
+    pub fn new(pool: PostgresPool) -> Self {
+        Self { pool }
+    }
+
+    async fn find_one(&self, column: &str, value: &str) -> Result<Option<Session>, DomainError> {
+        let row = sqlx::query(&format!("SELECT {} FROM sessions WHERE {} = $1", SESSION_COLUMNS, column))
+            .bind(value)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(row.as_ref().map(map_session))
+    }
+}
+
+fn map_session(row: &PgRow) -> Session {
+    Session {
+        id: SessionId::from_uuid(row.get("id")),
+        user_id: UserId::from_uuid(row.get("user_id")),
+        family_id: SessionId::from_uuid(row.get("family_id")),
+        token: Token::new(row.get("token")),
+        refresh_token_hash: RefreshTokenHash::new(row.get("refresh_token_hash")),
+        expires_at: row.get("expires_at"),
+        refresh_expires_at: row.get("refresh_expires_at"),
+        revoked_at: row.get("revoked_at"),
+        device: DeviceInfo::from_parts(row.get("user_agent"), row.get("ip_address"), row.get("device_label")),
+        last_seen_at: row.get("last_seen_at"),
+        delegation: row.get::<Option<String>, _>("client_id").map(|client_id| OAuthDelegation {
+            client_id,
+            scopes: row.get::<Option<String>, _>("scope")
+                .and_then(|scope| Scopes::parse(&scope).ok())
+                .unwrap_or_default(),
+        }),
+        organization_id: row.get::<Option<uuid::Uuid>, _>("organization_id").map(OrganizationId::from_uuid),
+        authenticated_at: row.get("authenticated_at"),
+        created_at: row.get("created_at"),
+    }
+}
+
+#[async_trait]
+impl SessionRepository for PostgresSessionRepository {
+    async fn create(&self, session: &Session) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO sessions (
+                id, user_id, family_id, token, refresh_token_hash, expires_at, refresh_expires_at, revoked_at,
+                user_agent, ip_address, device_label, last_seen_at, client_id, scope, organization_id, authenticated_at, created_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17)
+            "#,
+        )
+        .bind(session.id.as_uuid())
+        .bind(session.user_id.as_uuid())
+        .bind(session.family_id.as_uuid())
+        .bind(session.token.as_str())
+        .bind(session.refresh_token_hash.as_str())
+        .bind(session.expires_at)
+        .bind(session.refresh_expires_at)
+        .bind(session.revoked_at)
+        .bind(session.device.user_agent())
+        .bind(session.device.ip_address())
+        .bind(session.device.label())
+        .bind(session.last_seen_at)
+        .bind(session.delegation.as_ref().map(|d| d.client_id.clone()))
+        .bind(session.delegation.as_ref().map(|d| d.scopes.to_string()))
+        .bind(session.organization_id.map(|id| id.as_uuid()))
+        .bind(session.authenticated_at)
+        .bind(session.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &SessionId) -> Result<Option<Session>, DomainError> {
+        let row = sqlx::query(&format!("SELECT {} FROM sessions WHERE id = $1", SESSION_COLUMNS))
+            .bind(id.as_uuid())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(row.as_ref().map(map_session))
+    }
+
+    async fn find_by_token(&self, token: &Token) -> Result<Option<Session>, DomainError> {
+        self.find_one("token", token.as_str()).await
+    }
+
+    async fn find_by_refresh_token_hash(&self, hash: &RefreshTokenHash) -> Result<Option<Session>, DomainError> {
+        self.find_one("refresh_token_hash", hash.as_str()).await
+    }
+
+    async fn find_by_user_id(&self, user_id: &UserId) -> Result<Vec<Session>, DomainError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM sessions WHERE user_id = $1 ORDER BY created_at DESC",
+            SESSION_COLUMNS,
+        ))
+        .bind(user_id.as_uuid())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(rows.iter().map(map_session).collect())
+    }
+
+    async fn find_active_by_user_id(&self, user_id: &UserId) -> Result<Vec<Session>, DomainError> {
+        // Rotation revokes the previous session, so each family has at most one live row
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM sessions \
+             WHERE user_id = $1 AND revoked_at IS NULL AND refresh_expires_at > NOW() \
+             ORDER BY last_seen_at DESC",
+            SESSION_COLUMNS,
+        ))
+        .bind(user_id.as_uuid())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(rows.iter().map(map_session).collect())
+    }
+
+    async fn revoke(&self, id: &SessionId) -> Result<bool, DomainError> {
+        // The revoked_at guard makes this a compare-and-set: only one caller wins
+        let result = sqlx::query("UPDATE sessions SET revoked_at = NOW() WHERE id = $1 AND revoked_at IS NULL")
+            .bind(id.as_uuid())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn revoke_family(&self, family_id: &SessionId) -> Result<(), DomainError> {
+        sqlx::query("UPDATE sessions SET revoked_at = NOW() WHERE family_id = $1 AND revoked_at IS NULL")
+            .bind(family_id.as_uuid())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn revoke_by_user_id(&self, user_id: &UserId, keep_family: Option<&SessionId>) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            UPDATE sessions SET revoked_at = NOW()
+            WHERE user_id = $1 AND revoked_at IS NULL AND ($2::uuid IS NULL OR family_id <> $2)
+            "#,
+        )
+        .bind(user_id.as_uuid())
+        .bind(keep_family.map(|id| id.as_uuid()))
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn revoke_by_client_id(&self, user_id: &UserId, client_id: &str) -> Result<(), DomainError> {
+        sqlx::query("UPDATE sessions SET revoked_at = NOW() WHERE user_id = $1 AND client_id = $2 AND revoked_at IS NULL")
+            .bind(user_id.as_uuid())
+            .bind(client_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: &SessionId) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM sessions WHERE id = $1")
+            .bind(id.as_uuid())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn delete_by_user_id(&self, user_id: &UserId) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM sessions WHERE user_id = $1")
+            .bind(user_id.as_uuid())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+}
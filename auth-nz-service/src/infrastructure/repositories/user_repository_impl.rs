@@ -0,0 +1,110 @@
+use async_trait::async_trait;
+use sqlx::postgres::PgRow;
+use sqlx::Row;
+use crate::domain::entities::user::User;
+use crate::domain::repositories::UserRepository;
+use crate::domain::value_objects::{Email, PasswordHash, UserId};
+use crate::domain::errors::DomainError;
+use crate::infrastructure::persistence::PostgresPool;
+
+pub struct PostgresUserRepository {
+    pool: PostgresPool,
+}
+
+impl PostgresUserRepository {
+    pub fn new(pool: PostgresPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn map_user(row: &PgRow) -> Result<User, DomainError> {
+    Ok(User {
+        id: UserId::from_uuid(row.get("id")),
+        email: Email::new(row.get("email"))?,
+        password_hash: PasswordHash::new(row.get("password_hash")),
+        is_active: row.get("is_active"),
+        email_verified_at: row.get("email_verified_at"),
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+    })
+}
+
+#[async_trait]
+impl UserRepository for PostgresUserRepository {
+    async fn create(&self, user: &User) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO users (id, email, password_hash, is_active, email_verified_at, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            "#,
+        )
+        .bind(user.id.as_uuid())
+        .bind(user.email.as_str())
+        .bind(user.password_hash.as_str())
+        .bind(user.is_active)
+        .bind(user.email_verified_at)
+        .bind(user.created_at)
+        .bind(user.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::Database(ref db) if db.is_unique_violation() => DomainError::UserAlreadyExists,
+            _ => DomainError::Repository(format!("PostgreSQL error: {}", e)),
+        })?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &UserId) -> Result<Option<User>, DomainError> {
+        let row = sqlx::query(
+            r#"
+            SELECT id, email, password_hash, is_active, email_verified_at, created_at, updated_at
+            FROM users
+            WHERE id = $1
+            "#,
+        )
+        .bind(id.as_uuid())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        row.as_ref().map(map_user).transpose()
+    }
+
+    async fn find_by_email(&self, email: &Email) -> Result<Option<User>, DomainError> {
+        let row = sqlx::query(
+            r#"
+            SELECT id, email, password_hash, is_active, email_verified_at, created_at, updated_at
+            FROM users
+            WHERE email = $1
+            "#,
+        )
+        .bind(email.as_str())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        row.as_ref().map(map_user).transpose()
+    }
+
+    async fn update(&self, user: &User) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            UPDATE users
+            SET email = $2, password_hash = $3, is_active = $4, email_verified_at = $5, updated_at = $6
+            WHERE id = $1
+            "#,
+        )
+        .bind(user.id.as_uuid())
+        .bind(user.email.as_str())
+        .bind(user.password_hash.as_str())
+        .bind(user.is_active)
+        .bind(user.email_verified_at)
+        .bind(user.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,197 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use ed25519_dalek::SigningKey;
+use jsonwebtoken::jwk::{
+    AlgorithmParameters, CommonParameters, EllipticCurve, Jwk, KeyAlgorithm, OctetKeyPairParameters,
+    OctetKeyPairType, PublicKeyUse, RSAKeyParameters, RSAKeyType,
+};
+use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey};
+use rand::rngs::OsRng;
+use rand::RngCore;
+use rsa::pkcs1::DecodeRsaPublicKey;
+use rsa::pkcs8::DecodePublicKey;
+use rsa::traits::PublicKeyParts;
+use rsa::RsaPublicKey;
+use crate::domain::services::TokenKey;
+use crate::infrastructure::config::{JwtConfig, MIN_SECRET_BYTES};
+
+#[derive(Default)]
+struct KeyFiles {
+    secret: Option<Vec<u8>>,
+    private_pem: Option<Vec<u8>>,
+    public_pem: Option<Vec<u8>>,
+}
+
+/// Loads the signing and verification keys described by `JwtConfig`.
+///
+/// Without `keys_dir`, a single HS256 key is built from `secret`, or an EdDSA key
+/// is generated for this process only. With it, every
+/// key in the directory is loaded and identified by its file stem:
+/// - `<kid>.secret` holds an HS256 secret
+/// - `<kid>.pem` holds an RS256/EdDSA private key
+/// - `<kid>.pub.pem` holds the matching public key
+///
+/// A key whose private half has been removed is kept for verification only.
+pub fn load_token_keys(config: &JwtConfig) -> Result<Vec<TokenKey>, Box<dyn std::error::Error>> {
+    let algorithm = Algorithm::from_str(&config.algorithm)?;
+
+    let Some(keys_dir) = &config.keys_dir else {
+        if algorithm == Algorithm::EdDSA {
+            tracing::warn!(
+                "JWT_KEYS_DIR is not set, signing with a key generated at startup; \
+                 tokens will not survive a restart or validate on other instances"
+            );
+            let (private_pem, public_pem) = generate_ed25519_pem();
+            return Ok(vec![ed25519_key(config.signing_kid.clone(), Some(private_pem), &public_pem)?]);
+        }
+        if algorithm != Algorithm::HS256 {
+            return Err(format!("JWT_KEYS_DIR is required for {:?}", algorithm).into());
+        }
+        return Ok(vec![TokenKey {
+            kid: config.signing_kid.clone(),
+            algorithm,
+            encoding_key: Some(EncodingKey::from_secret(config.secret.as_bytes())),
+            decoding_key: DecodingKey::from_secret(config.secret.as_bytes()),
+            jwk: None,
+        }]);
+    };
+
+    let mut files: BTreeMap<String, KeyFiles> = BTreeMap::new();
+    for entry in fs::read_dir(Path::new(keys_dir))? {
+        let path = entry?.path();
+        let Some(file_name) = path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+
+        if let Some(kid) = file_name.strip_suffix(".secret") {
+            files.entry(kid.to_string()).or_default().secret = Some(fs::read(&path)?);
+        } else if let Some(kid) = file_name.strip_suffix(".pub.pem") {
+            files.entry(kid.to_string()).or_default().public_pem = Some(fs::read(&path)?);
+        } else if let Some(kid) = file_name.strip_suffix(".pem") {
+            files.entry(kid.to_string()).or_default().private_pem = Some(fs::read(&path)?);
+        }
+    }
+
+    let mut keys = Vec::with_capacity(files.len());
+    for (kid, files) in files {
+        let key = match algorithm {
+            Algorithm::HS256 => {
+                let Some(secret) = files.secret else {
+                    continue;
+                };
+                let secret = String::from_utf8(secret)?;
+                let secret = secret.trim().as_bytes();
+                if secret.len() < MIN_SECRET_BYTES {
+                    return Err(format!("{}.secret must be at least {} bytes", kid, MIN_SECRET_BYTES).into());
+                }
+                TokenKey {
+                    kid,
+                    algorithm,
+                    encoding_key: Some(EncodingKey::from_secret(secret)),
+                    decoding_key: DecodingKey::from_secret(secret),
+                    jwk: None,
+                }
+            }
+            Algorithm::RS256 => {
+                let public_pem = files.public_pem
+                    .ok_or_else(|| format!("Missing public key {}.pub.pem", kid))?;
+                TokenKey {
+                    algorithm,
+                    encoding_key: files.private_pem
+                        .map(|pem| EncodingKey::from_rsa_pem(&pem))
+                        .transpose()?,
+                    decoding_key: DecodingKey::from_rsa_pem(&public_pem)?,
+                    jwk: Some(rsa_jwk(&kid, &public_pem)?),
+                    kid,
+                }
+            }
+            Algorithm::EdDSA => {
+                let public_pem = files.public_pem
+                    .ok_or_else(|| format!("Missing public key {}.pub.pem", kid))?;
+                ed25519_key(kid, files.private_pem, &public_pem)?
+            }
+            other => return Err(format!("Unsupported JWT algorithm {:?}", other).into()),
+        };
+        keys.push(key);
+    }
+
+    Ok(keys)
+}
+
+fn ed25519_key(kid: String, private_pem: Option<Vec<u8>>, public_pem: &[u8]) -> Result<TokenKey, Box<dyn std::error::Error>> {
+    Ok(TokenKey {
+        algorithm: Algorithm::EdDSA,
+        encoding_key: private_pem
+            .map(|pem| EncodingKey::from_ed_pem(&pem))
+            .transpose()?,
+        decoding_key: DecodingKey::from_ed_pem(public_pem)?,
+        jwk: Some(ed25519_jwk(&kid, public_pem)?),
+        kid,
+    })
+}
+
+/// A fresh Ed25519 key pair as PKCS#8 private and SubjectPublicKeyInfo PEM.
+fn generate_ed25519_pem() -> (Vec<u8>, Vec<u8>) {
+    // Fixed DER prefixes of the two encodings for Ed25519 (RFC 8410)
+    const PRIVATE_KEY_PREFIX: [u8; 16] = [
+        0x30, 0x2e, 0x02, 0x01, 0x00, 0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x70, 0x04, 0x22, 0x04, 0x20,
+    ];
+    const PUBLIC_KEY_PREFIX: [u8; 12] = [0x30, 0x2a, 0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x70, 0x03, 0x21, 0x00];
+
+    let mut seed = [0u8; 32];
+    OsRng.fill_bytes(&mut seed);
+    let public_key = SigningKey::from_bytes(&seed).verifying_key().to_bytes();
+
+    let private_der = [&PRIVATE_KEY_PREFIX[..], &seed].concat();
+    let public_der = [&PUBLIC_KEY_PREFIX[..], &public_key].concat();
+    (
+        pem::encode(&pem::Pem::new("PRIVATE KEY", private_der)).into_bytes(),
+        pem::encode(&pem::Pem::new("PUBLIC KEY", public_der)).into_bytes(),
+    )
+}
+
+fn common_parameters(kid: &str, algorithm: KeyAlgorithm) -> CommonParameters {
+    CommonParameters {
+        public_key_use: Some(PublicKeyUse::Signature),
+        key_algorithm: Some(algorithm),
+        key_id: Some(kid.to_string()),
+        ..Default::default()
+    }
+}
+
+fn rsa_jwk(kid: &str, public_pem: &[u8]) -> Result<Jwk, Box<dyn std::error::Error>> {
+    let pem = std::str::from_utf8(public_pem)?;
+    let public_key = RsaPublicKey::from_public_key_pem(pem)
+        .or_else(|_| RsaPublicKey::from_pkcs1_pem(pem))?;
+
+    Ok(Jwk {
+        common: common_parameters(kid, KeyAlgorithm::RS256),
+        algorithm: AlgorithmParameters::RSA(RSAKeyParameters {
+            key_type: RSAKeyType::RSA,
+            n: URL_SAFE_NO_PAD.encode(public_key.n().to_bytes_be()),
+            e: URL_SAFE_NO_PAD.encode(public_key.e().to_bytes_be()),
+        }),
+    })
+}
+
+fn ed25519_jwk(kid: &str, public_pem: &[u8]) -> Result<Jwk, Box<dyn std::error::Error>> {
+    // An Ed25519 SubjectPublicKeyInfo ends with the raw 32-byte public key
+    let der = pem::parse(public_pem)?;
+    let contents = der.contents();
+    if contents.len() < 32 {
+        return Err(format!("Invalid Ed25519 public key for {}", kid).into());
+    }
+
+    Ok(Jwk {
+        common: common_parameters(kid, KeyAlgorithm::EdDSA),
+        algorithm: AlgorithmParameters::OctetKeyPair(OctetKeyPairParameters {
+            key_type: OctetKeyPairType::OctetKeyPair,
+            curve: EllipticCurve::Ed25519,
+            x: URL_SAFE_NO_PAD.encode(&contents[contents.len() - 32..]),
+        }),
+    })
+}
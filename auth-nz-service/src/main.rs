@@ -2,10 +2,10 @@ mod application;
 mod domain;
 mod infrastructure;
 mod presentation;
+mod di;
 
-use infrastructure::config::Config;
-use presentation::server::create_server;
-use tracing_subscriber;
+use infrastructure::Config;
+use presentation::create_server;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -14,8 +14,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .init();
 
     let config = Config::load()?;
-    let server = create_server(config).await?;
-    server.await?;
+    create_server(config).await?;
 
     Ok(())
 }
@@ -0,0 +1,233 @@
+use std::net::SocketAddr;
+use std::sync::Arc;
+use axum::{async_trait, extract::{ConnectInfo, FromRequestParts, Query}, http::{header, request::Parts, Method, StatusCode}};
+use chrono::Utc;
+use crate::application::dto::{AuthorizeQueryDto, ClientInfoDto};
+use crate::domain::errors::DomainError;
+use crate::domain::services::{AccessTokenClaims, AuthenticatedApiKey};
+use crate::domain::value_objects::{ApiKeyToken, Token, UserId};
+use crate::di::AppContext;
+
+/// Claims of a valid bearer access token issued to a user, either in a
+/// first-party session or delegated to an OAuth client. Tokens that were
+/// revoked, or whose session was logged out or revoked, are refused even
+/// though their signature still checks out.
+async fn user_token_claims(parts: &Parts, context: &AppContext) -> Result<AccessTokenClaims, (StatusCode, String)> {
+    let token = parts.headers.get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .ok_or((StatusCode::UNAUTHORIZED, "Missing bearer token".to_string()))?;
+
+    let claims = context.token_service.verify_access_token(&Token::new(token.to_string()))
+        .map_err(|e| (StatusCode::UNAUTHORIZED, e.to_string()))?;
+
+    if claims.sid.is_none() {
+        return Err((StatusCode::UNAUTHORIZED, "Token does not belong to a user".to_string()));
+    }
+
+    let active = context.revocation_service.is_access_token_active(&claims).await
+        .map_err(|e| {
+            tracing::error!("Token revocation check failed: {}", e);
+            (StatusCode::INTERNAL_SERVER_ERROR, "Internal server error".to_string())
+        })?;
+    if !active {
+        return Err((StatusCode::UNAUTHORIZED, "Token has been revoked".to_string()));
+    }
+    Ok(claims)
+}
+
+/// Claims of a valid bearer access token from the user's own first-party
+/// session. Client credentials tokens are rejected since there is no user
+/// behind them, and so are tokens delegated to an OAuth client: their scopes
+/// are narrower than the account, which these endpoints do not check.
+pub struct AuthenticatedUser(pub AccessTokenClaims);
+
+#[async_trait]
+impl FromRequestParts<Arc<AppContext>> for AuthenticatedUser {
+    type Rejection = (StatusCode, String);
+
+    async fn from_request_parts(parts: &mut Parts, context: &Arc<AppContext>) -> Result<Self, Self::Rejection> {
+        let claims = user_token_claims(parts, context).await?;
+
+        if claims.client_id.is_some() {
+            return Err((StatusCode::FORBIDDEN, "Token was issued to an OAuth client".to_string()));
+        }
+        Ok(AuthenticatedUser(claims))
+    }
+}
+
+/// An [`AuthenticatedUser`] who signed in within the last
+/// `REAUTHENTICATION_MAX_AGE_MINUTES`, for endpoints managing the credentials
+/// that sign the user in. Refreshing a session does not count as signing in,
+/// so a stolen refresh token alone cannot enrol a passkey or turn off MFA.
+pub struct RecentlyAuthenticatedUser(pub AccessTokenClaims);
+
+#[async_trait]
+impl FromRequestParts<Arc<AppContext>> for RecentlyAuthenticatedUser {
+    type Rejection = (StatusCode, String);
+
+    async fn from_request_parts(parts: &mut Parts, context: &Arc<AppContext>) -> Result<Self, Self::Rejection> {
+        let AuthenticatedUser(claims) = AuthenticatedUser::from_request_parts(parts, context).await?;
+
+        let signed_in_after = (Utc::now() - context.reauthentication_max_age).timestamp();
+        if claims.auth_time.is_none_or(|auth_time| auth_time < signed_in_after) {
+            return Err((StatusCode::UNAUTHORIZED, "Sign in again to manage your credentials".to_string()));
+        }
+        Ok(RecentlyAuthenticatedUser(claims))
+    }
+}
+
+/// Caller of an endpoint open to both users and machines: a user with a
+/// bearer access token, or a service account with `Authorization: ApiKey <key>`.
+/// User tokens delegated to an OAuth client are let through, so whoever takes
+/// a `Principal` must check their scopes.
+pub enum Principal {
+    User(AccessTokenClaims),
+    ServiceAccount(AuthenticatedApiKey),
+}
+
+impl Principal {
+    /// The user or service account id, used to attribute actions.
+    pub fn id(&self) -> String {
+        match self {
+            Principal::User(claims) => claims.sub.clone(),
+            Principal::ServiceAccount(authenticated) => authenticated.service_account.id.as_uuid().to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl FromRequestParts<Arc<AppContext>> for Principal {
+    type Rejection = (StatusCode, String);
+
+    async fn from_request_parts(parts: &mut Parts, context: &Arc<AppContext>) -> Result<Self, Self::Rejection> {
+        let api_key = parts.headers.get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("ApiKey "));
+        let Some(api_key) = api_key else {
+            return user_token_claims(parts, context).await.map(Principal::User);
+        };
+
+        context.api_key_service.authenticate(&ApiKeyToken::new(api_key.trim().to_string())).await
+            .map(Principal::ServiceAccount)
+            .map_err(|e| match e {
+                DomainError::InvalidApiKey => (StatusCode::UNAUTHORIZED, e.to_string()),
+                _ => {
+                    tracing::error!("API key authentication failed: {}", e);
+                    (StatusCode::INTERNAL_SERVER_ERROR, "Internal server error".to_string())
+                }
+            })
+    }
+}
+
+/// Guard for the admin API: the caller needs `admin:read` for GET requests and
+/// `admin:write` otherwise. Checked against the current role assignments rather
+/// than the token, so revoking an admin takes effect immediately. Tokens issued
+/// to an OAuth client must also carry the permission, i.e. have the scope, and
+/// so must API keys. Holds the caller, so admin actions can be attributed.
+pub struct AdminUser(pub Principal);
+
+#[async_trait]
+impl FromRequestParts<Arc<AppContext>> for AdminUser {
+    type Rejection = (StatusCode, String);
+
+    async fn from_request_parts(parts: &mut Parts, context: &Arc<AppContext>) -> Result<Self, Self::Rejection> {
+        let principal = Principal::from_request_parts(parts, context).await?;
+        let action = if parts.method == Method::GET { "read" } else { "write" };
+
+        let claims = match &principal {
+            Principal::User(claims) => claims,
+            // Resolved from the account's roles on every request, limited to the key's scopes
+            Principal::ServiceAccount(authenticated) => {
+                if !authenticated.grants.allows("admin", action) {
+                    return Err((StatusCode::FORBIDDEN, format!("API key lacks the admin:{} permission", action)));
+                }
+                return Ok(AdminUser(principal));
+            }
+        };
+
+        let user_id = uuid::Uuid::parse_str(&claims.sub)
+            .map_err(|_| (StatusCode::UNAUTHORIZED, "Invalid token subject".to_string()))?;
+
+        if claims.client_id.is_some() {
+            let scoped = claims.permissions.iter().any(|permission| {
+                permission == &format!("admin:{}", action) || permission == "admin:*" || permission == "*:*"
+            });
+            if !scoped {
+                return Err((StatusCode::FORBIDDEN, format!("Token lacks the admin:{} scope", action)));
+            }
+        }
+
+        let decision = context.authorization_service
+            .check(&UserId::from_uuid(user_id), "admin", action).await
+            .map_err(|e| {
+                tracing::error!("Admin authorization check failed: {}", e);
+                (StatusCode::INTERNAL_SERVER_ERROR, "Internal server error".to_string())
+            })?;
+
+        if !decision.allowed {
+            return Err((StatusCode::FORBIDDEN, decision.reason));
+        }
+
+        Ok(AdminUser(principal))
+    }
+}
+
+/// Caller of `POST /policies/decisions`. Any user or service account may ask
+/// for a decision, but `?explain=true` reveals the conditions of every policy
+/// and goes through the admin guard.
+pub struct PolicyCaller;
+
+#[async_trait]
+impl FromRequestParts<Arc<AppContext>> for PolicyCaller {
+    type Rejection = (StatusCode, String);
+
+    async fn from_request_parts(parts: &mut Parts, context: &Arc<AppContext>) -> Result<Self, Self::Rejection> {
+        let explain = Query::<AuthorizeQueryDto>::try_from_uri(&parts.uri)
+            .is_ok_and(|Query(query)| query.explain);
+
+        if explain {
+            AdminUser::from_request_parts(parts, context).await?;
+        } else {
+            Principal::from_request_parts(parts, context).await?;
+        }
+
+        Ok(PolicyCaller)
+    }
+}
+
+/// Address and user agent of the client. The address is the peer address, or
+/// the first `X-Forwarded-For` entry when the service is configured to trust its proxy.
+pub struct ClientInfo(pub ClientInfoDto);
+
+#[async_trait]
+impl FromRequestParts<Arc<AppContext>> for ClientInfo {
+    type Rejection = (StatusCode, String);
+
+    async fn from_request_parts(parts: &mut Parts, context: &Arc<AppContext>) -> Result<Self, Self::Rejection> {
+        let user_agent = parts.headers.get(header::USER_AGENT)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+
+        if context.trust_forwarded_for {
+            let forwarded = parts.headers.get("x-forwarded-for")
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.split(',').next())
+                .map(|ip| ip.trim().to_string())
+                .filter(|ip| !ip.is_empty());
+            if let Some(ip_address) = forwarded {
+                return Ok(ClientInfo(ClientInfoDto { ip_address, user_agent }));
+            }
+        }
+
+        parts.extensions.get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| ClientInfo(ClientInfoDto {
+                ip_address: addr.ip().to_string(),
+                user_agent,
+            }))
+            .ok_or_else(|| {
+                tracing::error!("Client address is missing, is the server serving with connect info?");
+                (StatusCode::INTERNAL_SERVER_ERROR, "Internal server error".to_string())
+            })
+    }
+}
@@ -1,25 +1,977 @@
-use axum::{http::StatusCode, Json};
-use crate::application::dto::{RegisterDto, LoginDto, AuthResponseDto};
+use std::sync::Arc;
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Redirect, Response},
+    Form, Json,
+};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use crate::application::dto::{
+    RegisterDto, LoginDto, RefreshSessionDto, AuthResponseDto, OpenIdConfigurationDto,
+    CreateRoleDto, SetRoleParentDto, RoleDto, CreatePermissionDto, PermissionDto,
+    GrantPermissionDto, AssignRoleDto, EffectivePermissionsDto,
+    AuthorizeRequestDto, AuthorizeQueryDto, AuthorizeResponseDto, LoginResponseDto,
+    MfaEnrollmentDto, ConfirmMfaDto, RecoveryCodesDto, VerifyMfaDto, DisableMfaDto,
+    VerifyEmailDto, ForgotPasswordDto, ResetPasswordDto, SessionDto,
+    AuthorizationRequestDto, AuthorizationResponseDto, ConsentDecisionDto, TokenRequestDto, OAuthErrorDto,
+    CreateOAuthClientDto, CreatedOAuthClientDto, OAuthClientDto, OAuthConsentDto,
+    FederatedCallbackDto, FederatedIdentityDto, IdentityProviderDto, TokenReferenceDto,
+    AuditEventPageDto, AuditEventFilterDto, AuditEventListDto, AuditChainStatusDto,
+    CreateServiceAccountDto, ServiceAccountDto, CreateApiKeyDto, RotateApiKeyDto, ApiKeyDto, CreatedApiKeyDto,
+    RequestMagicLinkDto, MagicLinkLoginDto, PasskeyRegistrationOptionsDto, RegisterPasskeyDto, PasskeyDto,
+    StartPasskeyLoginDto, PasskeyLoginOptionsDto, PasskeyLoginDto, FieldErrorsDto, EffectivePermissionsQueryDto,
+    CreateOrganizationDto, OrganizationDto, OrganizationMemberDto, InviteMemberDto, OrganizationInvitationDto,
+    AcceptInvitationDto,
+};
+use crate::application::errors::ApplicationError;
+use crate::domain::errors::DomainError;
+use crate::di::AppContext;
+use crate::presentation::extractors::{AdminUser, AuthenticatedUser, ClientInfo, PolicyCaller, RecentlyAuthenticatedUser};
+
+fn error_response(error: ApplicationError) -> (StatusCode, String) {
+    let status = match &error {
+        ApplicationError::Validation(_) => StatusCode::BAD_REQUEST,
+        ApplicationError::Repository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        ApplicationError::Domain(domain_error) => match domain_error {
+            DomainError::InvalidEmail(_)
+            | DomainError::InvalidRoleName(_)
+            | DomainError::InvalidPermissionName(_)
+            | DomainError::InvalidRoleHierarchy(_)
+            | DomainError::MfaNotEnabled
+            | DomainError::InvalidAttestation(_)
+            | DomainError::InvalidFields(_)
+            | DomainError::InvalidInvitation(_)
+            | DomainError::ValidationError(_) => StatusCode::BAD_REQUEST,
+            DomainError::InvalidPassword
+            | DomainError::InvalidCredentials
+            | DomainError::SessionExpired
+            | DomainError::InvalidToken
+            | DomainError::TokenExpired
+            | DomainError::RefreshTokenReused
+            | DomainError::InvalidMfaCode
+            | DomainError::InvalidMfaChallenge
+            | DomainError::FederatedLoginFailed(_)
+            | DomainError::InvalidApiKey
+            | DomainError::InvalidWebAuthnChallenge
+            | DomainError::InvalidAssertion(_)
+            | DomainError::Unauthorized => StatusCode::UNAUTHORIZED,
+            DomainError::InvalidGrant(_)
+            | DomainError::InvalidScope(_)
+            | DomainError::UnauthorizedClient(_)
+            | DomainError::UnsupportedGrantType(_)
+            | DomainError::UnsupportedResponseType(_)
+            | DomainError::InvalidOAuthRequest(_) => StatusCode::BAD_REQUEST,
+            DomainError::InvalidClient => StatusCode::UNAUTHORIZED,
+            DomainError::Forbidden => StatusCode::FORBIDDEN,
+            DomainError::AccountLocked(_) => StatusCode::TOO_MANY_REQUESTS,
+            DomainError::IdentityProviderUnavailable(_) => StatusCode::BAD_GATEWAY,
+            DomainError::UserNotFound
+            | DomainError::SessionNotFound
+            | DomainError::RoleNotFound
+            | DomainError::PermissionNotFound
+            | DomainError::PolicyNotFound
+            | DomainError::OAuthClientNotFound
+            | DomainError::OAuthConsentNotFound
+            | DomainError::IdentityProviderNotFound
+            | DomainError::ServiceAccountNotFound
+            | DomainError::ApiKeyNotFound
+            | DomainError::PasskeyNotFound
+            | DomainError::OrganizationNotFound
+            | DomainError::OrganizationMemberNotFound
+            | DomainError::InvitationNotFound => StatusCode::NOT_FOUND,
+            DomainError::UserAlreadyExists
+            | DomainError::RoleAlreadyExists
+            | DomainError::PermissionAlreadyExists
+            | DomainError::ServiceAccountAlreadyExists
+            | DomainError::PasskeyAlreadyRegistered
+            | DomainError::OrganizationAlreadyExists
+            | DomainError::OrganizationMemberAlreadyExists
+            | DomainError::MfaAlreadyEnabled
+            | DomainError::EmailAlreadyVerified => StatusCode::CONFLICT,
+            DomainError::PasswordHashing(_)
+            | DomainError::TokenSigning(_)
+            | DomainError::InvalidPolicy(_)
+            | DomainError::EventPublishing(_)
+            | DomainError::Repository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        },
+    };
+
+    if status == StatusCode::INTERNAL_SERVER_ERROR {
+        tracing::error!("{}", error);
+        return (status, "Internal server error".to_string());
+    }
+    (status, error.to_string())
+}
+
+/// Field validation failures come back as JSON listing every violation;
+/// anything else falls through to `error_response`.
+fn field_error_response(error: ApplicationError) -> Response {
+    match error {
+        ApplicationError::Domain(DomainError::InvalidFields(errors)) => {
+            let body = FieldErrorsDto {
+                error: "validation_failed".to_string(),
+                errors,
+            };
+            (StatusCode::BAD_REQUEST, Json(body)).into_response()
+        }
+        error => error_response(error).into_response(),
+    }
+}
+
+/// Token endpoint errors use the RFC 6749 JSON body instead of plain text.
+fn oauth_error_response(error: ApplicationError) -> Response {
+    let body = match &error {
+        ApplicationError::Domain(domain_error) => OAuthErrorDto::from_domain(domain_error),
+        ApplicationError::Validation(message) => OAuthErrorDto {
+            error: "invalid_request".to_string(),
+            error_description: message.clone(),
+        },
+        ApplicationError::Repository(_) => OAuthErrorDto {
+            error: "server_error".to_string(),
+            error_description: String::new(),
+        },
+    };
+
+    match body.error.as_str() {
+        "server_error" => {
+            tracing::error!("{}", error);
+            let body = OAuthErrorDto {
+                error: body.error,
+                error_description: "Internal server error".to_string(),
+            };
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(body)).into_response()
+        }
+        "invalid_client" => (
+            StatusCode::UNAUTHORIZED,
+            [(header::WWW_AUTHENTICATE, "Basic")],
+            Json(body),
+        ).into_response(),
+        _ => (StatusCode::BAD_REQUEST, Json(body)).into_response(),
+    }
+}
+
+/// Client id and secret from `Authorization: Basic`, both form-encoded as RFC 6749 section 2.3.1 requires.
+fn basic_credentials(headers: &HeaderMap) -> Option<(String, String)> {
+    let encoded = headers.get(header::AUTHORIZATION)?
+        .to_str().ok()?
+        .strip_prefix("Basic ")?;
+    let decoded = String::from_utf8(STANDARD.decode(encoded.trim()).ok()?).ok()?;
+    let (client_id, client_secret) = decoded.split_once(':')?;
+    Some((
+        urlencoding::decode(client_id).ok()?.into_owned(),
+        urlencoding::decode(client_secret).ok()?.into_owned(),
+    ))
+}
 
 pub async fn health_check() -> &'static str {
     "OK"
 }
 
 pub async fn register(
+    State(context): State<Arc<AppContext>>,
+    ClientInfo(client): ClientInfo,
     Json(dto): Json<RegisterDto>,
-) -> Result<(StatusCode, Json<AuthResponseDto>), (StatusCode, String)> {
-    Err((StatusCode::NOT_IMPLEMENTED, "Use case not injected".to_string()))
+) -> Result<(StatusCode, Json<AuthResponseDto>), Response> {
+    context.register_use_case.execute(dto, client).await
+        .map(|response| (StatusCode::CREATED, Json(response)))
+        .map_err(field_error_response)
 }
 
 pub async fn login(
+    State(context): State<Arc<AppContext>>,
+    ClientInfo(client): ClientInfo,
     Json(dto): Json<LoginDto>,
-) -> Result<(StatusCode, Json<AuthResponseDto>), (StatusCode, String)> {
-    Err((StatusCode::NOT_IMPLEMENTED, "Use case not injected".to_string()))
+) -> Result<(StatusCode, Json<LoginResponseDto>), (StatusCode, String)> {
+    context.login_use_case.execute(dto, client).await
+        .map(|response| (StatusCode::OK, Json(response)))
+        .map_err(error_response)
+}
+
+pub async fn refresh_session(
+    State(context): State<Arc<AppContext>>,
+    ClientInfo(client): ClientInfo,
+    Json(dto): Json<RefreshSessionDto>,
+) -> Result<Json<AuthResponseDto>, (StatusCode, String)> {
+    context.refresh_session_use_case.execute(dto, client).await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn verify_mfa(
+    State(context): State<Arc<AppContext>>,
+    ClientInfo(client): ClientInfo,
+    Json(dto): Json<VerifyMfaDto>,
+) -> Result<Json<AuthResponseDto>, (StatusCode, String)> {
+    context.verify_mfa_use_case.execute(dto, client).await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn request_magic_link(
+    State(context): State<Arc<AppContext>>,
+    Json(dto): Json<RequestMagicLinkDto>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    context.request_magic_link_use_case.execute(dto).await
+        .map(|_| StatusCode::ACCEPTED)
+        .map_err(error_response)
+}
+
+pub async fn magic_link_login(
+    State(context): State<Arc<AppContext>>,
+    ClientInfo(client): ClientInfo,
+    Json(dto): Json<MagicLinkLoginDto>,
+) -> Result<Json<LoginResponseDto>, (StatusCode, String)> {
+    context.magic_link_login_use_case.execute(dto, client).await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn start_passkey_login(
+    State(context): State<Arc<AppContext>>,
+    Json(dto): Json<StartPasskeyLoginDto>,
+) -> Result<Json<PasskeyLoginOptionsDto>, (StatusCode, String)> {
+    context.start_passkey_login_use_case.execute(dto).await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn passkey_login(
+    State(context): State<Arc<AppContext>>,
+    ClientInfo(client): ClientInfo,
+    Json(dto): Json<PasskeyLoginDto>,
+) -> Result<Json<LoginResponseDto>, (StatusCode, String)> {
+    context.passkey_login_use_case.execute(dto, client).await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn list_passkeys(
+    State(context): State<Arc<AppContext>>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+) -> Result<Json<Vec<PasskeyDto>>, (StatusCode, String)> {
+    context.list_passkeys_use_case.execute(&claims.sub).await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn start_passkey_registration(
+    State(context): State<Arc<AppContext>>,
+    RecentlyAuthenticatedUser(claims): RecentlyAuthenticatedUser,
+) -> Result<Json<PasskeyRegistrationOptionsDto>, (StatusCode, String)> {
+    context.start_passkey_registration_use_case.execute(&claims.sub).await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn finish_passkey_registration(
+    State(context): State<Arc<AppContext>>,
+    RecentlyAuthenticatedUser(claims): RecentlyAuthenticatedUser,
+    Json(dto): Json<RegisterPasskeyDto>,
+) -> Result<(StatusCode, Json<PasskeyDto>), (StatusCode, String)> {
+    context.finish_passkey_registration_use_case.execute(&claims.sub, dto).await
+        .map(|passkey| (StatusCode::CREATED, Json(passkey)))
+        .map_err(error_response)
+}
+
+pub async fn delete_passkey(
+    State(context): State<Arc<AppContext>>,
+    RecentlyAuthenticatedUser(claims): RecentlyAuthenticatedUser,
+    Path(id): Path<String>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    context.delete_passkey_use_case.execute(&claims.sub, &id).await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(error_response)
+}
+
+pub async fn create_organization(
+    State(context): State<Arc<AppContext>>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Json(dto): Json<CreateOrganizationDto>,
+) -> Result<(StatusCode, Json<OrganizationDto>), (StatusCode, String)> {
+    context.create_organization_use_case.execute(&claims.sub, dto).await
+        .map(|organization| (StatusCode::CREATED, Json(organization)))
+        .map_err(error_response)
+}
+
+pub async fn list_organizations(
+    State(context): State<Arc<AppContext>>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+) -> Result<Json<Vec<OrganizationDto>>, (StatusCode, String)> {
+    context.list_organizations_use_case.execute(&claims.sub).await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn switch_organization(
+    State(context): State<Arc<AppContext>>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    ClientInfo(client): ClientInfo,
+    Path(id): Path<String>,
+) -> Result<Json<AuthResponseDto>, (StatusCode, String)> {
+    context.switch_organization_use_case.execute(&claims, &id, client).await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn list_organization_members(
+    State(context): State<Arc<AppContext>>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path(id): Path<String>,
+) -> Result<Json<Vec<OrganizationMemberDto>>, (StatusCode, String)> {
+    context.list_members_use_case.execute(&claims.sub, &id).await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn remove_organization_member(
+    State(context): State<Arc<AppContext>>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path((id, user_id)): Path<(String, String)>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    context.remove_member_use_case.execute(&claims.sub, &id, &user_id).await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(error_response)
+}
+
+pub async fn assign_organization_role(
+    State(context): State<Arc<AppContext>>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path((id, user_id)): Path<(String, String)>,
+    Json(dto): Json<AssignRoleDto>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    context.assign_member_role_use_case.execute(&claims.sub, &id, &user_id, dto).await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(error_response)
+}
+
+pub async fn unassign_organization_role(
+    State(context): State<Arc<AppContext>>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path((id, user_id, role_id)): Path<(String, String, String)>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    context.unassign_member_role_use_case.execute(&claims.sub, &id, &user_id, &role_id).await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(error_response)
+}
+
+pub async fn invite_organization_member(
+    State(context): State<Arc<AppContext>>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path(id): Path<String>,
+    Json(dto): Json<InviteMemberDto>,
+) -> Result<(StatusCode, Json<OrganizationInvitationDto>), (StatusCode, String)> {
+    context.invite_member_use_case.execute(&claims.sub, &id, dto).await
+        .map(|invitation| (StatusCode::CREATED, Json(invitation)))
+        .map_err(error_response)
+}
+
+pub async fn list_organization_invitations(
+    State(context): State<Arc<AppContext>>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path(id): Path<String>,
+) -> Result<Json<Vec<OrganizationInvitationDto>>, (StatusCode, String)> {
+    context.list_invitations_use_case.execute(&claims.sub, &id).await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn revoke_organization_invitation(
+    State(context): State<Arc<AppContext>>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path((id, invitation_id)): Path<(String, String)>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    context.revoke_invitation_use_case.execute(&claims.sub, &id, &invitation_id).await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(error_response)
+}
+
+pub async fn accept_organization_invitation(
+    State(context): State<Arc<AppContext>>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Json(dto): Json<AcceptInvitationDto>,
+) -> Result<Json<OrganizationDto>, (StatusCode, String)> {
+    context.accept_invitation_use_case.execute(&claims.sub, dto).await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn enroll_mfa(
+    State(context): State<Arc<AppContext>>,
+    RecentlyAuthenticatedUser(claims): RecentlyAuthenticatedUser,
+) -> Result<Json<MfaEnrollmentDto>, (StatusCode, String)> {
+    context.enroll_mfa_use_case.execute(&claims.sub).await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn confirm_mfa(
+    State(context): State<Arc<AppContext>>,
+    RecentlyAuthenticatedUser(claims): RecentlyAuthenticatedUser,
+    Json(dto): Json<ConfirmMfaDto>,
+) -> Result<Json<RecoveryCodesDto>, (StatusCode, String)> {
+    context.confirm_mfa_use_case.execute(&claims.sub, dto).await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn disable_mfa(
+    State(context): State<Arc<AppContext>>,
+    RecentlyAuthenticatedUser(claims): RecentlyAuthenticatedUser,
+    Json(dto): Json<DisableMfaDto>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    context.disable_mfa_use_case.execute(&claims.sub, dto).await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(error_response)
+}
+
+pub async fn request_email_verification(
+    State(context): State<Arc<AppContext>>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+) -> Result<StatusCode, (StatusCode, String)> {
+    context.request_email_verification_use_case.execute(&claims.sub).await
+        .map(|_| StatusCode::ACCEPTED)
+        .map_err(error_response)
+}
+
+pub async fn verify_email(
+    State(context): State<Arc<AppContext>>,
+    Json(dto): Json<VerifyEmailDto>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    context.verify_email_use_case.execute(dto).await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(error_response)
+}
+
+pub async fn forgot_password(
+    State(context): State<Arc<AppContext>>,
+    Json(dto): Json<ForgotPasswordDto>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    context.forgot_password_use_case.execute(dto).await
+        .map(|_| StatusCode::ACCEPTED)
+        .map_err(error_response)
+}
+
+pub async fn reset_password(
+    State(context): State<Arc<AppContext>>,
+    Json(dto): Json<ResetPasswordDto>,
+) -> Result<StatusCode, Response> {
+    context.reset_password_use_case.execute(dto).await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(field_error_response)
 }
 
 pub async fn logout(
+    State(context): State<Arc<AppContext>>,
     Json(token): Json<String>,
 ) -> Result<StatusCode, (StatusCode, String)> {
-    Err((StatusCode::NOT_IMPLEMENTED, "Use case not injected".to_string()))
+    context.logout_use_case.execute(&token).await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(error_response)
+}
+
+pub async fn list_sessions(
+    State(context): State<Arc<AppContext>>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+) -> Result<Json<Vec<SessionDto>>, (StatusCode, String)> {
+    context.list_sessions_use_case.execute(&claims.sub, claims.sid.as_deref()).await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn revoke_session(
+    State(context): State<Arc<AppContext>>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path(id): Path<String>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    context.revoke_session_use_case.execute(&claims.sub, &claims.sub, &id).await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(error_response)
+}
+
+/// "Sign out everywhere else": keeps only the session making the request.
+pub async fn revoke_other_sessions(
+    State(context): State<Arc<AppContext>>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+) -> Result<StatusCode, (StatusCode, String)> {
+    context.revoke_sessions_use_case.execute(&claims.sub, &claims.sub, claims.sid.as_deref()).await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(error_response)
+}
+
+pub async fn jwks(
+    State(context): State<Arc<AppContext>>,
+) -> impl IntoResponse {
+    (
+        [(header::CACHE_CONTROL, "public, max-age=300")],
+        Json(context.token_service.jwks()),
+    )
+}
+
+pub async fn openid_configuration(
+    State(context): State<Arc<AppContext>>,
+) -> Json<OpenIdConfigurationDto> {
+    let issuer = context.token_service.issuer();
+
+    Json(OpenIdConfigurationDto {
+        issuer: issuer.to_string(),
+        jwks_uri: format!("{}/.well-known/jwks.json", issuer.trim_end_matches('/')),
+        subject_types_supported: vec!["public".to_string()],
+        id_token_signing_alg_values_supported: vec![
+            format!("{:?}", context.token_service.signing_algorithm()),
+        ],
+        authorization_endpoint: format!("{}/authorize", issuer.trim_end_matches('/')),
+        token_endpoint: format!("{}/token", issuer.trim_end_matches('/')),
+        introspection_endpoint: format!("{}/introspect", issuer.trim_end_matches('/')),
+        revocation_endpoint: format!("{}/revoke", issuer.trim_end_matches('/')),
+        response_types_supported: vec!["code".to_string()],
+        grant_types_supported: ["authorization_code", "refresh_token", "client_credentials"]
+            .iter()
+            .map(|grant_type| grant_type.to_string())
+            .collect(),
+        code_challenge_methods_supported: vec!["S256".to_string()],
+        token_endpoint_auth_methods_supported: ["client_secret_basic", "client_secret_post", "none"]
+            .iter()
+            .map(|method| method.to_string())
+            .collect(),
+        claims_supported: ["iss", "sub", "sid", "roles", "permissions", "client_id", "scope", "iat", "exp", "jti"]
+            .iter()
+            .map(|claim| claim.to_string())
+            .collect(),
+    })
+}
+
+pub async fn list_roles(
+    _admin: AdminUser,
+    State(context): State<Arc<AppContext>>,
+) -> Result<Json<Vec<RoleDto>>, (StatusCode, String)> {
+    context.list_roles_use_case.execute().await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn create_role(
+    _admin: AdminUser,
+    State(context): State<Arc<AppContext>>,
+    Json(dto): Json<CreateRoleDto>,
+) -> Result<(StatusCode, Json<RoleDto>), (StatusCode, String)> {
+    context.create_role_use_case.execute(dto).await
+        .map(|role| (StatusCode::CREATED, Json(role)))
+        .map_err(error_response)
+}
+
+pub async fn set_role_parent(
+    _admin: AdminUser,
+    State(context): State<Arc<AppContext>>,
+    Path(id): Path<String>,
+    Json(dto): Json<SetRoleParentDto>,
+) -> Result<Json<RoleDto>, (StatusCode, String)> {
+    context.set_role_parent_use_case.execute(&id, dto).await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn delete_role(
+    _admin: AdminUser,
+    State(context): State<Arc<AppContext>>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    context.delete_role_use_case.execute(&id).await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(error_response)
+}
+
+pub async fn grant_permission(
+    AdminUser(admin): AdminUser,
+    State(context): State<Arc<AppContext>>,
+    Path(id): Path<String>,
+    Json(dto): Json<GrantPermissionDto>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    context.grant_permission_use_case.execute(&admin.id(), &id, dto).await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(error_response)
+}
+
+pub async fn revoke_permission(
+    AdminUser(admin): AdminUser,
+    State(context): State<Arc<AppContext>>,
+    Path((id, permission_id)): Path<(String, String)>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    context.revoke_permission_use_case.execute(&admin.id(), &id, &permission_id).await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(error_response)
+}
+
+pub async fn list_permissions(
+    _admin: AdminUser,
+    State(context): State<Arc<AppContext>>,
+) -> Result<Json<Vec<PermissionDto>>, (StatusCode, String)> {
+    context.list_permissions_use_case.execute().await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn create_permission(
+    _admin: AdminUser,
+    State(context): State<Arc<AppContext>>,
+    Json(dto): Json<CreatePermissionDto>,
+) -> Result<(StatusCode, Json<PermissionDto>), (StatusCode, String)> {
+    context.create_permission_use_case.execute(dto).await
+        .map(|permission| (StatusCode::CREATED, Json(permission)))
+        .map_err(error_response)
+}
+
+pub async fn assign_role(
+    AdminUser(admin): AdminUser,
+    State(context): State<Arc<AppContext>>,
+    Path(id): Path<String>,
+    Json(dto): Json<AssignRoleDto>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    context.assign_role_use_case.execute(&admin.id(), &id, dto).await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(error_response)
+}
+
+pub async fn unassign_role(
+    AdminUser(admin): AdminUser,
+    State(context): State<Arc<AppContext>>,
+    Path((id, role_id)): Path<(String, String)>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    context.unassign_role_use_case.execute(&admin.id(), &id, &role_id).await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(error_response)
+}
+
+pub async fn get_user_permissions(
+    _admin: AdminUser,
+    State(context): State<Arc<AppContext>>,
+    Path(id): Path<String>,
+    Query(query): Query<EffectivePermissionsQueryDto>,
+) -> Result<Json<EffectivePermissionsDto>, (StatusCode, String)> {
+    context.get_user_permissions_use_case.execute(&id, query).await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn list_user_sessions(
+    _admin: AdminUser,
+    State(context): State<Arc<AppContext>>,
+    Path(id): Path<String>,
+) -> Result<Json<Vec<SessionDto>>, (StatusCode, String)> {
+    context.list_sessions_use_case.execute(&id, None).await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn revoke_user_session(
+    AdminUser(admin): AdminUser,
+    State(context): State<Arc<AppContext>>,
+    Path((id, session_id)): Path<(String, String)>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    context.revoke_session_use_case.execute(&admin.id(), &id, &session_id).await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(error_response)
+}
+
+pub async fn revoke_user_sessions(
+    AdminUser(admin): AdminUser,
+    State(context): State<Arc<AppContext>>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    context.revoke_sessions_use_case.execute(&admin.id(), &id, None).await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(error_response)
+}
+
+pub async fn list_audit_events(
+    _admin: AdminUser,
+    State(context): State<Arc<AppContext>>,
+    Query(dto): Query<AuditEventPageDto>,
+) -> Result<Json<AuditEventListDto>, (StatusCode, String)> {
+    context.list_audit_events_use_case.execute(dto).await
+        .map(Json)
+        .map_err(error_response)
+}
+
+/// The matching records as JSON Lines, for compliance reviews.
+pub async fn export_audit_events(
+    _admin: AdminUser,
+    State(context): State<Arc<AppContext>>,
+    Query(dto): Query<AuditEventFilterDto>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    context.export_audit_events_use_case.execute(dto).await
+        .map(|lines| (
+            [
+                (header::CONTENT_TYPE, "application/x-ndjson"),
+                (header::CONTENT_DISPOSITION, "attachment; filename=\"audit-events.jsonl\""),
+            ],
+            lines,
+        ))
+        .map_err(error_response)
+}
+
+pub async fn verify_audit_chain(
+    _admin: AdminUser,
+    State(context): State<Arc<AppContext>>,
+) -> Result<Json<AuditChainStatusDto>, (StatusCode, String)> {
+    context.verify_audit_chain_use_case.execute().await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn list_service_accounts(
+    _admin: AdminUser,
+    State(context): State<Arc<AppContext>>,
+) -> Result<Json<Vec<ServiceAccountDto>>, (StatusCode, String)> {
+    context.list_service_accounts_use_case.execute().await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn create_service_account(
+    AdminUser(admin): AdminUser,
+    State(context): State<Arc<AppContext>>,
+    Json(dto): Json<CreateServiceAccountDto>,
+) -> Result<(StatusCode, Json<ServiceAccountDto>), (StatusCode, String)> {
+    context.create_service_account_use_case.execute(&admin.id(), dto).await
+        .map(|service_account| (StatusCode::CREATED, Json(service_account)))
+        .map_err(error_response)
+}
+
+pub async fn delete_service_account(
+    AdminUser(admin): AdminUser,
+    State(context): State<Arc<AppContext>>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    context.delete_service_account_use_case.execute(&admin.id(), &id).await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(error_response)
+}
+
+pub async fn assign_service_account_role(
+    AdminUser(admin): AdminUser,
+    State(context): State<Arc<AppContext>>,
+    Path(id): Path<String>,
+    Json(dto): Json<AssignRoleDto>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    context.assign_service_account_role_use_case.execute(&admin.id(), &id, dto).await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(error_response)
 }
 
+pub async fn unassign_service_account_role(
+    AdminUser(admin): AdminUser,
+    State(context): State<Arc<AppContext>>,
+    Path((id, role_id)): Path<(String, String)>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    context.unassign_service_account_role_use_case.execute(&admin.id(), &id, &role_id).await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(error_response)
+}
+
+pub async fn list_api_keys(
+    _admin: AdminUser,
+    State(context): State<Arc<AppContext>>,
+    Path(id): Path<String>,
+) -> Result<Json<Vec<ApiKeyDto>>, (StatusCode, String)> {
+    context.list_api_keys_use_case.execute(&id).await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn create_api_key(
+    AdminUser(admin): AdminUser,
+    State(context): State<Arc<AppContext>>,
+    Path(id): Path<String>,
+    Json(dto): Json<CreateApiKeyDto>,
+) -> Result<(StatusCode, Json<CreatedApiKeyDto>), (StatusCode, String)> {
+    context.create_api_key_use_case.execute(&admin.id(), &id, dto).await
+        .map(|response| (StatusCode::CREATED, Json(response)))
+        .map_err(error_response)
+}
+
+pub async fn rotate_api_key(
+    AdminUser(admin): AdminUser,
+    State(context): State<Arc<AppContext>>,
+    Path((id, key_id)): Path<(String, String)>,
+    Json(dto): Json<RotateApiKeyDto>,
+) -> Result<(StatusCode, Json<CreatedApiKeyDto>), (StatusCode, String)> {
+    context.rotate_api_key_use_case.execute(&admin.id(), &id, &key_id, dto).await
+        .map(|response| (StatusCode::CREATED, Json(response)))
+        .map_err(error_response)
+}
+
+pub async fn revoke_api_key(
+    AdminUser(admin): AdminUser,
+    State(context): State<Arc<AppContext>>,
+    Path((id, key_id)): Path<(String, String)>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    context.revoke_api_key_use_case.execute(&admin.id(), &id, &key_id).await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(error_response)
+}
+
+pub async fn list_identity_providers(
+    State(context): State<Arc<AppContext>>,
+) -> Result<Json<Vec<IdentityProviderDto>>, (StatusCode, String)> {
+    context.list_identity_providers_use_case.execute().await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn start_federated_login(
+    State(context): State<Arc<AppContext>>,
+    Path(provider): Path<String>,
+) -> Result<Redirect, (StatusCode, String)> {
+    context.start_federated_login_use_case.execute(&provider).await
+        .map(|url| Redirect::to(&url))
+        .map_err(error_response)
+}
+
+pub async fn complete_federated_login(
+    State(context): State<Arc<AppContext>>,
+    ClientInfo(client): ClientInfo,
+    Path(provider): Path<String>,
+    Query(dto): Query<FederatedCallbackDto>,
+) -> Result<Json<LoginResponseDto>, (StatusCode, String)> {
+    context.complete_federated_login_use_case.execute(&provider, dto, client).await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn list_federated_identities(
+    State(context): State<Arc<AppContext>>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+) -> Result<Json<Vec<FederatedIdentityDto>>, (StatusCode, String)> {
+    context.list_federated_identities_use_case.execute(&claims.sub).await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn authorize_client(
+    State(context): State<Arc<AppContext>>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Query(dto): Query<AuthorizationRequestDto>,
+) -> Result<Json<AuthorizationResponseDto>, (StatusCode, String)> {
+    context.authorize_client_use_case.execute(&claims.sub, dto).await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn decide_consent(
+    State(context): State<Arc<AppContext>>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Json(dto): Json<ConsentDecisionDto>,
+) -> Result<Json<AuthorizationResponseDto>, (StatusCode, String)> {
+    context.decide_consent_use_case.execute(&claims.sub, dto).await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn token(
+    State(context): State<Arc<AppContext>>,
+    ClientInfo(client): ClientInfo,
+    headers: HeaderMap,
+    Form(mut dto): Form<TokenRequestDto>,
+) -> Response {
+    if let Some((client_id, client_secret)) = basic_credentials(&headers) {
+        dto.client_id = Some(client_id);
+        dto.client_secret = Some(client_secret);
+    }
+
+    match context.issue_token_use_case.execute(dto, client).await {
+        Ok(response) => ([(header::CACHE_CONTROL, "no-store")], Json(response)).into_response(),
+        Err(error) => oauth_error_response(error),
+    }
+}
+
+pub async fn introspect(
+    State(context): State<Arc<AppContext>>,
+    headers: HeaderMap,
+    Form(mut dto): Form<TokenReferenceDto>,
+) -> Response {
+    if let Some((client_id, client_secret)) = basic_credentials(&headers) {
+        dto.client_id = Some(client_id);
+        dto.client_secret = Some(client_secret);
+    }
+
+    match context.introspect_token_use_case.execute(dto).await {
+        Ok(response) => ([(header::CACHE_CONTROL, "no-store")], Json(response)).into_response(),
+        Err(error) => oauth_error_response(error),
+    }
+}
+
+pub async fn revoke(
+    State(context): State<Arc<AppContext>>,
+    headers: HeaderMap,
+    Form(mut dto): Form<TokenReferenceDto>,
+) -> Response {
+    if let Some((client_id, client_secret)) = basic_credentials(&headers) {
+        dto.client_id = Some(client_id);
+        dto.client_secret = Some(client_secret);
+    }
+
+    match context.revoke_token_use_case.execute(dto).await {
+        Ok(()) => StatusCode::OK.into_response(),
+        Err(error) => oauth_error_response(error),
+    }
+}
+
+pub async fn list_consents(
+    State(context): State<Arc<AppContext>>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+) -> Result<Json<Vec<OAuthConsentDto>>, (StatusCode, String)> {
+    context.list_consents_use_case.execute(&claims.sub).await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn revoke_consent(
+    State(context): State<Arc<AppContext>>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    Path(client_id): Path<String>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    context.revoke_consent_use_case.execute(&claims.sub, &client_id).await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(error_response)
+}
+
+pub async fn list_oauth_clients(
+    _admin: AdminUser,
+    State(context): State<Arc<AppContext>>,
+) -> Result<Json<Vec<OAuthClientDto>>, (StatusCode, String)> {
+    context.list_oauth_clients_use_case.execute().await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn create_oauth_client(
+    _admin: AdminUser,
+    State(context): State<Arc<AppContext>>,
+    Json(dto): Json<CreateOAuthClientDto>,
+) -> Result<(StatusCode, Json<CreatedOAuthClientDto>), (StatusCode, String)> {
+    context.create_oauth_client_use_case.execute(dto).await
+        .map(|response| (StatusCode::CREATED, Json(response)))
+        .map_err(error_response)
+}
+
+pub async fn delete_oauth_client(
+    _admin: AdminUser,
+    State(context): State<Arc<AppContext>>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    context.delete_oauth_client_use_case.execute(&id).await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(error_response)
+}
+
+pub async fn decide_policy(
+    State(context): State<Arc<AppContext>>,
+    _caller: PolicyCaller,
+    Query(query): Query<AuthorizeQueryDto>,
+    Json(dto): Json<AuthorizeRequestDto>,
+) -> Result<Json<AuthorizeResponseDto>, (StatusCode, String)> {
+    context.authorize_use_case.execute(dto, query.explain).await
+        .map(Json)
+        .map_err(error_response)
+}
@@ -1,6 +1,7 @@
 pub mod server;
 pub mod handlers;
 pub mod routes;
+pub mod extractors;
 
 pub use server::create_server;
 
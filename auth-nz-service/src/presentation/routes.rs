@@ -1,13 +1,81 @@
-use axum::{Router, routing::get, routing::post};
+use std::sync::Arc;
+use axum::{Router, routing::{delete, get, post, put}};
 use crate::presentation::handlers;
+use crate::di::AppContext;
 
-pub async fn create_router() -> Result<Router, Box<dyn std::error::Error>> {
+pub async fn create_router(context: Arc<AppContext>) -> Result<Router, Box<dyn std::error::Error>> {
     let router = Router::new()
         .route("/health", get(handlers::health_check))
         .route("/register", post(handlers::register))
         .route("/login", post(handlers::login))
-        .route("/logout", post(handlers::logout));
+        .route("/login/mfa", post(handlers::verify_mfa))
+        .route("/login/magic-link", post(handlers::request_magic_link))
+        .route("/login/magic-link/verify", post(handlers::magic_link_login))
+        .route("/login/passkey/options", post(handlers::start_passkey_login))
+        .route("/login/passkey", post(handlers::passkey_login))
+        .route("/login/oidc", get(handlers::list_identity_providers))
+        .route("/login/oidc/:provider", get(handlers::start_federated_login))
+        .route("/login/oidc/:provider/callback", get(handlers::complete_federated_login))
+        .route("/identities", get(handlers::list_federated_identities))
+        .route("/passkeys", get(handlers::list_passkeys))
+        .route("/passkeys/registration/options", post(handlers::start_passkey_registration))
+        .route("/passkeys/registration", post(handlers::finish_passkey_registration))
+        .route("/passkeys/:id", delete(handlers::delete_passkey))
+        .route("/organizations", get(handlers::list_organizations).post(handlers::create_organization))
+        .route("/organizations/invitations/accept", post(handlers::accept_organization_invitation))
+        .route("/organizations/:id/token", post(handlers::switch_organization))
+        .route("/organizations/:id/members", get(handlers::list_organization_members))
+        .route("/organizations/:id/members/:user_id", delete(handlers::remove_organization_member))
+        .route("/organizations/:id/members/:user_id/roles", post(handlers::assign_organization_role))
+        .route("/organizations/:id/members/:user_id/roles/:role_id", delete(handlers::unassign_organization_role))
+        .route("/organizations/:id/invitations", get(handlers::list_organization_invitations).post(handlers::invite_organization_member))
+        .route("/organizations/:id/invitations/:invitation_id", delete(handlers::revoke_organization_invitation))
+        .route("/logout", post(handlers::logout))
+        .route("/mfa/totp/enroll", post(handlers::enroll_mfa))
+        .route("/mfa/totp/confirm", post(handlers::confirm_mfa))
+        .route("/mfa/disable", post(handlers::disable_mfa))
+        .route("/email/verification", post(handlers::request_email_verification))
+        .route("/email/verify", post(handlers::verify_email))
+        .route("/password/forgot", post(handlers::forgot_password))
+        .route("/password/reset", post(handlers::reset_password))
+        .route("/sessions", get(handlers::list_sessions))
+        .route("/sessions/revoke-others", post(handlers::revoke_other_sessions))
+        .route("/sessions/:id", delete(handlers::revoke_session))
+        .route("/token/refresh", post(handlers::refresh_session))
+        .route("/policies/decisions", post(handlers::decide_policy))
+        .route("/.well-known/jwks.json", get(handlers::jwks))
+        .route("/.well-known/openid-configuration", get(handlers::openid_configuration))
+        .route("/authorize", get(handlers::authorize_client))
+        .route("/authorize/consent", post(handlers::decide_consent))
+        .route("/token", post(handlers::token))
+        .route("/introspect", post(handlers::introspect))
+        .route("/revoke", post(handlers::revoke))
+        .route("/oauth/consents", get(handlers::list_consents))
+        .route("/oauth/consents/:client_id", delete(handlers::revoke_consent))
+        .route("/admin/roles", get(handlers::list_roles).post(handlers::create_role))
+        .route("/admin/roles/:id", delete(handlers::delete_role))
+        .route("/admin/roles/:id/parent", put(handlers::set_role_parent))
+        .route("/admin/roles/:id/permissions", post(handlers::grant_permission))
+        .route("/admin/roles/:id/permissions/:permission_id", delete(handlers::revoke_permission))
+        .route("/admin/permissions", get(handlers::list_permissions).post(handlers::create_permission))
+        .route("/admin/oauth/clients", get(handlers::list_oauth_clients).post(handlers::create_oauth_client))
+        .route("/admin/oauth/clients/:id", delete(handlers::delete_oauth_client))
+        .route("/admin/users/:id/roles", post(handlers::assign_role))
+        .route("/admin/users/:id/roles/:role_id", delete(handlers::unassign_role))
+        .route("/admin/users/:id/permissions", get(handlers::get_user_permissions))
+        .route("/admin/users/:id/sessions", get(handlers::list_user_sessions).delete(handlers::revoke_user_sessions))
+        .route("/admin/users/:id/sessions/:session_id", delete(handlers::revoke_user_session))
+        .route("/admin/service-accounts", get(handlers::list_service_accounts).post(handlers::create_service_account))
+        .route("/admin/service-accounts/:id", delete(handlers::delete_service_account))
+        .route("/admin/service-accounts/:id/roles", post(handlers::assign_service_account_role))
+        .route("/admin/service-accounts/:id/roles/:role_id", delete(handlers::unassign_service_account_role))
+        .route("/admin/service-accounts/:id/api-keys", get(handlers::list_api_keys).post(handlers::create_api_key))
+        .route("/admin/service-accounts/:id/api-keys/:key_id", delete(handlers::revoke_api_key))
+        .route("/admin/service-accounts/:id/api-keys/:key_id/rotate", post(handlers::rotate_api_key))
+        .route("/admin/audit-events", get(handlers::list_audit_events))
+        .route("/admin/audit-events/export", get(handlers::export_audit_events))
+        .route("/admin/audit-events/verify", get(handlers::verify_audit_chain))
+        .with_state(context);
 
     Ok(router)
 }
-
@@ -1,12 +1,22 @@
-use axum::{Router, Server};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::net::TcpListener;
 use tower::ServiceBuilder;
 use tower_http::cors::CorsLayer;
 use tower_http::trace::TraceLayer;
 use crate::infrastructure::config::Config;
 use crate::presentation::routes::create_router;
+use crate::di::AppContext;
 
-pub async fn create_server(config: Config) -> Result<Server<axum::extract::connect_info::IntoMakeServiceWithConnectInfo<Router, std::net::SocketAddr>, Router>, Box<dyn std::error::Error>> {
-    let app = create_router().await?;
+pub async fn create_server(config: Config) -> Result<(), Box<dyn std::error::Error>> {
+    if config.jwt.algorithm.starts_with("HS") {
+        tracing::warn!(
+            "JWT_ALGORITHM={} publishes no JWKS keys; services using auth-middleware need AUTH_TOKEN_VALIDATION=introspection",
+            config.jwt.algorithm
+        );
+    }
+    let context = Arc::new(AppContext::new(config.clone()).await?);
+    let app = create_router(context).await?;
 
     let app = app
         .layer(
@@ -15,13 +25,11 @@ pub async fn create_server(config: Config) -> Result<Server<axum::extract::conne
                 .layer(CorsLayer::permissive())
         );
 
-    let addr = format!("{}:{}", config.server.host, config.server.port)
-        .parse()?;
-
-    let server = Server::bind(&addr).serve(app.into_make_service());
+    let addr = format!("{}:{}", config.server.host, config.server.port);
+    let listener = TcpListener::bind(&addr).await?;
 
     tracing::info!("Auth service running on {}", addr);
 
-    Ok(server)
+    axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>()).await?;
+    Ok(())
 }
-
@@ -0,0 +1,13 @@
+CREATE TABLE IF NOT EXISTS products (
+    id UUID PRIMARY KEY,
+    name TEXT NOT NULL,
+    sku TEXT NOT NULL UNIQUE,
+    description TEXT NOT NULL DEFAULT '',
+    price NUMERIC NOT NULL CHECK (price >= 0),
+    category_id UUID REFERENCES categories(id) ON DELETE SET NULL,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_products_category_id ON products(category_id);
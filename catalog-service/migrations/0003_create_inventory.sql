@@ -0,0 +1,6 @@
+CREATE TABLE IF NOT EXISTS inventory (
+    product_id UUID PRIMARY KEY REFERENCES products(id) ON DELETE CASCADE,
+    quantity INTEGER NOT NULL CHECK (quantity >= 0),
+    reserved_quantity INTEGER NOT NULL DEFAULT 0 CHECK (reserved_quantity >= 0 AND reserved_quantity <= quantity),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
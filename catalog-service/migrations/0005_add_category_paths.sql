@@ -0,0 +1,22 @@
+-- Materialized path of every category: the ids from the root down to the
+-- category itself, as `/<root id>/.../<id>/`. A subtree is every category
+-- whose path starts with the path of its root.
+ALTER TABLE categories ADD COLUMN IF NOT EXISTS path TEXT;
+
+WITH RECURSIVE tree AS (
+    SELECT id, '/' || id::text || '/' AS path
+    FROM categories
+    WHERE parent_id IS NULL
+    UNION ALL
+    SELECT c.id, tree.path || c.id::text || '/'
+    FROM categories c
+    JOIN tree ON c.parent_id = tree.id
+)
+UPDATE categories
+SET path = tree.path
+FROM tree
+WHERE categories.id = tree.id;
+
+ALTER TABLE categories ALTER COLUMN path SET NOT NULL;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_categories_path ON categories(path text_pattern_ops);
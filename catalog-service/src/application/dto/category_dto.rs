@@ -1,15 +1,76 @@
 use serde::{Deserialize, Serialize};
+use crate::domain::entities::category::Category;
+use crate::domain::services::CategoryNode;
 
 #[derive(Debug, Deserialize)]
 pub struct CreateCategoryDto {
     pub name: String,
+    #[serde(default)]
+    pub parent_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateCategoryDto {
+    pub name: String,
+}
+
+/// A missing or null `parent_id` moves the category to the root.
+#[derive(Debug, Deserialize)]
+pub struct MoveCategoryDto {
+    #[serde(default)]
+    pub parent_id: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
 pub struct CategoryDto {
     pub id: String,
     pub name: String,
+    pub parent_id: Option<String>,
     pub is_active: bool,
     pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<&Category> for CategoryDto {
+    fn from(category: &Category) -> Self {
+        Self {
+            id: category.id.as_uuid().to_string(),
+            name: category.name.as_str().to_string(),
+            parent_id: category.parent_id.map(|id| id.to_string()),
+            is_active: category.is_active,
+            created_at: category.created_at.to_rfc3339(),
+            updated_at: category.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct CategoryTreeDto {
+    #[serde(flatten)]
+    pub category: CategoryDto,
+    pub children: Vec<CategoryTreeDto>,
+}
+
+impl From<&CategoryNode> for CategoryTreeDto {
+    fn from(node: &CategoryNode) -> Self {
+        Self {
+            category: CategoryDto::from(&node.category),
+            children: node.children.iter().map(CategoryTreeDto::from).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct BreadcrumbDto {
+    pub id: String,
+    pub name: String,
 }
 
+impl From<&Category> for BreadcrumbDto {
+    fn from(category: &Category) -> Self {
+        Self {
+            id: category.id.as_uuid().to_string(),
+            name: category.name.as_str().to_string(),
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+use crate::domain::entities::inventory::Inventory;
+
+#[derive(Debug, Deserialize)]
+pub struct SetStockDto {
+    pub quantity: u32,
+}
+
+#[derive(Debug, Serialize)]
+pub struct InventoryDto {
+    pub product_id: String,
+    pub quantity: u32,
+    pub reserved_quantity: u32,
+    pub available_quantity: u32,
+    pub updated_at: String,
+}
+
+impl From<&Inventory> for InventoryDto {
+    fn from(inventory: &Inventory) -> Self {
+        Self {
+            product_id: inventory.product_id.as_uuid().to_string(),
+            quantity: inventory.quantity.value(),
+            reserved_quantity: inventory.reserved_quantity.value(),
+            available_quantity: inventory.available_quantity().value(),
+            updated_at: inventory.updated_at.to_rfc3339(),
+        }
+    }
+}
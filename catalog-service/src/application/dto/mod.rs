@@ -1,6 +1,11 @@
 pub mod product_dto;
 pub mod category_dto;
+pub mod inventory_dto;
+pub mod reservation_dto;
+pub mod search_dto;
 
 pub use product_dto::*;
 pub use category_dto::*;
-
+pub use inventory_dto::*;
+pub use reservation_dto::*;
+pub use search_dto::*;
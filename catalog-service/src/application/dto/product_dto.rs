@@ -1,4 +1,13 @@
+use std::str::FromStr;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{DateTime, SecondsFormat, Utc};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use crate::domain::entities::product::Product;
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::{ProductCursor, ProductQuery, ProductSortKey, ProductSortValue};
+use crate::domain::value_objects::{CategoryId, Price, ProductId};
 
 #[derive(Debug, Deserialize)]
 pub struct CreateProductDto {
@@ -6,6 +15,21 @@ pub struct CreateProductDto {
     pub sku: String,
     pub description: String,
     pub price: String,
+    #[serde(default)]
+    pub category_id: Option<String>,
+}
+
+/// Fields left out keep their current value. The SKU cannot be changed.
+#[derive(Debug, Deserialize)]
+pub struct UpdateProductDto {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub price: Option<String>,
+    #[serde(default)]
+    pub category_id: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -15,7 +39,145 @@ pub struct ProductDto {
     pub sku: String,
     pub description: String,
     pub price: String,
+    pub category_id: Option<String>,
     pub is_active: bool,
     pub created_at: String,
+    pub updated_at: String,
 }
 
+impl From<&Product> for ProductDto {
+    fn from(product: &Product) -> Self {
+        Self {
+            id: product.id.as_uuid().to_string(),
+            name: product.name.as_str().to_string(),
+            sku: product.sku.as_str().to_string(),
+            description: product.description.as_str().to_string(),
+            price: product.price.value().to_string(),
+            category_id: product.category_id.map(|id| id.to_string()),
+            is_active: product.is_active,
+            created_at: product.created_at.to_rfc3339(),
+            updated_at: product.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+/// Query string of `GET /products`. Prices are decimal strings and
+/// `created_after` an RFC 3339 time; all bounds are inclusive.
+#[derive(Debug, Default, Deserialize)]
+pub struct ProductListQueryDto {
+    pub category_id: Option<String>,
+    /// Also list products of the subcategories of `category_id`.
+    pub include_subcategories: Option<bool>,
+    pub min_price: Option<String>,
+    pub max_price: Option<String>,
+    pub active: Option<bool>,
+    pub created_after: Option<String>,
+    /// `name`, `price` or `created_at` (the default).
+    pub sort: Option<String>,
+    /// `asc` (the default) or `desc`.
+    pub order: Option<String>,
+    /// `next_cursor` of the previous page.
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl ProductListQueryDto {
+    /// The filters, order and cursor as a query, without a limit.
+    pub fn to_query(&self) -> Result<ProductQuery, DomainError> {
+        let category_id = match &self.category_id {
+            Some(id) => Some(CategoryId::from_uuid(uuid::Uuid::parse_str(id)
+                .map_err(|_| DomainError::ValidationError("Invalid category ID format".to_string()))?)),
+            None => None,
+        };
+        let min_price = self.min_price.as_deref().map(|price| parse_price_bound("min_price", price)).transpose()?;
+        let max_price = self.max_price.as_deref().map(|price| parse_price_bound("max_price", price)).transpose()?;
+        if let (Some(min_price), Some(max_price)) = (min_price, max_price) {
+            if min_price > max_price {
+                return Err(DomainError::ValidationError("min_price cannot be greater than max_price".to_string()));
+            }
+        }
+        let created_after = self.created_after.as_deref()
+            .map(|time| {
+                DateTime::parse_from_rfc3339(time)
+                    .map(|time| time.with_timezone(&Utc))
+                    .map_err(|_| DomainError::ValidationError("created_after must be an RFC 3339 time".to_string()))
+            })
+            .transpose()?;
+        let sort = self.sort.as_deref().map(ProductSortKey::parse).transpose()?.unwrap_or_default();
+        let descending = match self.order.as_deref() {
+            None | Some("asc") => false,
+            Some("desc") => true,
+            Some(order) => return Err(DomainError::ValidationError(format!("Unknown order: {}", order))),
+        };
+        let after = self.cursor.as_deref().map(|cursor| decode_cursor(cursor, sort, descending)).transpose()?;
+
+        Ok(ProductQuery {
+            category_id,
+            include_subcategories: self.include_subcategories.unwrap_or(false),
+            min_price,
+            max_price,
+            is_active: self.active,
+            created_after,
+            sort,
+            descending,
+            after,
+            ..Default::default()
+        })
+    }
+}
+
+pub(crate) fn parse_price_bound(name: &str, value: &str) -> Result<Price, DomainError> {
+    Decimal::from_str(value)
+        .map(Price::new)
+        .map_err(|_| DomainError::ValidationError(format!("{} must be a decimal number", name)))
+}
+
+/// What a cursor carries. The sort and order are kept so that a cursor is not
+/// reused with a different ordering, where its position means nothing.
+#[derive(Debug, Serialize, Deserialize)]
+struct CursorPayload {
+    sort: String,
+    desc: bool,
+    value: String,
+    id: uuid::Uuid,
+}
+
+pub fn encode_cursor(cursor: &ProductCursor, sort: ProductSortKey, descending: bool) -> String {
+    let value = match &cursor.value {
+        ProductSortValue::Name(name) => name.clone(),
+        ProductSortValue::Price(price) => price.to_string(),
+        ProductSortValue::CreatedAt(created_at) => created_at.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+    };
+    let payload = CursorPayload {
+        sort: sort.as_str().to_string(),
+        desc: descending,
+        value,
+        id: cursor.id.as_uuid(),
+    };
+    URL_SAFE_NO_PAD.encode(serde_json::to_vec(&payload).unwrap_or_default())
+}
+
+fn decode_cursor(cursor: &str, sort: ProductSortKey, descending: bool) -> Result<ProductCursor, DomainError> {
+    let invalid = || DomainError::ValidationError("Invalid cursor".to_string());
+    let bytes = URL_SAFE_NO_PAD.decode(cursor).map_err(|_| invalid())?;
+    let payload: CursorPayload = serde_json::from_slice(&bytes).map_err(|_| invalid())?;
+    if payload.sort != sort.as_str() || payload.desc != descending {
+        return Err(DomainError::ValidationError("Cursor was issued for a different sort order".to_string()));
+    }
+
+    let value = match sort {
+        ProductSortKey::Name => ProductSortValue::Name(payload.value),
+        ProductSortKey::Price => ProductSortValue::Price(Decimal::from_str(&payload.value).map_err(|_| invalid())?),
+        ProductSortKey::CreatedAt => ProductSortValue::CreatedAt(
+            DateTime::parse_from_rfc3339(&payload.value).map_err(|_| invalid())?.with_timezone(&Utc),
+        ),
+    };
+    Ok(ProductCursor { value, id: ProductId::from_uuid(payload.id) })
+}
+
+#[derive(Debug, Serialize)]
+pub struct ProductListDto {
+    pub products: Vec<ProductDto>,
+    /// Pass as `cursor` to fetch the next page; absent on the last page.
+    pub next_cursor: Option<String>,
+}
@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+use crate::domain::entities::reservation::Reservation;
+
+#[derive(Debug, Deserialize)]
+pub struct CreateReservationDto {
+    pub quantity: u32,
+    /// Order or cart the stock is held for.
+    pub order_reference: String,
+    /// Falls back to the configured default TTL.
+    #[serde(default)]
+    pub ttl_seconds: Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReservationDto {
+    pub id: String,
+    pub product_id: String,
+    pub quantity: u32,
+    pub order_reference: String,
+    pub status: String,
+    pub expires_at: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<&Reservation> for ReservationDto {
+    fn from(reservation: &Reservation) -> Self {
+        Self {
+            id: reservation.id.as_uuid().to_string(),
+            product_id: reservation.product_id.as_uuid().to_string(),
+            quantity: reservation.quantity.value(),
+            order_reference: reservation.order_reference.clone(),
+            status: reservation.status.as_str().to_string(),
+            expires_at: reservation.expires_at.to_rfc3339(),
+            created_at: reservation.created_at.to_rfc3339(),
+            updated_at: reservation.updated_at.to_rfc3339(),
+        }
+    }
+}
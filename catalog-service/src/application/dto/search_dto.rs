@@ -0,0 +1,96 @@
+use serde::{Deserialize, Serialize};
+use crate::application::dto::product_dto::parse_price_bound;
+use crate::application::dto::ProductDto;
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::{CategoryFacet, PriceBucketFacet, ProductSearchQuery};
+use crate::domain::value_objects::CategoryId;
+
+/// Query string of `GET /products/search`. `q` is matched against name,
+/// description and SKU; price bounds are inclusive decimal strings.
+#[derive(Debug, Default, Deserialize)]
+pub struct ProductSearchQueryDto {
+    #[serde(default)]
+    pub q: String,
+    pub category_id: Option<String>,
+    pub min_price: Option<String>,
+    pub max_price: Option<String>,
+    pub offset: Option<usize>,
+    pub limit: Option<usize>,
+}
+
+impl ProductSearchQueryDto {
+    /// The text and filters as a query, without paging.
+    pub fn to_query(&self) -> Result<ProductSearchQuery, DomainError> {
+        if !self.q.chars().any(char::is_alphanumeric) {
+            return Err(DomainError::ValidationError("q must contain a letter or digit".to_string()));
+        }
+        let category_id = match &self.category_id {
+            Some(id) => Some(CategoryId::from_uuid(uuid::Uuid::parse_str(id)
+                .map_err(|_| DomainError::ValidationError("Invalid category ID format".to_string()))?)),
+            None => None,
+        };
+
+        Ok(ProductSearchQuery {
+            text: self.q.clone(),
+            category_id,
+            min_price: self.min_price.as_deref().map(|price| parse_price_bound("min_price", price)).transpose()?,
+            max_price: self.max_price.as_deref().map(|price| parse_price_bound("max_price", price)).transpose()?,
+            offset: 0,
+            limit: 0,
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ProductSearchHitDto {
+    #[serde(flatten)]
+    pub product: ProductDto,
+    pub score: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CategoryFacetDto {
+    pub category_id: Option<String>,
+    pub count: usize,
+}
+
+impl From<&CategoryFacet> for CategoryFacetDto {
+    fn from(facet: &CategoryFacet) -> Self {
+        Self {
+            category_id: facet.category_id.map(|id| id.as_uuid().to_string()),
+            count: facet.count,
+        }
+    }
+}
+
+/// Prices from `min` up to, but not including, `max`; the last bucket has no `max`.
+#[derive(Debug, Serialize)]
+pub struct PriceBucketFacetDto {
+    pub min: String,
+    pub max: Option<String>,
+    pub count: usize,
+}
+
+impl From<&PriceBucketFacet> for PriceBucketFacetDto {
+    fn from(facet: &PriceBucketFacet) -> Self {
+        Self {
+            min: facet.min.to_string(),
+            max: facet.max.map(|max| max.to_string()),
+            count: facet.count,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct SearchFacetsDto {
+    pub categories: Vec<CategoryFacetDto>,
+    pub price_buckets: Vec<PriceBucketFacetDto>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ProductSearchResultsDto {
+    /// Matches across all pages.
+    pub total: usize,
+    pub products: Vec<ProductSearchHitDto>,
+    pub facets: SearchFacetsDto,
+}
@@ -6,9 +6,6 @@ pub enum ApplicationError {
     #[error("Domain error: {0}")]
     Domain(#[from] DomainError),
 
-    #[error("Repository error: {0}")]
-    Repository(String),
-
     #[error("Validation error: {0}")]
     Validation(String),
 }
@@ -1,8 +1,5 @@
 pub mod use_cases;
 pub mod dto;
 pub mod errors;
-
-pub use use_cases::*;
-pub use dto::*;
-pub use errors::*;
+pub mod retry;
 
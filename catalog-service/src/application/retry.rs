@@ -0,0 +1,31 @@
+use std::future::Future;
+use std::time::Duration;
+use rand::Rng;
+use crate::application::errors::ApplicationError;
+use crate::domain::errors::DomainError;
+
+const MAX_ATTEMPTS: u32 = 10;
+const BASE_BACKOFF_MS: u64 = 5;
+const MAX_BACKOFF_MS: u64 = 200;
+
+/// Runs a read-modify-write again, from a fresh read, when another request
+/// changed the same inventory in between. Attempts are spread out with a
+/// random, growing delay so that requests racing for one product do not keep
+/// colliding.
+pub async fn retry_on_conflict<T, F, Fut>(mut attempt: F) -> Result<T, ApplicationError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, ApplicationError>>,
+{
+    for retry in 0..MAX_ATTEMPTS - 1 {
+        match attempt().await {
+            Err(ApplicationError::Domain(DomainError::ConcurrentModification)) => {
+                let ceiling = (BASE_BACKOFF_MS << retry).min(MAX_BACKOFF_MS);
+                let delay = rand::thread_rng().gen_range(0..=ceiling);
+                tokio::time::sleep(Duration::from_millis(delay)).await;
+            }
+            result => return result,
+        }
+    }
+    attempt().await
+}
@@ -0,0 +1,34 @@
+use std::sync::Arc;
+use crate::application::dto::{CategoryDto, CreateCategoryDto};
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::CategoryRepository;
+use crate::domain::entities::category::Category;
+use crate::domain::value_objects::{CategoryId, CategoryName};
+use crate::domain::errors::DomainError;
+
+pub struct CreateCategoryUseCase<CR: CategoryRepository> {
+    category_repository: Arc<CR>,
+}
+
+impl<CR: CategoryRepository> CreateCategoryUseCase<CR> {
+    pub fn new(category_repository: Arc<CR>) -> Self {
+        Self { category_repository }
+    }
+
+    pub async fn execute(&self, dto: CreateCategoryDto) -> Result<CategoryDto, ApplicationError> {
+        let name = CategoryName::new(dto.name)?;
+
+        let mut category = Category::new(CategoryId::new(), name);
+        if let Some(parent_id) = dto.parent_id {
+            let parent_id = uuid::Uuid::parse_str(&parent_id)
+                .map_err(|_| ApplicationError::Validation("Invalid category ID format".to_string()))?;
+            let parent = self.category_repository.find_by_id(&CategoryId::from_uuid(parent_id)).await?
+                .ok_or(ApplicationError::Domain(DomainError::CategoryNotFound))?;
+            category.set_parent(&parent)?;
+        }
+
+        self.category_repository.create(&category).await?;
+
+        Ok(CategoryDto::from(&category))
+    }
+}
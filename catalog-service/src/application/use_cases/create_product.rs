@@ -1,23 +1,36 @@
 use std::sync::Arc;
-use std::str::FromStr;
 use crate::application::dto::{CreateProductDto, ProductDto};
 use crate::application::errors::ApplicationError;
-use crate::domain::repositories::ProductRepository;
+use crate::domain::repositories::{CategoryRepository, ProductRepository, ProductSearchIndex};
 use crate::domain::services::CatalogService;
 use crate::domain::entities::product::Product;
-use crate::domain::value_objects::{ProductId, ProductName, SKU, Description, Price};
-use rust_decimal::Decimal;
+use crate::domain::value_objects::{CategoryId, ProductId, ProductName, SKU, Description, Price};
+use crate::domain::errors::DomainError;
 
-pub struct CreateProductUseCase<R: ProductRepository> {
+pub struct CreateProductUseCase<R, CR, SI>
+where
+    R: ProductRepository,
+    CR: CategoryRepository,
+    SI: ProductSearchIndex,
+{
     product_repository: Arc<R>,
+    category_repository: Arc<CR>,
+    search_index: Arc<SI>,
     catalog_service: CatalogService<R>,
 }
 
-impl<R: ProductRepository> CreateProductUseCase<R> {
-    pub fn new(product_repository: Arc<R>) -> Self {
+impl<R, CR, SI> CreateProductUseCase<R, CR, SI>
+where
+    R: ProductRepository,
+    CR: CategoryRepository,
+    SI: ProductSearchIndex,
+{
+    pub fn new(product_repository: Arc<R>, category_repository: Arc<CR>, search_index: Arc<SI>) -> Self {
         let catalog_service = CatalogService::new(Arc::clone(&product_repository));
         Self {
             product_repository,
+            category_repository,
+            search_index,
             catalog_service,
         }
     }
@@ -26,26 +39,22 @@ impl<R: ProductRepository> CreateProductUseCase<R> {
         let name = ProductName::new(dto.name)?;
         let sku = SKU::new(dto.sku)?;
         let description = Description::new(dto.description);
-        
-        let price_decimal = Decimal::from_str(&dto.price)
-            .map_err(|_| ApplicationError::Validation("Invalid price format".to_string()))?;
-        let price = Price::new(price_decimal);
+        let price = Price::parse(&dto.price)?;
 
         self.catalog_service.validate_product_creation(&sku).await?;
 
-        let product = Product::new(ProductId::new(), name, sku, description, price);
-        self.product_repository.create(&product).await
-            .map_err(|e| ApplicationError::Repository(e.to_string()))?;
+        let mut product = Product::new(ProductId::new(), name, sku, description, price);
+        if let Some(category_id) = dto.category_id {
+            let category_id = uuid::Uuid::parse_str(&category_id)
+                .map_err(|_| ApplicationError::Validation("Invalid category ID format".to_string()))?;
+            let category = self.category_repository.find_by_id(&CategoryId::from_uuid(category_id)).await?
+                .ok_or(ApplicationError::Domain(DomainError::CategoryNotFound))?;
+            product.assign_to_category(category.id.as_uuid());
+        }
+
+        self.product_repository.create(&product).await?;
+        self.search_index.index(&product).await?;
 
-        Ok(ProductDto {
-            id: product.id.as_uuid().to_string(),
-            name: product.name.as_str().to_string(),
-            sku: product.sku.as_str().to_string(),
-            description: product.description.as_str().to_string(),
-            price: product.price.value().to_string(),
-            is_active: product.is_active,
-            created_at: product.created_at.to_rfc3339(),
-        })
+        Ok(ProductDto::from(&product))
     }
 }
-
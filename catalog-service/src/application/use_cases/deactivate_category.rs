@@ -0,0 +1,30 @@
+use std::sync::Arc;
+use crate::application::dto::CategoryDto;
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::CategoryRepository;
+use crate::domain::value_objects::CategoryId;
+use crate::domain::errors::DomainError;
+
+pub struct DeactivateCategoryUseCase<CR: CategoryRepository> {
+    category_repository: Arc<CR>,
+}
+
+impl<CR: CategoryRepository> DeactivateCategoryUseCase<CR> {
+    pub fn new(category_repository: Arc<CR>) -> Self {
+        Self { category_repository }
+    }
+
+    pub async fn execute(&self, category_id: &str) -> Result<CategoryDto, ApplicationError> {
+        let category_id = uuid::Uuid::parse_str(category_id)
+            .map_err(|_| ApplicationError::Validation("Invalid category ID format".to_string()))?;
+        let mut category = self.category_repository.find_by_id(&CategoryId::from_uuid(category_id)).await?
+            .ok_or(ApplicationError::Domain(DomainError::CategoryNotFound))?;
+
+        if category.is_active {
+            category.deactivate();
+            self.category_repository.update(&category).await?;
+        }
+
+        Ok(CategoryDto::from(&category))
+    }
+}
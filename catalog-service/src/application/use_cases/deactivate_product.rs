@@ -0,0 +1,35 @@
+use std::sync::Arc;
+use crate::application::dto::ProductDto;
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::{ProductRepository, ProductSearchIndex};
+use crate::domain::value_objects::ProductId;
+use crate::domain::errors::DomainError;
+
+pub struct DeactivateProductUseCase<R: ProductRepository, SI: ProductSearchIndex> {
+    product_repository: Arc<R>,
+    search_index: Arc<SI>,
+}
+
+impl<R: ProductRepository, SI: ProductSearchIndex> DeactivateProductUseCase<R, SI> {
+    pub fn new(product_repository: Arc<R>, search_index: Arc<SI>) -> Self {
+        Self {
+            product_repository,
+            search_index,
+        }
+    }
+
+    pub async fn execute(&self, product_id: &str) -> Result<ProductDto, ApplicationError> {
+        let product_id = uuid::Uuid::parse_str(product_id)
+            .map_err(|_| ApplicationError::Validation("Invalid product ID format".to_string()))?;
+        let mut product = self.product_repository.find_by_id(&ProductId::from_uuid(product_id)).await?
+            .ok_or(ApplicationError::Domain(DomainError::ProductNotFound))?;
+
+        if product.is_active {
+            product.deactivate();
+            self.product_repository.update(&product).await?;
+            self.search_index.remove(&product.id).await?;
+        }
+
+        Ok(ProductDto::from(&product))
+    }
+}
@@ -0,0 +1,32 @@
+use std::sync::Arc;
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::{ProductRepository, ProductSearchIndex};
+use crate::domain::value_objects::ProductId;
+use crate::domain::errors::DomainError;
+
+pub struct DeleteProductUseCase<R: ProductRepository, SI: ProductSearchIndex> {
+    product_repository: Arc<R>,
+    search_index: Arc<SI>,
+}
+
+impl<R: ProductRepository, SI: ProductSearchIndex> DeleteProductUseCase<R, SI> {
+    pub fn new(product_repository: Arc<R>, search_index: Arc<SI>) -> Self {
+        Self {
+            product_repository,
+            search_index,
+        }
+    }
+
+    /// Also removes the product's inventory.
+    pub async fn execute(&self, product_id: &str) -> Result<(), ApplicationError> {
+        let product_id = uuid::Uuid::parse_str(product_id)
+            .map_err(|_| ApplicationError::Validation("Invalid product ID format".to_string()))?;
+        let product = self.product_repository.find_by_id(&ProductId::from_uuid(product_id)).await?
+            .ok_or(ApplicationError::Domain(DomainError::ProductNotFound))?;
+
+        self.product_repository.delete(&product.id).await?;
+        self.search_index.remove(&product.id).await?;
+
+        Ok(())
+    }
+}
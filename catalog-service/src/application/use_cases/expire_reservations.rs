@@ -0,0 +1,62 @@
+use std::sync::Arc;
+use chrono::Utc;
+use crate::application::errors::ApplicationError;
+use crate::application::retry::retry_on_conflict;
+use crate::domain::repositories::{InventoryRepository, ReservationRepository};
+use crate::domain::errors::DomainError;
+
+const BATCH_SIZE: i64 = 100;
+
+pub struct ExpireReservationsUseCase<IR: InventoryRepository, RR: ReservationRepository> {
+    inventory_repository: Arc<IR>,
+    reservation_repository: Arc<RR>,
+}
+
+impl<IR: InventoryRepository, RR: ReservationRepository> ExpireReservationsUseCase<IR, RR> {
+    pub fn new(inventory_repository: Arc<IR>, reservation_repository: Arc<RR>) -> Self {
+        Self {
+            inventory_repository,
+            reservation_repository,
+        }
+    }
+
+    /// Gives back the stock of every active reservation past its TTL and
+    /// returns how many were expired. Reservations committed or released in
+    /// the meantime are left alone.
+    pub async fn execute(&self) -> Result<usize, ApplicationError> {
+        let now = Utc::now();
+        let mut expired = 0;
+
+        loop {
+            let batch = self.reservation_repository.find_expired(now, BATCH_SIZE).await?;
+            let mut changed = 0;
+            for candidate in &batch {
+                let was_expired = retry_on_conflict(|| async {
+                    let Some(mut reservation) = self.reservation_repository.find_by_id(&candidate.id).await? else {
+                        return Ok(false);
+                    };
+                    if !reservation.expire(now) {
+                        return Ok(false);
+                    }
+
+                    let mut inventory = self.inventory_repository.find_by_product_id(&reservation.product_id).await?
+                        .ok_or(ApplicationError::Domain(DomainError::ProductNotFound))?;
+                    inventory.release(reservation.quantity.value());
+                    self.reservation_repository.update(&reservation, &inventory).await?;
+                    Ok(true)
+                })
+                .await?;
+                if was_expired {
+                    changed += 1;
+                }
+            }
+            expired += changed;
+
+            // A full batch may have more behind it, unless nothing in it could
+            // be expired, in which case the same rows would come back again
+            if (batch.len() as i64) < BATCH_SIZE || changed == 0 {
+                return Ok(expired);
+            }
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use std::sync::Arc;
+use crate::application::dto::CategoryDto;
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::CategoryRepository;
+use crate::domain::services::CategoryTreeService;
+use crate::domain::value_objects::CategoryId;
+use crate::domain::errors::DomainError;
+
+pub struct GetCategoryAncestorsUseCase<CR: CategoryRepository> {
+    category_repository: Arc<CR>,
+    category_tree_service: CategoryTreeService<CR>,
+}
+
+impl<CR: CategoryRepository> GetCategoryAncestorsUseCase<CR> {
+    pub fn new(category_repository: Arc<CR>) -> Self {
+        let category_tree_service = CategoryTreeService::new(Arc::clone(&category_repository));
+        Self {
+            category_repository,
+            category_tree_service,
+        }
+    }
+
+    /// The ancestors of the category, root first.
+    pub async fn execute(&self, category_id: &str) -> Result<Vec<CategoryDto>, ApplicationError> {
+        let category_id = uuid::Uuid::parse_str(category_id)
+            .map_err(|_| ApplicationError::Validation("Invalid category ID format".to_string()))?;
+        let category = self.category_repository.find_by_id(&CategoryId::from_uuid(category_id)).await?
+            .ok_or(ApplicationError::Domain(DomainError::CategoryNotFound))?;
+
+        let ancestors = self.category_tree_service.ancestors(&category).await?;
+
+        Ok(ancestors.iter().map(CategoryDto::from).collect())
+    }
+}
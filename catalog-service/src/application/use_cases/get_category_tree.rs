@@ -0,0 +1,23 @@
+use std::sync::Arc;
+use crate::application::dto::CategoryTreeDto;
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::CategoryRepository;
+use crate::domain::services::CategoryTreeService;
+
+pub struct GetCategoryTreeUseCase<CR: CategoryRepository> {
+    category_tree_service: CategoryTreeService<CR>,
+}
+
+impl<CR: CategoryRepository> GetCategoryTreeUseCase<CR> {
+    pub fn new(category_repository: Arc<CR>) -> Self {
+        Self {
+            category_tree_service: CategoryTreeService::new(category_repository),
+        }
+    }
+
+    pub async fn execute(&self) -> Result<Vec<CategoryTreeDto>, ApplicationError> {
+        let tree = self.category_tree_service.tree().await?;
+
+        Ok(tree.iter().map(CategoryTreeDto::from).collect())
+    }
+}
@@ -0,0 +1,34 @@
+use std::sync::Arc;
+use crate::application::dto::InventoryDto;
+use crate::application::errors::ApplicationError;
+use crate::domain::entities::inventory::Inventory;
+use crate::domain::repositories::{InventoryRepository, ProductRepository};
+use crate::domain::value_objects::{ProductId, Quantity};
+use crate::domain::errors::DomainError;
+
+pub struct GetInventoryUseCase<R: ProductRepository, IR: InventoryRepository> {
+    product_repository: Arc<R>,
+    inventory_repository: Arc<IR>,
+}
+
+impl<R: ProductRepository, IR: InventoryRepository> GetInventoryUseCase<R, IR> {
+    pub fn new(product_repository: Arc<R>, inventory_repository: Arc<IR>) -> Self {
+        Self {
+            product_repository,
+            inventory_repository,
+        }
+    }
+
+    /// Products that were never stocked report a quantity of zero.
+    pub async fn execute(&self, product_id: &str) -> Result<InventoryDto, ApplicationError> {
+        let product_id = uuid::Uuid::parse_str(product_id)
+            .map_err(|_| ApplicationError::Validation("Invalid product ID format".to_string()))?;
+        let product = self.product_repository.find_by_id(&ProductId::from_uuid(product_id)).await?
+            .ok_or(ApplicationError::Domain(DomainError::ProductNotFound))?;
+
+        let inventory = self.inventory_repository.find_by_product_id(&product.id).await?
+            .unwrap_or_else(|| Inventory::new(product.id, Quantity::zero()));
+
+        Ok(InventoryDto::from(&inventory))
+    }
+}
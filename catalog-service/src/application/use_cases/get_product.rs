@@ -21,15 +21,6 @@ impl<R: ProductRepository> GetProductUseCase<R> {
         let product = self.product_repository.find_by_id(&product_id).await?
             .ok_or(ApplicationError::Domain(crate::domain::errors::DomainError::ProductNotFound))?;
 
-        Ok(ProductDto {
-            id: product.id.as_uuid().to_string(),
-            name: product.name.as_str().to_string(),
-            sku: product.sku.as_str().to_string(),
-            description: product.description.as_str().to_string(),
-            price: product.price.value().to_string(),
-            is_active: product.is_active,
-            created_at: product.created_at.to_rfc3339(),
-        })
+        Ok(ProductDto::from(&product))
     }
 }
-
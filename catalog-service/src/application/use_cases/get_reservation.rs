@@ -0,0 +1,25 @@
+use std::sync::Arc;
+use crate::application::dto::ReservationDto;
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::ReservationRepository;
+use crate::domain::value_objects::ReservationId;
+use crate::domain::errors::DomainError;
+
+pub struct GetReservationUseCase<RR: ReservationRepository> {
+    reservation_repository: Arc<RR>,
+}
+
+impl<RR: ReservationRepository> GetReservationUseCase<RR> {
+    pub fn new(reservation_repository: Arc<RR>) -> Self {
+        Self { reservation_repository }
+    }
+
+    pub async fn execute(&self, reservation_id: &str) -> Result<ReservationDto, ApplicationError> {
+        let reservation_id = uuid::Uuid::parse_str(reservation_id)
+            .map_err(|_| ApplicationError::Validation("Invalid reservation ID format".to_string()))?;
+        let reservation = self.reservation_repository.find_by_id(&ReservationId::from_uuid(reservation_id)).await?
+            .ok_or(ApplicationError::Domain(DomainError::ReservationNotFound))?;
+
+        Ok(ReservationDto::from(&reservation))
+    }
+}
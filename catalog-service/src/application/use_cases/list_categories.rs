@@ -0,0 +1,19 @@
+use std::sync::Arc;
+use crate::application::dto::CategoryDto;
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::CategoryRepository;
+
+pub struct ListCategoriesUseCase<CR: CategoryRepository> {
+    category_repository: Arc<CR>,
+}
+
+impl<CR: CategoryRepository> ListCategoriesUseCase<CR> {
+    pub fn new(category_repository: Arc<CR>) -> Self {
+        Self { category_repository }
+    }
+
+    pub async fn execute(&self) -> Result<Vec<CategoryDto>, ApplicationError> {
+        let categories = self.category_repository.find_all().await?;
+        Ok(categories.iter().map(CategoryDto::from).collect())
+    }
+}
@@ -0,0 +1,35 @@
+use std::sync::Arc;
+use crate::application::dto::{ProductListDto, ProductListQueryDto};
+use crate::application::errors::ApplicationError;
+use crate::application::use_cases::ListProductsUseCase;
+use crate::domain::repositories::{CategoryRepository, ProductRepository};
+use crate::domain::value_objects::CategoryId;
+use crate::domain::errors::DomainError;
+
+pub struct ListCategoryProductsUseCase<R: ProductRepository, CR: CategoryRepository> {
+    category_repository: Arc<CR>,
+    list_products: ListProductsUseCase<R>,
+}
+
+impl<R: ProductRepository, CR: CategoryRepository> ListCategoryProductsUseCase<R, CR> {
+    pub fn new(product_repository: Arc<R>, category_repository: Arc<CR>) -> Self {
+        Self {
+            category_repository,
+            list_products: ListProductsUseCase::new(product_repository),
+        }
+    }
+
+    /// Products of the category and all of its subcategories, with the same
+    /// filters, ordering and paging as the product listing.
+    pub async fn execute(&self, category_id: &str, mut dto: ProductListQueryDto) -> Result<ProductListDto, ApplicationError> {
+        let category_id = uuid::Uuid::parse_str(category_id)
+            .map_err(|_| ApplicationError::Validation("Invalid category ID format".to_string()))?;
+        let category = self.category_repository.find_by_id(&CategoryId::from_uuid(category_id)).await?
+            .ok_or(ApplicationError::Domain(DomainError::CategoryNotFound))?;
+
+        dto.category_id = Some(category.id.as_uuid().to_string());
+        dto.include_subcategories = Some(true);
+
+        self.list_products.execute(dto).await
+    }
+}
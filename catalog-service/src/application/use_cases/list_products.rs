@@ -0,0 +1,42 @@
+use std::sync::Arc;
+use crate::application::dto::{encode_cursor, ProductDto, ProductListDto, ProductListQueryDto};
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::{ProductCursor, ProductRepository};
+
+const DEFAULT_PAGE_SIZE: i64 = 20;
+const MAX_PAGE_SIZE: i64 = 100;
+
+pub struct ListProductsUseCase<R: ProductRepository> {
+    product_repository: Arc<R>,
+}
+
+impl<R: ProductRepository> ListProductsUseCase<R> {
+    pub fn new(product_repository: Arc<R>) -> Self {
+        Self { product_repository }
+    }
+
+    pub async fn execute(&self, dto: ProductListQueryDto) -> Result<ProductListDto, ApplicationError> {
+        let limit = dto.limit.unwrap_or(DEFAULT_PAGE_SIZE);
+        if !(1..=MAX_PAGE_SIZE).contains(&limit) {
+            return Err(ApplicationError::Validation(format!("limit must be between 1 and {}", MAX_PAGE_SIZE)));
+        }
+
+        let mut query = dto.to_query()?;
+        // One extra row tells whether another page follows
+        query.limit = limit + 1;
+
+        let mut products = self.product_repository.find_page(&query).await?;
+        let next_cursor = if products.len() as i64 > limit {
+            products.truncate(limit as usize);
+            products.last()
+                .map(|product| encode_cursor(&ProductCursor::after(product, query.sort), query.sort, query.descending))
+        } else {
+            None
+        };
+
+        Ok(ProductListDto {
+            products: products.iter().map(ProductDto::from).collect(),
+            next_cursor,
+        })
+    }
+}
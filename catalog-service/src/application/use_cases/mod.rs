@@ -1,6 +1,51 @@
 pub mod create_product;
 pub mod get_product;
+pub mod list_products;
+pub mod update_product;
+pub mod deactivate_product;
+pub mod delete_product;
+pub mod search_products;
+pub mod create_category;
+pub mod get_category;
+pub mod list_categories;
+pub mod update_category;
+pub mod deactivate_category;
+pub mod delete_category;
+pub mod get_category_tree;
+pub mod get_category_ancestors;
+pub mod get_category_breadcrumbs;
+pub mod move_category;
+pub mod list_category_products;
+pub mod get_inventory;
+pub mod set_stock;
+pub mod reserve_stock;
+pub mod get_reservation;
+pub mod commit_reservation;
+pub mod release_reservation;
+pub mod expire_reservations;
 
 pub use create_product::CreateProductUseCase;
 pub use get_product::GetProductUseCase;
-
+pub use list_products::ListProductsUseCase;
+pub use update_product::UpdateProductUseCase;
+pub use deactivate_product::DeactivateProductUseCase;
+pub use delete_product::DeleteProductUseCase;
+pub use search_products::SearchProductsUseCase;
+pub use create_category::CreateCategoryUseCase;
+pub use get_category::GetCategoryUseCase;
+pub use list_categories::ListCategoriesUseCase;
+pub use update_category::UpdateCategoryUseCase;
+pub use deactivate_category::DeactivateCategoryUseCase;
+pub use delete_category::DeleteCategoryUseCase;
+pub use get_category_tree::GetCategoryTreeUseCase;
+pub use get_category_ancestors::GetCategoryAncestorsUseCase;
+pub use get_category_breadcrumbs::GetCategoryBreadcrumbsUseCase;
+pub use move_category::MoveCategoryUseCase;
+pub use list_category_products::ListCategoryProductsUseCase;
+pub use get_inventory::GetInventoryUseCase;
+pub use set_stock::SetStockUseCase;
+pub use reserve_stock::ReserveStockUseCase;
+pub use get_reservation::GetReservationUseCase;
+pub use commit_reservation::CommitReservationUseCase;
+pub use release_reservation::ReleaseReservationUseCase;
+pub use expire_reservations::ExpireReservationsUseCase;
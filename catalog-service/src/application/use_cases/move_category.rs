@@ -0,0 +1,35 @@
+use std::sync::Arc;
+use crate::application::dto::{CategoryDto, MoveCategoryDto};
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::CategoryRepository;
+use crate::domain::value_objects::CategoryId;
+
+pub struct MoveCategoryUseCase<CR: CategoryRepository> {
+    category_repository: Arc<CR>,
+}
+
+impl<CR: CategoryRepository> MoveCategoryUseCase<CR> {
+    pub fn new(category_repository: Arc<CR>) -> Self {
+        Self { category_repository }
+    }
+
+    /// Moves the category together with its subcategories. Moving it under
+    /// itself or one of its subcategories is rejected.
+    pub async fn execute(&self, category_id: &str, dto: MoveCategoryDto) -> Result<CategoryDto, ApplicationError> {
+        let category_id = uuid::Uuid::parse_str(category_id)
+            .map_err(|_| ApplicationError::Validation("Invalid category ID format".to_string()))?;
+        let parent_id = dto.parent_id
+            .map(|parent_id| {
+                uuid::Uuid::parse_str(&parent_id)
+                    .map(CategoryId::from_uuid)
+                    .map_err(|_| ApplicationError::Validation("Invalid category ID format".to_string()))
+            })
+            .transpose()?;
+
+        let category = self.category_repository
+            .move_subtree(&CategoryId::from_uuid(category_id), parent_id.as_ref())
+            .await?;
+
+        Ok(CategoryDto::from(&category))
+    }
+}
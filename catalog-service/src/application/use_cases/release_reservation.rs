@@ -0,0 +1,49 @@
+use std::sync::Arc;
+use chrono::Utc;
+use crate::application::dto::ReservationDto;
+use crate::application::errors::ApplicationError;
+use crate::application::retry::retry_on_conflict;
+use crate::domain::repositories::{InventoryRepository, ReservationRepository};
+use crate::domain::value_objects::ReservationId;
+use crate::domain::errors::DomainError;
+
+pub struct ReleaseReservationUseCase<IR: InventoryRepository, RR: ReservationRepository> {
+    inventory_repository: Arc<IR>,
+    reservation_repository: Arc<RR>,
+}
+
+impl<IR: InventoryRepository, RR: ReservationRepository> ReleaseReservationUseCase<IR, RR> {
+    pub fn new(inventory_repository: Arc<IR>, reservation_repository: Arc<RR>) -> Self {
+        Self {
+            inventory_repository,
+            reservation_repository,
+        }
+    }
+
+    /// Gives the reserved stock back. Releasing a released or expired
+    /// reservation again returns it unchanged; a committed one cannot be
+    /// released.
+    pub async fn execute(&self, reservation_id: &str) -> Result<ReservationDto, ApplicationError> {
+        let reservation_id = ReservationId::from_uuid(
+            uuid::Uuid::parse_str(reservation_id)
+                .map_err(|_| ApplicationError::Validation("Invalid reservation ID format".to_string()))?,
+        );
+
+        let reservation = retry_on_conflict(|| async {
+            let mut reservation = self.reservation_repository.find_by_id(&reservation_id).await?
+                .ok_or(ApplicationError::Domain(DomainError::ReservationNotFound))?;
+            if !reservation.release(Utc::now())? {
+                return Ok(reservation);
+            }
+
+            let mut inventory = self.inventory_repository.find_by_product_id(&reservation.product_id).await?
+                .ok_or(ApplicationError::Domain(DomainError::ProductNotFound))?;
+            inventory.release(reservation.quantity.value());
+            self.reservation_repository.update(&reservation, &inventory).await?;
+            Ok(reservation)
+        })
+        .await?;
+
+        Ok(ReservationDto::from(&reservation))
+    }
+}
@@ -0,0 +1,94 @@
+use std::sync::Arc;
+use chrono::Duration;
+use crate::application::dto::{CreateReservationDto, ReservationDto};
+use crate::application::errors::ApplicationError;
+use crate::application::retry::retry_on_conflict;
+use crate::domain::entities::reservation::Reservation;
+use crate::domain::repositories::{InventoryRepository, ProductRepository, ReservationRepository};
+use crate::domain::value_objects::{ProductId, Quantity};
+use crate::domain::errors::DomainError;
+
+const MAX_ORDER_REFERENCE_LENGTH: usize = 128;
+
+pub struct ReserveStockUseCase<R, IR, RR>
+where
+    R: ProductRepository,
+    IR: InventoryRepository,
+    RR: ReservationRepository,
+{
+    product_repository: Arc<R>,
+    inventory_repository: Arc<IR>,
+    reservation_repository: Arc<RR>,
+    default_ttl_seconds: u64,
+    max_ttl_seconds: u64,
+}
+
+impl<R, IR, RR> ReserveStockUseCase<R, IR, RR>
+where
+    R: ProductRepository,
+    IR: InventoryRepository,
+    RR: ReservationRepository,
+{
+    pub fn new(
+        product_repository: Arc<R>,
+        inventory_repository: Arc<IR>,
+        reservation_repository: Arc<RR>,
+        default_ttl_seconds: u64,
+        max_ttl_seconds: u64,
+    ) -> Self {
+        Self {
+            product_repository,
+            inventory_repository,
+            reservation_repository,
+            default_ttl_seconds,
+            max_ttl_seconds,
+        }
+    }
+
+    pub async fn execute(&self, product_id: &str, dto: CreateReservationDto) -> Result<ReservationDto, ApplicationError> {
+        let product_id = uuid::Uuid::parse_str(product_id)
+            .map_err(|_| ApplicationError::Validation("Invalid product ID format".to_string()))?;
+        if dto.quantity == 0 || dto.quantity > i32::MAX as u32 {
+            return Err(ApplicationError::Validation("Quantity must be between 1 and 2147483647".to_string()));
+        }
+        let order_reference = dto.order_reference.trim().to_string();
+        if order_reference.is_empty() || order_reference.chars().count() > MAX_ORDER_REFERENCE_LENGTH {
+            return Err(ApplicationError::Validation(format!(
+                "order_reference must be between 1 and {} characters",
+                MAX_ORDER_REFERENCE_LENGTH,
+            )));
+        }
+        let ttl_seconds = dto.ttl_seconds.unwrap_or(self.default_ttl_seconds);
+        if ttl_seconds == 0 || ttl_seconds > self.max_ttl_seconds {
+            return Err(ApplicationError::Validation(format!(
+                "ttl_seconds must be between 1 and {}",
+                self.max_ttl_seconds,
+            )));
+        }
+
+        let product = self.product_repository.find_by_id(&ProductId::from_uuid(product_id)).await?
+            .ok_or(ApplicationError::Domain(DomainError::ProductNotFound))?;
+        if !product.is_active {
+            return Err(ApplicationError::Validation("Product is not for sale".to_string()));
+        }
+
+        let reservation = retry_on_conflict(|| async {
+            // Products that were never stocked have nothing to reserve
+            let mut inventory = self.inventory_repository.find_by_product_id(&product.id).await?
+                .ok_or(ApplicationError::Domain(DomainError::InsufficientStock))?;
+            inventory.reserve(dto.quantity)?;
+
+            let reservation = Reservation::new(
+                product.id,
+                Quantity::new(dto.quantity),
+                order_reference.clone(),
+                Duration::seconds(ttl_seconds as i64),
+            );
+            self.reservation_repository.create(&reservation, &inventory).await?;
+            Ok(reservation)
+        })
+        .await?;
+
+        Ok(ReservationDto::from(&reservation))
+    }
+}
@@ -0,0 +1,65 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use crate::application::dto::{
+    CategoryFacetDto, PriceBucketFacetDto, ProductDto, ProductSearchHitDto, ProductSearchQueryDto, ProductSearchResultsDto,
+    SearchFacetsDto,
+};
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::{ProductRepository, ProductSearchIndex};
+use crate::domain::value_objects::ProductId;
+
+const DEFAULT_PAGE_SIZE: usize = 20;
+const MAX_PAGE_SIZE: usize = 100;
+const MAX_OFFSET: usize = 10_000;
+
+pub struct SearchProductsUseCase<R: ProductRepository, SI: ProductSearchIndex> {
+    product_repository: Arc<R>,
+    search_index: Arc<SI>,
+}
+
+impl<R: ProductRepository, SI: ProductSearchIndex> SearchProductsUseCase<R, SI> {
+    pub fn new(product_repository: Arc<R>, search_index: Arc<SI>) -> Self {
+        Self {
+            product_repository,
+            search_index,
+        }
+    }
+
+    pub async fn execute(&self, dto: ProductSearchQueryDto) -> Result<ProductSearchResultsDto, ApplicationError> {
+        let limit = dto.limit.unwrap_or(DEFAULT_PAGE_SIZE);
+        if !(1..=MAX_PAGE_SIZE).contains(&limit) {
+            return Err(ApplicationError::Validation(format!("limit must be between 1 and {}", MAX_PAGE_SIZE)));
+        }
+        let offset = dto.offset.unwrap_or(0);
+        if offset > MAX_OFFSET {
+            return Err(ApplicationError::Validation(format!("offset cannot exceed {}", MAX_OFFSET)));
+        }
+
+        let mut query = dto.to_query()?;
+        query.offset = offset;
+        query.limit = limit;
+        let results = self.search_index.search(&query).await?;
+
+        let ids: Vec<ProductId> = results.hits.iter().map(|hit| hit.product_id).collect();
+        let mut products: HashMap<ProductId, _> = self.product_repository.find_by_ids(&ids).await?
+            .into_iter()
+            .map(|product| (product.id, product))
+            .collect();
+        // A product deleted since it was scored is left out rather than failing the search
+        let hits = results.hits.iter()
+            .filter_map(|hit| products.remove(&hit.product_id).map(|product| ProductSearchHitDto {
+                product: ProductDto::from(&product),
+                score: hit.score,
+            }))
+            .collect();
+
+        Ok(ProductSearchResultsDto {
+            total: results.total,
+            products: hits,
+            facets: SearchFacetsDto {
+                categories: results.categories.iter().map(CategoryFacetDto::from).collect(),
+                price_buckets: results.price_buckets.iter().map(PriceBucketFacetDto::from).collect(),
+            },
+        })
+    }
+}
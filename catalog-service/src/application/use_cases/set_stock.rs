@@ -0,0 +1,50 @@
+use std::sync::Arc;
+use crate::application::dto::{InventoryDto, SetStockDto};
+use crate::application::errors::ApplicationError;
+use crate::application::retry::retry_on_conflict;
+use crate::domain::entities::inventory::Inventory;
+use crate::domain::repositories::{InventoryRepository, ProductRepository};
+use crate::domain::value_objects::{ProductId, Quantity};
+use crate::domain::errors::DomainError;
+
+pub struct SetStockUseCase<R: ProductRepository, IR: InventoryRepository> {
+    product_repository: Arc<R>,
+    inventory_repository: Arc<IR>,
+}
+
+impl<R: ProductRepository, IR: InventoryRepository> SetStockUseCase<R, IR> {
+    pub fn new(product_repository: Arc<R>, inventory_repository: Arc<IR>) -> Self {
+        Self {
+            product_repository,
+            inventory_repository,
+        }
+    }
+
+    pub async fn execute(&self, product_id: &str, dto: SetStockDto) -> Result<InventoryDto, ApplicationError> {
+        let product_id = uuid::Uuid::parse_str(product_id)
+            .map_err(|_| ApplicationError::Validation("Invalid product ID format".to_string()))?;
+        let product = self.product_repository.find_by_id(&ProductId::from_uuid(product_id)).await?
+            .ok_or(ApplicationError::Domain(DomainError::ProductNotFound))?;
+        if dto.quantity > i32::MAX as u32 {
+            return Err(ApplicationError::Validation("Quantity is too large".to_string()));
+        }
+
+        let inventory = retry_on_conflict(|| async {
+            match self.inventory_repository.find_by_product_id(&product.id).await? {
+                Some(mut inventory) => {
+                    inventory.set_quantity(Quantity::new(dto.quantity))?;
+                    self.inventory_repository.update(&inventory).await?;
+                    Ok(inventory)
+                }
+                None => {
+                    let inventory = Inventory::new(product.id, Quantity::new(dto.quantity));
+                    self.inventory_repository.create(&inventory).await?;
+                    Ok(inventory)
+                }
+            }
+        })
+        .await?;
+
+        Ok(InventoryDto::from(&inventory))
+    }
+}
@@ -0,0 +1,61 @@
+use std::sync::Arc;
+use crate::application::dto::{ProductDto, UpdateProductDto};
+use crate::application::errors::ApplicationError;
+use crate::domain::repositories::{CategoryRepository, ProductRepository, ProductSearchIndex};
+use crate::domain::value_objects::{CategoryId, Description, Price, ProductId, ProductName};
+use crate::domain::errors::DomainError;
+
+pub struct UpdateProductUseCase<R, CR, SI>
+where
+    R: ProductRepository,
+    CR: CategoryRepository,
+    SI: ProductSearchIndex,
+{
+    product_repository: Arc<R>,
+    category_repository: Arc<CR>,
+    search_index: Arc<SI>,
+}
+
+impl<R, CR, SI> UpdateProductUseCase<R, CR, SI>
+where
+    R: ProductRepository,
+    CR: CategoryRepository,
+    SI: ProductSearchIndex,
+{
+    pub fn new(product_repository: Arc<R>, category_repository: Arc<CR>, search_index: Arc<SI>) -> Self {
+        Self {
+            product_repository,
+            category_repository,
+            search_index,
+        }
+    }
+
+    pub async fn execute(&self, product_id: &str, dto: UpdateProductDto) -> Result<ProductDto, ApplicationError> {
+        let product_id = uuid::Uuid::parse_str(product_id)
+            .map_err(|_| ApplicationError::Validation("Invalid product ID format".to_string()))?;
+        let mut product = self.product_repository.find_by_id(&ProductId::from_uuid(product_id)).await?
+            .ok_or(ApplicationError::Domain(DomainError::ProductNotFound))?;
+
+        if let Some(name) = dto.name {
+            product.rename(ProductName::new(name)?);
+        }
+        if let Some(description) = dto.description {
+            product.update_description(Description::new(description));
+        }
+        if let Some(price) = dto.price {
+            product.update_price(Price::parse(&price)?);
+        }
+        if let Some(category_id) = dto.category_id {
+            let category_id = uuid::Uuid::parse_str(&category_id)
+                .map_err(|_| ApplicationError::Validation("Invalid category ID format".to_string()))?;
+            let category = self.category_repository.find_by_id(&CategoryId::from_uuid(category_id)).await?
+                .ok_or(ApplicationError::Domain(DomainError::CategoryNotFound))?;
+            product.assign_to_category(category.id.as_uuid());
+        }
+
+        self.product_repository.update(&product).await?;
+        self.search_index.index(&product).await?;
+
+        Ok(ProductDto::from(&product))
+    }
+}
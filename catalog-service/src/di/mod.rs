@@ -0,0 +1,131 @@
+use std::sync::Arc;
+use crate::application::use_cases::{
+    CreateProductUseCase, GetProductUseCase, ListProductsUseCase, UpdateProductUseCase, DeactivateProductUseCase,
+    DeleteProductUseCase, SearchProductsUseCase, CreateCategoryUseCase, GetCategoryUseCase, ListCategoriesUseCase, UpdateCategoryUseCase,
+    DeactivateCategoryUseCase, DeleteCategoryUseCase, GetCategoryTreeUseCase, GetCategoryAncestorsUseCase,
+    GetCategoryBreadcrumbsUseCase, MoveCategoryUseCase, ListCategoryProductsUseCase, GetInventoryUseCase, SetStockUseCase,
+    ReserveStockUseCase, GetReservationUseCase, CommitReservationUseCase, ReleaseReservationUseCase,
+    ExpireReservationsUseCase,
+};
+use crate::infrastructure::config::Config;
+use crate::infrastructure::persistence::{create_pool, run_migrations};
+use crate::infrastructure::repositories::{
+    InMemoryProductSearchIndex, PostgresCategoryRepository, PostgresInventoryRepository, PostgresProductRepository,
+    PostgresReservationRepository,
+};
+
+type ProductRepo = PostgresProductRepository;
+type CategoryRepo = PostgresCategoryRepository;
+type InventoryRepo = PostgresInventoryRepository;
+type ReservationRepo = PostgresReservationRepository;
+type SearchIndex = InMemoryProductSearchIndex;
+
+#[derive(Clone)]
+pub struct AppContext {
+    pub create_product_use_case: Arc<CreateProductUseCase<ProductRepo, CategoryRepo, SearchIndex>>,
+    pub get_product_use_case: Arc<GetProductUseCase<ProductRepo>>,
+    pub list_products_use_case: Arc<ListProductsUseCase<ProductRepo>>,
+    pub update_product_use_case: Arc<UpdateProductUseCase<ProductRepo, CategoryRepo, SearchIndex>>,
+    pub deactivate_product_use_case: Arc<DeactivateProductUseCase<ProductRepo, SearchIndex>>,
+    pub delete_product_use_case: Arc<DeleteProductUseCase<ProductRepo, SearchIndex>>,
+    pub search_products_use_case: Arc<SearchProductsUseCase<ProductRepo, SearchIndex>>,
+    pub create_category_use_case: Arc<CreateCategoryUseCase<CategoryRepo>>,
+    pub get_category_use_case: Arc<GetCategoryUseCase<CategoryRepo>>,
+    pub list_categories_use_case: Arc<ListCategoriesUseCase<CategoryRepo>>,
+    pub update_category_use_case: Arc<UpdateCategoryUseCase<CategoryRepo>>,
+    pub deactivate_category_use_case: Arc<DeactivateCategoryUseCase<CategoryRepo>>,
+    pub delete_category_use_case: Arc<DeleteCategoryUseCase<CategoryRepo>>,
+    pub get_category_tree_use_case: Arc<GetCategoryTreeUseCase<CategoryRepo>>,
+    pub get_category_ancestors_use_case: Arc<GetCategoryAncestorsUseCase<CategoryRepo>>,
+    pub get_category_breadcrumbs_use_case: Arc<GetCategoryBreadcrumbsUseCase<CategoryRepo>>,
+    pub move_category_use_case: Arc<MoveCategoryUseCase<CategoryRepo>>,
+    pub list_category_products_use_case: Arc<ListCategoryProductsUseCase<ProductRepo, CategoryRepo>>,
+    pub get_inventory_use_case: Arc<GetInventoryUseCase<ProductRepo, InventoryRepo>>,
+    pub set_stock_use_case: Arc<SetStockUseCase<ProductRepo, InventoryRepo>>,
+    pub reserve_stock_use_case: Arc<ReserveStockUseCase<ProductRepo, InventoryRepo, ReservationRepo>>,
+    pub get_reservation_use_case: Arc<GetReservationUseCase<ReservationRepo>>,
+    pub commit_reservation_use_case: Arc<CommitReservationUseCase<InventoryRepo, ReservationRepo>>,
+    pub release_reservation_use_case: Arc<ReleaseReservationUseCase<InventoryRepo, ReservationRepo>>,
+    pub expire_reservations_use_case: Arc<ExpireReservationsUseCase<InventoryRepo, ReservationRepo>>,
+}
+
+impl AppContext {
+    pub async fn new(config: Config) -> Result<Self, Box<dyn std::error::Error>> {
+        let pool = create_pool(&config.database).await?;
+        run_migrations(&pool).await?;
+
+        let product_repository = Arc::new(PostgresProductRepository::new(pool.clone()));
+        let category_repository = Arc::new(PostgresCategoryRepository::new(pool.clone()));
+        let inventory_repository = Arc::new(PostgresInventoryRepository::new(pool.clone()));
+        let reservation_repository = Arc::new(PostgresReservationRepository::new(pool));
+        let search_index = Arc::new(
+            InMemoryProductSearchIndex::load(&product_repository, config.search.price_buckets.clone()).await?,
+        );
+
+        Ok(Self {
+            create_product_use_case: Arc::new(CreateProductUseCase::new(
+                Arc::clone(&product_repository),
+                Arc::clone(&category_repository),
+                Arc::clone(&search_index),
+            )),
+            get_product_use_case: Arc::new(GetProductUseCase::new(Arc::clone(&product_repository))),
+            list_products_use_case: Arc::new(ListProductsUseCase::new(Arc::clone(&product_repository))),
+            update_product_use_case: Arc::new(UpdateProductUseCase::new(
+                Arc::clone(&product_repository),
+                Arc::clone(&category_repository),
+                Arc::clone(&search_index),
+            )),
+            deactivate_product_use_case: Arc::new(DeactivateProductUseCase::new(
+                Arc::clone(&product_repository),
+                Arc::clone(&search_index),
+            )),
+            delete_product_use_case: Arc::new(DeleteProductUseCase::new(
+                Arc::clone(&product_repository),
+                Arc::clone(&search_index),
+            )),
+            search_products_use_case: Arc::new(SearchProductsUseCase::new(Arc::clone(&product_repository), search_index)),
+            create_category_use_case: Arc::new(CreateCategoryUseCase::new(Arc::clone(&category_repository))),
+            get_category_use_case: Arc::new(GetCategoryUseCase::new(Arc::clone(&category_repository))),
+            list_categories_use_case: Arc::new(ListCategoriesUseCase::new(Arc::clone(&category_repository))),
+            update_category_use_case: Arc::new(UpdateCategoryUseCase::new(Arc::clone(&category_repository))),
+            deactivate_category_use_case: Arc::new(DeactivateCategoryUseCase::new(Arc::clone(&category_repository))),
+            delete_category_use_case: Arc::new(DeleteCategoryUseCase::new(Arc::clone(&category_repository))),
+            get_category_tree_use_case: Arc::new(GetCategoryTreeUseCase::new(Arc::clone(&category_repository))),
+            get_category_ancestors_use_case: Arc::new(GetCategoryAncestorsUseCase::new(Arc::clone(&category_repository))),
+            get_category_breadcrumbs_use_case: Arc::new(GetCategoryBreadcrumbsUseCase::new(Arc::clone(&category_repository))),
+            move_category_use_case: Arc::new(MoveCategoryUseCase::new(Arc::clone(&category_repository))),
+            list_category_products_use_case: Arc::new(ListCategoryProductsUseCase::new(
+                Arc::clone(&product_repository),
+                category_repository,
+            )),
+            get_inventory_use_case: Arc::new(GetInventoryUseCase::new(
+                Arc::clone(&product_repository),
+                Arc::clone(&inventory_repository),
+            )),
+            set_stock_use_case: Arc::new(SetStockUseCase::new(
+                Arc::clone(&product_repository),
+                Arc::clone(&inventory_repository),
+            )),
+            reserve_stock_use_case: Arc::new(ReserveStockUseCase::new(
+                product_repository,
+                Arc::clone(&inventory_repository),
+                Arc::clone(&reservation_repository),
+                config.reservations.default_ttl_seconds,
+                config.reservations.max_ttl_seconds,
+            )),
+            get_reservation_use_case: Arc::new(GetReservationUseCase::new(Arc::clone(&reservation_repository))),
+            commit_reservation_use_case: Arc::new(CommitReservationUseCase::new(
+                Arc::clone(&inventory_repository),
+                Arc::clone(&reservation_repository),
+            )),
+            release_reservation_use_case: Arc::new(ReleaseReservationUseCase::new(
+                Arc::clone(&inventory_repository),
+                Arc::clone(&reservation_repository),
+            )),
+            expire_reservations_use_case: Arc::new(ExpireReservationsUseCase::new(
+                inventory_repository,
+                reservation_repository,
+            )),
+        })
+    }
+}
@@ -1,4 +1,5 @@
 use crate::domain::value_objects::{CategoryId, CategoryName};
+use crate::domain::errors::DomainError;
 use chrono::{DateTime, Utc};
 
 #[derive(Debug, Clone)]
@@ -6,6 +7,8 @@ pub struct Category {
     pub id: CategoryId,
     pub name: CategoryName,
     pub parent_id: Option<uuid::Uuid>,
+    /// Ids from the root down to the parent; empty for a root category.
+    pub ancestor_ids: Vec<uuid::Uuid>,
     pub is_active: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -18,14 +21,40 @@ impl Category {
             id,
             name,
             parent_id: None,
+            ancestor_ids: Vec::new(),
             is_active: true,
             created_at: now,
             updated_at: now,
         }
     }
 
-    pub fn set_parent(&mut self, parent_id: uuid::Uuid) {
-        self.parent_id = Some(parent_id);
+    pub fn rename(&mut self, name: CategoryName) {
+        self.name = name;
+        self.updated_at = Utc::now();
+    }
+
+    pub fn deactivate(&mut self) {
+        self.is_active = false;
+        self.updated_at = Utc::now();
+    }
+
+    /// Fails if `parent` is this category or one of its descendants, which
+    /// would turn the tree into a cycle.
+    pub fn set_parent(&mut self, parent: &Category) -> Result<(), DomainError> {
+        let id = self.id.as_uuid();
+        if parent.id == self.id || parent.ancestor_ids.contains(&id) {
+            return Err(DomainError::CategoryCycle);
+        }
+
+        self.ancestor_ids = parent.ancestor_ids.iter().copied().chain([parent.id.as_uuid()]).collect();
+        self.parent_id = Some(parent.id.as_uuid());
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    pub fn move_to_root(&mut self) {
+        self.ancestor_ids.clear();
+        self.parent_id = None;
         self.updated_at = Utc::now();
     }
 }
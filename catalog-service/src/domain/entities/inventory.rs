@@ -1,4 +1,5 @@
 use crate::domain::value_objects::{ProductId, Quantity};
+use crate::domain::errors::DomainError;
 use chrono::{DateTime, Utc};
 
 #[derive(Debug, Clone)]
@@ -7,6 +8,10 @@ pub struct Inventory {
     pub quantity: Quantity,
     pub reserved_quantity: Quantity,
     pub updated_at: DateTime<Utc>,
+    /// Bumped by every change, so it is the version the inventory has once saved.
+    pub version: i64,
+    /// Version the inventory was read at; saving fails if it has moved on.
+    pub read_version: i64,
 }
 
 impl Inventory {
@@ -16,6 +21,8 @@ impl Inventory {
             quantity,
             reserved_quantity: Quantity::zero(),
             updated_at: Utc::now(),
+            version: 0,
+            read_version: 0,
         }
     }
 
@@ -23,21 +30,91 @@ impl Inventory {
         Quantity::new(self.quantity.value() - self.reserved_quantity.value())
     }
 
-    pub fn reserve(&mut self, amount: u32) -> Result<(), crate::domain::errors::DomainError> {
+    /// Sets the stock on hand; it cannot drop below what is already reserved.
+    pub fn set_quantity(&mut self, quantity: Quantity) -> Result<(), DomainError> {
+        if quantity < self.reserved_quantity {
+            return Err(DomainError::ValidationError(
+                "Quantity cannot be lower than the reserved quantity".to_string(),
+            ));
+        }
+        self.quantity = quantity;
+        self.touch();
+        Ok(())
+    }
+
+    pub fn reserve(&mut self, amount: u32) -> Result<(), DomainError> {
         let available = self.available_quantity().value();
         if amount > available {
-            return Err(crate::domain::errors::DomainError::InsufficientStock);
+            return Err(DomainError::InsufficientStock);
         }
         self.reserved_quantity = Quantity::new(self.reserved_quantity.value() + amount);
-        self.updated_at = Utc::now();
+        self.touch();
         Ok(())
     }
 
     pub fn release(&mut self, amount: u32) {
         if amount <= self.reserved_quantity.value() {
             self.reserved_quantity = Quantity::new(self.reserved_quantity.value() - amount);
-            self.updated_at = Utc::now();
+            self.touch();
+        }
+    }
+
+    /// Takes reserved stock out of the inventory once the order is placed.
+    pub fn commit(&mut self, amount: u32) -> Result<(), DomainError> {
+        if amount > self.reserved_quantity.value() {
+            return Err(DomainError::ValidationError(
+                "Cannot commit more than the reserved quantity".to_string(),
+            ));
         }
+        self.quantity = Quantity::new(self.quantity.value() - amount);
+        self.reserved_quantity = Quantity::new(self.reserved_quantity.value() - amount);
+        self.touch();
+        Ok(())
+    }
+
+    fn touch(&mut self) {
+        self.updated_at = Utc::now();
+        self.version += 1;
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stocked(quantity: u32) -> Inventory {
+        Inventory::new(ProductId::new(), Quantity::new(quantity))
+    }
+
+    #[test]
+    fn every_change_bumps_the_version() {
+        let mut inventory = stocked(10);
+
+        inventory.set_quantity(Quantity::new(8)).unwrap();
+        assert_eq!(inventory.version, 1);
+        inventory.reserve(3).unwrap();
+        assert_eq!(inventory.version, 2);
+        inventory.release(1);
+        assert_eq!(inventory.version, 3);
+        inventory.commit(2).unwrap();
+        assert_eq!(inventory.version, 4);
+
+        assert_eq!(inventory.read_version, 0);
+        assert_eq!(inventory.quantity, Quantity::new(6));
+        assert!(inventory.reserved_quantity.is_zero());
+    }
+
+    #[test]
+    fn rejected_changes_keep_the_version() {
+        let mut inventory = stocked(5);
+        inventory.reserve(4).unwrap();
+
+        assert_eq!(inventory.reserve(2), Err(DomainError::InsufficientStock));
+        assert!(inventory.set_quantity(Quantity::new(3)).is_err());
+        assert!(inventory.commit(5).is_err());
+        inventory.release(5);
+
+        assert_eq!(inventory.version, 1);
+        assert_eq!(inventory.reserved_quantity, Quantity::new(4));
+    }
+}
@@ -1,4 +1,5 @@
 pub mod product;
 pub mod category;
 pub mod inventory;
+pub mod reservation;
 
@@ -41,6 +41,16 @@ impl Product {
         self.updated_at = Utc::now();
     }
 
+    pub fn rename(&mut self, name: ProductName) {
+        self.name = name;
+        self.updated_at = Utc::now();
+    }
+
+    pub fn update_description(&mut self, description: Description) {
+        self.description = description;
+        self.updated_at = Utc::now();
+    }
+
     pub fn update_price(&mut self, new_price: Price) {
         self.price = new_price;
         self.updated_at = Utc::now();
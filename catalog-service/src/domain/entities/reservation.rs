@@ -0,0 +1,155 @@
+use crate::domain::value_objects::{ProductId, Quantity, ReservationId};
+use crate::domain::errors::DomainError;
+use chrono::{DateTime, Duration, Utc};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReservationStatus {
+    Active,
+    Committed,
+    Released,
+    Expired,
+}
+
+impl ReservationStatus {
+    pub fn parse(value: &str) -> Result<Self, DomainError> {
+        match value {
+            "active" => Ok(Self::Active),
+            "committed" => Ok(Self::Committed),
+            "released" => Ok(Self::Released),
+            "expired" => Ok(Self::Expired),
+            _ => Err(DomainError::ValidationError(format!("Unknown reservation status: {}", value))),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Active => "active",
+            Self::Committed => "committed",
+            Self::Released => "released",
+            Self::Expired => "expired",
+        }
+    }
+}
+
+/// Stock held for an order until it is committed, released or its TTL runs
+/// out. Only an active reservation counts against the available stock.
+#[derive(Debug, Clone)]
+pub struct Reservation {
+    pub id: ReservationId,
+    pub product_id: ProductId,
+    pub quantity: Quantity,
+    pub order_reference: String,
+    pub status: ReservationStatus,
+    pub expires_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Reservation {
+    pub fn new(product_id: ProductId, quantity: Quantity, order_reference: String, ttl: Duration) -> Self {
+        let now = Utc::now();
+        Self {
+            id: ReservationId::new(),
+            product_id,
+            quantity,
+            order_reference,
+            status: ReservationStatus::Active,
+            expires_at: now + ttl,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.status == ReservationStatus::Active && self.expires_at <= now
+    }
+
+    /// Returns false when the reservation was already committed, so that
+    /// repeating a commit changes nothing.
+    pub fn commit(&mut self, now: DateTime<Utc>) -> Result<bool, DomainError> {
+        match self.status {
+            ReservationStatus::Committed => Ok(false),
+            ReservationStatus::Active if !self.is_expired(now) => {
+                self.status = ReservationStatus::Committed;
+                self.updated_at = now;
+                Ok(true)
+            }
+            ReservationStatus::Active => Err(DomainError::ReservationClosed(ReservationStatus::Expired.as_str().to_string())),
+            status => Err(DomainError::ReservationClosed(status.as_str().to_string())),
+        }
+    }
+
+    /// Returns false when the stock was already given back, by an earlier
+    /// release or by expiry.
+    pub fn release(&mut self, now: DateTime<Utc>) -> Result<bool, DomainError> {
+        match self.status {
+            ReservationStatus::Released | ReservationStatus::Expired => Ok(false),
+            ReservationStatus::Active => {
+                self.status = ReservationStatus::Released;
+                self.updated_at = now;
+                Ok(true)
+            }
+            ReservationStatus::Committed => Err(DomainError::ReservationClosed(ReservationStatus::Committed.as_str().to_string())),
+        }
+    }
+
+    /// Returns false unless the reservation is active and past its TTL.
+    pub fn expire(&mut self, now: DateTime<Utc>) -> bool {
+        if !self.is_expired(now) {
+            return false;
+        }
+        self.status = ReservationStatus::Expired;
+        self.updated_at = now;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reservation() -> (Reservation, DateTime<Utc>) {
+        let reservation = Reservation::new(ProductId::new(), Quantity::new(2), "order-1".to_string(), Duration::minutes(15));
+        let now = reservation.created_at;
+        (reservation, now)
+    }
+
+    #[test]
+    fn committing_twice_changes_nothing_the_second_time() {
+        let (mut reservation, now) = reservation();
+
+        assert_eq!(reservation.commit(now), Ok(true));
+        assert_eq!(reservation.commit(now), Ok(false));
+        assert_eq!(reservation.status, ReservationStatus::Committed);
+    }
+
+    #[test]
+    fn committed_reservation_cannot_be_released() {
+        let (mut reservation, now) = reservation();
+        reservation.commit(now).unwrap();
+
+        assert_eq!(reservation.release(now), Err(DomainError::ReservationClosed("committed".to_string())));
+        assert_eq!(reservation.status, ReservationStatus::Committed);
+    }
+
+    #[test]
+    fn released_reservation_does_not_expire() {
+        let (mut reservation, now) = reservation();
+        reservation.release(now).unwrap();
+
+        assert!(!reservation.expire(now + Duration::hours(1)));
+        assert_eq!(reservation.status, ReservationStatus::Released);
+        assert_eq!(reservation.release(now), Ok(false));
+    }
+
+    #[test]
+    fn reservation_past_its_ttl_expires_and_cannot_be_committed() {
+        let (mut reservation, now) = reservation();
+        let later = now + Duration::minutes(16);
+
+        assert_eq!(reservation.commit(later), Err(DomainError::ReservationClosed("expired".to_string())));
+        assert!(reservation.expire(later));
+        assert_eq!(reservation.status, ReservationStatus::Expired);
+        assert_eq!(reservation.release(later), Ok(false));
+    }
+}
@@ -20,12 +20,30 @@ pub enum DomainError {
     #[error("Insufficient stock")]
     InsufficientStock,
 
+    #[error("Reservation not found")]
+    ReservationNotFound,
+
+    #[error("Reservation is {0}")]
+    ReservationClosed(String),
+
+    #[error("Inventory was changed by another request")]
+    ConcurrentModification,
+
     #[error("Product already exists")]
     ProductAlreadyExists,
 
     #[error("SKU already exists")]
     SKUAlreadyExists,
 
+    #[error("Category has subcategories")]
+    CategoryHasChildren,
+
+    #[error("Category cannot be moved under itself or one of its subcategories")]
+    CategoryCycle,
+
+    #[error("Repository error: {0}")]
+    Repository(String),
+
     #[error("Domain validation error: {0}")]
     ValidationError(String),
 }
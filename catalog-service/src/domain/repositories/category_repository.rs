@@ -8,8 +8,18 @@ use crate::domain::errors::DomainError;
 pub trait CategoryRepository: Send + Sync {
     async fn create(&self, category: &Category) -> Result<(), DomainError>;
     async fn find_by_id(&self, id: &CategoryId) -> Result<Option<Category>, DomainError>;
+    /// The categories that exist among `ids`, in no particular order.
+    async fn find_by_ids(&self, ids: &[CategoryId]) -> Result<Vec<Category>, DomainError>;
     async fn find_all(&self) -> Result<Vec<Category>, DomainError>;
+    /// Saves the name and status; the parent only changes through
+    /// `move_subtree`.
     async fn update(&self, category: &Category) -> Result<(), DomainError>;
+    /// Moves a category and all of its descendants under `parent_id`, or to
+    /// the root when it is `None`, and returns the moved category. The tree
+    /// is read and written in one transaction so that concurrent moves
+    /// cannot form a cycle.
+    async fn move_subtree(&self, id: &CategoryId, parent_id: Option<&CategoryId>) -> Result<Category, DomainError>;
+    async fn delete(&self, id: &CategoryId) -> Result<(), DomainError>;
 }
 
 #[async_trait]
@@ -22,6 +32,10 @@ impl<R: CategoryRepository> CategoryRepository for Arc<R> {
         (**self).find_by_id(id).await
     }
 
+    async fn find_by_ids(&self, ids: &[CategoryId]) -> Result<Vec<Category>, DomainError> {
+        (**self).find_by_ids(ids).await
+    }
+
     async fn find_all(&self) -> Result<Vec<Category>, DomainError> {
         (**self).find_all().await
     }
@@ -29,5 +43,13 @@ impl<R: CategoryRepository> CategoryRepository for Arc<R> {
     async fn update(&self, category: &Category) -> Result<(), DomainError> {
         (**self).update(category).await
     }
+
+    async fn move_subtree(&self, id: &CategoryId, parent_id: Option<&CategoryId>) -> Result<Category, DomainError> {
+        (**self).move_subtree(id, parent_id).await
+    }
+
+    async fn delete(&self, id: &CategoryId) -> Result<(), DomainError> {
+        (**self).delete(id).await
+    }
 }
 
@@ -8,6 +8,8 @@ use crate::domain::errors::DomainError;
 pub trait InventoryRepository: Send + Sync {
     async fn create(&self, inventory: &Inventory) -> Result<(), DomainError>;
     async fn find_by_product_id(&self, product_id: &ProductId) -> Result<Option<Inventory>, DomainError>;
+    /// Fails with `ConcurrentModification` if the inventory changed since it
+    /// was read.
     async fn update(&self, inventory: &Inventory) -> Result<(), DomainError>;
 }
 
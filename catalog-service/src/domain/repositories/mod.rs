@@ -1,8 +1,13 @@
 pub mod product_repository;
 pub mod category_repository;
 pub mod inventory_repository;
+pub mod reservation_repository;
+pub mod product_search_index;
 
-pub use product_repository::ProductRepository;
+pub use product_repository::{ProductCursor, ProductQuery, ProductRepository, ProductSortKey, ProductSortValue};
 pub use category_repository::CategoryRepository;
 pub use inventory_repository::InventoryRepository;
-
+pub use reservation_repository::ReservationRepository;
+pub use product_search_index::{
+    CategoryFacet, PriceBucketFacet, ProductSearchHit, ProductSearchIndex, ProductSearchQuery, ProductSearchResults,
+};
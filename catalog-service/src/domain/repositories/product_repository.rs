@@ -1,15 +1,92 @@
 use std::sync::Arc;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 use crate::domain::entities::product::Product;
-use crate::domain::value_objects::{ProductId, SKU};
+use crate::domain::value_objects::{CategoryId, Price, ProductId, SKU};
 use crate::domain::errors::DomainError;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProductSortKey {
+    Name,
+    Price,
+    #[default]
+    CreatedAt,
+}
+
+impl ProductSortKey {
+    pub fn parse(value: &str) -> Result<Self, DomainError> {
+        match value {
+            "name" => Ok(Self::Name),
+            "price" => Ok(Self::Price),
+            "created_at" => Ok(Self::CreatedAt),
+            _ => Err(DomainError::ValidationError(format!("Unknown sort key: {}", value))),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Name => "name",
+            Self::Price => "price",
+            Self::CreatedAt => "created_at",
+        }
+    }
+}
+
+/// The sort value of the last product on a page, matching the sort key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProductSortValue {
+    Name(String),
+    Price(Decimal),
+    CreatedAt(DateTime<Utc>),
+}
+
+/// Where a page ends: the next page holds the products sorted after this one.
+/// The id breaks ties between products with the same sort value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProductCursor {
+    pub value: ProductSortValue,
+    pub id: ProductId,
+}
+
+impl ProductCursor {
+    pub fn after(product: &Product, sort: ProductSortKey) -> Self {
+        let value = match sort {
+            ProductSortKey::Name => ProductSortValue::Name(product.name.as_str().to_string()),
+            ProductSortKey::Price => ProductSortValue::Price(product.price.value()),
+            ProductSortKey::CreatedAt => ProductSortValue::CreatedAt(product.created_at),
+        };
+        Self { value, id: product.id }
+    }
+}
+
+/// Filters, order and position of one page of products. Price bounds and
+/// `created_after` are inclusive.
+#[derive(Debug, Clone, Default)]
+pub struct ProductQuery {
+    pub category_id: Option<CategoryId>,
+    /// Also match products of every descendant of `category_id`.
+    pub include_subcategories: bool,
+    pub min_price: Option<Price>,
+    pub max_price: Option<Price>,
+    pub is_active: Option<bool>,
+    pub created_after: Option<DateTime<Utc>>,
+    pub sort: ProductSortKey,
+    pub descending: bool,
+    pub after: Option<ProductCursor>,
+    pub limit: i64,
+}
+
 #[async_trait]
 pub trait ProductRepository: Send + Sync {
     async fn create(&self, product: &Product) -> Result<(), DomainError>;
     async fn find_by_id(&self, id: &ProductId) -> Result<Option<Product>, DomainError>;
     async fn find_by_sku(&self, sku: &SKU) -> Result<Option<Product>, DomainError>;
-    async fn find_all(&self) -> Result<Vec<Product>, DomainError>;
+    /// The products that exist among `ids`, in no particular order.
+    async fn find_by_ids(&self, ids: &[ProductId]) -> Result<Vec<Product>, DomainError>;
+    /// At most `query.limit` products matching the filters, in query order,
+    /// starting after `query.after`.
+    async fn find_page(&self, query: &ProductQuery) -> Result<Vec<Product>, DomainError>;
     async fn update(&self, product: &Product) -> Result<(), DomainError>;
     async fn delete(&self, id: &ProductId) -> Result<(), DomainError>;
 }
@@ -28,8 +105,12 @@ impl<R: ProductRepository> ProductRepository for Arc<R> {
         (**self).find_by_sku(sku).await
     }
 
-    async fn find_all(&self) -> Result<Vec<Product>, DomainError> {
-        (**self).find_all().await
+    async fn find_by_ids(&self, ids: &[ProductId]) -> Result<Vec<Product>, DomainError> {
+        (**self).find_by_ids(ids).await
+    }
+
+    async fn find_page(&self, query: &ProductQuery) -> Result<Vec<Product>, DomainError> {
+        (**self).find_page(query).await
     }
 
     async fn update(&self, product: &Product) -> Result<(), DomainError> {
@@ -40,4 +121,3 @@ impl<R: ProductRepository> ProductRepository for Arc<R> {
         (**self).delete(id).await
     }
 }
-
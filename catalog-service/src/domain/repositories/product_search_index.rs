@@ -0,0 +1,73 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use crate::domain::entities::product::Product;
+use crate::domain::value_objects::{CategoryId, Price, ProductId};
+use crate::domain::errors::DomainError;
+
+/// A free-text search over the active products. Price bounds are inclusive.
+#[derive(Debug, Clone)]
+pub struct ProductSearchQuery {
+    pub text: String,
+    pub category_id: Option<CategoryId>,
+    pub min_price: Option<Price>,
+    pub max_price: Option<Price>,
+    pub offset: usize,
+    pub limit: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct ProductSearchHit {
+    pub product_id: ProductId,
+    pub score: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CategoryFacet {
+    /// `None` counts the products without a category.
+    pub category_id: Option<CategoryId>,
+    pub count: usize,
+}
+
+/// Products priced from `min` (inclusive) up to `max` (exclusive).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PriceBucketFacet {
+    pub min: Decimal,
+    pub max: Option<Decimal>,
+    pub count: usize,
+}
+
+/// One page of hits, best first, and the facet counts of all matches. Each
+/// facet ignores its own filter, so the counts show what choosing another
+/// category or price range would return.
+#[derive(Debug, Clone)]
+pub struct ProductSearchResults {
+    pub total: usize,
+    pub hits: Vec<ProductSearchHit>,
+    pub categories: Vec<CategoryFacet>,
+    pub price_buckets: Vec<PriceBucketFacet>,
+}
+
+/// Full-text index over product name, description and SKU.
+#[async_trait]
+pub trait ProductSearchIndex: Send + Sync {
+    /// Adds or refreshes the product; inactive products are taken out.
+    async fn index(&self, product: &Product) -> Result<(), DomainError>;
+    async fn remove(&self, product_id: &ProductId) -> Result<(), DomainError>;
+    async fn search(&self, query: &ProductSearchQuery) -> Result<ProductSearchResults, DomainError>;
+}
+
+#[async_trait]
+impl<I: ProductSearchIndex> ProductSearchIndex for Arc<I> {
+    async fn index(&self, product: &Product) -> Result<(), DomainError> {
+        (**self).index(product).await
+    }
+
+    async fn remove(&self, product_id: &ProductId) -> Result<(), DomainError> {
+        (**self).remove(product_id).await
+    }
+
+    async fn search(&self, query: &ProductSearchQuery) -> Result<ProductSearchResults, DomainError> {
+        (**self).search(query).await
+    }
+}
@@ -0,0 +1,41 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::domain::entities::inventory::Inventory;
+use crate::domain::entities::reservation::Reservation;
+use crate::domain::value_objects::ReservationId;
+use crate::domain::errors::DomainError;
+
+/// Reservations are always written together with the inventory they hold
+/// stock from, in one transaction. Both writes fail with
+/// `ConcurrentModification` if the inventory or the reservation changed
+/// since they were read.
+#[async_trait]
+pub trait ReservationRepository: Send + Sync {
+    async fn create(&self, reservation: &Reservation, inventory: &Inventory) -> Result<(), DomainError>;
+    async fn find_by_id(&self, id: &ReservationId) -> Result<Option<Reservation>, DomainError>;
+    /// At most `limit` active reservations whose TTL ran out by `now`,
+    /// soonest expiry first.
+    async fn find_expired(&self, now: DateTime<Utc>, limit: i64) -> Result<Vec<Reservation>, DomainError>;
+    /// Saves the new status of a reservation that was active when read.
+    async fn update(&self, reservation: &Reservation, inventory: &Inventory) -> Result<(), DomainError>;
+}
+
+#[async_trait]
+impl<R: ReservationRepository> ReservationRepository for Arc<R> {
+    async fn create(&self, reservation: &Reservation, inventory: &Inventory) -> Result<(), DomainError> {
+        (**self).create(reservation, inventory).await
+    }
+
+    async fn find_by_id(&self, id: &ReservationId) -> Result<Option<Reservation>, DomainError> {
+        (**self).find_by_id(id).await
+    }
+
+    async fn find_expired(&self, now: DateTime<Utc>, limit: i64) -> Result<Vec<Reservation>, DomainError> {
+        (**self).find_expired(now, limit).await
+    }
+
+    async fn update(&self, reservation: &Reservation, inventory: &Inventory) -> Result<(), DomainError> {
+        (**self).update(reservation, inventory).await
+    }
+}
@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use crate::domain::entities::category::Category;
+use crate::domain::repositories::CategoryRepository;
+use crate::domain::value_objects::CategoryId;
+use crate::domain::errors::DomainError;
+
+#[derive(Debug, Clone)]
+pub struct CategoryNode {
+    pub category: Category,
+    pub children: Vec<CategoryNode>,
+}
+
+pub struct CategoryTreeService<CR: CategoryRepository> {
+    category_repository: Arc<CR>,
+}
+
+impl<CR: CategoryRepository> CategoryTreeService<CR> {
+    pub fn new(category_repository: Arc<CR>) -> Self {
+        Self { category_repository }
+    }
+
+    /// Every category, as root categories with their descendants nested.
+    /// Siblings keep the repository order, by name.
+    pub async fn tree(&self) -> Result<Vec<CategoryNode>, DomainError> {
+        let mut children: HashMap<Option<uuid::Uuid>, Vec<Category>> = HashMap::new();
+        for category in self.category_repository.find_all().await? {
+            children.entry(category.parent_id).or_default().push(category);
+        }
+
+        Ok(build_nodes(None, &mut children))
+    }
+
+    /// The ancestors of `category`, from the root down to its parent.
+    pub async fn ancestors(&self, category: &Category) -> Result<Vec<Category>, DomainError> {
+        let ids: Vec<CategoryId> = category.ancestor_ids.iter().copied().map(CategoryId::from_uuid).collect();
+        let mut ancestors: HashMap<uuid::Uuid, Category> = self.category_repository.find_by_ids(&ids).await?
+            .into_iter()
+            .map(|ancestor| (ancestor.id.as_uuid(), ancestor))
+            .collect();
+
+        category.ancestor_ids.iter()
+            .map(|id| ancestors.remove(id).ok_or(DomainError::CategoryNotFound))
+            .collect()
+    }
+}
+
+fn build_nodes(parent_id: Option<uuid::Uuid>, children: &mut HashMap<Option<uuid::Uuid>, Vec<Category>>) -> Vec<CategoryNode> {
+    children.remove(&parent_id)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|category| {
+            let children = build_nodes(Some(category.id.as_uuid()), children);
+            CategoryNode { category, children }
+        })
+        .collect()
+}
@@ -1,4 +1,5 @@
 pub mod catalog_service;
+pub mod category_tree_service;
 
 pub use catalog_service::CatalogService;
-
+pub use category_tree_service::{CategoryNode, CategoryTreeService};
@@ -6,6 +6,7 @@ pub mod description;
 pub mod category_id;
 pub mod category_name;
 pub mod quantity;
+pub mod reservation_id;
 
 pub use product_id::ProductId;
 pub use product_name::ProductName;
@@ -15,4 +16,5 @@ pub use description::Description;
 pub use category_id::CategoryId;
 pub use category_name::CategoryName;
 pub use quantity::Quantity;
+pub use reservation_id::ReservationId;
 
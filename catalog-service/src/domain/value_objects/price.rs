@@ -1,5 +1,7 @@
+use std::str::FromStr;
 use serde::{Deserialize, Serialize};
 use rust_decimal::Decimal;
+use crate::domain::errors::DomainError;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct Price(Decimal);
@@ -9,6 +11,17 @@ impl Price {
         Self(amount)
     }
 
+    /// Parses a decimal amount such as `"19.99"`; prices must be positive.
+    pub fn parse(amount: &str) -> Result<Self, DomainError> {
+        let price = Decimal::from_str(amount)
+            .map(Self)
+            .map_err(|_| DomainError::ValidationError("Invalid price format".to_string()))?;
+        if !price.is_positive() {
+            return Err(DomainError::ValidationError("Price must be positive".to_string()));
+        }
+        Ok(price)
+    }
+
     pub fn from_cents(cents: i64) -> Self {
         Self(Decimal::new(cents, 2))
     }
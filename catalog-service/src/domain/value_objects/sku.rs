@@ -2,6 +2,7 @@ use crate::domain::errors::DomainError;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[allow(clippy::upper_case_acronyms)]
 pub struct SKU(String);
 
 impl SKU {
@@ -3,6 +3,4 @@ pub mod persistence;
 pub mod repositories;
 
 pub use config::Config;
-pub use persistence::*;
-pub use repositories::*;
 
@@ -1,4 +1,4 @@
 pub mod postgres;
 
-pub use postgres::PostgresPool;
+pub use postgres::{PostgresPool, create_pool, run_migrations};
 
@@ -11,3 +11,8 @@ pub async fn create_pool(config: &DatabaseConfig) -> Result<PostgresPool, sqlx::
         .await
 }
 
+/// Applies the versioned migrations under `migrations/` that have not run yet.
+pub async fn run_migrations(pool: &PostgresPool) -> Result<(), sqlx::migrate::MigrateError> {
+    sqlx::migrate!("./migrations").run(pool).await
+}
+
@@ -0,0 +1,197 @@
+use async_trait::async_trait;
+use sqlx::postgres::PgRow;
+use sqlx::{Executor, Postgres, Row};
+use crate::domain::entities::category::Category;
+use crate::domain::repositories::CategoryRepository;
+use crate::domain::value_objects::{CategoryId, CategoryName};
+use crate::domain::errors::DomainError;
+use crate::infrastructure::persistence::PostgresPool;
+
+const CATEGORY_COLUMNS: &str = "id, name, parent_id, path, is_active, created_at, updated_at";
+
+pub struct PostgresCategoryRepository {
+    pool: PostgresPool,
+}
+
+impl PostgresCategoryRepository {
+    pub fn new(pool: PostgresPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn map_category(row: &PgRow) -> Result<Category, DomainError> {
+    let id: uuid::Uuid = row.get("id");
+    let path: String = row.get("path");
+    let mut ancestor_ids = path.split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| {
+            uuid::Uuid::parse_str(segment)
+                .map_err(|_| DomainError::Repository(format!("Invalid path of category {}: {}", id, path)))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if ancestor_ids.pop() != Some(id) {
+        return Err(DomainError::Repository(format!("Invalid path of category {}: {}", id, path)));
+    }
+
+    Ok(Category {
+        id: CategoryId::from_uuid(id),
+        name: CategoryName::new(row.get("name"))?,
+        parent_id: row.get("parent_id"),
+        ancestor_ids,
+        is_active: row.get("is_active"),
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+    })
+}
+
+/// `/<root id>/.../<id>/`, so that the path of a category is a prefix of the
+/// paths of all its descendants.
+fn path_of(category: &Category) -> String {
+    category.ancestor_ids.iter()
+        .chain([category.id.as_uuid()].iter())
+        .fold(String::from("/"), |path, id| format!("{}{}/", path, id))
+}
+
+async fn find_in<'e, E>(executor: E, id: &CategoryId) -> Result<Option<Category>, DomainError>
+where
+    E: Executor<'e, Database = Postgres>,
+{
+    let row = sqlx::query(&format!("SELECT {} FROM categories WHERE id = $1", CATEGORY_COLUMNS))
+        .bind(id.as_uuid())
+        .fetch_optional(executor)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+    row.as_ref().map(map_category).transpose()
+}
+
+#[async_trait]
+impl CategoryRepository for PostgresCategoryRepository {
+    async fn create(&self, category: &Category) -> Result<(), DomainError> {
+        // The path is built from the parent's stored path in the same statement,
+        // so a move of the parent running at the same time cannot leave the
+        // new category with a stale path.
+        let result = sqlx::query(
+            r#"
+            INSERT INTO categories (id, name, parent_id, path, is_active, created_at, updated_at)
+            SELECT $1, $2, $3, COALESCE((SELECT path FROM categories WHERE id = $3), '/') || $1::text || '/', $4, $5, $6
+            WHERE $3::uuid IS NULL OR EXISTS (SELECT 1 FROM categories WHERE id = $3)
+            "#,
+        )
+        .bind(category.id.as_uuid())
+        .bind(category.name.as_str())
+        .bind(category.parent_id)
+        .bind(category.is_active)
+        .bind(category.created_at)
+        .bind(category.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::Database(ref db) if db.is_foreign_key_violation() => DomainError::CategoryNotFound,
+            _ => DomainError::Repository(format!("PostgreSQL error: {}", e)),
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::CategoryNotFound);
+        }
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &CategoryId) -> Result<Option<Category>, DomainError> {
+        find_in(&self.pool, id).await
+    }
+
+    async fn find_by_ids(&self, ids: &[CategoryId]) -> Result<Vec<Category>, DomainError> {
+        let ids: Vec<uuid::Uuid> = ids.iter().map(CategoryId::as_uuid).collect();
+        let rows = sqlx::query(&format!("SELECT {} FROM categories WHERE id = ANY($1)", CATEGORY_COLUMNS))
+            .bind(ids)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        rows.iter().map(map_category).collect()
+    }
+
+    async fn find_all(&self) -> Result<Vec<Category>, DomainError> {
+        let rows = sqlx::query(&format!("SELECT {} FROM categories ORDER BY name, id", CATEGORY_COLUMNS))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        rows.iter().map(map_category).collect()
+    }
+
+    async fn update(&self, category: &Category) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            UPDATE categories
+            SET name = $2, is_active = $3, updated_at = $4
+            WHERE id = $1
+            "#,
+        )
+        .bind(category.id.as_uuid())
+        .bind(category.name.as_str())
+        .bind(category.is_active)
+        .bind(category.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn move_subtree(&self, id: &CategoryId, parent_id: Option<&CategoryId>) -> Result<Category, DomainError> {
+        let mut tx = self.pool.begin().await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        // Moves wait for each other and for category writes in flight, so the
+        // paths read below stay current until the move commits.
+        sqlx::query("LOCK TABLE categories IN SHARE ROW EXCLUSIVE MODE")
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        let mut category = find_in(&mut *tx, id).await?.ok_or(DomainError::CategoryNotFound)?;
+        let old_path = path_of(&category);
+        match parent_id {
+            Some(parent_id) => {
+                let parent = find_in(&mut *tx, parent_id).await?.ok_or(DomainError::CategoryNotFound)?;
+                category.set_parent(&parent)?;
+            }
+            None => category.move_to_root(),
+        }
+        let new_path = path_of(&category);
+
+        sqlx::query("UPDATE categories SET parent_id = $2, updated_at = $3 WHERE id = $1")
+            .bind(category.id.as_uuid())
+            .bind(category.parent_id)
+            .bind(category.updated_at)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+        sqlx::query("UPDATE categories SET path = $2 || substr(path, length($1) + 1) WHERE path LIKE $1 || '%'")
+            .bind(&old_path)
+            .bind(&new_path)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        tx.commit().await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(category)
+    }
+
+    async fn delete(&self, id: &CategoryId) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM categories WHERE id = $1")
+            .bind(id.as_uuid())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| match e {
+                sqlx::Error::Database(ref db) if db.is_foreign_key_violation() => DomainError::CategoryHasChildren,
+                _ => DomainError::Repository(format!("PostgreSQL error: {}", e)),
+            })?;
+
+        Ok(())
+    }
+}
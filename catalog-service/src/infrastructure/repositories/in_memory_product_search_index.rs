@@ -0,0 +1,486 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::sync::Arc;
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use tokio::sync::RwLock;
+use crate::domain::entities::product::Product;
+use crate::domain::repositories::{
+    CategoryFacet, PriceBucketFacet, ProductCursor, ProductQuery, ProductRepository, ProductSearchHit, ProductSearchIndex,
+    ProductSearchQuery, ProductSearchResults,
+};
+use crate::domain::value_objects::{CategoryId, ProductId};
+use crate::domain::errors::DomainError;
+
+const NAME_WEIGHT: f64 = 3.0;
+const SKU_WEIGHT: f64 = 2.0;
+const DESCRIPTION_WEIGHT: f64 = 1.0;
+/// Share of the score kept when a query word is only a prefix of the term.
+const PREFIX_FACTOR: f64 = 0.7;
+/// Share of the score kept when a query word matches the term with typos.
+const FUZZY_FACTOR: f64 = 0.5;
+const MAX_PREFIX_EXPANSIONS: usize = 64;
+const MAX_FUZZY_EXPANSIONS: usize = 64;
+const LOAD_BATCH_SIZE: i64 = 500;
+
+struct IndexedProduct {
+    terms: Vec<String>,
+    name: String,
+    category_id: Option<CategoryId>,
+    price: Decimal,
+}
+
+#[derive(Default)]
+struct Index {
+    /// For every term, the products containing it and the summed field
+    /// weight of its occurrences.
+    postings: BTreeMap<String, HashMap<ProductId, f64>>,
+    /// The terms of `postings` by length in characters, so fuzzy matching only
+    /// compares a word with terms it could be within a few typos of.
+    terms_by_length: BTreeMap<usize, BTreeSet<String>>,
+    products: HashMap<ProductId, IndexedProduct>,
+}
+
+impl Index {
+    fn remove(&mut self, product_id: &ProductId) {
+        let Some(product) = self.products.remove(product_id) else {
+            return;
+        };
+        for term in product.terms {
+            if let Some(postings) = self.postings.get_mut(&term) {
+                postings.remove(product_id);
+                if postings.is_empty() {
+                    self.postings.remove(&term);
+                    self.remove_term_length(&term);
+                }
+            }
+        }
+    }
+
+    fn insert(&mut self, product: &Product) {
+        self.remove(&product.id);
+        if !product.is_active {
+            return;
+        }
+
+        let mut weights: HashMap<String, f64> = HashMap::new();
+        for term in tokenize(product.name.as_str()) {
+            *weights.entry(term).or_default() += NAME_WEIGHT;
+        }
+        for term in tokenize(product.description.as_str()) {
+            *weights.entry(term).or_default() += DESCRIPTION_WEIGHT;
+        }
+        let sku = product.sku.as_str().to_lowercase();
+        let mut sku_terms = tokenize(&sku);
+        if !sku_terms.contains(&sku) {
+            sku_terms.push(sku);
+        }
+        for term in sku_terms {
+            *weights.entry(term).or_default() += SKU_WEIGHT;
+        }
+
+        for (term, weight) in &weights {
+            self.postings.entry(term.clone()).or_default().insert(product.id, *weight);
+            self.terms_by_length.entry(term.chars().count()).or_default().insert(term.clone());
+        }
+        self.products.insert(product.id, IndexedProduct {
+            terms: weights.into_keys().collect(),
+            name: product.name.as_str().to_lowercase(),
+            category_id: product.category_id.map(CategoryId::from_uuid),
+            price: product.price.value(),
+        });
+    }
+
+    fn remove_term_length(&mut self, term: &str) {
+        let length = term.chars().count();
+        if let Some(terms) = self.terms_by_length.get_mut(&length) {
+            terms.remove(term);
+            if terms.is_empty() {
+                self.terms_by_length.remove(&length);
+            }
+        }
+    }
+
+    /// The indexed terms a query word stands for, with the share of the score
+    /// each keeps: the word itself, longer words it starts, and words within
+    /// a few typos of it.
+    fn expand(&self, word: &str) -> Vec<(&str, f64)> {
+        let mut terms = Vec::new();
+        if let Some((term, _)) = self.postings.get_key_value(word) {
+            terms.push((term.as_str(), 1.0));
+        }
+
+        let length = word.chars().count();
+        if length >= 2 {
+            terms.extend(
+                self.postings.range::<str, _>((std::ops::Bound::Excluded(word), std::ops::Bound::Unbounded))
+                    .take_while(|(term, _)| term.starts_with(word))
+                    .take(MAX_PREFIX_EXPANSIONS)
+                    .map(|(term, _)| (term.as_str(), PREFIX_FACTOR)),
+            );
+        }
+
+        let max_typos = match length {
+            0..=3 => 0,
+            4..=7 => 1,
+            _ => 2,
+        };
+        if max_typos > 0 {
+            let matched: HashSet<&str> = terms.iter().map(|(term, _)| *term).collect();
+            let word: Vec<char> = word.chars().collect();
+            let fuzzy: Vec<&str> = self.terms_by_length.range(length - max_typos..=length + max_typos)
+                .flat_map(|(_, terms)| terms)
+                .filter(|term| !matched.contains(term.as_str()))
+                .filter(|term| edit_distance(&word, &term.chars().collect::<Vec<_>>()) <= max_typos)
+                .take(MAX_FUZZY_EXPANSIONS)
+                .map(String::as_str)
+                .collect();
+            terms.extend(fuzzy.into_iter().map(|term| (term, FUZZY_FACTOR)));
+        }
+
+        terms
+    }
+
+    /// Products containing every query word, with their relevance.
+    fn score(&self, words: &[String]) -> HashMap<ProductId, f64> {
+        let product_count = self.products.len() as f64;
+        let mut scores: Option<HashMap<ProductId, f64>> = None;
+
+        for word in words {
+            let mut word_scores: HashMap<ProductId, f64> = HashMap::new();
+            for (term, factor) in self.expand(word) {
+                let postings = &self.postings[term];
+                let idf = (1.0 + product_count / postings.len() as f64).ln();
+                for (product_id, weight) in postings {
+                    let score = weight * idf * factor;
+                    let best = word_scores.entry(*product_id).or_default();
+                    if score > *best {
+                        *best = score;
+                    }
+                }
+            }
+
+            let combined = match scores {
+                None => word_scores,
+                Some(previous) => previous.into_iter()
+                    .filter_map(|(product_id, score)| word_scores.get(&product_id).map(|word_score| (product_id, score + word_score)))
+                    .collect(),
+            };
+            if combined.is_empty() {
+                return combined;
+            }
+            scores = Some(combined);
+        }
+
+        scores.unwrap_or_default()
+    }
+
+    /// Ranked hits for the query, with facet counts over all matching products.
+    fn search(&self, query: &ProductSearchQuery, price_bounds: &[Decimal]) -> ProductSearchResults {
+        let mut words = tokenize(&query.text);
+        words.sort();
+        words.dedup();
+
+        let matches: Vec<(ProductId, f64, &IndexedProduct)> = self.score(&words).into_iter()
+            .map(|(product_id, score)| (product_id, score, &self.products[&product_id]))
+            .collect();
+
+        let in_category = |product: &IndexedProduct| query.category_id.is_none() || product.category_id == query.category_id;
+        let in_price_range = |product: &IndexedProduct| {
+            query.min_price.is_none_or(|min| product.price >= min.value())
+                && query.max_price.is_none_or(|max| product.price <= max.value())
+        };
+
+        let mut category_counts: HashMap<Option<CategoryId>, usize> = HashMap::new();
+        let mut bucket_counts = vec![0; price_bounds.len() + 1];
+        for (_, _, product) in &matches {
+            if in_price_range(product) {
+                *category_counts.entry(product.category_id).or_default() += 1;
+            }
+            if in_category(product) {
+                bucket_counts[bucket_of(price_bounds, product.price)] += 1;
+            }
+        }
+
+        let mut categories: Vec<CategoryFacet> = category_counts.into_iter()
+            .map(|(category_id, count)| CategoryFacet { category_id, count })
+            .collect();
+        categories.sort_by(|a, b| {
+            b.count.cmp(&a.count)
+                .then_with(|| a.category_id.map(|id| id.as_uuid()).cmp(&b.category_id.map(|id| id.as_uuid())))
+        });
+
+        let price_buckets = bucket_counts.into_iter().enumerate()
+            .map(|(bucket, count)| PriceBucketFacet {
+                min: if bucket == 0 { Decimal::ZERO } else { price_bounds[bucket - 1] },
+                max: price_bounds.get(bucket).copied(),
+                count,
+            })
+            .collect();
+
+        let mut hits: Vec<(ProductId, f64, &IndexedProduct)> = matches.into_iter()
+            .filter(|(_, _, product)| in_category(product) && in_price_range(product))
+            .collect();
+        hits.sort_by(|a, b| {
+            b.1.total_cmp(&a.1)
+                .then_with(|| a.2.name.cmp(&b.2.name))
+                .then_with(|| a.0.as_uuid().cmp(&b.0.as_uuid()))
+        });
+
+        ProductSearchResults {
+            total: hits.len(),
+            hits: hits.into_iter()
+                .skip(query.offset)
+                .take(query.limit)
+                .map(|(product_id, score, _)| ProductSearchHit { product_id, score })
+                .collect(),
+            categories,
+            price_buckets,
+        }
+    }
+}
+
+/// Inverted index kept in the memory of each instance, so every instance
+/// builds its own at startup and only sees the writes it handles itself.
+pub struct InMemoryProductSearchIndex {
+    index: Arc<RwLock<Index>>,
+    /// Ascending upper bounds of the price buckets; the last bucket is open.
+    price_bounds: Vec<Decimal>,
+}
+
+impl InMemoryProductSearchIndex {
+    pub fn new(price_bounds: Vec<Decimal>) -> Self {
+        Self {
+            index: Arc::new(RwLock::new(Index::default())),
+            price_bounds,
+        }
+    }
+
+    /// Builds the index from every active product in the repository.
+    pub async fn load<R: ProductRepository>(product_repository: &R, price_bounds: Vec<Decimal>) -> Result<Self, DomainError> {
+        let search_index = Self::new(price_bounds);
+        let mut query = ProductQuery {
+            is_active: Some(true),
+            limit: LOAD_BATCH_SIZE,
+            ..Default::default()
+        };
+
+        loop {
+            let products = product_repository.find_page(&query).await?;
+            let mut index = search_index.index.write().await;
+            for product in &products {
+                index.insert(product);
+            }
+            match products.last() {
+                Some(last) if products.len() as i64 == LOAD_BATCH_SIZE => query.after = Some(ProductCursor::after(last, query.sort)),
+                _ => break,
+            }
+        }
+
+        tracing::info!("Indexed {} products for search", search_index.index.read().await.products.len());
+        Ok(search_index)
+    }
+}
+
+#[async_trait]
+impl ProductSearchIndex for InMemoryProductSearchIndex {
+    async fn index(&self, product: &Product) -> Result<(), DomainError> {
+        self.index.write().await.insert(product);
+        Ok(())
+    }
+
+    async fn remove(&self, product_id: &ProductId) -> Result<(), DomainError> {
+        self.index.write().await.remove(product_id);
+        Ok(())
+    }
+
+    async fn search(&self, query: &ProductSearchQuery) -> Result<ProductSearchResults, DomainError> {
+        // Scoring walks the postings of every expanded term, so it runs on the
+        // blocking pool; writers wait for the lock without holding up a worker
+        let index = self.index.clone().read_owned().await;
+        let query = query.clone();
+        let price_bounds = self.price_bounds.clone();
+        tokio::task::spawn_blocking(move || index.search(&query, &price_bounds)).await
+            .map_err(|e| DomainError::Repository(format!("Search task failed: {}", e)))
+    }
+}
+
+/// Index of the price bucket `price` falls in.
+fn bucket_of(price_bounds: &[Decimal], price: Decimal) -> usize {
+    price_bounds.iter().position(|bound| price < *bound).unwrap_or(price_bounds.len())
+}
+
+/// Lowercased runs of letters and digits.
+fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Optimal string alignment distance: insertions, deletions, substitutions
+/// and swaps of two adjacent characters each count as one edit.
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, distance) in distances[0].iter_mut().enumerate() {
+        *distance = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1)
+                .min(distances[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+            distances[i][j] = distance;
+        }
+    }
+
+    distances[a.len()][b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::{Description, Price, ProductName, SKU};
+
+    fn product(name: &str, sku: &str, price: i64, category_id: Option<CategoryId>) -> Product {
+        let mut product = Product::new(
+            ProductId::new(),
+            ProductName::new(name.to_string()).unwrap(),
+            SKU::new(sku.to_string()).unwrap(),
+            Description::new(String::new()),
+            Price::new(Decimal::new(price, 0)),
+        );
+        if let Some(category_id) = category_id {
+            product.assign_to_category(category_id.as_uuid());
+        }
+        product
+    }
+
+    fn query(text: &str) -> ProductSearchQuery {
+        ProductSearchQuery {
+            text: text.to_string(),
+            category_id: None,
+            min_price: None,
+            max_price: None,
+            offset: 0,
+            limit: 20,
+        }
+    }
+
+    async fn search_index(products: &[Product]) -> InMemoryProductSearchIndex {
+        let search_index = InMemoryProductSearchIndex::new(vec![Decimal::new(50, 0)]);
+        for product in products {
+            search_index.index(product).await.unwrap();
+        }
+        search_index
+    }
+
+    fn distance(a: &str, b: &str) -> usize {
+        edit_distance(&a.chars().collect::<Vec<_>>(), &b.chars().collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn edit_distance_counts_each_edit_once() {
+        assert_eq!(distance("lamp", "lamp"), 0);
+        assert_eq!(distance("lamp", "lame"), 1);
+        assert_eq!(distance("lamp", "lamps"), 1);
+        assert_eq!(distance("lamp", "amp"), 1);
+        assert_eq!(distance("", "lamp"), 4);
+        assert_eq!(distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn edit_distance_counts_a_transposition_as_one_edit() {
+        assert_eq!(distance("lamp", "lapm"), 1);
+        assert_eq!(distance("headphones", "haedphones"), 1);
+        // Optimal string alignment edits each substring at most once
+        assert_eq!(distance("ca", "abc"), 3);
+    }
+
+    #[tokio::test]
+    async fn search_tolerates_typos_in_longer_words() {
+        let headphones = product("Wireless Headphones", "HP-100", 80, None);
+        let mouse = product("Wired Mouse", "MS-200", 20, None);
+        let search_index = search_index(&[headphones.clone(), mouse.clone()]).await;
+
+        let results = search_index.search(&query("haedphones")).await.unwrap();
+        assert_eq!(results.hits.iter().map(|hit| hit.product_id).collect::<Vec<_>>(), vec![headphones.id]);
+
+        let results = search_index.search(&query("wirelss")).await.unwrap();
+        assert_eq!(results.hits.iter().map(|hit| hit.product_id).collect::<Vec<_>>(), vec![headphones.id]);
+
+        // Words of three characters or fewer must match exactly or as a prefix
+        assert_eq!(search_index.search(&query("msu")).await.unwrap().total, 0);
+    }
+
+    #[tokio::test]
+    async fn exact_match_outranks_fuzzy_match() {
+        let lamp = product("Desk Lamp", "LP-1", 30, None);
+        let lame = product("Lame Joke Book", "BK-1", 10, None);
+        let search_index = search_index(&[lamp.clone(), lame.clone()]).await;
+
+        let results = search_index.search(&query("lamp")).await.unwrap();
+
+        assert_eq!(results.hits.iter().map(|hit| hit.product_id).collect::<Vec<_>>(), vec![lamp.id, lame.id]);
+    }
+
+    #[tokio::test]
+    async fn removed_product_is_no_longer_a_fuzzy_candidate() {
+        let headphones = product("Wireless Headphones", "HP-100", 80, None);
+        let search_index = search_index(std::slice::from_ref(&headphones)).await;
+
+        search_index.remove(&headphones.id).await.unwrap();
+
+        assert_eq!(search_index.search(&query("haedphones")).await.unwrap().total, 0);
+        assert!(search_index.index.read().await.terms_by_length.is_empty());
+    }
+
+    #[tokio::test]
+    async fn facets_ignore_their_own_filter() {
+        let lighting = CategoryId::new();
+        let garden = CategoryId::new();
+        let search_index = search_index(&[
+            product("Desk Lamp", "LP-1", 30, Some(lighting)),
+            product("Floor Lamp", "LP-2", 90, Some(lighting)),
+            product("Garden Lamp", "LP-3", 60, Some(garden)),
+        ])
+        .await;
+
+        let results = search_index.search(&ProductSearchQuery {
+            category_id: Some(lighting),
+            ..query("lamp")
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(results.total, 2);
+        let categories: Vec<(Option<CategoryId>, usize)> = results.categories.iter()
+            .map(|facet| (facet.category_id, facet.count))
+            .collect();
+        assert_eq!(categories, vec![(Some(lighting), 2), (Some(garden), 1)]);
+        let buckets: Vec<usize> = results.price_buckets.iter().map(|bucket| bucket.count).collect();
+        assert_eq!(buckets, vec![1, 1]);
+
+        let results = search_index.search(&ProductSearchQuery {
+            max_price: Some(Price::new(Decimal::new(50, 0))),
+            ..query("lamp")
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(results.total, 1);
+        let categories: Vec<(Option<CategoryId>, usize)> = results.categories.iter()
+            .map(|facet| (facet.category_id, facet.count))
+            .collect();
+        assert_eq!(categories, vec![(Some(lighting), 1)]);
+        let buckets: Vec<usize> = results.price_buckets.iter().map(|bucket| bucket.count).collect();
+        assert_eq!(buckets, vec![1, 2]);
+    }
+}
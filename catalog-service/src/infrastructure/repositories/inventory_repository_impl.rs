@@ -0,0 +1,99 @@
+use async_trait::async_trait;
+use sqlx::postgres::PgRow;
+use sqlx::{Executor, Postgres, Row};
+use crate::domain::entities::inventory::Inventory;
+use crate::domain::repositories::InventoryRepository;
+use crate::domain::value_objects::{ProductId, Quantity};
+use crate::domain::errors::DomainError;
+use crate::infrastructure::persistence::PostgresPool;
+
+pub struct PostgresInventoryRepository {
+    pool: PostgresPool,
+}
+
+impl PostgresInventoryRepository {
+    pub fn new(pool: PostgresPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn map_inventory(row: &PgRow) -> Inventory {
+    Inventory {
+        product_id: ProductId::from_uuid(row.get("product_id")),
+        quantity: Quantity::new(row.get::<i32, _>("quantity") as u32),
+        reserved_quantity: Quantity::new(row.get::<i32, _>("reserved_quantity") as u32),
+        updated_at: row.get("updated_at"),
+        version: row.get("version"),
+        read_version: row.get("version"),
+    }
+}
+
+/// Saves the inventory if it is still at the version it was read at, and
+/// moves it to the version its changes brought it to.
+pub(crate) async fn update_inventory<'e, E>(executor: E, inventory: &Inventory) -> Result<(), DomainError>
+where
+    E: Executor<'e, Database = Postgres>,
+{
+    let result = sqlx::query(
+        r#"
+        UPDATE inventory
+        SET quantity = $2, reserved_quantity = $3, updated_at = $4, version = $6
+        WHERE product_id = $1 AND version = $5
+        "#,
+    )
+    .bind(inventory.product_id.as_uuid())
+    .bind(inventory.quantity.value() as i32)
+    .bind(inventory.reserved_quantity.value() as i32)
+    .bind(inventory.updated_at)
+    .bind(inventory.read_version)
+    .bind(inventory.version)
+    .execute(executor)
+    .await
+    .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+    if result.rows_affected() == 0 {
+        return Err(DomainError::ConcurrentModification);
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl InventoryRepository for PostgresInventoryRepository {
+    async fn create(&self, inventory: &Inventory) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO inventory (product_id, quantity, reserved_quantity, updated_at, version)
+            VALUES ($1, $2, $3, $4, $5)
+            "#,
+        )
+        .bind(inventory.product_id.as_uuid())
+        .bind(inventory.quantity.value() as i32)
+        .bind(inventory.reserved_quantity.value() as i32)
+        .bind(inventory.updated_at)
+        .bind(inventory.version)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::Database(ref db) if db.is_foreign_key_violation() => DomainError::ProductNotFound,
+            // Another request stocked the product first
+            sqlx::Error::Database(ref db) if db.is_unique_violation() => DomainError::ConcurrentModification,
+            _ => DomainError::Repository(format!("PostgreSQL error: {}", e)),
+        })?;
+
+        Ok(())
+    }
+
+    async fn find_by_product_id(&self, product_id: &ProductId) -> Result<Option<Inventory>, DomainError> {
+        let row = sqlx::query("SELECT product_id, quantity, reserved_quantity, updated_at, version FROM inventory WHERE product_id = $1")
+            .bind(product_id.as_uuid())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(row.as_ref().map(map_inventory))
+    }
+
+    async fn update(&self, inventory: &Inventory) -> Result<(), DomainError> {
+        update_inventory(&self.pool, inventory).await
+    }
+}
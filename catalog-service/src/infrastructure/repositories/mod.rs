@@ -1,2 +1,11 @@
-// Repository implementations will be added here
+pub mod product_repository_impl;
+pub mod category_repository_impl;
+pub mod inventory_repository_impl;
+pub mod reservation_repository_impl;
+pub mod in_memory_product_search_index;
 
+pub use product_repository_impl::PostgresProductRepository;
+pub use category_repository_impl::PostgresCategoryRepository;
+pub use inventory_repository_impl::PostgresInventoryRepository;
+pub use reservation_repository_impl::PostgresReservationRepository;
+pub use in_memory_product_search_index::InMemoryProductSearchIndex;
@@ -0,0 +1,179 @@
+use async_trait::async_trait;
+use sqlx::postgres::PgRow;
+use sqlx::{Postgres, QueryBuilder, Row};
+use crate::domain::entities::product::Product;
+use crate::domain::repositories::{ProductQuery, ProductRepository, ProductSortValue};
+use crate::domain::value_objects::{Description, Price, ProductId, ProductName, SKU};
+use crate::domain::errors::DomainError;
+use crate::infrastructure::persistence::PostgresPool;
+
+const PRODUCT_COLUMNS: &str = "id, name, sku, description, price, category_id, is_active, created_at, updated_at";
+
+pub struct PostgresProductRepository {
+    pool: PostgresPool,
+}
+
+impl PostgresProductRepository {
+    pub fn new(pool: PostgresPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn map_product(row: &PgRow) -> Result<Product, DomainError> {
+    Ok(Product {
+        id: ProductId::from_uuid(row.get("id")),
+        name: ProductName::new(row.get("name"))?,
+        sku: SKU::new(row.get("sku"))?,
+        description: Description::new(row.get("description")),
+        price: Price::new(row.get("price")),
+        category_id: row.get("category_id"),
+        is_active: row.get("is_active"),
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+    })
+}
+
+fn map_write_error(e: sqlx::Error) -> DomainError {
+    match e {
+        sqlx::Error::Database(ref db) if db.is_unique_violation() => DomainError::SKUAlreadyExists,
+        sqlx::Error::Database(ref db) if db.is_foreign_key_violation() => DomainError::CategoryNotFound,
+        _ => DomainError::Repository(format!("PostgreSQL error: {}", e)),
+    }
+}
+
+#[async_trait]
+impl ProductRepository for PostgresProductRepository {
+    async fn create(&self, product: &Product) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO products (id, name, sku, description, price, category_id, is_active, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            "#,
+        )
+        .bind(product.id.as_uuid())
+        .bind(product.name.as_str())
+        .bind(product.sku.as_str())
+        .bind(product.description.as_str())
+        .bind(product.price.value())
+        .bind(product.category_id)
+        .bind(product.is_active)
+        .bind(product.created_at)
+        .bind(product.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(map_write_error)?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &ProductId) -> Result<Option<Product>, DomainError> {
+        let row = sqlx::query(&format!("SELECT {} FROM products WHERE id = $1", PRODUCT_COLUMNS))
+            .bind(id.as_uuid())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        row.as_ref().map(map_product).transpose()
+    }
+
+    async fn find_by_sku(&self, sku: &SKU) -> Result<Option<Product>, DomainError> {
+        let row = sqlx::query(&format!("SELECT {} FROM products WHERE sku = $1", PRODUCT_COLUMNS))
+            .bind(sku.as_str())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        row.as_ref().map(map_product).transpose()
+    }
+
+    async fn find_by_ids(&self, ids: &[ProductId]) -> Result<Vec<Product>, DomainError> {
+        let ids: Vec<uuid::Uuid> = ids.iter().map(|id| id.as_uuid()).collect();
+        let rows = sqlx::query(&format!("SELECT {} FROM products WHERE id = ANY($1)", PRODUCT_COLUMNS))
+            .bind(ids)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        rows.iter().map(map_product).collect()
+    }
+
+    async fn find_page(&self, query: &ProductQuery) -> Result<Vec<Product>, DomainError> {
+        let mut builder = QueryBuilder::<Postgres>::new(format!("SELECT {} FROM products WHERE TRUE", PRODUCT_COLUMNS));
+        match query.category_id {
+            Some(category_id) if query.include_subcategories => {
+                builder.push(" AND category_id IN (SELECT id FROM categories WHERE path LIKE (SELECT path FROM categories WHERE id = ")
+                    .push_bind(category_id.as_uuid())
+                    .push(") || '%')");
+            }
+            Some(category_id) => {
+                builder.push(" AND category_id = ").push_bind(category_id.as_uuid());
+            }
+            None => {}
+        }
+        if let Some(min_price) = query.min_price {
+            builder.push(" AND price >= ").push_bind(min_price.value());
+        }
+        if let Some(max_price) = query.max_price {
+            builder.push(" AND price <= ").push_bind(max_price.value());
+        }
+        if let Some(is_active) = query.is_active {
+            builder.push(" AND is_active = ").push_bind(is_active);
+        }
+        if let Some(created_after) = query.created_after {
+            builder.push(" AND created_at >= ").push_bind(created_after);
+        }
+
+        let column = query.sort.as_str();
+        let (comparison, direction) = if query.descending { ("<", "DESC") } else { (">", "ASC") };
+        if let Some(cursor) = &query.after {
+            builder.push(format!(" AND ({}, id) {} (", column, comparison));
+            match &cursor.value {
+                ProductSortValue::Name(name) => builder.push_bind(name.clone()),
+                ProductSortValue::Price(price) => builder.push_bind(*price),
+                ProductSortValue::CreatedAt(created_at) => builder.push_bind(*created_at),
+            };
+            builder.push(", ").push_bind(cursor.id.as_uuid()).push(")");
+        }
+        builder.push(format!(" ORDER BY {} {}, id {} LIMIT ", column, direction, direction));
+        builder.push_bind(query.limit);
+
+        let rows = builder.build()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        rows.iter().map(map_product).collect()
+    }
+
+    async fn update(&self, product: &Product) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            UPDATE products
+            SET name = $2, description = $3, price = $4, category_id = $5, is_active = $6, updated_at = $7
+            WHERE id = $1
+            "#,
+        )
+        .bind(product.id.as_uuid())
+        .bind(product.name.as_str())
+        .bind(product.description.as_str())
+        .bind(product.price.value())
+        .bind(product.category_id)
+        .bind(product.is_active)
+        .bind(product.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(map_write_error)?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: &ProductId) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM products WHERE id = $1")
+            .bind(id.as_uuid())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+}
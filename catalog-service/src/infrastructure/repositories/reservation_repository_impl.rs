@@ -0,0 +1,125 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::postgres::PgRow;
+use sqlx::Row;
+use crate::domain::entities::inventory::Inventory;
+use crate::domain::entities::reservation::{Reservation, ReservationStatus};
+use crate::domain::repositories::ReservationRepository;
+use crate::domain::value_objects::{ProductId, Quantity, ReservationId};
+use crate::domain::errors::DomainError;
+use crate::infrastructure::persistence::PostgresPool;
+use crate::infrastructure::repositories::inventory_repository_impl::update_inventory;
+
+const RESERVATION_COLUMNS: &str = "id, product_id, quantity, order_reference, status, expires_at, created_at, updated_at";
+
+pub struct PostgresReservationRepository {
+    pool: PostgresPool,
+}
+
+impl PostgresReservationRepository {
+    pub fn new(pool: PostgresPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn map_reservation(row: &PgRow) -> Result<Reservation, DomainError> {
+    Ok(Reservation {
+        id: ReservationId::from_uuid(row.get("id")),
+        product_id: ProductId::from_uuid(row.get("product_id")),
+        quantity: Quantity::new(row.get::<i32, _>("quantity") as u32),
+        order_reference: row.get("order_reference"),
+        status: ReservationStatus::parse(row.get("status"))?,
+        expires_at: row.get("expires_at"),
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+    })
+}
+
+#[async_trait]
+impl ReservationRepository for PostgresReservationRepository {
+    async fn create(&self, reservation: &Reservation, inventory: &Inventory) -> Result<(), DomainError> {
+        let mut tx = self.pool.begin().await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        update_inventory(&mut *tx, inventory).await?;
+        sqlx::query(
+            r#"
+            INSERT INTO stock_reservations (id, product_id, quantity, order_reference, status, expires_at, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#,
+        )
+        .bind(reservation.id.as_uuid())
+        .bind(reservation.product_id.as_uuid())
+        .bind(reservation.quantity.value() as i32)
+        .bind(&reservation.order_reference)
+        .bind(reservation.status.as_str())
+        .bind(reservation.expires_at)
+        .bind(reservation.created_at)
+        .bind(reservation.updated_at)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::Database(ref db) if db.is_foreign_key_violation() => DomainError::ProductNotFound,
+            _ => DomainError::Repository(format!("PostgreSQL error: {}", e)),
+        })?;
+
+        tx.commit().await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &ReservationId) -> Result<Option<Reservation>, DomainError> {
+        let row = sqlx::query(&format!("SELECT {} FROM stock_reservations WHERE id = $1", RESERVATION_COLUMNS))
+            .bind(id.as_uuid())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        row.as_ref().map(map_reservation).transpose()
+    }
+
+    async fn find_expired(&self, now: DateTime<Utc>, limit: i64) -> Result<Vec<Reservation>, DomainError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM stock_reservations WHERE status = 'active' AND expires_at <= $1 ORDER BY expires_at, id LIMIT $2",
+            RESERVATION_COLUMNS,
+        ))
+        .bind(now)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        rows.iter().map(map_reservation).collect()
+    }
+
+    async fn update(&self, reservation: &Reservation, inventory: &Inventory) -> Result<(), DomainError> {
+        let mut tx = self.pool.begin().await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        // Only an active reservation can change status, so of two requests
+        // closing the same reservation only the first one gets through.
+        let result = sqlx::query(
+            r#"
+            UPDATE stock_reservations
+            SET status = $2, updated_at = $3
+            WHERE id = $1 AND status = 'active'
+            "#,
+        )
+        .bind(reservation.id.as_uuid())
+        .bind(reservation.status.as_str())
+        .bind(reservation.updated_at)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+        if result.rows_affected() == 0 {
+            return Err(DomainError::ConcurrentModification);
+        }
+        update_inventory(&mut *tx, inventory).await?;
+
+        tx.commit().await
+            .map_err(|e| DomainError::Repository(format!("PostgreSQL error: {}", e)))?;
+
+        Ok(())
+    }
+}
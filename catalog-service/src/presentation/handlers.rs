@@ -1,19 +1,263 @@
-use axum::{extract::Path, http::StatusCode, Json};
-use crate::application::dto::{CreateProductDto, ProductDto};
+use std::sync::Arc;
+use axum::{extract::{Path, Query, State}, http::StatusCode, Json};
+use crate::application::dto::{
+    BreadcrumbDto, CategoryDto, CategoryTreeDto, CreateCategoryDto, CreateProductDto, CreateReservationDto, InventoryDto,
+    MoveCategoryDto, ProductDto, ProductListDto, ProductListQueryDto, ProductSearchQueryDto, ProductSearchResultsDto,
+    ReservationDto, SetStockDto, UpdateCategoryDto, UpdateProductDto,
+};
+use crate::application::errors::ApplicationError;
+use crate::domain::errors::DomainError;
+use crate::di::AppContext;
+
+fn error_response(error: ApplicationError) -> (StatusCode, String) {
+    let status = match &error {
+        ApplicationError::Validation(_) => StatusCode::BAD_REQUEST,
+        ApplicationError::Domain(domain_error) => match domain_error {
+            DomainError::InvalidProductName(_)
+            | DomainError::InvalidSKU(_)
+            | DomainError::InvalidCategoryName(_)
+            | DomainError::ValidationError(_) => StatusCode::BAD_REQUEST,
+            DomainError::ProductNotFound
+            | DomainError::CategoryNotFound
+            | DomainError::ReservationNotFound => StatusCode::NOT_FOUND,
+            DomainError::ProductAlreadyExists
+            | DomainError::SKUAlreadyExists
+            | DomainError::CategoryHasChildren
+            | DomainError::CategoryCycle
+            | DomainError::InsufficientStock
+            | DomainError::ReservationClosed(_)
+            | DomainError::ConcurrentModification => StatusCode::CONFLICT,
+            DomainError::Repository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        },
+    };
+
+    if status == StatusCode::INTERNAL_SERVER_ERROR {
+        tracing::error!("{}", error);
+        return (status, "Internal server error".to_string());
+    }
+    (status, error.to_string())
+}
 
 pub async fn health_check() -> &'static str {
     "OK"
 }
 
 pub async fn create_product(
+    State(context): State<Arc<AppContext>>,
     Json(dto): Json<CreateProductDto>,
 ) -> Result<(StatusCode, Json<ProductDto>), (StatusCode, String)> {
-    Err((StatusCode::NOT_IMPLEMENTED, "Use case not injected".to_string()))
+    context.create_product_use_case.execute(dto).await
+        .map(|product| (StatusCode::CREATED, Json(product)))
+        .map_err(error_response)
+}
+
+pub async fn list_products(
+    State(context): State<Arc<AppContext>>,
+    Query(dto): Query<ProductListQueryDto>,
+) -> Result<Json<ProductListDto>, (StatusCode, String)> {
+    context.list_products_use_case.execute(dto).await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn search_products(
+    State(context): State<Arc<AppContext>>,
+    Query(dto): Query<ProductSearchQueryDto>,
+) -> Result<Json<ProductSearchResultsDto>, (StatusCode, String)> {
+    context.search_products_use_case.execute(dto).await
+        .map(Json)
+        .map_err(error_response)
 }
 
 pub async fn get_product(
+    State(context): State<Arc<AppContext>>,
     Path(id): Path<String>,
 ) -> Result<Json<ProductDto>, (StatusCode, String)> {
-    Err((StatusCode::NOT_IMPLEMENTED, "Use case not injected".to_string()))
+    context.get_product_use_case.execute(&id).await
+        .map(Json)
+        .map_err(error_response)
 }
 
+pub async fn update_product(
+    State(context): State<Arc<AppContext>>,
+    Path(id): Path<String>,
+    Json(dto): Json<UpdateProductDto>,
+) -> Result<Json<ProductDto>, (StatusCode, String)> {
+    context.update_product_use_case.execute(&id, dto).await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn deactivate_product(
+    State(context): State<Arc<AppContext>>,
+    Path(id): Path<String>,
+) -> Result<Json<ProductDto>, (StatusCode, String)> {
+    context.deactivate_product_use_case.execute(&id).await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn delete_product(
+    State(context): State<Arc<AppContext>>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    context.delete_product_use_case.execute(&id).await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(error_response)
+}
+
+pub async fn get_inventory(
+    State(context): State<Arc<AppContext>>,
+    Path(id): Path<String>,
+) -> Result<Json<InventoryDto>, (StatusCode, String)> {
+    context.get_inventory_use_case.execute(&id).await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn set_stock(
+    State(context): State<Arc<AppContext>>,
+    Path(id): Path<String>,
+    Json(dto): Json<SetStockDto>,
+) -> Result<Json<InventoryDto>, (StatusCode, String)> {
+    context.set_stock_use_case.execute(&id, dto).await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn create_category(
+    State(context): State<Arc<AppContext>>,
+    Json(dto): Json<CreateCategoryDto>,
+) -> Result<(StatusCode, Json<CategoryDto>), (StatusCode, String)> {
+    context.create_category_use_case.execute(dto).await
+        .map(|category| (StatusCode::CREATED, Json(category)))
+        .map_err(error_response)
+}
+
+pub async fn list_categories(
+    State(context): State<Arc<AppContext>>,
+) -> Result<Json<Vec<CategoryDto>>, (StatusCode, String)> {
+    context.list_categories_use_case.execute().await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn get_category(
+    State(context): State<Arc<AppContext>>,
+    Path(id): Path<String>,
+) -> Result<Json<CategoryDto>, (StatusCode, String)> {
+    context.get_category_use_case.execute(&id).await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn update_category(
+    State(context): State<Arc<AppContext>>,
+    Path(id): Path<String>,
+    Json(dto): Json<UpdateCategoryDto>,
+) -> Result<Json<CategoryDto>, (StatusCode, String)> {
+    context.update_category_use_case.execute(&id, dto).await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn deactivate_category(
+    State(context): State<Arc<AppContext>>,
+    Path(id): Path<String>,
+) -> Result<Json<CategoryDto>, (StatusCode, String)> {
+    context.deactivate_category_use_case.execute(&id).await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn delete_category(
+    State(context): State<Arc<AppContext>>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    context.delete_category_use_case.execute(&id).await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(error_response)
+}
+
+pub async fn get_category_tree(
+    State(context): State<Arc<AppContext>>,
+) -> Result<Json<Vec<CategoryTreeDto>>, (StatusCode, String)> {
+    context.get_category_tree_use_case.execute().await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn get_category_ancestors(
+    State(context): State<Arc<AppContext>>,
+    Path(id): Path<String>,
+) -> Result<Json<Vec<CategoryDto>>, (StatusCode, String)> {
+    context.get_category_ancestors_use_case.execute(&id).await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn get_category_breadcrumbs(
+    State(context): State<Arc<AppContext>>,
+    Path(id): Path<String>,
+) -> Result<Json<Vec<BreadcrumbDto>>, (StatusCode, String)> {
+    context.get_category_breadcrumbs_use_case.execute(&id).await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn move_category(
+    State(context): State<Arc<AppContext>>,
+    Path(id): Path<String>,
+    Json(dto): Json<MoveCategoryDto>,
+) -> Result<Json<CategoryDto>, (StatusCode, String)> {
+    context.move_category_use_case.execute(&id, dto).await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn list_category_products(
+    State(context): State<Arc<AppContext>>,
+    Path(id): Path<String>,
+    Query(dto): Query<ProductListQueryDto>,
+) -> Result<Json<ProductListDto>, (StatusCode, String)> {
+    context.list_category_products_use_case.execute(&id, dto).await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn reserve_stock(
+    State(context): State<Arc<AppContext>>,
+    Path(id): Path<String>,
+    Json(dto): Json<CreateReservationDto>,
+) -> Result<(StatusCode, Json<ReservationDto>), (StatusCode, String)> {
+    context.reserve_stock_use_case.execute(&id, dto).await
+        .map(|reservation| (StatusCode::CREATED, Json(reservation)))
+        .map_err(error_response)
+}
+
+pub async fn get_reservation(
+    State(context): State<Arc<AppContext>>,
+    Path(id): Path<String>,
+) -> Result<Json<ReservationDto>, (StatusCode, String)> {
+    context.get_reservation_use_case.execute(&id).await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn commit_reservation(
+    State(context): State<Arc<AppContext>>,
+    Path(id): Path<String>,
+) -> Result<Json<ReservationDto>, (StatusCode, String)> {
+    context.commit_reservation_use_case.execute(&id).await
+        .map(Json)
+        .map_err(error_response)
+}
+
+pub async fn release_reservation(
+    State(context): State<Arc<AppContext>>,
+    Path(id): Path<String>,
+) -> Result<Json<ReservationDto>, (StatusCode, String)> {
+    context.release_reservation_use_case.execute(&id).await
+        .map(Json)
+        .map_err(error_response)
+}
@@ -1,6 +1,7 @@
 pub mod server;
 pub mod handlers;
 pub mod routes;
+pub mod reservation_sweeper;
 
 pub use server::create_server;
 
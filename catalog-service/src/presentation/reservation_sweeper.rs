@@ -0,0 +1,23 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::MissedTickBehavior;
+use crate::di::AppContext;
+
+/// Releases expired stock reservations every `interval` for as long as the
+/// service runs. Every instance sweeps; an instance that loses the race for a
+/// reservation simply finds it already expired.
+pub fn spawn_reservation_sweeper(context: Arc<AppContext>, interval: Duration) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            match context.expire_reservations_use_case.execute().await {
+                Ok(0) => {}
+                Ok(expired) => tracing::info!("Released {} expired stock reservations", expired),
+                Err(e) => tracing::error!("Failed to release expired stock reservations: {}", e),
+            }
+        }
+    });
+}
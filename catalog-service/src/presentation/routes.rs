@@ -1,12 +1,62 @@
-use axum::{Router, routing::get, routing::post};
+use std::sync::Arc;
+use auth_middleware::{AuthLayer, Authenticator, RequirePermission};
+use axum::{Router, routing::{get, post, put}};
 use crate::presentation::handlers;
+use crate::di::AppContext;
+
+pub async fn create_router(
+    context: Arc<AppContext>,
+    authenticator: Arc<Authenticator>,
+) -> Result<Router, Box<dyn std::error::Error>> {
+    let write = || RequirePermission::new("catalog", "write");
 
-pub async fn create_router() -> Result<Router, Box<dyn std::error::Error>> {
     let router = Router::new()
         .route("/health", get(handlers::health_check))
-        .route("/products", post(handlers::create_product))
-        .route("/products/:id", get(handlers::get_product));
+        .route(
+            "/products",
+            get(handlers::list_products).merge(post(handlers::create_product).route_layer(write())),
+        )
+        .route("/products/search", get(handlers::search_products))
+        .route(
+            "/products/:id",
+            get(handlers::get_product)
+                .merge(put(handlers::update_product).delete(handlers::delete_product).route_layer(write())),
+        )
+        .route(
+            "/products/:id/deactivate",
+            post(handlers::deactivate_product).route_layer(write()),
+        )
+        .route(
+            "/products/:id/inventory",
+            get(handlers::get_inventory).merge(put(handlers::set_stock).route_layer(write())),
+        )
+        .route(
+            "/products/:id/reservations",
+            post(handlers::reserve_stock).route_layer(write()),
+        )
+        .route("/reservations/:id", get(handlers::get_reservation).route_layer(write()))
+        .route("/reservations/:id/commit", post(handlers::commit_reservation).route_layer(write()))
+        .route("/reservations/:id/release", post(handlers::release_reservation).route_layer(write()))
+        .route(
+            "/categories",
+            get(handlers::list_categories).merge(post(handlers::create_category).route_layer(write())),
+        )
+        .route("/categories/tree", get(handlers::get_category_tree))
+        .route(
+            "/categories/:id",
+            get(handlers::get_category)
+                .merge(put(handlers::update_category).delete(handlers::delete_category).route_layer(write())),
+        )
+        .route(
+            "/categories/:id/deactivate",
+            post(handlers::deactivate_category).route_layer(write()),
+        )
+        .route("/categories/:id/ancestors", get(handlers::get_category_ancestors))
+        .route("/categories/:id/breadcrumbs", get(handlers::get_category_breadcrumbs))
+        .route("/categories/:id/move", post(handlers::move_category).route_layer(write()))
+        .route("/categories/:id/products", get(handlers::list_category_products))
+        .layer(AuthLayer::new(authenticator))
+        .with_state(context);
 
     Ok(router)
 }
-
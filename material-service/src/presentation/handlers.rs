@@ -6,13 +6,13 @@ pub async fn health_check() -> &'static str {
 }
 
 pub async fn create_material(
-    Json(dto): Json<CreateMaterialDto>,
+    Json(_dto): Json<CreateMaterialDto>,
 ) -> Result<(StatusCode, Json<MaterialDto>), (StatusCode, String)> {
     Err((StatusCode::NOT_IMPLEMENTED, "Use case not injected".to_string()))
 }
 
 pub async fn get_material(
-    Path(id): Path<String>,
+    Path(_id): Path<String>,
 ) -> Result<Json<MaterialDto>, (StatusCode, String)> {
     Err((StatusCode::NOT_IMPLEMENTED, "Use case not injected".to_string()))
 }
@@ -1,12 +1,20 @@
+use std::sync::Arc;
+use auth_middleware::{AuthLayer, Authenticator, RequirePermission};
 use axum::{Router, routing::get, routing::post};
 use crate::presentation::handlers;
 
-pub async fn create_router() -> Result<Router, Box<dyn std::error::Error>> {
+pub async fn create_router(authenticator: Arc<Authenticator>) -> Result<Router, Box<dyn std::error::Error>> {
     let router = Router::new()
         .route("/health", get(handlers::health_check))
-        .route("/materials", post(handlers::create_material))
-        .route("/materials/:id", get(handlers::get_material));
+        .route(
+            "/materials",
+            post(handlers::create_material).route_layer(RequirePermission::new("material", "write")),
+        )
+        .route(
+            "/materials/:id",
+            get(handlers::get_material).route_layer(RequirePermission::new("material", "read")),
+        )
+        .layer(AuthLayer::new(authenticator));
 
     Ok(router)
 }
-
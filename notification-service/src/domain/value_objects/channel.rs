@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Channel {
     Email,
+    #[allow(clippy::upper_case_acronyms)]
     SMS,
     Push,
     InApp,
@@ -1,11 +1,14 @@
+// Scaffolding the handlers do not call into yet
+#[allow(dead_code, unused_imports)]
 mod application;
+#[allow(dead_code, unused_imports)]
 mod domain;
+#[allow(dead_code, unused_imports)]
 mod infrastructure;
 mod presentation;
 
 use infrastructure::config::Config;
-use presentation::server::create_server;
-use tracing_subscriber;
+use presentation::create_server;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -14,8 +17,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .init();
 
     let config = Config::load()?;
-    let server = create_server(config).await?;
-    server.await?;
+    create_server(config).await?;
 
     Ok(())
 }
@@ -1,4 +1,5 @@
-use axum::{extract::Path, http::StatusCode, Json};
+use auth_middleware::{AuthError, AuthenticatedPrincipal};
+use axum::{extract::Path, http::StatusCode, response::{IntoResponse, Response}, Json};
 use crate::application::dto::{CreateNotificationDto, NotificationDto};
 
 pub async fn health_check() -> &'static str {
@@ -6,14 +7,20 @@ pub async fn health_check() -> &'static str {
 }
 
 pub async fn send_notification(
-    Json(dto): Json<CreateNotificationDto>,
+    Json(_dto): Json<CreateNotificationDto>,
 ) -> Result<(StatusCode, Json<NotificationDto>), (StatusCode, String)> {
     Err((StatusCode::NOT_IMPLEMENTED, "Use case not injected".to_string()))
 }
 
 pub async fn get_notifications(
+    principal: AuthenticatedPrincipal,
     Path(user_id): Path<String>,
-) -> Result<Json<Vec<NotificationDto>>, (StatusCode, String)> {
-    Err((StatusCode::NOT_IMPLEMENTED, "Use case not injected".to_string()))
+) -> Result<Json<Vec<NotificationDto>>, Response> {
+    if principal.user_id != user_id {
+        principal.require_permission("notification", "read")
+            .map_err(AuthError::into_response)?;
+    }
+
+    Err((StatusCode::NOT_IMPLEMENTED, "Use case not injected".to_string()).into_response())
 }
 
@@ -1,12 +1,18 @@
+use std::sync::Arc;
+use auth_middleware::{AuthLayer, Authenticator, RequirePermission};
 use axum::{Router, routing::get, routing::post};
 use crate::presentation::handlers;
 
-pub async fn create_router() -> Result<Router, Box<dyn std::error::Error>> {
+pub async fn create_router(authenticator: Arc<Authenticator>) -> Result<Router, Box<dyn std::error::Error>> {
     let router = Router::new()
         .route("/health", get(handlers::health_check))
-        .route("/notifications", post(handlers::send_notification))
-        .route("/notifications/:user_id", get(handlers::get_notifications));
+        .route(
+            "/notifications",
+            post(handlers::send_notification).route_layer(RequirePermission::new("notification", "write")),
+        )
+        // Users may read their own notifications; the handler checks ownership
+        .route("/notifications/:user_id", get(handlers::get_notifications))
+        .layer(AuthLayer::new(authenticator));
 
     Ok(router)
 }
-
@@ -1,27 +1,29 @@
-use axum::{Router, Server};
+use std::sync::Arc;
+use auth_middleware::Authenticator;
+use tokio::net::TcpListener;
 use tower::ServiceBuilder;
 use tower_http::cors::CorsLayer;
 use tower_http::trace::TraceLayer;
 use crate::infrastructure::config::Config;
 use crate::presentation::routes::create_router;
 
-pub async fn create_server(config: Config) -> Result<Server<axum::extract::connect_info::IntoMakeServiceWithConnectInfo<Router, std::net::SocketAddr>, Router>, Box<dyn std::error::Error>> {
-    let app = create_router().await?;
+pub async fn create_server(config: Config) -> Result<(), Box<dyn std::error::Error>> {
+    let authenticator = Arc::new(Authenticator::from_config(&config.auth)?);
+    authenticator.check().await?;
+    let app = create_router(authenticator).await?;
 
     let app = app
-        .layer(     
+        .layer(
             ServiceBuilder::new()
                 .layer(TraceLayer::new_for_http())
                 .layer(CorsLayer::permissive())
         );
 
-    let addr = format!("{}:{}", config.server.host, config.server.port)
-        .parse()?;
-
-    let server = Server::bind(&addr).serve(app.into_make_service());
+    let addr = format!("{}:{}", config.server.host, config.server.port);
+    let listener = TcpListener::bind(&addr).await?;
 
     tracing::info!("Notification service running on {}", addr);
 
-    Ok(server)
+    axum::serve(listener, app).await?;
+    Ok(())
 }
-
@@ -1,3 +1,4 @@
+use auth_middleware::AuthConfig;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -7,6 +8,7 @@ pub struct Config {
     pub postgresql: Option<PostgreSQLConfig>,
     pub cassandra: Option<CassandraConfig>,
     pub redis: Option<RedisConfig>,
+    pub auth: AuthConfig,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -97,6 +99,7 @@ impl Config {
                     .ok()
                     .and_then(|s| s.parse().ok()),
             }),
+            auth: AuthConfig::load()?,
         })
     }
 }
@@ -1,25 +1,36 @@
-use axum::{Router, routing::get};
+use auth_middleware::{AuthLayer, AuthenticatedPrincipal, Authenticator};
+use axum::{Extension, Router, routing::get};
 use async_graphql_axum::{GraphQLRequest, GraphQLResponse};
 use crate::presentation::graphql::AppSchema;
 use crate::di::AppContext;
 use std::sync::Arc;
 
-pub async fn create_router(context: Arc<AppContext>) -> Result<Router, Box<dyn std::error::Error>> {
+pub async fn create_router(
+    context: Arc<AppContext>,
+    authenticator: Arc<Authenticator>,
+) -> Result<Router, Box<dyn std::error::Error>> {
     let schema = crate::presentation::graphql::create_schema((*context).clone());
     
     let router = Router::new()
         .route("/graphql", axum::routing::post(graphql_handler))
         .route("/graphiql", get(graphql_playground))
-        .with_state(schema);
+        .with_state(schema)
+        .layer(AuthLayer::new(authenticator));
 
     Ok(router)
 }
 
+/// Resolvers reach the caller through `ctx.data_opt::<AuthenticatedPrincipal>()`.
 async fn graphql_handler(
     axum::extract::State(schema): axum::extract::State<AppSchema>,
+    principal: Option<Extension<AuthenticatedPrincipal>>,
     req: GraphQLRequest,
 ) -> GraphQLResponse {
-    schema.execute(req.into_inner()).await.into()
+    let mut request = req.into_inner();
+    if let Some(Extension(principal)) = principal {
+        request = request.data(principal);
+    }
+    schema.execute(request).await.into()
 }
 
 async fn graphql_playground() -> impl axum::response::IntoResponse {
@@ -1,4 +1,4 @@
-use axum::Router;
+use auth_middleware::Authenticator;
 use tokio::net::TcpListener;
 use tower::ServiceBuilder;
 use tower_http::cors::CorsLayer;
@@ -10,7 +10,9 @@ use std::sync::Arc;
 
 pub async fn create_server(config: Config) -> Result<(), Box<dyn std::error::Error>> {
     let context = Arc::new(AppContext::new(config.clone()).await?);
-    let app = create_router(context).await?;
+    let authenticator = Arc::new(Authenticator::from_config(&config.auth)?);
+    authenticator.check().await?;
+    let app = create_router(context, authenticator).await?;
 
     let app = app
         .layer(